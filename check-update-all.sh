@@ -13,12 +13,12 @@ cargo nextest run -p rustemo-compiler
 cargo install --path rustemo-compiler --debug
 
 # README Examples
-rcomp docs/src/readme_example/src/testlr/calclr.rustemo
-rcomp --parser-algo glr docs/src/readme_example/src/testglr/calc.rustemo
+rcomp --builder-loc-info=false docs/src/readme_example/src/testlr/calclr.rustemo
+rcomp --builder-loc-info=false --parser-algo glr docs/src/readme_example/src/testglr/calc.rustemo
 
 cd docs/src/tutorials/calculator/
 for i in {1..5}; do
-    rcomp calculator$i/src/calculator.rustemo;
+    rcomp --builder-loc-info=false calculator$i/src/calculator.rustemo;
 done
 
 cd -
@@ -4,7 +4,10 @@ fn main() {
     let settings = rustemo_compiler::Settings::new()
         .parser_algo(rustemo_compiler::ParserAlgo::GLR)
         .builder_type(rustemo_compiler::BuilderType::Default)
-        .fancy_regex(true);
+        .actions_in_source_tree()
+        .builder_loc_info(false)
+        .fancy_regex(true)
+        .visitors(true);
     if let Err(e) = settings.process_dir() {
         eprintln!("{e}");
         exit(1);
@@ -53,7 +53,10 @@ pub fn external_decl1_c1(
     external_decl1.push(external_decl);
     external_decl1
 }
-pub fn external_decl1_external_decl(_ctx: &Ctx, external_decl: external_decl) -> external_decl1 {
+pub fn external_decl1_external_decl(
+    _ctx: &Ctx,
+    external_decl: external_decl,
+) -> external_decl1 {
     vec![external_decl]
 }
 #[derive(Debug, Clone)]
@@ -74,7 +77,10 @@ pub enum external_decl {
     Declaration(ExternalDeclaration),
     LineDirective(ExternalLineDirective),
 }
-pub fn external_decl_function(_ctx: &Ctx, function: function_definition) -> external_decl {
+pub fn external_decl_function(
+    _ctx: &Ctx,
+    function: function_definition,
+) -> external_decl {
     external_decl::Function(ExternalFunction { function })
 }
 pub fn external_decl_declaration(_ctx: &Ctx, declaration: decl) -> external_decl {
@@ -83,7 +89,9 @@ pub fn external_decl_declaration(_ctx: &Ctx, declaration: decl) -> external_decl
     })
 }
 pub fn external_decl_line_directive(_ctx: &Ctx, ld: line_directive) -> external_decl {
-    external_decl::LineDirective(ExternalLineDirective { ld: Box::new(ld) })
+    external_decl::LineDirective(ExternalLineDirective {
+        ld: Box::new(ld),
+    })
 }
 #[derive(Debug, Clone)]
 pub struct line_directive {
@@ -526,7 +534,11 @@ pub fn init_declarator_decl(_ctx: &Ctx, decl: declarator) -> init_declarator {
         decl: Box::new(decl),
     })
 }
-pub fn init_declarator_init(_ctx: &Ctx, decl: declarator, init: initializer) -> init_declarator {
+pub fn init_declarator_init(
+    _ctx: &Ctx,
+    decl: declarator,
+    init: initializer,
+) -> init_declarator {
     init_declarator::Init(InitDeclaratorInit {
         decl: Box::new(decl),
         init,
@@ -552,7 +564,10 @@ pub fn struct_decl_struct_decl(
         struct_declarator1,
     })
 }
-pub fn struct_decl_line_directive(_ctx: &Ctx, line_directive: line_directive) -> struct_decl {
+pub fn struct_decl_line_directive(
+    _ctx: &Ctx,
+    line_directive: line_directive,
+) -> struct_decl {
     struct_decl::line_directive(line_directive)
 }
 pub type struct_declarator1 = Vec<struct_declarator>;
@@ -597,7 +612,10 @@ pub fn spec_qualifier_list_type_recursive(
         spec_qualifier_list: Box::new(spec_qualifier_list),
     })
 }
-pub fn spec_qualifier_list_type_base(_ctx: &Ctx, type_spec: type_spec) -> spec_qualifier_list {
+pub fn spec_qualifier_list_type_base(
+    _ctx: &Ctx,
+    type_spec: type_spec,
+) -> spec_qualifier_list {
     spec_qualifier_list::TypeBase(Box::new(type_spec))
 }
 pub fn spec_qualifier_list_qual_recursive(
@@ -640,7 +658,10 @@ pub fn struct_declarator_bit_field(
         const_exp: Box::new(const_exp),
     })
 }
-pub fn struct_declarator_anon_bit_field(_ctx: &Ctx, const_exp: const_exp) -> struct_declarator {
+pub fn struct_declarator_anon_bit_field(
+    _ctx: &Ctx,
+    const_exp: const_exp,
+) -> struct_declarator {
     struct_declarator::AnonBitField(Box::new(const_exp))
 }
 #[derive(Debug, Clone)]
@@ -676,7 +697,11 @@ pub fn enum_spec_enum(
         comma_opt,
     })
 }
-pub fn enum_spec_anon_enum(_ctx: &Ctx, fields: enumerator1, comma_opt: commaOpt) -> enum_spec {
+pub fn enum_spec_anon_enum(
+    _ctx: &Ctx,
+    fields: enumerator1,
+    comma_opt: commaOpt,
+) -> enum_spec {
     enum_spec::AnonEnum(EnumSpecAnonEnum {
         fields: Box::new(fields),
         comma_opt: Box::new(comma_opt),
@@ -803,7 +828,10 @@ pub fn direct_declarator_array(
         const_exp,
     })
 }
-pub fn direct_declarator_array_unbound(_ctx: &Ctx, array: direct_declarator) -> direct_declarator {
+pub fn direct_declarator_array_unbound(
+    _ctx: &Ctx,
+    array: direct_declarator,
+) -> direct_declarator {
     direct_declarator::ArrayUnbound(DirectDeclaratorArrayUnbound {
         array: Box::new(array),
     })
@@ -828,7 +856,10 @@ pub fn direct_declarator_func_id(
         id1,
     })
 }
-pub fn direct_declarator_func_empty(_ctx: &Ctx, fnc_decl: direct_declarator) -> direct_declarator {
+pub fn direct_declarator_func_empty(
+    _ctx: &Ctx,
+    fnc_decl: direct_declarator,
+) -> direct_declarator {
     direct_declarator::FuncEmpty(DirectDeclaratorFuncEmpty {
         fnc_decl: Box::new(fnc_decl),
     })
@@ -1058,7 +1089,10 @@ pub fn type_name_full(
         abstract_declarator,
     })
 }
-pub fn type_name_base(_ctx: &Ctx, spec_qualifier_list: spec_qualifier_list) -> type_name {
+pub fn type_name_base(
+    _ctx: &Ctx,
+    spec_qualifier_list: spec_qualifier_list,
+) -> type_name {
     type_name::Base(Box::new(spec_qualifier_list))
 }
 #[derive(Debug, Clone)]
@@ -1141,7 +1175,9 @@ pub fn direct_abstract_declarator_array_unbound(
 ) -> direct_abstract_declarator {
     direct_abstract_declarator::ArrayUnbound(Box::new(direct_abstract_declarator))
 }
-pub fn direct_abstract_declarator_array_unbound_base(_ctx: &Ctx) -> direct_abstract_declarator {
+pub fn direct_abstract_declarator_array_unbound_base(
+    _ctx: &Ctx,
+) -> direct_abstract_declarator {
     direct_abstract_declarator::ArrayUnboundBase
 }
 pub fn direct_abstract_declarator_func(
@@ -1166,7 +1202,9 @@ pub fn direct_abstract_declarator_func_empty(
 ) -> direct_abstract_declarator {
     direct_abstract_declarator::FuncEmpty(Box::new(direct_abstract_declarator))
 }
-pub fn direct_abstract_declarator_func_empty_base(_ctx: &Ctx) -> direct_abstract_declarator {
+pub fn direct_abstract_declarator_func_empty_base(
+    _ctx: &Ctx,
+) -> direct_abstract_declarator {
     direct_abstract_declarator::FuncEmptyBase
 }
 pub type typedef_name = Box<id>;
@@ -1258,7 +1296,10 @@ pub fn block_item_stat(_ctx: &Ctx, stat: stat) -> block_item {
     block_item::stat(stat)
 }
 pub type compound_stat = block_item0;
-pub fn compound_stat_compound_stat(_ctx: &Ctx, block_item0: block_item0) -> compound_stat {
+pub fn compound_stat_compound_stat(
+    _ctx: &Ctx,
+    block_item0: block_item0,
+) -> compound_stat {
     block_item0
 }
 pub type block_item1 = Vec<block_item>;
@@ -1308,7 +1349,12 @@ pub fn selection_stat_if(_ctx: &Ctx, exp: exp, stat: stat) -> selection_stat {
         stat: Box::new(stat),
     })
 }
-pub fn selection_stat_if_else(_ctx: &Ctx, exp: exp, stat_5: stat, stat_7: stat) -> selection_stat {
+pub fn selection_stat_if_else(
+    _ctx: &Ctx,
+    exp: exp,
+    stat_5: stat,
+    stat_7: stat,
+) -> selection_stat {
     selection_stat::IfElse(SelectionIfElse {
         exp: Box::new(exp),
         stat_5: Box::new(stat_5),
@@ -1445,7 +1491,10 @@ pub enum assignment_exp {
     Base(Box<conditional_exp>),
     Assign(AssignmentExpAssign),
 }
-pub fn assignment_exp_base(_ctx: &Ctx, conditional_exp: conditional_exp) -> assignment_exp {
+pub fn assignment_exp_base(
+    _ctx: &Ctx,
+    conditional_exp: conditional_exp,
+) -> assignment_exp {
     assignment_exp::Base(Box::new(conditional_exp))
 }
 pub fn assignment_exp_assign(
@@ -1518,7 +1567,10 @@ pub enum conditional_exp {
     Base(logical_or_exp),
     Conditional(ConditionalExpConditional),
 }
-pub fn conditional_exp_base(_ctx: &Ctx, logical_or_exp: logical_or_exp) -> conditional_exp {
+pub fn conditional_exp_base(
+    _ctx: &Ctx,
+    logical_or_exp: logical_or_exp,
+) -> conditional_exp {
     conditional_exp::Base(logical_or_exp)
 }
 pub fn conditional_exp_conditional(
@@ -1534,7 +1586,10 @@ pub fn conditional_exp_conditional(
     })
 }
 pub type const_exp = conditional_exp;
-pub fn const_exp_conditional_exp(_ctx: &Ctx, conditional_exp: conditional_exp) -> const_exp {
+pub fn const_exp_conditional_exp(
+    _ctx: &Ctx,
+    conditional_exp: conditional_exp,
+) -> const_exp {
     conditional_exp
 }
 #[derive(Debug, Clone)]
@@ -1547,7 +1602,10 @@ pub enum logical_or_exp {
     Base(logical_and_exp),
     Or(LogicalOrExpOr),
 }
-pub fn logical_or_exp_base(_ctx: &Ctx, logical_and_exp: logical_and_exp) -> logical_or_exp {
+pub fn logical_or_exp_base(
+    _ctx: &Ctx,
+    logical_and_exp: logical_and_exp,
+) -> logical_or_exp {
     logical_or_exp::Base(logical_and_exp)
 }
 pub fn logical_or_exp_or(
@@ -1570,7 +1628,10 @@ pub enum logical_and_exp {
     Base(inclusive_or_exp),
     And(LogicalAndExpAnd),
 }
-pub fn logical_and_exp_base(_ctx: &Ctx, inclusive_or_exp: inclusive_or_exp) -> logical_and_exp {
+pub fn logical_and_exp_base(
+    _ctx: &Ctx,
+    inclusive_or_exp: inclusive_or_exp,
+) -> logical_and_exp {
     logical_and_exp::Base(inclusive_or_exp)
 }
 pub fn logical_and_exp_and(
@@ -1593,7 +1654,10 @@ pub enum inclusive_or_exp {
     Base(exclusive_or_exp),
     Or(InclusiveOrExpOr),
 }
-pub fn inclusive_or_exp_base(_ctx: &Ctx, exclusive_or_exp: exclusive_or_exp) -> inclusive_or_exp {
+pub fn inclusive_or_exp_base(
+    _ctx: &Ctx,
+    exclusive_or_exp: exclusive_or_exp,
+) -> inclusive_or_exp {
     inclusive_or_exp::Base(exclusive_or_exp)
 }
 pub fn inclusive_or_exp_or(
@@ -1715,7 +1779,10 @@ pub enum relational_exp {
     Lte(RelationalExpLte),
     Gte(RelationalExpGte),
 }
-pub fn relational_exp_base(_ctx: &Ctx, shift_expression: shift_expression) -> relational_exp {
+pub fn relational_exp_base(
+    _ctx: &Ctx,
+    shift_expression: shift_expression,
+) -> relational_exp {
     relational_exp::Base(shift_expression)
 }
 pub fn relational_exp_lt(
@@ -1774,7 +1841,10 @@ pub enum shift_expression {
     LShift(ShiftExpressionLShift),
     RShift(ShiftExpressionRShift),
 }
-pub fn shift_expression_base(_ctx: &Ctx, additive_exp: additive_exp) -> shift_expression {
+pub fn shift_expression_base(
+    _ctx: &Ctx,
+    additive_exp: additive_exp,
+) -> shift_expression {
     shift_expression::Base(additive_exp)
 }
 pub fn shift_expression_lshift(
@@ -2013,7 +2083,11 @@ pub enum postfix_exp {
 pub fn postfix_exp_primary(_ctx: &Ctx, primary_exp: primary_exp) -> postfix_exp {
     postfix_exp::Primary(primary_exp)
 }
-pub fn postfix_exp_subscript(_ctx: &Ctx, postfix_exp: postfix_exp, exp: exp) -> postfix_exp {
+pub fn postfix_exp_subscript(
+    _ctx: &Ctx,
+    postfix_exp: postfix_exp,
+    exp: exp,
+) -> postfix_exp {
     postfix_exp::Subscript(ArrayPostfix {
         postfix_exp: Box::new(postfix_exp),
         exp: Box::new(exp),
@@ -11,6 +11,7 @@ pub mod analysis;
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[allow(clippy::enum_variant_names)]
+#[rustfmt::skip]
 pub mod c_actions;
 
 fn main() {
@@ -62,6 +62,14 @@ echo -e "\nCheck files above or in target/package, then press enter to publish..
 read
 cargo publish -p rustemo-compiler
 
+# Publish rustemo-macros
+echo -e "\nPublishing rustemo-macros..."
+cargo publish --dry-run -p rustemo-macros
+cargo package --list -p rustemo-macros
+echo -e "\nCheck files above or in target/package, then press enter to publish..."
+read
+cargo publish -p rustemo-macros
+
 # Merge to main
 echo -e "\nMerging to main..."
 git checkout main
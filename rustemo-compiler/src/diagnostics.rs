@@ -0,0 +1,299 @@
+//! Errors and warnings reported for grammars.
+//!
+//! Each [Diagnostic] has a stable [Code], a primary span in the grammar file
+//! and optional related spans, notes and help. Diagnostics are rendered the
+//! same way as [rustemo::ParseError].
+use std::{fmt::Display, str::FromStr};
+
+pub use rustemo::Severity;
+use rustemo::{ParseError, SourceSpan};
+
+use crate::{settings::Settings, Error, Result};
+
+/// Stable codes of grammar diagnostics. Error codes start with `E`, warning
+/// codes with `W`. Codes are never reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Code {
+    /// Terminal priority is out of range.
+    InvalidPriority,
+    /// An inline string match used in a production is not defined as a
+    /// terminal.
+    UndefinedTerminal,
+    /// A reference to an unexisting grammar symbol.
+    UndefinedSymbol,
+    /// A rule can't derive any finite sentence.
+    InfiniteRecursion,
+    /// A name is not a valid Rust identifier.
+    InvalidIdentifier,
+    /// Invalid modifiers of a repetition operator.
+    InvalidRepetition,
+    /// A terminal recognizer is missing or is not allowed.
+    InvalidRecognizer,
+    /// Indentation terminals are not defined when indentation is enabled.
+    MissingIndentationTerminal,
+    /// A terminal type is invalid or the terminal can't have a type.
+    InvalidTerminalType,
+    /// A derive is not a valid Rust path.
+    InvalidDerive,
+    /// LR conflicts in a grammar used for LR parsing.
+    Conflicts,
+    /// Invalid symbol in the precedence block or an undeclared `prec` symbol.
+    InvalidPrecedence,
+    /// A lexical mode is defined more than once or an undefined mode is
+    /// entered.
+    InvalidMode,
+
+    /// A terminal is not used in any production.
+    UnusedTerminal,
+    /// A rule is not reachable from the start rule.
+    UnreachableRule,
+    /// The same string match is used by multiple terminals.
+    DuplicateMatch,
+    /// A rule can't derive any string of terminals.
+    NonProductiveRule,
+    /// A string match terminal can't be recognized as a regex terminal matches
+    /// the same string.
+    ShadowedTerminal,
+    /// A rule can derive itself, e.g. through nullable symbols.
+    NullableCycle,
+    /// A state where a syntax error can be detected has no message in the
+    /// `.messages` file.
+    MissingErrorMessage,
+}
+
+impl Code {
+    pub const ALL: [Code; 20] = [
+        Code::InvalidPriority,
+        Code::UndefinedTerminal,
+        Code::UndefinedSymbol,
+        Code::InfiniteRecursion,
+        Code::InvalidIdentifier,
+        Code::InvalidRepetition,
+        Code::InvalidRecognizer,
+        Code::MissingIndentationTerminal,
+        Code::InvalidTerminalType,
+        Code::InvalidDerive,
+        Code::Conflicts,
+        Code::InvalidPrecedence,
+        Code::InvalidMode,
+        Code::UnusedTerminal,
+        Code::UnreachableRule,
+        Code::DuplicateMatch,
+        Code::NonProductiveRule,
+        Code::ShadowedTerminal,
+        Code::NullableCycle,
+        Code::MissingErrorMessage,
+    ];
+
+    /// The code as reported, e.g. `E0003` or `W0001`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Code::InvalidPriority => "E0001",
+            Code::UndefinedTerminal => "E0002",
+            Code::UndefinedSymbol => "E0003",
+            Code::InfiniteRecursion => "E0004",
+            Code::InvalidIdentifier => "E0005",
+            Code::InvalidRepetition => "E0006",
+            Code::InvalidRecognizer => "E0007",
+            Code::MissingIndentationTerminal => "E0008",
+            Code::InvalidTerminalType => "E0009",
+            Code::InvalidDerive => "E0010",
+            Code::Conflicts => "E0011",
+            Code::InvalidPrecedence => "E0012",
+            Code::InvalidMode => "E0013",
+            Code::UnusedTerminal => "W0001",
+            Code::UnreachableRule => "W0002",
+            Code::DuplicateMatch => "W0003",
+            Code::NonProductiveRule => "W0004",
+            Code::ShadowedTerminal => "W0005",
+            Code::NullableCycle => "W0006",
+            Code::MissingErrorMessage => "W0007",
+        }
+    }
+
+    /// The severity the diagnostic with this code is reported with by
+    /// default.
+    pub fn severity(&self) -> Severity {
+        if self.as_str().starts_with('W') {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Code {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Code::ALL
+            .into_iter()
+            .find(|code| code.as_str() == s)
+            .ok_or_else(|| format!("Unknown diagnostic code '{s}'."))
+    }
+}
+
+/// An error or a warning found in the grammar.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub code: Code,
+    /// The report used for rendering. Holds the message, spans, notes and
+    /// help.
+    pub report: ParseError,
+}
+
+impl Diagnostic {
+    pub fn new(code: Code, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            report: ParseError::new(message)
+                .with_code(code.as_str())
+                .with_severity(code.severity()),
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.report.severity
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.report.severity = severity;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.report.file = Some(file.into());
+        self
+    }
+
+    /// Sets the primary span. Symbols created by the compiler may not have a
+    /// span, thus the span is optional.
+    pub fn with_span(mut self, span: Option<SourceSpan>) -> Self {
+        self.report.span = span;
+        self
+    }
+
+    /// Adds a related span. Skipped if the span is not known.
+    pub fn with_label(mut self, span: Option<SourceSpan>, message: impl Into<String>) -> Self {
+        if let Some(span) = span {
+            self.report = self.report.with_label(span, message);
+        }
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.report.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.report.help = Some(help.into());
+        self
+    }
+
+    /// Adds the grammar source used to render the spans. An already set
+    /// source is kept.
+    pub fn with_source(mut self, src: &str) -> Self {
+        if self.report.src.is_none() {
+            self.report.src = Some(src.to_string());
+        }
+        self
+    }
+
+    /// A single line rendering used for `cargo:warning` output of build
+    /// scripts, e.g. `grammar.rustemo:3:5: warning[W0001]: Terminal...`.
+    pub fn to_cargo_str(&self) -> String {
+        let mut loc = self.report.file.clone().unwrap_or_default();
+        if let Some(line_col) = self.report.span.and_then(|s| s.start.line_col) {
+            loc.push_str(&format!(":{}:{}", line_col.line, line_col.column));
+        }
+        let severity = match self.severity() {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let message = self.report.message.replace('\n', " ");
+        if loc.is_empty() {
+            format!("{severity}[{}]: {message}", self.code)
+        } else {
+            format!("{loc}: {severity}[{}]: {message}", self.code)
+        }
+    }
+
+    /// A string representation without the full file path and the source
+    /// snippet. Used in tests to yield the same results at different
+    /// location.
+    pub fn to_locfile_str(&self) -> String {
+        let mut report = ParseError::new(self.report.message.clone());
+        report.file = self.report.file.clone();
+        report.span = self.report.span;
+        report.code = self.report.code.clone();
+        report.severity = self.report.severity;
+        let mut s = rustemo::Error::ParseError(Box::new(report)).to_pos_str();
+        for label in &self.report.labels {
+            s.push_str(&format!("\n\t{:?}: {}", label.span, label.message));
+        }
+        for note in &self.report.notes {
+            s.push_str(&format!("\n\tnote: {note}"));
+        }
+        if let Some(help) = &self.report.help {
+            s.push_str(&format!("\n\thelp: {help}"));
+        }
+        s
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.report)
+    }
+}
+
+impl From<Diagnostic> for rustemo::Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        rustemo::Error::ParseError(Box::new(diagnostic.report))
+    }
+}
+
+impl<R> From<Diagnostic> for rustemo::Result<R> {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Err(diagnostic.into())
+    }
+}
+
+/// Filters the warnings according to the settings. Warnings are returned as
+/// errors if warnings are turned into errors.
+pub(crate) fn filter_warnings(
+    diagnostics: Vec<Diagnostic>,
+    settings: &Settings,
+) -> Result<Vec<Diagnostic>> {
+    let diagnostics = diagnostics
+        .into_iter()
+        .filter(|d| !settings.silence_warnings && !settings.allowed_warnings.contains(&d.code))
+        .collect::<Vec<_>>();
+    if settings.warnings_as_errors && !diagnostics.is_empty() {
+        return Err(Error::Diagnostics(
+            diagnostics
+                .into_iter()
+                .map(|d| d.with_severity(Severity::Error))
+                .collect(),
+        ));
+    }
+    Ok(diagnostics)
+}
+
+/// Prints the warnings, as `cargo:warning` lines if requested.
+pub(crate) fn print_warnings(diagnostics: &[Diagnostic], settings: &Settings) {
+    for diagnostic in diagnostics {
+        if settings.cargo_warnings {
+            println!("cargo:warning={}", diagnostic.to_cargo_str());
+        } else {
+            println!("{diagnostic}");
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::diagnostics::Diagnostic;
+
 pub type Result<R> = std::result::Result<R, Error>;
 
 #[derive(Debug, thiserror::Error)]
@@ -14,6 +16,9 @@ pub enum Error {
 
     #[error("Syn error: {0}")]
     SynError(#[from] syn::Error),
+
+    #[error("{}", .0.iter().map(|d| d.to_string()).collect::<Vec<_>>().join("\n"))]
+    Diagnostics(Vec<Diagnostic>),
 }
 
 impl Error {
@@ -25,8 +30,43 @@ impl Error {
             Error::SynError(e) => format!("Syn error: {e}"),
             Error::IOError(e) => format!("IOError: {e}"),
             Error::Error(e) => format!("Error: {e}"),
+            Error::Diagnostics(diagnostics) => diagnostics
+                .iter()
+                .map(|d| d.to_locfile_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
         }
     }
+
+    /// Adds the grammar file name and source used to render spans of the
+    /// error if not already given.
+    pub(crate) fn with_grammar(self, file: &str, src: &str) -> Self {
+        match self {
+            Error::RustemoError(mut e) => {
+                if let rustemo::Error::ParseError(ref mut e) = *e {
+                    e.file.get_or_insert_with(|| file.to_string());
+                    e.src.get_or_insert_with(|| src.to_string());
+                }
+                Error::RustemoError(e)
+            }
+            Error::Diagnostics(diagnostics) => Error::Diagnostics(
+                diagnostics
+                    .into_iter()
+                    .map(|mut d| {
+                        d.report.file.get_or_insert_with(|| file.to_string());
+                        d.with_source(src)
+                    })
+                    .collect(),
+            ),
+            e => e,
+        }
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Error::Diagnostics(vec![diagnostic])
+    }
 }
 
 impl From<rustemo::Error> for Error {
@@ -0,0 +1,279 @@
+//! ANTLR notation.
+//!
+//! Regexes of lexical rules are translated to ANTLR lexer expressions. Regexes
+//! using features ANTLR lexers don't have, e.g. lookarounds, anchors or word
+//! boundaries, are given in comments instead of the rules.
+use std::{collections::BTreeSet, iter::Peekable, str::Chars};
+
+use super::{
+    ebnf::{postfix_expr, Prec},
+    ExportGrammar, Expr,
+};
+
+pub(crate) fn antlr(grammar: &ExportGrammar, name: &str) -> String {
+    let lexical = grammar
+        .rules
+        .iter()
+        .filter(|rule| rule.lexical)
+        .map(|rule| rule.name.as_str())
+        .collect::<BTreeSet<_>>();
+    // Parser rules must start with a lowercase letter and lexer rules with an
+    // uppercase letter.
+    let reference = |name: &str| {
+        let mut chars = name.chars();
+        let first = chars.next().map_or(String::new(), |c| {
+            if lexical.contains(name) {
+                c.to_uppercase().to_string()
+            } else {
+                c.to_lowercase().to_string()
+            }
+        });
+        format!("{first}{}", chars.as_str())
+    };
+    let expr = |expr: &Expr, prec| postfix_expr(expr, prec, &reference, literal);
+
+    let mut out = format!("grammar {name};\n");
+    for rule in &grammar.rules {
+        out.push('\n');
+        let name = reference(&rule.name);
+        match &rule.expr {
+            Expr::Empty if rule.lexical => out.push_str(&format!(
+                "// {name} is recognized by a custom recognizer.\n"
+            )),
+            Expr::Regex(regex) => match RegexTranslator::translate(regex) {
+                Some(expr) => out.push_str(&format!("{name}\n    : {expr}\n    ;\n")),
+                None => out.push_str(&format!(
+                    "// {name} is recognized by the regex /{regex}/ which can't be translated.\n"
+                )),
+            },
+            Expr::Choice(alternatives) => {
+                out.push_str(&format!("{name}\n"));
+                for (idx, alternative) in alternatives.iter().enumerate() {
+                    let sep = if idx == 0 { ':' } else { '|' };
+                    out.push_str(&format!("    {sep} {}\n", expr(alternative, Prec::Seq)));
+                }
+                out.push_str("    ;\n");
+            }
+            Expr::Empty => out.push_str(&format!("{name}\n    :\n    ;\n")),
+            e => out.push_str(&format!("{name}\n    : {}\n    ;\n", expr(e, Prec::Choice))),
+        }
+    }
+    out
+}
+
+/// ANTLR string literals use single quotes only.
+fn literal(s: &str) -> String {
+    format!(
+        "'{}'",
+        s.replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+            .replace('\t', "\\t")
+    )
+}
+
+/// A part of a translated regex sequence.
+enum Atom {
+    /// A single character. Consecutive characters are given as one literal.
+    Char(char),
+    /// An expression which doesn't need parentheses to be repeated.
+    Expr(String),
+}
+
+impl Atom {
+    fn text(&self) -> String {
+        match self {
+            Atom::Char(c) => literal(&c.to_string()),
+            Atom::Expr(expr) => expr.clone(),
+        }
+    }
+}
+
+/// Translates the subset of the regex syntax which has a counterpart in ANTLR
+/// lexer rules: literals, escapes, `.`, character classes, `\d`, `\w`, `\s`
+/// and their negations, groups, alternatives and repetitions, including
+/// bounded ones which are expanded.
+struct RegexTranslator<'r> {
+    chars: Peekable<Chars<'r>>,
+}
+
+impl<'r> RegexTranslator<'r> {
+    /// The ANTLR expression for the regex or `None` if it can't be translated.
+    fn translate(regex: &'r str) -> Option<String> {
+        let mut translator = Self {
+            chars: regex.chars().peekable(),
+        };
+        let expr = translator.alternatives()?;
+        translator.chars.next().is_none().then_some(expr)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.chars.next_if_eq(&c).is_some()
+    }
+
+    fn alternatives(&mut self) -> Option<String> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.eat('|') {
+            alternatives.push(self.sequence()?);
+        }
+        Some(alternatives.join(" | "))
+    }
+
+    fn sequence(&mut self) -> Option<String> {
+        let mut atoms = vec![];
+        while !matches!(self.chars.peek(), None | Some('|' | ')')) {
+            let atom = self.atom()?;
+            atoms.push(self.repetition(atom)?);
+        }
+        let mut parts = vec![];
+        let mut chars = String::new();
+        for atom in atoms {
+            match atom {
+                Atom::Char(c) => chars.push(c),
+                Atom::Expr(expr) => {
+                    if !chars.is_empty() {
+                        parts.push(literal(&std::mem::take(&mut chars)));
+                    }
+                    parts.push(expr);
+                }
+            }
+        }
+        if !chars.is_empty() {
+            parts.push(literal(&chars));
+        }
+        Some(parts.join(" "))
+    }
+
+    fn atom(&mut self) -> Option<Atom> {
+        Some(match self.chars.next()? {
+            '.' => Atom::Expr("~[\\n]".into()),
+            '[' => Atom::Expr(self.class()?),
+            '(' => {
+                if self.eat('?') {
+                    // Only non-capturing and named groups.
+                    match self.chars.next()? {
+                        ':' => (),
+                        'P' if self.eat('<') => self.group_name()?,
+                        '<' if !matches!(self.chars.peek(), Some('=' | '!')) => {
+                            self.group_name()?
+                        }
+                        _ => return None,
+                    }
+                }
+                let expr = self.alternatives()?;
+                if !self.eat(')') {
+                    return None;
+                }
+                Atom::Expr(format!("({expr})"))
+            }
+            '\\' => match self.chars.next()? {
+                'n' => Atom::Char('\n'),
+                'r' => Atom::Char('\r'),
+                't' => Atom::Char('\t'),
+                c @ ('d' | 'w' | 's') => Atom::Expr(format!("[{}]", class_escape(c)?)),
+                c @ ('D' | 'W' | 'S') => {
+                    Atom::Expr(format!("~[{}]", class_escape(c.to_ascii_lowercase())?))
+                }
+                c if c.is_ascii_punctuation() => Atom::Char(c),
+                _ => return None,
+            },
+            '^' | '$' | '*' | '+' | '?' | '{' => return None,
+            c => Atom::Char(c),
+        })
+    }
+
+    /// Skips the name of a named group up to the closing `>`.
+    fn group_name(&mut self) -> Option<()> {
+        while self.chars.next()? != '>' {}
+        Some(())
+    }
+
+    /// A character class after the opening `[`.
+    fn class(&mut self) -> Option<String> {
+        let negated = self.eat('^');
+        let mut class = String::new();
+        let mut first = true;
+        loop {
+            let c = self.chars.next()?;
+            match c {
+                ']' if !first => break,
+                '[' => return None,
+                '\\' => match self.chars.next()? {
+                    'n' => class.push_str("\\n"),
+                    'r' => class.push_str("\\r"),
+                    't' => class.push_str("\\t"),
+                    c @ ('d' | 'w' | 's') => class.push_str(class_escape(c)?),
+                    c @ (']' | '\\' | '-') => {
+                        class.push('\\');
+                        class.push(c);
+                    }
+                    c if c.is_ascii_punctuation() => class.push(c),
+                    _ => return None,
+                },
+                // A literal `-` at the start or the end of the class.
+                '-' if first || self.chars.peek() == Some(&']') => class.push_str("\\-"),
+                ']' => class.push_str("\\]"),
+                c => class.push(c),
+            }
+            first = false;
+        }
+        Some(format!("{}[{class}]", if negated { "~" } else { "" }))
+    }
+
+    /// Applies the repetition operator following the atom, if any.
+    fn repetition(&mut self, atom: Atom) -> Option<Atom> {
+        let op = match self.chars.peek() {
+            Some('*' | '+' | '?') => self.chars.next()?.to_string(),
+            Some('{') => {
+                self.chars.next();
+                let mut bounds = String::new();
+                while let Some(c) = self.chars.next_if(|&c| c != '}') {
+                    bounds.push(c);
+                }
+                if !self.eat('}') || self.eat('?') {
+                    return None;
+                }
+                let (min, max) = match bounds.split_once(',') {
+                    Some((min, "")) => (min.trim().parse::<usize>().ok()?, None),
+                    Some((min, max)) => (
+                        min.trim().parse().ok()?,
+                        Some(max.trim().parse::<usize>().ok()?),
+                    ),
+                    None => {
+                        let n = bounds.trim().parse().ok()?;
+                        (n, Some(n))
+                    }
+                };
+                let text = atom.text();
+                let mut parts = vec![text.clone(); min];
+                match max {
+                    Some(max) if max >= min => {
+                        parts.extend(vec![format!("{text}?"); max - min]);
+                    }
+                    Some(_) => return None,
+                    None => parts.push(format!("{text}*")),
+                }
+                return Some(Atom::Expr(match parts.len() {
+                    0 => return None,
+                    1 => parts.remove(0),
+                    _ => format!("({})", parts.join(" ")),
+                }));
+            }
+            _ => return Some(atom),
+        };
+        // Lazy repetitions are supported by ANTLR as well.
+        let lazy = if self.eat('?') { "?" } else { "" };
+        Some(Atom::Expr(format!("{}{op}{lazy}", atom.text())))
+    }
+}
+
+/// The content of the character class for the `\d`, `\w` or `\s` escape.
+fn class_escape(c: char) -> Option<&'static str> {
+    match c {
+        'd' => Some("0-9"),
+        'w' => Some("a-zA-Z0-9_"),
+        's' => Some(" \\t\\r\\n\\f"),
+        _ => None,
+    }
+}
@@ -0,0 +1,150 @@
+//! ISO and W3C EBNF notations.
+use super::{ExportGrammar, ExportRule, Expr};
+
+/// Operator precedence used to decide where parentheses are needed.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub(crate) enum Prec {
+    Choice,
+    Seq,
+    Postfix,
+    Atom,
+}
+
+pub(crate) fn iso(grammar: &ExportGrammar) -> String {
+    rules(grammar, |rule| {
+        let expr = match &rule.expr {
+            Expr::Empty if rule.lexical => "? custom recognizer ?".into(),
+            expr => iso_expr(expr, Prec::Choice),
+        };
+        format!("{} = {expr} ;", rule.name)
+    })
+}
+
+pub(crate) fn w3c(grammar: &ExportGrammar) -> String {
+    rules(grammar, |rule| {
+        let expr = match &rule.expr {
+            Expr::Empty if rule.lexical => "/* custom recognizer */".into(),
+            Expr::Empty => "/* empty */".into(),
+            expr => w3c_expr(expr, Prec::Choice),
+        };
+        format!("{} ::= {expr}", rule.name)
+    })
+}
+
+/// Each rule on its own line. Lexical rules are separated from other rules by
+/// an empty line.
+fn rules(grammar: &ExportGrammar, rule: impl Fn(&ExportRule) -> String) -> String {
+    let mut out = String::new();
+    let mut lexical = false;
+    for r in &grammar.rules {
+        if r.lexical && !lexical && !out.is_empty() {
+            out.push('\n');
+        }
+        lexical = r.lexical;
+        out.push_str(&rule(r));
+        out.push('\n');
+    }
+    out
+}
+
+fn iso_expr(expr: &Expr, prec: Prec) -> String {
+    let (text, expr_prec) = match expr {
+        Expr::Empty => (String::new(), Prec::Atom),
+        Expr::Literal(s) => (quote(s), Prec::Atom),
+        // Special sequences can't contain `?` so the regex is given in a
+        // comment.
+        Expr::Regex(regex) => (
+            format!("? regex ? (* /{}/ *)", regex.replace("*)", "*\\)")),
+            Prec::Atom,
+        ),
+        Expr::Ref(name) => (name.clone(), Prec::Atom),
+        Expr::Seq(exprs) => (
+            exprs
+                .iter()
+                .map(|e| iso_expr(e, Prec::Postfix))
+                .collect::<Vec<_>>()
+                .join(" , "),
+            Prec::Seq,
+        ),
+        Expr::Choice(exprs) => (
+            exprs
+                .iter()
+                .map(|e| iso_expr(e, Prec::Seq))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Prec::Choice,
+        ),
+        Expr::Optional(e) => (format!("[ {} ]", iso_expr(e, Prec::Choice)), Prec::Atom),
+        Expr::ZeroOrMore(e) => (format!("{{ {} }}", iso_expr(e, Prec::Choice)), Prec::Atom),
+        // ISO EBNF has no one or more operator.
+        Expr::OneOrMore(e) => (
+            format!(
+                "{} , {{ {} }}",
+                iso_expr(e, Prec::Postfix),
+                iso_expr(e, Prec::Choice)
+            ),
+            Prec::Seq,
+        ),
+    };
+    parenthesize(text, expr_prec, prec)
+}
+
+fn w3c_expr(expr: &Expr, prec: Prec) -> String {
+    postfix_expr(expr, prec, &|name| name.to_string(), quote)
+}
+
+/// Prints the expression in a notation with regex-like postfix operators `?`,
+/// `*` and `+`. Used for W3C EBNF and ANTLR.
+pub(crate) fn postfix_expr(
+    expr: &Expr,
+    prec: Prec,
+    reference: &dyn Fn(&str) -> String,
+    literal: fn(&str) -> String,
+) -> String {
+    let sub = |e: &Expr, prec| postfix_expr(e, prec, reference, literal);
+    let postfix = |e: &Expr, op| (format!("{}{op}", sub(e, Prec::Atom)), Prec::Postfix);
+    let (text, expr_prec) = match expr {
+        Expr::Empty => ("()".into(), Prec::Atom),
+        Expr::Literal(s) => (literal(s), Prec::Atom),
+        Expr::Regex(regex) => (regex.clone(), Prec::Atom),
+        Expr::Ref(name) => (reference(name), Prec::Atom),
+        Expr::Seq(exprs) => (
+            exprs
+                .iter()
+                .map(|e| sub(e, Prec::Postfix))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Prec::Seq,
+        ),
+        Expr::Choice(exprs) => (
+            exprs
+                .iter()
+                .map(|e| sub(e, Prec::Seq))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Prec::Choice,
+        ),
+        Expr::Optional(e) => postfix(e, '?'),
+        Expr::ZeroOrMore(e) => postfix(e, '*'),
+        Expr::OneOrMore(e) => postfix(e, '+'),
+    };
+    parenthesize(text, expr_prec, prec)
+}
+
+fn parenthesize(text: String, expr_prec: Prec, prec: Prec) -> String {
+    if expr_prec < prec {
+        format!("({text})")
+    } else {
+        text
+    }
+}
+
+/// Quotes the string match. Double quotes are used if the string contains a
+/// single quote.
+fn quote(s: &str) -> String {
+    if s.contains('\'') && !s.contains('"') {
+        format!("\"{s}\"")
+    } else {
+        format!("'{s}'")
+    }
+}
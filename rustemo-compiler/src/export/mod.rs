@@ -0,0 +1,350 @@
+//! Export of grammars to other notations.
+//!
+//! The grammar is first converted to [ExportGrammar], a notation-neutral
+//! representation with regular operators, which is then printed as [ISO
+//! EBNF](https://www.iso.org/standard/26153.html), [W3C
+//! EBNF](https://www.w3.org/TR/xml/#sec-notation), an ANTLR grammar or drawn as
+//! railroad diagrams.
+//!
+//! The grammar can be exported as written ([ExportGrammar::from_file]) where
+//! repetitions and groups are kept, or after desugaring
+//! ([ExportGrammar::from_grammar]) where rules created by the compiler for
+//! repetitions and groups are exported as well.
+use std::{path::Path, str::FromStr};
+
+use clap::ValueEnum;
+use rustemo::Parser;
+
+use crate::{
+    grammar::{self, Grammar},
+    lang::{
+        rustemo::RustemoParser,
+        rustemo_actions::{
+            Assignment, File, GrammarRuleRHS, GrammarSymbol, GrammarSymbolRef, Production,
+            Recognizer, RepetitionOperator, RepetitionOperatorOp,
+        },
+    },
+    Error, Result,
+};
+
+mod antlr;
+mod ebnf;
+mod railroad;
+
+/// The notation the grammar is exported to.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// ISO/IEC 14977 EBNF.
+    #[default]
+    EBNF,
+    /// EBNF notation used in W3C specifications, e.g. XML.
+    W3C_EBNF,
+    /// ANTLR grammar.
+    ANTLR,
+    /// SVG railroad diagram for each rule.
+    RAILROAD,
+}
+
+/// A grammar expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// Matches the empty string.
+    Empty,
+    /// A string match.
+    Literal(String),
+    /// A regex match. Used only in lexical rules.
+    Regex(String),
+    /// A reference to a rule.
+    Ref(String),
+    Seq(Vec<Expr>),
+    Choice(Vec<Expr>),
+    Optional(Box<Expr>),
+    ZeroOrMore(Box<Expr>),
+    OneOrMore(Box<Expr>),
+}
+
+impl Expr {
+    /// A sequence. Nested sequences are flattened, empty expressions are
+    /// dropped and a single element is not wrapped.
+    fn seq(exprs: Vec<Expr>) -> Expr {
+        let mut exprs = exprs
+            .into_iter()
+            .flat_map(|e| match e {
+                Expr::Seq(exprs) => exprs,
+                Expr::Empty => vec![],
+                e => vec![e],
+            })
+            .collect::<Vec<_>>();
+        match exprs.len() {
+            0 => Expr::Empty,
+            1 => exprs.remove(0),
+            _ => Expr::Seq(exprs),
+        }
+    }
+
+    /// A choice. A choice with an empty alternative is an optional of the
+    /// other alternatives.
+    fn choice(exprs: Vec<Expr>) -> Expr {
+        let has_empty = exprs.contains(&Expr::Empty);
+        let mut exprs = exprs
+            .into_iter()
+            .filter(|e| *e != Expr::Empty)
+            .collect::<Vec<_>>();
+        let choice = match exprs.len() {
+            0 => return Expr::Empty,
+            1 => exprs.remove(0),
+            _ => Expr::Choice(exprs),
+        };
+        if has_empty {
+            Expr::optional(choice)
+        } else {
+            choice
+        }
+    }
+
+    fn optional(expr: Expr) -> Expr {
+        match expr {
+            Expr::Empty | Expr::Optional(_) | Expr::ZeroOrMore(_) => expr,
+            Expr::OneOrMore(expr) => Expr::ZeroOrMore(expr),
+            _ => Expr::Optional(Box::new(expr)),
+        }
+    }
+}
+
+/// A rule of the exported grammar.
+#[derive(Debug, Clone)]
+pub struct ExportRule {
+    pub name: String,
+    pub expr: Expr,
+    /// Is this a terminal rule. Lexical rules without an expression are
+    /// recognized by custom recognizers.
+    pub lexical: bool,
+}
+
+/// A grammar prepared for export.
+#[derive(Debug, Clone)]
+pub struct ExportGrammar {
+    pub rules: Vec<ExportRule>,
+}
+
+impl ExportGrammar {
+    /// The grammar as written in the given file, i.e. before desugaring.
+    /// Imported grammars are not exported.
+    pub fn from_file<F: AsRef<Path>>(file: F) -> Result<Self> {
+        let file = RustemoParser::new().parse_file(file.as_ref())?;
+        Ok(Self::from_ast(&file))
+    }
+
+    /// The grammar after desugaring. Each rule created for repetitions and
+    /// groups is exported.
+    pub fn from_grammar(grammar: &Grammar) -> Self {
+        let excluded = ["EMPTY", "AUG", "AUGL"];
+        let mut rules = grammar
+            .nonterminals
+            .iter()
+            .filter(|nt| !excluded.contains(&nt.name.as_str()))
+            .map(|nt| ExportRule {
+                name: nt.name.clone(),
+                expr: Expr::choice(
+                    nt.productions(grammar)
+                        .iter()
+                        .map(|prod| desugared_production(grammar, prod))
+                        .collect(),
+                ),
+                lexical: false,
+            })
+            .collect::<Vec<_>>();
+        rules.extend(
+            grammar
+                .terminals
+                .iter()
+                .skip(1)
+                .map(|term| lexical_rule(&term.name, term.recognizer.as_ref())),
+        );
+        Self { rules }
+    }
+
+    fn from_ast(file: &File) -> Self {
+        let mut rules = file
+            .grammar_rules
+            .iter()
+            .flatten()
+            .map(|rule| ExportRule {
+                name: rule.name.to_string(),
+                expr: rhs(&rule.rhs),
+                lexical: false,
+            })
+            .collect::<Vec<_>>();
+        rules.extend(
+            file.terminal_rules
+                .iter()
+                .flatten()
+                .chain(
+                    file.lexical_modes
+                        .iter()
+                        .flatten()
+                        .flat_map(|mode| &mode.terminal_rules),
+                )
+                .map(|rule| lexical_rule(rule.name.as_ref(), rule.recognizer.as_ref())),
+        );
+        Self { rules }
+    }
+
+    /// The grammar in ISO/IEC 14977 EBNF. Regexes are given as `? regex ?`
+    /// special sequences followed by the regex in a comment.
+    pub fn to_ebnf(&self) -> String {
+        ebnf::iso(self)
+    }
+
+    /// The grammar in EBNF notation used in W3C specifications. Regexes are
+    /// given as they are.
+    pub fn to_w3c_ebnf(&self) -> String {
+        ebnf::w3c(self)
+    }
+
+    /// The grammar in ANTLR syntax with the given grammar name. Names of
+    /// parser rules start with a lowercase letter while names of lexer rules
+    /// start with an uppercase letter. Regexes are translated to ANTLR lexer
+    /// expressions or given in comments if they can't be translated.
+    pub fn to_antlr(&self, name: &str) -> String {
+        antlr::antlr(self, name)
+    }
+
+    /// A railroad diagram in SVG format for each non-lexical rule. Returns
+    /// pairs of the rule name and the SVG document.
+    pub fn to_railroad(&self) -> Vec<(String, String)> {
+        self.rules
+            .iter()
+            .filter(|rule| !rule.lexical)
+            .map(|rule| (rule.name.clone(), railroad::diagram(&rule.expr)))
+            .collect()
+    }
+}
+
+impl FromStr for ExportGrammar {
+    type Err = Error;
+
+    /// Parses the grammar given as a string. The grammar is exported as
+    /// written.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from_ast(&RustemoParser::new().parse(s)?))
+    }
+}
+
+/// String matches are inlined as literals.
+fn desugared_production(grammar: &Grammar, production: &grammar::Production) -> Expr {
+    Expr::seq(
+        production
+            .rhs_symbols()
+            .into_iter()
+            .map(|symbol| {
+                if grammar.is_nonterm(symbol) {
+                    return Expr::Ref(grammar.symbol_name(symbol));
+                }
+                let term = &grammar.terminals[grammar.symbol_to_term_index(symbol)];
+                match &term.recognizer {
+                    Some(Recognizer::StrConst(s)) => Expr::Literal(s.as_ref().clone()),
+                    _ => Expr::Ref(term.name.clone()),
+                }
+            })
+            .collect(),
+    )
+}
+
+fn lexical_rule(name: &str, recognizer: Option<&Recognizer>) -> ExportRule {
+    ExportRule {
+        name: name.to_string(),
+        expr: match recognizer {
+            Some(Recognizer::StrConst(s)) => Expr::Literal(s.as_ref().clone()),
+            Some(Recognizer::RegexTerm(r)) => Expr::Regex(r.as_ref().clone()),
+            None => Expr::Empty,
+        },
+        lexical: true,
+    }
+}
+
+fn rhs(rhs: &GrammarRuleRHS) -> Expr {
+    Expr::choice(rhs.iter().map(production).collect())
+}
+
+fn production(production: &Production) -> Expr {
+    Expr::seq(
+        production
+            .assignments
+            .iter()
+            .map(|assignment| match assignment {
+                Assignment::PlainAssignment(a) | Assignment::BoolAssignment(a) => {
+                    symbol_ref(&a.gsymref)
+                }
+                Assignment::GrammarSymbolRef(r) => symbol_ref(r),
+            })
+            .collect(),
+    )
+}
+
+fn symbol_ref(symbol_ref: &GrammarSymbolRef) -> Expr {
+    let expr = match (&symbol_ref.gsymbol, &symbol_ref.production_group) {
+        (Some(GrammarSymbol::Name(name)), _) if name.as_ref() == "EMPTY" => Expr::Empty,
+        (Some(GrammarSymbol::Name(name)), _) => Expr::Ref(name.to_string()),
+        (Some(GrammarSymbol::StrConst(s)), _) => Expr::Literal(s.as_ref().clone()),
+        (None, Some(group)) => rhs(&group.0),
+        (None, None) => Expr::Empty,
+    };
+    match &symbol_ref.repetition_op {
+        Some(op) => repetition(expr, op),
+        None => expr,
+    }
+}
+
+/// Expands the repetition modifiers, e.g. `A+[Comma, min: 2]` is exported as
+/// `A Comma A (Comma A)*`.
+fn repetition(expr: Expr, op: &RepetitionOperator) -> Expr {
+    use RepetitionOperatorOp::*;
+    if let Optional | OptionalGreedy = op.rep_op {
+        return Expr::optional(expr);
+    }
+    let mut separator = None;
+    let mut trailing = false;
+    let mut min = 1;
+    let mut max = None;
+    for modifier in op.rep_modifiers.iter().flatten() {
+        match (modifier.name.as_str(), &modifier.value) {
+            ("min", Some(value)) => min = *value.as_ref(),
+            ("max", Some(value)) => max = Some(*value.as_ref()),
+            ("trailing", None) => trailing = true,
+            ("left" | "right", None) => (),
+            (name, _) => separator = Some(Expr::Ref(name.to_string())),
+        }
+    }
+    let next = match &separator {
+        Some(separator) => Expr::seq(vec![separator.clone(), expr.clone()]),
+        None => expr.clone(),
+    };
+    let mut items = vec![expr.clone()];
+    items.extend((1..min).map(|_| next.clone()));
+    match max {
+        // Each of the optional elements up to `max` is nested in the previous.
+        Some(max) => items.extend((min..max).map(|_| next.clone()).rev().fold(
+            None,
+            |rest: Option<Expr>, item| {
+                Some(Expr::optional(Expr::seq(
+                    [Some(item), rest].into_iter().flatten().collect(),
+                )))
+            },
+        )),
+        None => items.push(Expr::ZeroOrMore(Box::new(next))),
+    }
+    if trailing {
+        items.push(Expr::optional(separator.clone().unwrap_or(Expr::Empty)));
+    }
+    let one_or_more = if separator.is_none() && min == 1 && max.is_none() && !trailing {
+        Expr::OneOrMore(Box::new(expr))
+    } else {
+        Expr::seq(items)
+    };
+    match op.rep_op {
+        ZeroOrMore | ZeroOrMoreGreedy => Expr::optional(one_or_more),
+        _ => one_or_more,
+    }
+}
@@ -0,0 +1,289 @@
+//! Railroad diagrams in SVG format.
+//!
+//! Each expression is laid out as a box with a baseline. The expression is
+//! entered from the left and left to the right on the baseline. Alternatives
+//! and repetition loops are drawn below the baseline while optional bypasses
+//! are drawn above.
+use super::Expr;
+
+/// Width of a character in box labels.
+const CHAR_WIDTH: usize = 8;
+/// Height of terminal and non-terminal boxes.
+const BOX_HEIGHT: usize = 22;
+/// Radius of the arcs connecting branches.
+const ARC: usize = 10;
+/// Space between elements.
+const GAP: usize = 10;
+/// Space around the diagram.
+const PADDING: usize = 20;
+
+const STYLE: &str = "\
+path { stroke-width: 2; stroke: black; fill: none; }
+rect { stroke-width: 2; stroke: black; fill: #ffffe0; }
+rect.terminal { fill: #e0f0ff; }
+text { font: 13px monospace; text-anchor: middle; }";
+
+/// An SVG railroad diagram of the expression.
+pub(crate) fn diagram(expr: &Expr) -> String {
+    let node = Node::from(expr);
+    let size = node.size();
+    let width = size.width + 2 * PADDING;
+    let height = size.up + size.down + 2 * PADDING;
+    let y = PADDING + size.up;
+    let mut svg = Svg::default();
+    // Start and end markers.
+    svg.path(format!(
+        "M {} {} v {BOX_HEIGHT}",
+        PADDING / 2,
+        y - BOX_HEIGHT / 2
+    ));
+    svg.line(PADDING / 2, PADDING, y);
+    node.draw(PADDING, y, &mut svg);
+    svg.line(PADDING + size.width, width - PADDING / 2, y);
+    svg.path(format!(
+        "M {} {} v {BOX_HEIGHT}",
+        width - PADDING / 2,
+        y - BOX_HEIGHT / 2
+    ));
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n<style>\n{STYLE}\n</style>\n{}</svg>\n",
+        svg.0
+    )
+}
+
+enum Node {
+    Skip,
+    Terminal(String),
+    NonTerminal(String),
+    Seq(Vec<Node>),
+    Choice(Vec<Node>),
+    Optional(Box<Node>),
+    /// One or more repetitions.
+    Repeat(Box<Node>),
+}
+
+impl From<&Expr> for Node {
+    fn from(expr: &Expr) -> Self {
+        match expr {
+            Expr::Empty => Node::Skip,
+            Expr::Literal(s) => Node::Terminal(format!("'{s}'")),
+            Expr::Regex(regex) => Node::Terminal(format!("/{regex}/")),
+            Expr::Ref(name) => Node::NonTerminal(name.clone()),
+            Expr::Seq(exprs) => Node::Seq(exprs.iter().map(Node::from).collect()),
+            Expr::Choice(exprs) => Node::Choice(exprs.iter().map(Node::from).collect()),
+            Expr::Optional(e) => Node::Optional(Box::new(Node::from(&**e))),
+            Expr::ZeroOrMore(e) => {
+                Node::Optional(Box::new(Node::Repeat(Box::new(Node::from(&**e)))))
+            }
+            Expr::OneOrMore(e) => Node::Repeat(Box::new(Node::from(&**e))),
+        }
+    }
+}
+
+/// The size of a node. `up` and `down` are the extents above and below the
+/// baseline.
+#[derive(Clone, Copy)]
+struct Size {
+    width: usize,
+    up: usize,
+    down: usize,
+}
+
+impl Node {
+    fn size(&self) -> Size {
+        match self {
+            Node::Skip => Size {
+                width: 0,
+                up: 0,
+                down: 0,
+            },
+            Node::Terminal(label) | Node::NonTerminal(label) => Size {
+                width: label.chars().count() * CHAR_WIDTH + 2 * GAP,
+                up: BOX_HEIGHT / 2,
+                down: BOX_HEIGHT / 2,
+            },
+            Node::Seq(nodes) => {
+                let sizes = nodes.iter().map(Node::size).collect::<Vec<_>>();
+                Size {
+                    width: sizes.iter().map(|s| s.width).sum::<usize>()
+                        + GAP * nodes.len().saturating_sub(1),
+                    up: sizes.iter().map(|s| s.up).max().unwrap_or(0),
+                    down: sizes.iter().map(|s| s.down).max().unwrap_or(0),
+                }
+            }
+            Node::Choice(nodes) => {
+                let sizes = nodes.iter().map(Node::size).collect::<Vec<_>>();
+                let offsets = choice_offsets(&sizes);
+                let last = sizes.len() - 1;
+                Size {
+                    width: sizes.iter().map(|s| s.width).max().unwrap_or(0) + 4 * ARC,
+                    up: sizes[0].up,
+                    down: offsets[last] + sizes[last].down,
+                }
+            }
+            Node::Optional(node) => {
+                let size = node.size();
+                Size {
+                    width: size.width + 4 * ARC,
+                    up: bypass_offset(size),
+                    down: size.down,
+                }
+            }
+            Node::Repeat(node) => {
+                let size = node.size();
+                Size {
+                    width: size.width + 4 * ARC,
+                    up: size.up,
+                    down: loop_offset(size),
+                }
+            }
+        }
+    }
+
+    /// Draws the node entered at `(x, y)` and left at `(x + width, y)`.
+    fn draw(&self, x: usize, y: usize, svg: &mut Svg) {
+        let size = self.size();
+        match self {
+            Node::Skip => (),
+            Node::Terminal(label) | Node::NonTerminal(label) => {
+                let class = if matches!(self, Node::Terminal(_)) {
+                    " class=\"terminal\" rx=\"10\""
+                } else {
+                    ""
+                };
+                svg.0.push_str(&format!(
+                    "<rect x=\"{x}\" y=\"{}\" width=\"{}\" height=\"{BOX_HEIGHT}\"{class}/>\n\
+                     <text x=\"{}\" y=\"{}\">{}</text>\n",
+                    y - BOX_HEIGHT / 2,
+                    size.width,
+                    x + size.width / 2,
+                    y + 4,
+                    escape(label)
+                ));
+            }
+            Node::Seq(nodes) => {
+                let mut x = x;
+                for (idx, node) in nodes.iter().enumerate() {
+                    if idx > 0 {
+                        svg.line(x, x + GAP, y);
+                        x += GAP;
+                    }
+                    node.draw(x, y, svg);
+                    x += node.size().width;
+                }
+            }
+            Node::Choice(nodes) => {
+                let sizes = nodes.iter().map(Node::size).collect::<Vec<_>>();
+                let end = x + size.width;
+                for ((node, node_size), offset) in
+                    nodes.iter().zip(&sizes).zip(choice_offsets(&sizes))
+                {
+                    let node_y = y + offset;
+                    if offset == 0 {
+                        svg.line(x, x + 2 * ARC, y);
+                    } else {
+                        svg.path(format!(
+                            "M {x} {y} Q {x1} {y} {x1} {y1} L {x1} {y2} Q {x1} {node_y} {x2} {node_y}",
+                            x1 = x + ARC,
+                            y1 = y + ARC,
+                            y2 = node_y - ARC,
+                            x2 = x + 2 * ARC,
+                        ));
+                    }
+                    node.draw(x + 2 * ARC, node_y, svg);
+                    svg.line(x + 2 * ARC + node_size.width, end - 2 * ARC, node_y);
+                    if offset == 0 {
+                        svg.line(end - 2 * ARC, end, y);
+                    } else {
+                        svg.path(format!(
+                            "M {x2} {node_y} Q {x1} {node_y} {x1} {y2} L {x1} {y1} Q {x1} {y} {end} {y}",
+                            x1 = end - ARC,
+                            y1 = y + ARC,
+                            y2 = node_y - ARC,
+                            x2 = end - 2 * ARC,
+                        ));
+                    }
+                }
+            }
+            Node::Optional(node) => {
+                let end = x + size.width;
+                let by = y - size.up;
+                svg.line(x, x + 2 * ARC, y);
+                node.draw(x + 2 * ARC, y, svg);
+                svg.line(x + 2 * ARC + node.size().width, end, y);
+                svg.path(format!(
+                    "M {x} {y} Q {x1} {y} {x1} {y1} L {x1} {by1} Q {x1} {by} {x2} {by} \
+                     L {x3} {by} Q {x4} {by} {x4} {by1} L {x4} {y1} Q {x4} {y} {end} {y}",
+                    x1 = x + ARC,
+                    x2 = x + 2 * ARC,
+                    x3 = end - 2 * ARC,
+                    x4 = end - ARC,
+                    y1 = y - ARC,
+                    by1 = by + ARC,
+                ));
+            }
+            Node::Repeat(node) => {
+                let end = x + size.width;
+                let ly = y + size.down;
+                svg.line(x, x + 2 * ARC, y);
+                node.draw(x + 2 * ARC, y, svg);
+                svg.line(x + 2 * ARC + node.size().width, end, y);
+                // The loop back to the start.
+                svg.path(format!(
+                    "M {x3} {y} Q {x4} {y} {x4} {y1} L {x4} {ly1} Q {x4} {ly} {x3} {ly} \
+                     L {x2} {ly} Q {x1} {ly} {x1} {ly1} L {x1} {y1} Q {x1} {y} {x2} {y}",
+                    x1 = x + ARC,
+                    x2 = x + 2 * ARC,
+                    x3 = end - 2 * ARC,
+                    x4 = end - ARC,
+                    y1 = y + ARC,
+                    ly1 = ly - ARC,
+                ));
+            }
+        }
+    }
+}
+
+/// Offsets of the alternatives from the baseline. The first alternative is on
+/// the baseline.
+fn choice_offsets(sizes: &[Size]) -> Vec<usize> {
+    let mut offsets = vec![0];
+    for (prev, size) in sizes.iter().zip(sizes.iter().skip(1)) {
+        let last = offsets[offsets.len() - 1];
+        offsets.push(last + (prev.down + GAP + size.up).max(2 * ARC));
+    }
+    offsets
+}
+
+/// The offset of the optional bypass above the baseline.
+fn bypass_offset(size: Size) -> usize {
+    (size.up + GAP).max(2 * ARC)
+}
+
+/// The offset of the repetition loop below the baseline.
+fn loop_offset(size: Size) -> usize {
+    (size.down + GAP).max(2 * ARC)
+}
+
+#[derive(Default)]
+struct Svg(String);
+
+impl Svg {
+    fn path(&mut self, d: String) {
+        self.0.push_str(&format!("<path d=\"{d}\"/>\n"));
+    }
+
+    fn line(&mut self, x1: usize, x2: usize, y: usize) {
+        if x2 > x1 {
+            self.path(format!("M {x1} {y} H {x2}"));
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
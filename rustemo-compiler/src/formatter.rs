@@ -0,0 +1,688 @@
+//! Formatting of Rustemo grammars.
+//!
+//! The grammar is parsed with the Rustemo grammar parser and printed back in a
+//! canonical form:
+//!
+//! - rules which fit in 80 columns are on a single line, otherwise each
+//!   production is on its own line with `|` aligned with the `:` of the rule,
+//! - metadata are given in the order: production kind, priority,
+//!   associativity, `prec`, other keywords, `push`/`pop` and user metadata
+//!   sorted by name, e.g. `{Add, 1, left}`,
+//! - each level of the precedence block and each terminal of a lexical mode
+//!   block is on its own indented line,
+//! - grammar and terminal rules are separated from the `terminals` keyword by a
+//!   single empty line while multiple empty lines between rules are collapsed
+//!   into one.
+//!
+//! The `:` of consecutive rules, i.e. rules not separated by empty lines, are
+//! aligned.
+//!
+//! Comments are kept. A comment given at the end of a line is kept at the end of
+//! the output line of the rule, production or block it follows. Other comments
+//! are put on their own lines before the following rule or production.
+use std::{fs, path::Path};
+
+use rustemo::{Parser, SourceSpan};
+
+use crate::{
+    lang::{
+        rustemo::RustemoParser,
+        rustemo_actions::{
+            Assignment, ConstVal, File, GrammarRule, GrammarRuleRHS, GrammarSymbol,
+            GrammarSymbolRef, ImportStm, LexicalMode, Precedence, PrecedenceAssoc, ProdMetaDatas,
+            Production, Recognizer, RepetitionOperator, RepetitionOperatorOp, TerminalRule,
+        },
+    },
+    Result,
+};
+
+/// Rules which fit in this width are kept on a single line.
+const MAX_WIDTH: usize = 80;
+
+/// Indentation of precedence levels and terminals of lexical modes.
+const INDENT: &str = "    ";
+
+/// Formats the grammar given as a string.
+pub fn format_grammar(src: &str) -> Result<String> {
+    let file = RustemoParser::new().parse(src)?;
+    Ok(Formatter::new(src).format(&file))
+}
+
+/// Formats the grammar from the given file.
+pub fn format_file(grammar_path: &Path) -> Result<String> {
+    let file = RustemoParser::new().parse_file(grammar_path)?;
+    let src = fs::read_to_string(grammar_path)?;
+    Ok(Formatter::new(&src).format(&file))
+}
+
+#[derive(Clone, Copy)]
+struct Comment<'s> {
+    pos: usize,
+    text: &'s str,
+    /// Is the comment given after other content on the same line.
+    trailing: bool,
+}
+
+/// The output lines of a rule whose `:` is aligned with the rules around it.
+struct Head {
+    /// The index of the line with the rule name.
+    line: usize,
+    /// The index of the line after the rule.
+    end: usize,
+    /// The length of the line up to the `:`.
+    width: usize,
+}
+
+struct Formatter<'s> {
+    src: &'s str,
+    comments: Vec<Comment<'s>>,
+    /// The position of the `terminals` keyword.
+    terminals_pos: Option<usize>,
+    /// The position of the `precedence` keyword.
+    precedence_pos: Option<usize>,
+    /// The positions of `}` closing the precedence and lexical mode blocks.
+    block_ends: Vec<usize>,
+    /// The index of the next comment to output.
+    next_comment: usize,
+    lines: Vec<String>,
+    heads: Vec<Head>,
+}
+
+impl<'s> Formatter<'s> {
+    fn new(src: &'s str) -> Self {
+        let scanned = scan(src);
+        Self {
+            src,
+            comments: scanned.comments,
+            terminals_pos: scanned.terminals_pos,
+            precedence_pos: scanned.precedence_pos,
+            block_ends: scanned.block_ends,
+            next_comment: 0,
+            lines: vec![],
+            heads: vec![],
+        }
+    }
+
+    fn format(mut self, file: &File) -> String {
+        for import in file.imports.iter().flatten() {
+            self.import(import);
+        }
+        if let Some(precedence) = &file.precedence {
+            self.precedence(precedence);
+        }
+        for rule in file.grammar_rules.iter().flatten() {
+            self.grammar_rule(rule);
+        }
+        if let Some(terminal_rules) = &file.terminal_rules {
+            let pos = self.terminals_pos.unwrap_or_else(|| {
+                terminal_rules
+                    .first()
+                    .map_or(self.src.len(), |rule| self.terminal_rule_pos(rule))
+            });
+            self.comments_before(pos, "", true);
+            if !self.lines.is_empty() {
+                self.empty_line();
+            }
+            self.lines.push("terminals".into());
+            if let Some(terminals_pos) = self.terminals_pos {
+                self.trailing_comments(terminals_pos);
+            }
+            self.lines.push(String::new());
+            for rule in terminal_rules {
+                self.terminal_rule(rule, "");
+            }
+        }
+        for mode in file.lexical_modes.iter().flatten() {
+            self.lexical_mode(mode);
+        }
+        self.comments_before(usize::MAX, "", true);
+        while self.lines.last().is_some_and(|l| l.is_empty()) {
+            self.lines.pop();
+        }
+        self.align_heads();
+        let mut out = self.lines.join("\n");
+        out.push('\n');
+        out
+    }
+
+    fn import(&mut self, import: &ImportStm) {
+        let pos = span_start(import.path.span);
+        self.comments_before(pos, "", true);
+        self.keep_empty_line(pos);
+        let mut line = format!(
+            "import {}",
+            self.text(import.path.span, import.path.as_ref())
+        );
+        if let Some(name) = &import.name {
+            line.push_str(&format!(" as {name}"));
+        }
+        self.lines.push(line);
+    }
+
+    /// The precedence block with each level on its own line.
+    fn precedence(&mut self, precedence: &Precedence) {
+        let pos = self
+            .precedence_pos
+            .unwrap_or_else(|| symbol_pos(&precedence[0].symbols[0]));
+        self.comments_before(pos, "", true);
+        self.keep_empty_line(pos);
+        self.lines.push("precedence {".into());
+        self.trailing_comments(pos);
+        for level in precedence {
+            self.comments_before(symbol_pos(&level.symbols[0]), INDENT, false);
+            let mut line = INDENT.to_string();
+            if let Some(assoc) = level.assoc {
+                line.push_str(match assoc {
+                    PrecedenceAssoc::Left => "left ",
+                    PrecedenceAssoc::Reduce => "reduce ",
+                    PrecedenceAssoc::Right => "right ",
+                    PrecedenceAssoc::Shift => "shift ",
+                });
+            }
+            let symbols = level
+                .symbols
+                .iter()
+                .map(|symbol| match symbol {
+                    GrammarSymbol::Name(name) => name.to_string(),
+                    GrammarSymbol::StrConst(s) => self.text(s.span, &format!("'{}'", s.as_ref())),
+                })
+                .collect::<Vec<_>>();
+            line.push_str(&symbols.join(" "));
+            line.push(';');
+            self.lines.push(line);
+            self.trailing_comments(symbol_pos(&level.symbols[level.symbols.len() - 1]));
+        }
+        self.block_end(pos, INDENT);
+        self.empty_line();
+    }
+
+    fn grammar_rule(&mut self, rule: &GrammarRule) {
+        let pos = rule
+            .annotation
+            .as_ref()
+            .map_or(span_start(rule.name.span), |a| span_start(a.span));
+        self.comments_before(pos, "", true);
+        self.keep_empty_line(pos);
+        if let Some(annotation) = &rule.annotation {
+            self.lines.push(format!("@{annotation}"));
+            self.trailing_comments(pos);
+        }
+        let mut head = rule.name.to_string();
+        if !rule.meta.is_empty() {
+            head.push_str(&format!(" {{{}}}", self.meta(&rule.meta)));
+        }
+        let indent = " ".repeat(head.len());
+        let productions = rule
+            .rhs
+            .iter()
+            .map(|p| self.production(p))
+            .collect::<Vec<_>>();
+        let single_line = format!("{head}: {};", productions.join(" | "));
+        let last_pos = production_pos(&rule.rhs[rule.rhs.len() - 1]);
+        let line = self.lines.len();
+        if single_line.len() <= MAX_WIDTH
+            && self
+                .comments
+                .get(self.next_comment)
+                .is_none_or(|c| c.pos >= last_pos)
+        {
+            self.lines.push(single_line);
+        } else {
+            for (idx, (production, text)) in rule.rhs.iter().zip(&productions).enumerate() {
+                if idx == 0 {
+                    self.lines.push(format!("{head}: {text}"));
+                } else {
+                    self.comments_before(production_pos(production), &indent, false);
+                    self.lines.push(format!("{indent}| {text}"));
+                }
+            }
+            self.end_rule();
+        }
+        self.heads.push(Head {
+            line,
+            end: self.lines.len(),
+            width: head.len(),
+        });
+    }
+
+    /// The lexical mode block with each terminal on its own indented line.
+    fn lexical_mode(&mut self, mode: &LexicalMode) {
+        let name_pos = span_start(mode.name.span);
+        let pos = self.src[..name_pos].rfind("mode").unwrap_or(name_pos);
+        let len = self.lines.len();
+        self.comments_before(pos, "", true);
+        // The mode is separated from the previous terminals by an empty line
+        // given before its comments.
+        if self.lines.len() > len {
+            if len > 0 && !self.lines[len - 1].is_empty() && !self.lines[len].is_empty() {
+                self.lines.insert(len, String::new());
+            }
+        } else {
+            self.empty_line();
+        }
+        self.lines.push(format!("mode {} {{", mode.name));
+        for rule in &mode.terminal_rules {
+            self.terminal_rule(rule, INDENT);
+        }
+        self.block_end(name_pos, INDENT);
+    }
+
+    /// Outputs the `}` closing the block started at the given position with
+    /// the comments given before and after it.
+    fn block_end(&mut self, pos: usize, indent: &str) {
+        let end = self
+            .block_ends
+            .iter()
+            .copied()
+            .find(|&end| end > pos)
+            .unwrap_or(self.src.len());
+        self.comments_before(end, indent, false);
+        self.lines.push("}".into());
+        self.trailing_comments(end);
+    }
+
+    fn terminal_rule(&mut self, rule: &TerminalRule, indent: &str) {
+        let pos = self.terminal_rule_pos(rule);
+        self.comments_before(pos, indent, indent.is_empty());
+        self.keep_empty_line(pos);
+        if let Some(annotation) = &rule.annotation {
+            self.lines.push(format!("{indent}@{annotation}"));
+            self.trailing_comments(pos);
+        }
+        self.heads.push(Head {
+            line: self.lines.len(),
+            end: self.lines.len() + 1,
+            width: indent.len() + rule.name.len(),
+        });
+        let mut line = format!("{indent}{}:", rule.name);
+        if let Some(recognizer) = &rule.recognizer {
+            let text = match recognizer {
+                Recognizer::StrConst(s) => self.text(s.span, &format!("'{}'", s.as_ref())),
+                Recognizer::RegexTerm(r) => self.text(r.span, &format!("/{}/", r.as_ref())),
+            };
+            line.push_str(&format!(" {text}"));
+        }
+        if !rule.meta.is_empty() {
+            line.push_str(&format!(" {{{}}}", self.meta(&rule.meta)));
+        }
+        if rule.recognizer.is_none() && rule.meta.is_empty() {
+            line.push(' ');
+        }
+        self.lines.push(line);
+        self.end_rule();
+    }
+
+    fn terminal_rule_pos(&self, rule: &TerminalRule) -> usize {
+        rule.annotation
+            .as_ref()
+            .map_or(span_start(rule.name.span), |a| span_start(a.span))
+    }
+
+    fn end_rule(&mut self) {
+        if let Some(line) = self.lines.last_mut() {
+            line.push(';');
+        }
+    }
+
+    fn rhs(&self, rhs: &GrammarRuleRHS) -> String {
+        rhs.iter()
+            .map(|p| self.production(p))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn production(&self, production: &Production) -> String {
+        let mut text = production
+            .assignments
+            .iter()
+            .map(|a| self.assignment(a))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !production.meta.is_empty() {
+            text.push_str(&format!(" {{{}}}", self.meta(&production.meta)));
+        }
+        text
+    }
+
+    fn assignment(&self, assignment: &Assignment) -> String {
+        match assignment {
+            Assignment::PlainAssignment(a) => {
+                format!("{}={}", a.name, self.symbol_ref(&a.gsymref))
+            }
+            Assignment::BoolAssignment(a) => {
+                format!("{}?={}", a.name, self.symbol_ref(&a.gsymref))
+            }
+            Assignment::GrammarSymbolRef(r) => self.symbol_ref(r),
+        }
+    }
+
+    fn symbol_ref(&self, symbol_ref: &GrammarSymbolRef) -> String {
+        let mut text = match (&symbol_ref.gsymbol, &symbol_ref.production_group) {
+            (Some(GrammarSymbol::Name(name)), _) => name.to_string(),
+            (Some(GrammarSymbol::StrConst(s)), _) => {
+                self.text(s.span, &format!("'{}'", s.as_ref()))
+            }
+            (None, Some(group)) => format!("({})", self.rhs(&group.0)),
+            (None, None) => unreachable!("Symbol reference without a symbol or a group."),
+        };
+        if let Some(op) = &symbol_ref.repetition_op {
+            text.push_str(&repetition(op));
+        }
+        text
+    }
+
+    /// Production and terminal metadata in the canonical order.
+    fn meta(&self, meta: &ProdMetaDatas) -> String {
+        let mut items = vec![];
+        // Names given as keywords. Other meta-data, including user meta-data
+        // named as keywords but of a different type, are given as `name: value`.
+        let mut known = vec![];
+        if let Some(kind) = meta.get("kind") {
+            let text = self.const_val(kind);
+            if text.starts_with(['\'', '"']) {
+                items.push(format!("kind: {text}"));
+            } else {
+                items.push(text);
+            }
+            known.push("kind");
+        }
+        if let Some(priority @ ConstVal::Int(_)) = meta.get("priority") {
+            items.push(self.const_val(priority));
+            known.push("priority");
+        }
+        let keyword = |items: &mut Vec<String>, known: &mut Vec<&str>, keyword: &'static str| {
+            if let Some(ConstVal::Bool(true_val)) = meta.get(keyword) {
+                if *true_val.as_ref() {
+                    items.push(keyword.into());
+                    known.push(keyword);
+                }
+            }
+        };
+        keyword(&mut items, &mut known, "left");
+        keyword(&mut items, &mut known, "right");
+        if let Some(ConstVal::String(prec)) = meta.get("prec") {
+            items.push(format!("prec {prec}"));
+            known.push("prec");
+        }
+        for name in ["dynamic", "nops", "nopse", "prefer"] {
+            keyword(&mut items, &mut known, name);
+        }
+        if let Some(ConstVal::Bool(finish)) = meta.get("finish") {
+            items.push(
+                if *finish.as_ref() {
+                    "finish"
+                } else {
+                    "nofinish"
+                }
+                .into(),
+            );
+            known.push("finish");
+        }
+        if let Some(ConstVal::String(mode)) = meta.get("push") {
+            items.push(format!("push {mode}"));
+            known.push("push");
+        }
+        keyword(&mut items, &mut known, "pop");
+        for (name, value) in meta
+            .iter()
+            .filter(|(name, _)| !known.contains(&name.as_str()))
+        {
+            items.push(format!("{name}: {}", self.const_val(value)));
+        }
+        items.join(", ")
+    }
+
+    fn const_val(&self, value: &ConstVal) -> String {
+        match value {
+            ConstVal::Int(v) => self.text(v.span, &v.to_string()),
+            ConstVal::Float(v) => self.text(v.span, &v.to_string()),
+            ConstVal::Bool(v) => self.text(v.span, &v.to_string()),
+            ConstVal::String(v) => self.text(v.span, v.as_ref()),
+        }
+    }
+
+    /// The source text of the given span. Used to keep the original form of
+    /// string matches, regexes and constants.
+    fn text(&self, span: Option<SourceSpan>, default: &str) -> String {
+        span.and_then(|span| self.src.get(span.start.pos..span.end.pos))
+            .unwrap_or(default)
+            .to_string()
+    }
+
+    /// Outputs comments given before the position. Trailing comments are
+    /// appended to the last line.
+    fn comments_before(&mut self, pos: usize, indent: &str, top_level: bool) {
+        while let Some(&comment) = self.comments.get(self.next_comment) {
+            if comment.pos >= pos {
+                break;
+            }
+            self.next_comment += 1;
+            match self.lines.last_mut() {
+                Some(line) if comment.trailing && !line.is_empty() => {
+                    line.push(' ');
+                    line.push_str(comment.text);
+                }
+                _ => {
+                    if top_level {
+                        self.keep_empty_line(comment.pos);
+                    }
+                    self.lines.push(format!("{indent}{}", comment.text));
+                }
+            }
+        }
+    }
+
+    /// Appends the comments given after the position on the same source line
+    /// to the last line.
+    fn trailing_comments(&mut self, pos: usize) {
+        let line_end = self.src[pos..]
+            .find('\n')
+            .map_or(self.src.len(), |end| pos + end);
+        while let Some(&comment) = self.comments.get(self.next_comment) {
+            if comment.pos < pos || comment.pos >= line_end {
+                break;
+            }
+            self.next_comment += 1;
+            if let Some(line) = self.lines.last_mut() {
+                line.push(' ');
+                line.push_str(comment.text);
+            }
+        }
+    }
+
+    /// Aligns the `:` of consecutive rules. Rules separated only by comments
+    /// and annotations are consecutive. Productions are indented to stay
+    /// aligned with the `:`.
+    fn align_heads(&mut self) {
+        let mut start = 0;
+        while start < self.heads.len() {
+            let mut end = start + 1;
+            while end < self.heads.len()
+                && self.lines[self.heads[end - 1].end..self.heads[end].line]
+                    .iter()
+                    .all(|line| line.trim_start().starts_with(['/', '@']))
+            {
+                end += 1;
+            }
+            let width = self.heads[start..end]
+                .iter()
+                .map(|head| head.width)
+                .max()
+                .unwrap_or_default();
+            for head in &self.heads[start..end] {
+                let padding = " ".repeat(width - head.width);
+                self.lines[head.line].insert_str(head.width, &padding);
+                for line in &mut self.lines[head.line + 1..head.end] {
+                    line.insert_str(0, &padding);
+                }
+            }
+            start = end;
+        }
+    }
+
+    /// Outputs an empty line if the given position is preceded by an empty
+    /// line in the source.
+    fn keep_empty_line(&mut self, pos: usize) {
+        let before = self.src[..pos].trim_end_matches([' ', '\t', '\r']);
+        let whitespace = &before[before.trim_end().len()..];
+        if whitespace.matches('\n').count() > 1 && !self.lines.is_empty() {
+            self.empty_line();
+        }
+    }
+
+    fn empty_line(&mut self) {
+        if self.lines.last().is_some_and(|l| !l.is_empty()) {
+            self.lines.push(String::new());
+        }
+    }
+}
+
+fn repetition(op: &RepetitionOperator) -> String {
+    let mut text = match op.rep_op {
+        RepetitionOperatorOp::ZeroOrMore => "*",
+        RepetitionOperatorOp::ZeroOrMoreGreedy => "*!",
+        RepetitionOperatorOp::OneOrMore => "+",
+        RepetitionOperatorOp::OneOrMoreGreedy => "+!",
+        RepetitionOperatorOp::Optional => "?",
+        RepetitionOperatorOp::OptionalGreedy => "?!",
+    }
+    .to_string();
+    if let Some(modifiers) = &op.rep_modifiers {
+        let modifiers = modifiers
+            .iter()
+            .map(|m| match &m.value {
+                Some(value) => format!("{}: {value}", m.name),
+                None => m.name.to_string(),
+            })
+            .collect::<Vec<_>>();
+        text.push_str(&format!("[{}]", modifiers.join(", ")));
+    }
+    text
+}
+
+fn span_start(span: Option<SourceSpan>) -> usize {
+    span.map_or(0, |span| span.start.pos)
+}
+
+fn production_pos(production: &Production) -> usize {
+    match &production.assignments[0] {
+        Assignment::PlainAssignment(a) | Assignment::BoolAssignment(a) => span_start(a.name.span),
+        Assignment::GrammarSymbolRef(r) => symbol_ref_pos(r),
+    }
+}
+
+fn symbol_pos(symbol: &GrammarSymbol) -> usize {
+    match symbol {
+        GrammarSymbol::Name(name) => span_start(name.span),
+        GrammarSymbol::StrConst(s) => span_start(s.span),
+    }
+}
+
+fn symbol_ref_pos(symbol_ref: &GrammarSymbolRef) -> usize {
+    match (&symbol_ref.gsymbol, &symbol_ref.production_group) {
+        (Some(GrammarSymbol::Name(name)), _) => span_start(name.span),
+        (Some(GrammarSymbol::StrConst(s)), _) => span_start(s.span),
+        (None, Some(group)) => production_pos(&group.0[0]),
+        (None, None) => 0,
+    }
+}
+
+/// Comments and positions of keywords found in the source.
+struct Scanned<'s> {
+    comments: Vec<Comment<'s>>,
+    terminals_pos: Option<usize>,
+    precedence_pos: Option<usize>,
+    block_ends: Vec<usize>,
+}
+
+/// Finds comments, the positions of the `terminals` and `precedence` keywords
+/// and the ends of blocks skipping strings and regexes.
+fn scan(src: &str) -> Scanned<'_> {
+    let bytes = src.as_bytes();
+    let mut comments = vec![];
+    let mut terminals_pos = None;
+    let mut precedence_pos = None;
+    let mut block_ends = vec![];
+    // Is the next `{` the start of a block and are the open braces blocks.
+    let mut block_next = false;
+    let mut braces = vec![];
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        match bytes[pos] {
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                pos = src[pos..].find('\n').map_or(src.len(), |end| pos + end);
+                comments.push(comment(src, start, pos));
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                // Block comments can be nested.
+                let mut depth = 0;
+                while pos < bytes.len() {
+                    if bytes[pos..].starts_with(b"/*") {
+                        depth += 1;
+                        pos += 2;
+                    } else if bytes[pos..].starts_with(b"*/") {
+                        depth -= 1;
+                        pos += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        pos += 1;
+                    }
+                }
+                comments.push(comment(src, start, pos));
+            }
+            quote @ (b'/' | b'\'' | b'"') => {
+                pos += 1;
+                while pos < bytes.len() && bytes[pos] != quote {
+                    pos += if bytes[pos] == b'\\' { 2 } else { 1 };
+                }
+                pos += 1;
+            }
+            c if c.is_ascii_alphabetic() || c == b'_' => {
+                while pos < bytes.len()
+                    && (bytes[pos].is_ascii_alphanumeric() || b"_.".contains(&bytes[pos]))
+                {
+                    pos += 1;
+                }
+                match &src[start..pos] {
+                    "terminals" if terminals_pos.is_none() => terminals_pos = Some(start),
+                    "precedence" => {
+                        precedence_pos.get_or_insert(start);
+                        block_next = true;
+                    }
+                    "mode" => block_next = true,
+                    _ => (),
+                }
+            }
+            b'{' => {
+                braces.push(block_next);
+                block_next = false;
+                pos += 1;
+            }
+            b'}' => {
+                if braces.pop() == Some(true) {
+                    block_ends.push(pos);
+                }
+                pos += 1;
+            }
+            _ => pos += 1,
+        }
+    }
+    Scanned {
+        comments,
+        terminals_pos,
+        precedence_pos,
+        block_ends,
+    }
+}
+
+fn comment(src: &str, start: usize, end: usize) -> Comment<'_> {
+    let line_start = src[..start].rfind('\n').map_or(0, |p| p + 1);
+    Comment {
+        pos: start,
+        text: src[start..end.min(src.len())].trim_end(),
+        trailing: !src[line_start..start].trim().is_empty(),
+    }
+}
@@ -0,0 +1,518 @@
+//! Checking existing actions against the actions generated for the grammar.
+//!
+//! Types and actions in the actions file are maintained by the user. When the
+//! grammar changes, existing items may no longer match what the parser expects.
+//! Only structural differences are checked: signatures of actions (parameter
+//! names and bodies are ignored) and fields/variants of structs/enums. Type
+//! aliases and items whose kind has been changed by the user (e.g. an enum
+//! replaced by a type alias) are considered customizations and not checked.
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{parse_quote, FnArg, Item};
+
+/// The name of the module where orphaned items are moved during migration.
+const ORPHANED_MOD: &str = "orphaned";
+
+/// Type aliases from the header of the actions file.
+const HEADER_TYPES: [&str; 3] = ["Input", "Ctx", "Token"];
+
+#[derive(Debug)]
+pub(crate) enum ActionsIssue {
+    /// An existing item differs from the generated one.
+    Mismatch { name: String, diff: String },
+    /// An existing type or action is no longer used by the parser.
+    Orphan { name: String },
+}
+
+impl Display for ActionsIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionsIssue::Mismatch { name, diff } => {
+                write!(f, "'{name}' differs from the grammar:\n{diff}")
+            }
+            ActionsIssue::Orphan { name } => write!(f, "'{name}' is not used by the parser."),
+        }
+    }
+}
+
+/// Checks the existing items in the actions file against the `generated` ones.
+/// If `migrate` is set, mismatched items are updated and orphaned items are
+/// moved to the `orphaned` module.
+pub(crate) fn check_actions(
+    ast: &mut syn::File,
+    generated: &[Item],
+    migrate: bool,
+) -> Vec<ActionsIssue> {
+    let mut issues = vec![];
+
+    for item in ast.items.iter_mut() {
+        let Some(name) = item_name(item) else {
+            continue;
+        };
+        let Some(new) = generated.iter().find(|g| {
+            item_name(g).as_ref() == Some(&name)
+                && matches!(g, Item::Fn(_)) == matches!(item, Item::Fn(_))
+        }) else {
+            continue;
+        };
+        if let Some(old_shape) = shape(item) {
+            let Some(new_shape) = shape(new) else {
+                continue;
+            };
+            if !same_kind(item, new) || old_shape == new_shape {
+                continue;
+            }
+            issues.push(ActionsIssue::Mismatch {
+                name: name.clone(),
+                diff: diff(&render(item), &render(new)),
+            });
+            if migrate {
+                migrate_item(item, new);
+            }
+        }
+    }
+
+    let generated_names: BTreeSet<String> = generated.iter().filter_map(item_name).collect();
+    let orphans = orphans(ast, &generated_names);
+    for &idx in &orphans {
+        issues.push(ActionsIssue::Orphan {
+            name: item_name(&ast.items[idx]).unwrap(),
+        });
+    }
+    if migrate && !orphans.is_empty() {
+        let mut orphaned = vec![];
+        for idx in orphans.into_iter().rev() {
+            orphaned.insert(0, ast.items.remove(idx));
+        }
+        let existing = ast.items.iter_mut().find_map(|item| match item {
+            Item::Mod(m) if m.ident == ORPHANED_MOD => m.content.as_mut(),
+            _ => None,
+        });
+        if let Some((_, items)) = existing {
+            items.extend(orphaned);
+        } else {
+            let ident = quote::format_ident!("{ORPHANED_MOD}");
+            ast.items.push(parse_quote! {
+                /// Items no longer used by the parser. Excluded from compilation.
+                #[cfg(any())]
+                mod #ident {
+                    #(#orphaned)*
+                }
+            });
+        }
+    }
+
+    issues
+}
+
+fn item_name(item: &Item) -> Option<String> {
+    match item {
+        Item::Fn(f) => Some(f.sig.ident.to_string()),
+        Item::Struct(s) => Some(s.ident.to_string()),
+        Item::Enum(e) => Some(e.ident.to_string()),
+        Item::Type(t) => Some(t.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn same_kind(first: &Item, second: &Item) -> bool {
+    std::mem::discriminant(first) == std::mem::discriminant(second)
+}
+
+/// A string representing the checked structure of the item. `None` for items
+/// which are not checked.
+fn shape(item: &Item) -> Option<String> {
+    match item {
+        Item::Fn(f) => {
+            let inputs = f.sig.inputs.iter().map(|input| match input {
+                FnArg::Typed(pat_type) => pat_type.ty.to_token_stream().to_string(),
+                FnArg::Receiver(r) => r.to_token_stream().to_string(),
+            });
+            Some(format!(
+                "({}) {}",
+                inputs.collect::<Vec<_>>().join(", "),
+                f.sig.output.to_token_stream()
+            ))
+        }
+        Item::Struct(s) => Some(format!(
+            "{} {}",
+            s.generics.to_token_stream(),
+            s.fields.to_token_stream()
+        )),
+        Item::Enum(e) => Some(format!(
+            "{} {}",
+            e.generics.to_token_stream(),
+            e.variants.to_token_stream()
+        )),
+        _ => None,
+    }
+}
+
+/// Renders the checked part of the item. Attributes and bodies are omitted.
+fn render(item: &Item) -> String {
+    let mut item = item.clone();
+    match &mut item {
+        Item::Fn(f) => {
+            f.attrs.clear();
+            f.block = parse_quote!({});
+        }
+        Item::Struct(s) => s.attrs.clear(),
+        Item::Enum(e) => e.attrs.clear(),
+        _ => (),
+    }
+    prettyplease::unparse(&parse_quote!(#item))
+}
+
+fn migrate_item(item: &mut Item, new: &Item) {
+    match (item, new) {
+        (Item::Fn(old), Item::Fn(new)) => migrate_fn(old, &new.sig),
+        (Item::Struct(old), Item::Struct(new)) => {
+            old.generics = new.generics.clone();
+            old.fields = new.fields.clone();
+            old.semi_token = new.semi_token;
+        }
+        (Item::Enum(old), Item::Enum(new)) => {
+            old.generics = new.generics.clone();
+            old.variants = new.variants.clone();
+        }
+        _ => (),
+    }
+}
+
+/// A doc comment added to migrated actions whose parameters can't all be kept.
+const REVIEW_NOTE: &str = " TODO: Parameters changed with the grammar. Review the body.";
+
+/// Migrates the `old` action to the signature of the `new` action. Parameters
+/// are matched by type in order and the names of matched `old` parameters are
+/// kept as they are used in the kept body. Other parameters get generated
+/// names, made unique if needed, and the action is marked for review.
+fn migrate_fn(old: &mut syn::ItemFn, new: &syn::Signature) {
+    let ty = |input: &FnArg| match input {
+        FnArg::Typed(pat_type) => pat_type.ty.to_token_stream().to_string(),
+        FnArg::Receiver(r) => r.to_token_stream().to_string(),
+    };
+    let old_types: Vec<String> = old.sig.inputs.iter().map(ty).collect();
+    let new_types: Vec<String> = new.inputs.iter().map(ty).collect();
+    let matches = lcs_matches(&old_types, &new_types);
+
+    let mut sig = new.clone();
+    let mut names = BTreeSet::new();
+    for (new_idx, old_idx) in matches.iter().enumerate() {
+        if let (Some(old_idx), FnArg::Typed(input)) = (old_idx, &mut sig.inputs[new_idx]) {
+            if let Some(FnArg::Typed(old_input)) = old.sig.inputs.iter().nth(*old_idx) {
+                input.pat = old_input.pat.clone();
+                names.insert(input.pat.to_token_stream().to_string());
+            }
+        }
+    }
+    for (idx, input) in sig.inputs.iter_mut().enumerate() {
+        let FnArg::Typed(input) = input else {
+            continue;
+        };
+        if matches[idx].is_some() {
+            continue;
+        }
+        let name = input.pat.to_token_stream().to_string();
+        if names.contains(&name) {
+            let unique = quote::format_ident!("{name}_{idx}");
+            input.pat = parse_quote!(#unique);
+        }
+        names.insert(input.pat.to_token_stream().to_string());
+    }
+    old.sig = sig;
+
+    let all_kept = old_types.len() == new_types.len() && matches.iter().all(Option::is_some);
+    let note: syn::Attribute = parse_quote!(#[doc = #REVIEW_NOTE]);
+    let note_str = note.to_token_stream().to_string();
+    if !all_kept
+        && !old
+            .attrs
+            .iter()
+            .any(|attr| attr.to_token_stream().to_string() == note_str)
+    {
+        old.attrs.push(note);
+    }
+}
+
+/// For each element of `new` the index of the matched element of `old` in the
+/// longest common subsequence of the two.
+fn lcs_matches(old: &[String], new: &[String]) -> Vec<Option<usize>> {
+    let lcs = lcs_table(old, new);
+    let mut matches = vec![None; new.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            matches[j] = Some(i);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+/// Longest common subsequence lengths of suffixes of the given sequences.
+fn lcs_table<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Vec<usize>> {
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    lcs
+}
+
+/// Finds indexes of types and actions which are not generated for the grammar
+/// nor used by other items.
+fn orphans(ast: &syn::File, generated_names: &BTreeSet<String>) -> Vec<usize> {
+    let mut candidates: BTreeSet<usize> = ast
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            let Some(name) = item_name(item) else {
+                return false;
+            };
+            if generated_names.contains(&name) || HEADER_TYPES.contains(&name.as_str()) {
+                return false;
+            }
+            match item {
+                // Only functions with the signature of actions are considered.
+                Item::Fn(f) => matches!(f.sig.inputs.first(),
+                    Some(FnArg::Typed(arg)) if arg.ty.to_token_stream().to_string().starts_with("& Ctx")),
+                _ => true,
+            }
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+
+    // Candidates used by other items are not orphans.
+    loop {
+        let mut used = BTreeSet::new();
+        for (idx, item) in ast.items.iter().enumerate() {
+            let is_orphaned_mod = matches!(item, Item::Mod(m) if m.ident == ORPHANED_MOD);
+            if !candidates.contains(&idx) && !is_orphaned_mod {
+                collect_idents(item.to_token_stream(), &mut used);
+            }
+        }
+        let before = candidates.len();
+        candidates.retain(|&idx| !used.contains(&item_name(&ast.items[idx]).unwrap()));
+        if candidates.len() == before {
+            break;
+        }
+    }
+    candidates.into_iter().collect()
+}
+
+fn collect_idents(tokens: TokenStream, idents: &mut BTreeSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => (),
+        }
+    }
+}
+
+/// A line diff of the given strings.
+fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let lcs = lcs_table(&old, &new);
+
+    // Removed lines are given before the added ones.
+    let mut result = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            result.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            result.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            result.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    result.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use crate::output_cmp;
+
+    use super::check_actions;
+
+    fn existing() -> syn::File {
+        parse_quote! {
+            use super::calc::{TokenKind, Context};
+            pub type Input = str;
+            pub type Ctx<'i> = Context<'i, Input>;
+            pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+            /// Number is customized.
+            pub type Number = f32;
+            pub fn number(_ctx: &Ctx, token: Token) -> Number {
+                token.value.parse().unwrap()
+            }
+            #[derive(Debug, Clone)]
+            pub struct Add {
+                pub e_1: Box<E>,
+                pub e_3: Box<E>,
+            }
+            #[derive(Debug, Clone)]
+            pub enum E {
+                Add(Add),
+                Number(Number),
+            }
+            pub fn e_add(_ctx: &Ctx, left: E, right: E) -> E {
+                E::Add(Add { e_1: Box::new(left), e_3: Box::new(right) })
+            }
+            pub fn e_number(_ctx: &Ctx, number: Number) -> E {
+                E::Number(number)
+            }
+            pub fn e_paren(_ctx: &Ctx, e: E) -> E {
+                e
+            }
+            pub fn helper(value: &Number) -> Number {
+                *value
+            }
+            pub fn e_neg(_ctx: &Ctx, e: E) -> E {
+                helper(&e)
+            }
+        }
+    }
+
+    fn generated() -> Vec<syn::Item> {
+        let file: syn::File = parse_quote! {
+            pub type Number = String;
+            pub fn number(_ctx: &Ctx, token: Token) -> Number {
+                token.value.into()
+            }
+            #[derive(Debug, Clone)]
+            pub struct Add {
+                pub e_1: Box<E>,
+                pub e_3: Box<E>,
+            }
+            #[derive(Debug, Clone)]
+            pub struct Mul {
+                pub e_1: Box<E>,
+                pub e_3: Box<E>,
+            }
+            #[derive(Debug, Clone)]
+            pub enum E {
+                Add(Add),
+                Mul(Mul),
+                Number(Number),
+            }
+            pub fn e_add(_ctx: &Ctx, e_1: E, plus: Token, e_3: E) -> E {
+                E::Add(Add { e_1: Box::new(e_1), e_3: Box::new(e_3) })
+            }
+            pub fn e_mul(_ctx: &Ctx, e_1: E, e_3: E) -> E {
+                E::Mul(Mul { e_1: Box::new(e_1), e_3: Box::new(e_3) })
+            }
+            pub fn e_number(_ctx: &Ctx, n: Number) -> E {
+                E::Number(n)
+            }
+        };
+        file.items
+    }
+
+    #[test]
+    fn actions_check() {
+        let mut ast = existing();
+        let issues = check_actions(&mut ast, &generated(), false);
+        output_cmp!(
+            "src/generator/actions/actions_check.expected",
+            issues
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        );
+        // The file is not changed.
+        assert_eq!(
+            prettyplease::unparse(&ast),
+            prettyplease::unparse(&existing())
+        );
+    }
+
+    #[test]
+    fn actions_migrate_keeps_names() {
+        let mut ast: syn::File = parse_quote! {
+            pub fn e_add(ctx: &Ctx, left: E, right: E) -> E {
+                combine(ctx, left, right)
+            }
+            pub fn e_neg(ctx: &Ctx, operand: E) -> E {
+                negate(ctx, operand)
+            }
+            pub fn e_num(ctx: &Ctx, num: Num) -> E {
+                E::Num(num)
+            }
+        };
+        let generated: syn::File = parse_quote! {
+            pub fn e_add(_ctx: &Ctx, e_1: E, plus: Token, e_3: E) -> E {
+                E::Add(Add { e_1: Box::new(e_1), e_3: Box::new(e_3) })
+            }
+            pub fn e_neg(_ctx: &Ctx, minus: Token) -> E {
+                E::Neg(minus)
+            }
+            pub fn e_num(_ctx: &Ctx, num: Num) -> E {
+                E::Num(num)
+            }
+        };
+        check_actions(&mut ast, &generated.items, true);
+        // Names are kept for parameters matched by type. Others get the
+        // generated names and the action is marked for review.
+        let migrated: syn::File = parse_quote! {
+            /// TODO: Parameters changed with the grammar. Review the body.
+            pub fn e_add(ctx: &Ctx, left: E, plus: Token, right: E) -> E {
+                combine(ctx, left, right)
+            }
+            /// TODO: Parameters changed with the grammar. Review the body.
+            pub fn e_neg(ctx: &Ctx, minus: Token) -> E {
+                negate(ctx, operand)
+            }
+            pub fn e_num(ctx: &Ctx, num: Num) -> E {
+                E::Num(num)
+            }
+        };
+        assert_eq!(
+            prettyplease::unparse(&ast),
+            prettyplease::unparse(&migrated)
+        );
+    }
+
+    #[test]
+    fn actions_migrate() {
+        let mut ast = existing();
+        check_actions(&mut ast, &generated(), true);
+        output_cmp!(
+            "src/generator/actions/actions_migrate.expected",
+            prettyplease::unparse(&ast)
+        );
+
+        // Migration is idempotent and keeps previously orphaned items.
+        let migrated = prettyplease::unparse(&ast);
+        let issues = check_actions(&mut ast, &generated(), true);
+        assert!(issues.is_empty());
+        assert_eq!(prettyplease::unparse(&ast), migrated);
+    }
+}
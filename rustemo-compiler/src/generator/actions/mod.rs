@@ -3,10 +3,15 @@
 //! Provides default semantics actions implementation but allow for manual
 //! changes.
 
-use std::{collections::BTreeSet, path::PathBuf};
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
 
 use quote::format_ident;
+use rustemo::WARN;
 use syn::{self, parse_quote};
+use yansi::Paint;
 
 use crate::{
     error::Result,
@@ -15,26 +20,57 @@ use crate::{
     Error,
 };
 
+pub(crate) use self::production::arena_field;
+
+use self::check::{check_actions, ActionsIssue};
+use self::spanned::{spanned_mod, SPANNED_MOD};
+use self::unparse::{unparse_mod, UNPARSE_MOD};
+pub(crate) use self::visitor::visitor_mod;
 use super::ParserGenerator;
 
+mod check;
 mod production;
+mod spanned;
+mod unparse;
+mod visitor;
 
 pub(crate) trait ActionsGenerator {
     fn terminal_type(&self, terminal: &Terminal, settings: &Settings) -> syn::Item {
         let type_name = format_ident!("{}", terminal.name);
+        let value_type = terminal_value(terminal)
+            .map(|(value_type, _)| value_type)
+            .unwrap_or_else(|| parse_quote! { String });
         if settings.builder_loc_info {
             parse_quote! {
-                pub type #type_name = ValSpan<String>;
+                pub type #type_name = ValSpan<#value_type>;
             }
         } else {
             parse_quote! {
-                pub type #type_name = String;
+                pub type #type_name = #value_type;
             }
         }
     }
     fn terminal_action(&self, terminal: &Terminal, settings: &Settings) -> syn::Item {
         let type_name = format_ident!("{}", terminal.name);
         let action_name = format_ident!("{}", to_snake_case(&terminal.name));
+        if let Some((_, conversion)) = terminal_value(terminal) {
+            let body: syn::Expr = if settings.builder_loc_info {
+                parse_quote! {
+                    #conversion
+                        .map(|value| #type_name::new(value, Some(_ctx.span())))
+                        .map_err(|e| rustemo::token_value_error(_ctx, token.value, e))
+                }
+            } else {
+                parse_quote! {
+                    #conversion.map_err(|e| rustemo::token_value_error(_ctx, token.value, e))
+                }
+            };
+            return parse_quote! {
+                pub fn #action_name(_ctx: &Ctx, token: Token) -> rustemo::Result<#type_name> {
+                    #body
+                }
+            };
+        }
         let body: syn::Expr = if settings.builder_loc_info {
             parse_quote! { #type_name::new(token.value.into(), Some(_ctx.span())) }
         } else {
@@ -58,22 +94,130 @@ pub(crate) trait ActionsGenerator {
     ) -> Vec<(String, syn::Item)>;
 }
 
-pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<()> {
+/// Built-in conversions of terminal values given by `type` meta-data.
+pub(crate) const BUILTIN_VALUE_TYPES: [&str; 4] = ["int", "float", "bool", "string"];
+
+/// The Rust type and the conversion of the terminal value if the terminal has a
+/// `type` meta-data.
+fn terminal_value(terminal: &Terminal) -> Option<(syn::Type, syn::Expr)> {
+    let value_type: syn::Type = match terminal.value_type.as_deref()? {
+        "int" => parse_quote! { i64 },
+        "float" => parse_quote! { f64 },
+        "bool" => parse_quote! { bool },
+        "string" => {
+            return Some((
+                parse_quote! { String },
+                parse_quote! { rustemo::unescape(token.value) },
+            ))
+        }
+        value_type => syn::parse_str(value_type).expect("Terminal value types are checked."),
+    };
+    let conversion = parse_quote! { token.value.parse::<#value_type>() };
+    Some((value_type, conversion))
+}
+
+fn actions_file(generator: &ParserGenerator) -> PathBuf {
+    let mut file_name = String::from(&generator.file_name);
+    file_name.push_str("_actions.rs");
+    generator.out_dir_actions.join(file_name)
+}
+
+/// Returns the actions module written to the actions file.
+pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<syn::File> {
+    let action_file = actions_file(generator);
+
+    let existing = if action_file.exists() && !generator.settings.force {
+        log!("Parsing action file with Syn: {:?}", action_file);
+        Some(syn::parse_file(&std::fs::read_to_string(&action_file)?)?)
+    } else {
+        log!("Creating: {:?}", action_file);
+        None
+    };
+    let (ast, issues) = parser_actions(generator, existing);
+    if !issues.is_empty() {
+        print_issues(&action_file, &issues, generator.settings);
+    }
+
+    println!("Writing actions file {action_file:?}");
+    std::fs::create_dir_all(&generator.out_dir_actions).map_err(|e| {
+        Error::Error(format!(
+            "Cannot create directories for path '{:?}': {e:?}.",
+            generator.out_dir_actions
+        ))
+    })?;
+    std::fs::write(action_file, prettyplease::unparse(&ast))?;
+
+    Ok(ast)
+}
+
+/// Returns the actions module used by the parser when actions are not
+/// generated. This is the existing actions file or the module which would be
+/// generated if there is none.
+pub(super) fn existing_parser_actions(generator: &ParserGenerator) -> Result<syn::File> {
+    let action_file = actions_file(generator);
+    Ok(if action_file.exists() {
+        syn::parse_file(&std::fs::read_to_string(&action_file)?)?
+    } else {
+        parser_actions(generator, None).0
+    })
+}
+
+/// Prints issues found in the existing actions file. Issues are given as
+/// `cargo:warning` lines if [Settings::cargo_warnings] is set, as grammar
+/// warnings are.
+fn print_issues(action_file: &Path, issues: &[ActionsIssue], settings: &Settings) {
+    let migrated = settings.migrate_actions;
+    let header = format!(
+        "{} {action_file:?}",
+        if migrated {
+            "Migrated actions file"
+        } else {
+            "Actions file is out of sync with the grammar"
+        }
+    );
+    let hint = (!migrated).then_some(
+        "Use `--migrate-actions` (`Settings::migrate_actions`) to update signatures \
+         and fields and move unused items to the `orphaned` module.",
+    );
+    if settings.cargo_warnings {
+        let issues = issues.iter().map(|issue| issue.to_string());
+        for text in std::iter::once(header)
+            .chain(issues)
+            .chain(hint.map(String::from))
+        {
+            for line in text.lines() {
+                println!("cargo:warning={line}");
+            }
+        }
+    } else {
+        println!("{}", header.paint(WARN));
+        for issue in issues {
+            println!("{issue}");
+        }
+        if let Some(hint) = hint {
+            println!("{}", hint.paint(WARN));
+        }
+    }
+}
+
+/// Generates the actions module by adding missing types and actions to the
+/// `existing` actions or to a new module if `None`. Returns the issues found in
+/// the `existing` actions.
+pub(super) fn parser_actions(
+    generator: &ParserGenerator,
+    existing: Option<syn::File>,
+) -> (syn::File, Vec<ActionsIssue>) {
+    let is_existing = existing.is_some();
     let parser_mod = PathBuf::from(&generator.file_name)
         .file_stem()
         .unwrap()
         .to_string_lossy()
         .to_string();
-    let mut file_name = String::from(&generator.file_name);
-    file_name.push_str("_actions.rs");
-    let action_file = generator.out_dir_actions.join(file_name);
 
-    let mut ast = if action_file.exists() && !generator.settings.force {
-        log!("Parsing action file with Syn: {:?}", action_file);
-        syn::parse_file(&std::fs::read_to_string(&action_file)?)?
+    let mut ast = if let Some(existing) = existing {
+        existing
     } else {
         // Create new empty file with common uses statements.
-        log!("Creating: {:?}", action_file);
         let lexer_mod = format_ident!("{parser_mod}_lexer");
         let parser_mod = format_ident!("{}", parser_mod);
         let input_type: syn::Stmt = match generator.settings.lexer_type {
@@ -86,7 +230,10 @@ pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<()>
         };
         let mut base_use: Vec<syn::Stmt> = vec![];
         if generator.settings.builder_loc_info {
-            base_use.push(parse_quote! {use rustemo::{ValSpan, Context as C};})
+            base_use.push(parse_quote! {use rustemo::{ValSpan, SourceSpan, Context as _};})
+        };
+        if generator.settings.builder_arena {
+            base_use.push(parse_quote! {use rustemo::Id;})
         };
         base_use.push(parse_quote! {use rustemo::Token as RustemoToken;});
         base_use.push(parse_quote! {use super::#parser_mod::{TokenKind, Context};});
@@ -140,6 +287,9 @@ pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<()>
         generator.types.as_ref().unwrap(),
     );
 
+    // All items generated for the grammar. Used to check the existing items.
+    let mut generated = vec![];
+
     // Generate types and actions for terminals
     generator
         .grammar
@@ -149,18 +299,20 @@ pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<()>
         .for_each(|terminal| {
             // Add terminal types
             let type_name = &terminal.name;
+            let ty = actions_generator.terminal_type(terminal, generator.settings);
             if !type_names.contains(type_name) {
                 log!("Create type for terminal '{type_name}'.");
-                ast.items
-                    .push(actions_generator.terminal_type(terminal, generator.settings));
+                ast.items.push(ty.clone());
             }
+            generated.push(ty);
             // Add terminal actions
             let action_name = to_snake_case(&terminal.name);
+            let action = actions_generator.terminal_action(terminal, generator.settings);
             if !action_names.contains(&action_name) {
                 log!("Create action function for terminal '{type_name}'.");
-                ast.items
-                    .push(actions_generator.terminal_action(terminal, generator.settings))
+                ast.items.push(action.clone())
             }
+            generated.push(action);
         });
 
     // Generate types and actions for non-terminals
@@ -171,12 +323,12 @@ pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<()>
         .filter(|nt| nt.reachable.get())
         .for_each(|nonterminal| {
             // Add non-terminal type
+            let types = actions_generator.nonterminal_types(nonterminal, generator.settings);
             if !type_names.contains(&nonterminal.name) {
                 log!("Creating types for non-terminal '{}'.", nonterminal.name);
-                for ty in actions_generator.nonterminal_types(nonterminal, generator.settings) {
-                    ast.items.push(ty);
-                }
+                ast.items.extend(types.iter().cloned());
             }
+            generated.extend(types);
 
             // Add non-terminal actions
             for (action_name, action) in
@@ -184,19 +336,52 @@ pub(super) fn generate_parser_actions(generator: &ParserGenerator) -> Result<()>
             {
                 if !action_names.contains(&action_name) {
                     log!("Creating action '{action_name}'.");
-                    ast.items.push(action);
+                    ast.items.push(action.clone());
                 }
+                generated.push(action);
             }
         });
 
-    println!("Writing actions file {action_file:?}");
-    std::fs::create_dir_all(&generator.out_dir_actions).map_err(|e| {
-        Error::Error(format!(
-            "Cannot create directories for path '{:?}': {e:?}.",
-            generator.out_dir_actions
-        ))
-    })?;
-    std::fs::write(action_file, prettyplease::unparse(&ast))?;
+    // Add the arena for recursive values
+    if generator.settings.builder_arena {
+        let arena = production::arena_type(generator.types.as_ref().unwrap(), generator.settings);
+        if !type_names.contains("AstArena") {
+            log!("Creating arena type.");
+            ast.items.push(arena.clone());
+        }
+        generated.push(arena);
+    }
 
-    Ok(())
+    let issues = if is_existing {
+        check_actions(&mut ast, &generated, generator.settings.migrate_actions)
+    } else {
+        vec![]
+    };
+
+    // Generated modules are always regenerated in place for the current types.
+    if generator.settings.builder_loc_info {
+        let spanned = spanned_mod(&ast.items);
+        replace_mod(&mut ast, SPANNED_MOD, spanned);
+    }
+    if generator.settings.unparse {
+        let unparse = unparse_mod(
+            generator.grammar,
+            generator.types.as_ref().unwrap(),
+            generator.settings,
+        );
+        replace_mod(&mut ast, UNPARSE_MOD, unparse);
+    }
+    (ast, issues)
+}
+
+/// Replaces the module with the given name or adds it at the end of the file.
+fn replace_mod(ast: &mut syn::File, name: &str, module: syn::Item) {
+    match ast
+        .items
+        .iter_mut()
+        .find(|item| matches!(item, syn::Item::Mod(m) if m.ident == name))
+    {
+        Some(existing) => *existing = module,
+        None => ast.items.push(module),
+    }
 }
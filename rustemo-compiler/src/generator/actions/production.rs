@@ -4,7 +4,9 @@ use syn::{parse::Parser, parse_quote};
 use crate::{
     generator::action_name,
     grammar::{
-        types::{to_snake_case, Choice, ChoiceKind, SymbolType, SymbolTypeKind, SymbolTypes},
+        types::{
+            to_snake_case, Choice, ChoiceKind, Field, SymbolType, SymbolTypeKind, SymbolTypes,
+        },
         Grammar, NonTerminal,
     },
     settings::Settings,
@@ -12,16 +14,6 @@ use crate::{
 
 use super::ActionsGenerator;
 
-macro_rules! name_valloc {
-    ($base_str:expr, $s:ident) => {
-        if $s.builder_loc_info {
-            format!("{}Base", $base_str)
-        } else {
-            $base_str.to_string()
-        }
-    };
-}
-
 pub(crate) struct ProductionActionsGenerator<'t> {
     types: &'t SymbolTypes,
     term_len: usize,
@@ -37,8 +29,16 @@ impl<'t> ProductionActionsGenerator<'t> {
         })
     }
 
-    fn get_action_args(&self, ty: &SymbolType, choice: &Choice) -> Vec<syn::FnArg> {
+    fn get_action_args(
+        &self,
+        ty: &SymbolType,
+        choice: &Choice,
+        settings: &Settings,
+    ) -> Vec<syn::FnArg> {
         let mut fn_args: Vec<syn::FnArg> = vec![];
+        if settings.builder_arena && ty.boxes(choice) {
+            fn_args.push(parse_quote! { arena: &mut AstArena });
+        }
 
         match &choice.kind {
             ChoiceKind::Plain => (), // No args for plain enum
@@ -75,50 +75,41 @@ impl<'t> ProductionActionsGenerator<'t> {
         settings: &Settings,
     ) -> syn::Expr {
         let target_type = format_ident!("{target_type}");
-        let target_type_base = format_ident!("{target_type}Base");
         let choice_ident = format_ident!("{}", choice.name);
         let expr: syn::Expr = match &choice.kind {
             ChoiceKind::Plain => {
-                parse_quote! { #target_type::#choice_ident }
+                if settings.builder_loc_info {
+                    parse_quote! { #target_type::#choice_ident(_ctx.span()) }
+                } else {
+                    parse_quote! { #target_type::#choice_ident }
+                }
             }
             ChoiceKind::Struct { type_name, fields } => {
                 let struct_ty = format_ident!("{type_name}");
-                let struct_ty_base = format_ident!("{type_name}Base");
-                let fields: Vec<syn::FieldValue> = fields
+                let mut fields: Vec<syn::FieldValue> = fields
                     .iter()
                     .map(|f| {
                         let field = format_ident!("{}", f.name);
                         if f.recursive.get() {
-                            parse_quote! { #field: Box::new(#field) }
+                            let value =
+                                boxed_value(&f.ref_type, parse_quote! { #field }, settings);
+                            parse_quote! { #field: #value }
                         } else {
                             parse_quote! { #field }
                         }
                     })
                     .collect();
+                if settings.builder_loc_info {
+                    fields.push(parse_quote! { span: _ctx.span() });
+                }
 
                 if matches!(ty.kind, SymbolTypeKind::Enum { .. }) {
-                    if settings.builder_loc_info {
-                        parse_quote! {
-                            #target_type::#choice_ident(
-                                #struct_ty::new(#struct_ty_base {
-                                    #(#fields),*
-                                }, Some(_ctx.span()))
-                            )
-                        }
-                    } else {
-                        parse_quote! {
-                            #target_type::#choice_ident(
-                                #struct_ty {
-                                    #(#fields),*
-                                }
-                            )
-                        }
-                    }
-                } else if settings.builder_loc_info {
                     parse_quote! {
-                        #target_type::new(#target_type_base {
-                            #(#fields),*
-                        }, Some(_ctx.span()))
+                        #target_type::#choice_ident(
+                            #struct_ty {
+                                #(#fields),*
+                            }
+                        )
                     }
                 } else {
                     parse_quote! {
@@ -136,12 +127,16 @@ impl<'t> ProductionActionsGenerator<'t> {
                 let mut ref_type_var: syn::Expr = parse_quote! { #ref_type_var_ident };
 
                 if recursive.get() {
-                    ref_type_var = parse_quote! { Box::new(#ref_type_var) }
+                    ref_type_var = boxed_value(ref_type, ref_type_var, settings)
                 }
                 if matches!(&ty.kind, SymbolTypeKind::Ref { .. }) {
                     parse_quote! {
                         #ref_type_var
                     }
+                } else if settings.builder_loc_info {
+                    parse_quote! {
+                        #target_type::#choice_ident(#ref_type_var, _ctx.span())
+                    }
                 } else {
                     parse_quote! {
                         #target_type::#choice_ident(#ref_type_var)
@@ -159,12 +154,87 @@ impl<'t> ProductionActionsGenerator<'t> {
     }
 }
 
+/// The type of a value boxed to break a recursion. With `builder_arena` the
+/// value is allocated in the arena.
+fn boxed_type(ref_type: &str, settings: &Settings) -> syn::Type {
+    let ref_type = format_ident!("{ref_type}");
+    if settings.builder_arena {
+        parse_quote! { Id<#ref_type> }
+    } else {
+        parse_quote! { Box<#ref_type> }
+    }
+}
+
+/// Boxes the value of the given type or allocates it in the arena.
+fn boxed_value(ref_type: &str, value: syn::Expr, settings: &Settings) -> syn::Expr {
+    if settings.builder_arena {
+        let field = arena_field(ref_type);
+        parse_quote! { arena.#field.alloc(#value) }
+    } else {
+        parse_quote! { Box::new(#value) }
+    }
+}
+
+/// The field of `AstArena` holding the values of the given type.
+pub(crate) fn arena_field(ref_type: &str) -> syn::Ident {
+    let field = to_snake_case(ref_type);
+    if syn::parse_str::<syn::Ident>(&field).is_ok() {
+        format_ident!("{field}")
+    } else {
+        // Keywords
+        format_ident!("{field}_")
+    }
+}
+
+/// The `AstArena` type holding values of all boxed types.
+pub(crate) fn arena_type(types: &SymbolTypes, settings: &Settings) -> syn::Item {
+    let fields = types.boxed_types().into_iter().map(|ref_type| {
+        let field = arena_field(&ref_type);
+        let ref_type = format_ident!("{ref_type}");
+        quote! { pub #field: rustemo::Arena<#ref_type> }
+    });
+    let serde = settings
+        .serde
+        .then(|| quote! { , serde::Serialize, serde::Deserialize });
+    parse_quote! {
+        /// Values of recursive AST types referenced by `Id`.
+        #[derive(Debug, Clone, Default #serde)]
+        pub struct AstArena {
+            #(#fields),*
+        }
+    }
+}
+
+/// The derive attribute for the types of the given non-terminal. `Debug` and
+/// `Clone` are always derived, followed by the derives from the settings and
+/// the non-terminal meta-data.
+fn derive_attr(nonterminal: &NonTerminal, settings: &Settings) -> syn::Attribute {
+    let serde = ["serde::Serialize", "serde::Deserialize"];
+    let mut derives: Vec<&str> = vec!["Debug", "Clone"];
+    for derive in settings
+        .derives
+        .iter()
+        .map(String::as_str)
+        .chain(settings.serde.then_some(serde).into_iter().flatten())
+        .chain(nonterminal.derives.iter().map(String::as_str))
+    {
+        if !derives.contains(&derive) {
+            derives.push(derive);
+        }
+    }
+    let derives = derives
+        .into_iter()
+        .map(|derive| syn::parse_str::<syn::Path>(derive).expect("Derives are checked."));
+    parse_quote! { #[derive(#(#derives),*)] }
+}
+
 impl ActionsGenerator for ProductionActionsGenerator<'_> {
     fn nonterminal_types(&self, nonterminal: &NonTerminal, settings: &Settings) -> Vec<syn::Item> {
         let ty = self
             .types
             .get_type(nonterminal.idx.symbol_index(self.term_len));
         let type_ident = format_ident!("{}", ty.name);
+        let derive = derive_attr(nonterminal, settings);
 
         let get_choice_type =
             |choice: &Choice, type_name: Option<&str>| -> Option<Vec<syn::Item>> {
@@ -178,9 +248,9 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                         } else {
                             struct_type.into()
                         };
-                        let type_ident = format_ident!("{}", name_valloc!(type_name, settings));
+                        let type_ident = format_ident!("{type_name}");
 
-                        let fields: Vec<syn::Field> = fields
+                        let mut fields: Vec<syn::Field> = fields
                             .iter()
                             .map(|f| {
                                 let field_name = format_ident!("{}", f.name);
@@ -188,28 +258,28 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                                 syn::Field::parse_named
                                     .parse2(if f.recursive.get() {
                                         // Handle direct recursion
-                                        quote! { pub #field_name: Box<#field_type> }
+                                        let field_type = boxed_type(&f.ref_type, settings);
+                                        quote! { pub #field_name: #field_type }
                                     } else {
                                         quote! {pub #field_name: #field_type}
                                     })
                                     .unwrap()
                             })
                             .collect();
+                        if settings.builder_loc_info {
+                            fields.push(
+                                syn::Field::parse_named
+                                    .parse2(quote! { pub span: SourceSpan })
+                                    .unwrap(),
+                            );
+                        }
 
-                        let mut types = vec![];
-                        types.push(parse_quote! {
-                            #[derive(Debug, Clone)]
+                        Some(vec![parse_quote! {
+                            #derive
                             pub struct #type_ident {
                                 #(#fields),*
                             }
-                        });
-                        if settings.builder_loc_info {
-                            let type_ident_loc = format_ident!("{type_name}");
-                            types.push(parse_quote! {
-                                pub type #type_ident_loc = ValSpan<#type_ident>;
-                            });
-                        }
-                        Some(types)
+                        }])
                     }
                     _ => None,
                 }
@@ -229,6 +299,9 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                 .filter_map(|v| {
                     let variant_ident = format_ident!("{}", v.name);
                     match &v.kind {
+                        ChoiceKind::Plain if settings.builder_loc_info => {
+                            Some(parse_quote! { #variant_ident(SourceSpan) })
+                        }
                         ChoiceKind::Plain => Some(parse_quote! { #variant_ident }),
                         ChoiceKind::Struct { type_name, .. } => {
                             let type_ident = format_ident!("{type_name}");
@@ -238,13 +311,17 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                             ref_type,
                             recursive,
                         } => {
-                            let ref_type = format_ident!("{ref_type}");
                             let ref_type: syn::Type = if recursive.get() {
-                                parse_quote! { Box<#ref_type> }
+                                boxed_type(ref_type, settings)
                             } else {
+                                let ref_type = format_ident!("{ref_type}");
                                 parse_quote! { #ref_type }
                             };
-                            Some(parse_quote! { #variant_ident(#ref_type) })
+                            if settings.builder_loc_info {
+                                Some(parse_quote! { #variant_ident(#ref_type, SourceSpan) })
+                            } else {
+                                Some(parse_quote! { #variant_ident(#ref_type) })
+                            }
                         }
                         ChoiceKind::Empty => None,
                     }
@@ -264,7 +341,7 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                     types.push(parse_quote! {pub type #type_ident = Option<#enum_type>;});
                 }
                 types.push(parse_quote! {
-                    #[derive(Debug, Clone)]
+                    #derive
                     pub enum #enum_type {
                         #(#variants),*
                     }
@@ -285,10 +362,10 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                 ref_type,
                 recursive,
             } => {
-                let ref_type = format_ident!("{ref_type}");
                 let ref_type: syn::Type = if recursive.get() {
-                    parse_quote! { Box<#ref_type> }
+                    boxed_type(ref_type, settings)
                 } else {
+                    let ref_type = format_ident!("{ref_type}");
                     parse_quote! { #ref_type }
                 };
                 if ty.optional {
@@ -301,10 +378,11 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                 ref_type,
                 recursive,
             } => {
-                let ref_type = format_ident!("{ref_type}");
                 if recursive.get() {
-                    vec![parse_quote! { pub type #type_ident = Vec<Box<#ref_type>>; }]
+                    let ref_type = boxed_type(ref_type, settings);
+                    vec![parse_quote! { pub type #type_ident = Vec<#ref_type>; }]
                 } else {
+                    let ref_type = format_ident!("{ref_type}");
                     vec![parse_quote! { pub type #type_ident = Vec<#ref_type>; }]
                 }
             }
@@ -338,7 +416,7 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                 .map(|choice| {
                     let action_name = action_name(nonterminal, choice);
                     let action = format_ident!("{action_name}");
-                    let args = self.get_action_args(ty, choice);
+                    let args = self.get_action_args(ty, choice, settings);
                     let body = self.get_action_body(ty, target_type, choice, settings);
                     (
                         action_name,
@@ -350,38 +428,60 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                     )
                 })
                 .collect(),
-            SymbolTypeKind::Vec { recursive, .. } => ty
+            SymbolTypeKind::Vec {
+                recursive,
+                ref_type: item_type,
+            } => ty
                 .choices
                 .iter()
                 .map(|choice| {
                     let action_name = action_name(nonterminal, choice);
                     let action = format_ident!("{action_name}");
-                    let args = self.get_action_args(ty, choice);
+                    let args = self.get_action_args(ty, choice, settings);
 
                     let mut body: Vec<syn::Expr> = vec![];
 
                     match &choice.kind {
                         ChoiceKind::Empty => body.push(parse_quote! { vec![] }),
                         ChoiceKind::Struct { fields, .. } => {
+                            let item = |field: &Field| -> syn::Expr {
+                                let field = format_ident!("{}", field.name);
+                                if recursive.get() {
+                                    boxed_value(item_type, parse_quote! { #field }, settings)
+                                } else {
+                                    parse_quote! { #field }
+                                }
+                            };
                             match &fields[..] {
-                                [a, b] => {
-                                    let mut a_i = format_ident!("{}", a.name);
-                                    let mut b_i = format_ident!("{}", b.name);
-                                    // Find which one is a vector
-                                    if b.ref_type == nonterminal.name {
-                                        (a_i, b_i) = (b_i, a_i)
-                                    }
-                                    body.push(if recursive.get() {
-                                        parse_quote! { #a_i.push(Box::new(#b_i)) }
+                                [a, b] if a.ref_type == nonterminal.name => {
+                                    let a_i = format_ident!("{}", a.name);
+                                    let b_i = item(b);
+                                    body.push(parse_quote! { #a_i.push(#b_i) });
+                                    body.push(parse_quote! { #a_i });
+                                }
+                                [a, b] if b.ref_type == nonterminal.name => {
+                                    let a_i = item(a);
+                                    let b_i = format_ident!("{}", b.name);
+                                    body.push(if nonterminal.in_order {
+                                        parse_quote! { #b_i.insert(0, #a_i) }
                                     } else {
-                                        parse_quote! { #a_i.push(#b_i) }
+                                        parse_quote! { #b_i.push(#a_i) }
                                     });
-                                    body.push(parse_quote! { #a_i });
+                                    body.push(parse_quote! { #b_i });
+                                }
+                                [_, _, ..] => {
+                                    let items = fields.iter().map(item);
+                                    body.push(parse_quote! { vec![#(#items),*] });
                                 }
                                 [a] => {
                                     let a_i = format_ident!("{}", to_snake_case(&a.name));
                                     if recursive.get() {
-                                        body.push(parse_quote! { vec![Box::new(#a_i)] });
+                                        let a_i = boxed_value(
+                                            item_type,
+                                            parse_quote! { #a_i },
+                                            settings,
+                                        );
+                                        body.push(parse_quote! { vec![#a_i] });
                                     } else {
                                         body.push(parse_quote! { vec![#a_i] });
                                     }
@@ -393,7 +493,8 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                             let i = format_ident!("{}", to_snake_case(ref_type));
                             if recursive.get() {
                                 log!("{:?}", i);
-                                body.push(parse_quote! { vec![Box::new(#i)] });
+                                let i = boxed_value(item_type, parse_quote! { #i }, settings);
+                                body.push(parse_quote! { vec![#i] });
                             } else {
                                 body.push(parse_quote! { vec![#i] });
                             }
@@ -415,3 +516,162 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{output_cmp, settings::Settings};
+
+    const GRAMMAR: &str = r#"
+        E: left=E '+' right=E {Add, left}
+         | '(' E ')' {Paren}
+         | items=Item+ {List}
+         | Number;
+        Item {derive: "PartialOrd, Ord"}: name=Name value=Number?;
+
+        terminals
+        Plus: '+';
+        LParen: '(';
+        RParen: ')';
+        Name: /[a-z]+/;
+        Number: /\d+/;
+        "#;
+
+    #[test]
+    fn nonterminal_types_derives() {
+        let settings = Settings::new()
+            .builder_loc_info(false)
+            .derives(vec!["PartialEq".into(), "Eq".into()])
+            .serde(true);
+        let source = settings.process_grammar_str("calc", GRAMMAR).unwrap();
+        let actions = syn::parse_file(&source.actions.unwrap()).unwrap();
+        output_cmp!(
+            "src/generator/actions/actions_derives.expected",
+            prettyplease::unparse(&actions)
+        );
+    }
+
+    #[test]
+    fn nonterminal_types_invalid_derive() {
+        let settings = Settings::new().derives(vec!["Partial Eq".into()]);
+        let error = settings.process_grammar_str("calc", GRAMMAR).unwrap_err();
+        assert_eq!(
+            error.to_locfile_str(),
+            "Error[E0010] at <str>:\n\tInvalid derive 'Partial Eq'."
+        );
+    }
+
+    #[test]
+    fn nonterminal_types_visitors() {
+        let settings = Settings::new().builder_loc_info(false).visitors(true);
+        let source = settings.process_grammar_str("calc", GRAMMAR).unwrap();
+        let parser = syn::parse_file(&source.parser).unwrap();
+        let visitor = parser
+            .items
+            .into_iter()
+            .filter(|item| matches!(item, syn::Item::Mod(m) if m.ident == "visitor"))
+            .collect();
+        output_cmp!(
+            "src/generator/actions/actions_visitors.expected",
+            prettyplease::unparse(&syn::File {
+                shebang: None,
+                attrs: vec![],
+                items: visitor,
+            })
+        );
+    }
+
+    #[test]
+    fn nonterminal_types_arena() {
+        let settings = Settings::new()
+            .builder_loc_info(false)
+            .builder_arena(true)
+            .serde(true);
+        let source = settings.process_grammar_str("calc", GRAMMAR).unwrap();
+        let actions = syn::parse_file(&source.actions.unwrap()).unwrap();
+        output_cmp!(
+            "src/generator/actions/actions_arena.expected",
+            prettyplease::unparse(&actions)
+        );
+    }
+
+    #[test]
+    fn nonterminal_types_unparse() {
+        let settings = Settings::new().builder_loc_info(false).unparse(true);
+        let source = settings.process_grammar_str("calc", GRAMMAR).unwrap();
+        let actions = syn::parse_file(&source.actions.unwrap()).unwrap();
+        let unparse = actions
+            .items
+            .into_iter()
+            .filter(|item| matches!(item, syn::Item::Mod(m) if m.ident == "unparse"))
+            .collect();
+        output_cmp!(
+            "src/generator/actions/actions_unparse.expected",
+            prettyplease::unparse(&syn::File {
+                shebang: None,
+                attrs: vec![],
+                items: unparse,
+            })
+        );
+
+        let error = Settings::new()
+            .unparse(true)
+            .builder_arena(true)
+            .process_grammar_str("calc", GRAMMAR)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Error: Unparse is not supported with arena allocation."
+        );
+    }
+
+    #[test]
+    fn terminal_types_typed() {
+        let grammar = r#"
+            Value: Int | Str | Percent;
+            terminals
+            Int: /\d+/ {type: "int"};
+            Str: /"[^"]*"/ {type: "string"};
+            Percent: /\d+%/ {type: "super::Percent"};
+            "#;
+        let settings = Settings::new().builder_loc_info(true);
+        let source = settings.process_grammar_str("value", grammar).unwrap();
+        let actions = syn::parse_file(&source.actions.unwrap()).unwrap();
+        output_cmp!(
+            "src/generator/actions/actions_typed_terminals.expected",
+            prettyplease::unparse(&actions)
+        );
+    }
+
+    #[test]
+    fn nonterminal_types_spans() {
+        let actions = |settings: Settings| {
+            let source = settings.process_grammar_str("calc", GRAMMAR).unwrap();
+            prettyplease::unparse(&syn::parse_file(&source.actions.unwrap()).unwrap())
+        };
+
+        // Each generated struct and enum variant carries its span by default.
+        output_cmp!(
+            "src/generator/actions/actions_spans.expected",
+            actions(Settings::new())
+        );
+        assert!(!actions(Settings::new().builder_loc_info(false)).contains("SourceSpan"));
+    }
+
+    #[test]
+    fn terminal_types_errors() {
+        let error = |grammar| {
+            Settings::new()
+                .process_grammar_str("value", grammar)
+                .unwrap_err()
+                .to_locfile_str()
+        };
+        assert_eq!(
+            error("A: Int; terminals Int: /\\d+/ {type: \"Vec<\"};"),
+            "Error[E0009] at <str>:[18(1,18)-21(1,21)]:\n\tInvalid type 'Vec<' of terminal 'Int'."
+        );
+        assert_eq!(
+            error("A: Plus; terminals Plus: '+' {type: \"int\"};"),
+            "Error[E0009] at <str>:[19(1,19)-23(1,23)]:\n\tTerminal 'Plus' has no content and can't have a type."
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Generating `rustemo::Spanned` implementations for the AST types of the
+//! default builder with location info.
+//!
+//! Like visitors, implementations follow the types in the actions file.
+//! Structs are spanned by their `span` field while enum variants are spanned
+//! by their last `SourceSpan` field or by their single spanned value. Types
+//! whose span can't be found this way are skipped.
+use std::collections::BTreeSet;
+
+use quote::quote;
+use syn::parse_quote;
+
+use super::visitor::generic;
+
+/// The name of the generated module in the actions file.
+pub(crate) const SPANNED_MOD: &str = "spanned";
+
+fn is_source_span(ty: &syn::Type) -> bool {
+    matches!(generic(ty), Some((name, None)) if name == "SourceSpan")
+}
+
+/// Is the value of the given type spanned by the runtime implementations or by
+/// the implementations for the given types.
+fn is_spanned(ty: &syn::Type, spanned: &BTreeSet<String>) -> bool {
+    match generic(ty) {
+        Some((name, Some(inner))) if name == "Box" => is_spanned(inner, spanned),
+        Some((name, Some(_))) => name == "ValSpan",
+        Some((name, None)) => name == "SourceSpan" || spanned.contains(&name),
+        None => false,
+    }
+}
+
+/// The match arm returning the span of the given variant.
+fn variant_arm(
+    enum_ident: &syn::Ident,
+    variant: &syn::Variant,
+    spanned: &BTreeSet<String>,
+) -> Option<syn::Arm> {
+    let ident = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Named(fields)
+            if fields
+                .named
+                .iter()
+                .any(|f| f.ident.as_ref().unwrap() == "span" && is_source_span(&f.ty)) =>
+        {
+            Some(parse_quote! { #enum_ident::#ident { span, .. } => *span, })
+        }
+        syn::Fields::Unnamed(fields) if is_source_span(&fields.unnamed.last()?.ty) => {
+            Some(parse_quote! { #enum_ident::#ident(.., span) => *span, })
+        }
+        syn::Fields::Unnamed(fields)
+            if fields.unnamed.len() == 1 && is_spanned(&fields.unnamed[0].ty, spanned) =>
+        {
+            Some(parse_quote! { #enum_ident::#ident(inner) => inner.span(), })
+        }
+        _ => None,
+    }
+}
+
+/// Creates the module with `Spanned` implementations for the types from the
+/// given items of the actions file.
+pub(crate) fn spanned_mod(items: &[syn::Item]) -> syn::Item {
+    let mut impls: Vec<(syn::Ident, syn::Expr)> = vec![];
+    let mut spanned = BTreeSet::new();
+
+    for item in items {
+        if let syn::Item::Struct(s) = item {
+            if s.generics.params.is_empty()
+                && s.fields.iter().any(|f| {
+                    f.ident.as_ref().is_some_and(|ident| ident == "span") && is_source_span(&f.ty)
+                })
+            {
+                spanned.insert(s.ident.to_string());
+                impls.push((s.ident.clone(), parse_quote! { self.span }));
+            }
+        }
+    }
+
+    // Enums may be spanned by other enums so iterate until no new
+    // implementation is found.
+    let enums = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Enum(e) if e.generics.params.is_empty() && !e.variants.is_empty() => {
+                Some(e)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    loop {
+        let mut found = false;
+        for e in &enums {
+            if spanned.contains(&e.ident.to_string()) {
+                continue;
+            }
+            let ident = &e.ident;
+            let arms = e
+                .variants
+                .iter()
+                .map(|v| variant_arm(ident, v, &spanned))
+                .collect::<Option<Vec<_>>>();
+            if let Some(arms) = arms {
+                spanned.insert(ident.to_string());
+                impls.push((
+                    ident.clone(),
+                    parse_quote! {
+                        match self {
+                            #(#arms)*
+                        }
+                    },
+                ));
+                found = true;
+            }
+        }
+        if !found {
+            break;
+        }
+    }
+
+    // Keep the order of the types in the file.
+    let order = |ident: &syn::Ident| {
+        items.iter().position(|item| match item {
+            syn::Item::Struct(s) => &s.ident == ident,
+            syn::Item::Enum(e) => &e.ident == ident,
+            _ => false,
+        })
+    };
+    impls.sort_by_key(|(ident, _)| order(ident));
+    let uses = (!impls.is_empty()).then(|| {
+        quote! {
+            use super::*;
+            use rustemo::{SourceSpan, Spanned};
+        }
+    });
+    let impls = impls.into_iter().map(|(ident, body)| {
+        quote! {
+            impl Spanned for #ident {
+                fn span(&self) -> SourceSpan {
+                    #body
+                }
+            }
+        }
+    });
+
+    parse_quote! {
+        /// `Spanned` implementations for the AST types. Generated by rustemo on
+        /// each run, do not edit.
+        pub mod spanned {
+            #uses
+            #(#impls)*
+        }
+    }
+}
@@ -0,0 +1,484 @@
+//! Generating the printer of the default builder AST types back to the source
+//! text.
+//!
+//! Each rule gets an `unparse_*` function which prints its productions. Values
+//! of the AST are printed by the functions of the referenced symbols while
+//! string match terminals, which are not kept in the AST, are printed from the
+//! grammar. Struct and enum types implement `rustemo::Unparse` and `Display`.
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse_quote;
+
+use crate::{
+    generator::{DEDENT, INDENT, NEWLINE},
+    grammar::{
+        types::{to_snake_case, ChoiceKind, SymbolTypeKind, SymbolTypes},
+        Grammar, Production,
+    },
+    index::SymbolIndex,
+    lang::rustemo_actions::{ConstVal, Recognizer},
+    settings::Settings,
+};
+
+/// The name of the generated module in the actions file.
+pub(crate) const UNPARSE_MOD: &str = "unparse";
+
+struct UnparseGenerator<'g> {
+    grammar: &'g Grammar,
+    types: &'g SymbolTypes,
+    settings: &'g Settings,
+    /// Names of the printing functions of symbols with content.
+    functions: BTreeMap<SymbolIndex, syn::Ident>,
+}
+
+impl UnparseGenerator<'_> {
+    /// Statements printing the given production. Content symbols are printed
+    /// from the given values in order.
+    fn production(&self, production: &Production, values: &[syn::Expr]) -> Vec<TokenStream> {
+        let mut values = values.iter();
+        production
+            .rhs_assign()
+            .iter()
+            .filter_map(|assign| self.symbol(assign.symbol, &mut values))
+            .collect()
+    }
+
+    /// The statement printing the given symbol, taking the value from `values`
+    /// if the symbol has content.
+    fn symbol<'a>(
+        &self,
+        symbol: SymbolIndex,
+        values: &mut impl Iterator<Item = &'a syn::Expr>,
+    ) -> Option<TokenStream> {
+        if self.grammar.is_empty(symbol) {
+            return None;
+        }
+        if self.grammar.symbol_has_content(symbol) {
+            let function = &self.functions[&symbol];
+            let value = values.next().expect("A value for each content symbol.");
+            return Some(quote! { #function(out, #value); });
+        }
+        let terminal = self.grammar.symbol_to_term(symbol);
+        match &terminal.recognizer {
+            Some(Recognizer::StrConst(literal)) => {
+                let literal = literal.as_ref();
+                Some(quote! { out.token(#literal); })
+            }
+            _ if self.settings.indentation => match terminal.name.as_str() {
+                NEWLINE => Some(quote! { out.newline(); }),
+                INDENT => Some(quote! { out.indent(); }),
+                DEDENT => Some(quote! { out.dedent(); }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The printing function of the given symbol.
+    fn function(&self, symbol: SymbolIndex, body: Vec<TokenStream>) -> syn::Item {
+        let function = &self.functions[&symbol];
+        let ty = format_ident!("{}", self.grammar.symbol_name(symbol));
+        let (out, node) = if body.is_empty() {
+            (format_ident!("_out"), format_ident!("_node"))
+        } else {
+            (format_ident!("out"), format_ident!("node"))
+        };
+        parse_quote! {
+            pub fn #function(#out: &mut Unparser, #node: &#ty) {
+                #(#body)*
+            }
+        }
+    }
+
+    /// `Unparse` and `Display` implementations for the type.
+    fn impls(&self, type_name: &str, body: TokenStream) -> Vec<syn::Item> {
+        let ty = format_ident!("{type_name}");
+        vec![
+            parse_quote! {
+                impl Unparse for #ty {
+                    fn unparse(&self, out: &mut Unparser) {
+                        #body
+                    }
+                }
+            },
+            parse_quote! {
+                impl std::fmt::Display for #ty {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(&Unparser::new().unparse(self))
+                    }
+                }
+            },
+        ]
+    }
+
+    /// Wraps statements for the value of an optional type.
+    fn optional(optional: bool, body: Vec<TokenStream>) -> Vec<TokenStream> {
+        if optional {
+            vec![quote! {
+                if let Some(node) = node {
+                    #(#body)*
+                }
+            }]
+        } else {
+            body
+        }
+    }
+
+    fn items(&self) -> Vec<syn::Item> {
+        let mut items = vec![];
+        for terminal in self
+            .grammar
+            .terminals
+            .iter()
+            .filter(|t| t.has_content && t.reachable.get())
+        {
+            let symbol = self.grammar.term_to_symbol_index(terminal.idx);
+            items.push(self.function(symbol, vec![quote! { out.value(node); }]));
+        }
+
+        let term_len = self.grammar.terminals.len();
+        for nonterminal in self
+            .grammar
+            .nonterminals()
+            .into_iter()
+            .filter(|nt| nt.reachable.get())
+        {
+            let symbol = nonterminal.idx.symbol_index(term_len);
+            let ty = self.types.get_type(symbol);
+            let productions = nonterminal.productions(self.grammar);
+            let body = match &ty.kind {
+                SymbolTypeKind::Enum { type_name } => {
+                    let enum_type = format_ident!("{type_name}");
+                    let arms = ty
+                        .choices
+                        .iter()
+                        .zip(&productions)
+                        .filter_map(|(choice, production)| {
+                            let variant = format_ident!("{}", choice.name);
+                            let loc_info = self.settings.builder_loc_info;
+                            Some(match &choice.kind {
+                                ChoiceKind::Empty => return None,
+                                ChoiceKind::Plain => {
+                                    let pattern = if loc_info {
+                                        quote! { #enum_type::#variant(..) }
+                                    } else {
+                                        quote! { #enum_type::#variant }
+                                    };
+                                    let body = self.production(production, &[]);
+                                    quote! { #pattern => { #(#body)* } }
+                                }
+                                ChoiceKind::Ref { .. } => {
+                                    let pattern = if loc_info {
+                                        quote! { #enum_type::#variant(inner, ..) }
+                                    } else {
+                                        quote! { #enum_type::#variant(inner) }
+                                    };
+                                    let body = self.production(production, &[parse_quote!(inner)]);
+                                    quote! { #pattern => { #(#body)* } }
+                                }
+                                ChoiceKind::Struct { type_name, fields } => {
+                                    let values = fields
+                                        .iter()
+                                        .map(|f| {
+                                            let field = format_ident!("{}", f.name);
+                                            parse_quote! { &self.#field }
+                                        })
+                                        .collect::<Vec<_>>();
+                                    let body = self.production(production, &values);
+                                    items.extend(self.impls(type_name, quote! { #(#body)* }));
+                                    quote! { #enum_type::#variant(inner) => inner.unparse(out), }
+                                }
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    items.extend(self.impls(
+                        type_name,
+                        quote! {
+                            match self {
+                                #(#arms)*
+                            }
+                        },
+                    ));
+                    Self::optional(ty.optional, vec![quote! { node.unparse(out); }])
+                }
+                SymbolTypeKind::Struct { type_name } => {
+                    let (choice, production) = ty
+                        .choices
+                        .iter()
+                        .zip(&productions)
+                        .find(|(choice, _)| matches!(choice.kind, ChoiceKind::Struct { .. }))
+                        .expect("Struct choice exists.");
+                    let ChoiceKind::Struct { fields, .. } = &choice.kind else {
+                        unreachable!()
+                    };
+                    let values = fields
+                        .iter()
+                        .map(|f| {
+                            let field = format_ident!("{}", f.name);
+                            parse_quote! { &self.#field }
+                        })
+                        .collect::<Vec<_>>();
+                    let body = self.production(production, &values);
+                    items.extend(self.impls(type_name, quote! { #(#body)* }));
+                    Self::optional(ty.optional, vec![quote! { node.unparse(out); }])
+                }
+                SymbolTypeKind::Ref { .. } => {
+                    let (_, production) = ty
+                        .choices
+                        .iter()
+                        .zip(&productions)
+                        .find(|(choice, _)| !matches!(choice.kind, ChoiceKind::Empty))
+                        .expect("Ref choice exists.");
+                    Self::optional(
+                        ty.optional,
+                        self.production(production, &[parse_quote!(node)]),
+                    )
+                }
+                SymbolTypeKind::Vec { .. } => self.vec(symbol, &ty.choices, &productions),
+                SymbolTypeKind::Terminal => unreachable!(),
+            };
+            items.push(self.function(symbol, body));
+        }
+        items
+    }
+
+    /// Statements printing the elements of a vector type. The base production
+    /// prints the first elements while the recursive one prints the others. If
+    /// there are several base productions, the one with `unparse` meta-data
+    /// set to `true` is used, otherwise the first one. Vectors without a
+    /// recursive production, i.e. bounded repetitions, are printed by the base
+    /// production with the number of elements of the vector.
+    fn vec(
+        &self,
+        symbol: SymbolIndex,
+        choices: &[crate::grammar::types::Choice],
+        productions: &[&Production],
+    ) -> Vec<TokenStream> {
+        let mut recursive = None;
+        // Base productions by the number of elements.
+        let mut bases: BTreeMap<usize, Vec<&Production>> = BTreeMap::new();
+        for (choice, production) in choices.iter().zip(productions) {
+            let elements = match &choice.kind {
+                ChoiceKind::Struct { fields, .. } => fields.len(),
+                ChoiceKind::Ref { .. } => 1,
+                ChoiceKind::Empty | ChoiceKind::Plain => continue,
+            };
+            if production.rhs_symbols().contains(&symbol) {
+                recursive = Some(*production)
+            } else {
+                bases.entry(elements).or_default().push(*production)
+            }
+        }
+        fn base<'p>(productions: &[&'p Production]) -> &'p Production {
+            productions
+                .iter()
+                .find(|p| matches!(p.meta.get("unparse"), Some(ConstVal::Bool(b)) if *b.as_ref()))
+                .or(productions.first())
+                .expect("Base production of a vector.")
+        }
+        let elements = |offset: TokenStream, count: usize| -> Vec<syn::Expr> {
+            (0..count)
+                .map(|idx| {
+                    let idx = Literal::usize_unsuffixed(idx);
+                    parse_quote!(&node[#offset #idx])
+                })
+                .collect()
+        };
+
+        let Some(recursive) = recursive else {
+            let arms = bases.iter().map(|(&count, productions)| {
+                let body = self.production(base(productions), &elements(quote!(), count));
+                let count = Literal::usize_unsuffixed(count);
+                quote! { #count => { #(#body)* } }
+            });
+            return vec![quote! {
+                match node.len() {
+                    #(#arms)*
+                    _ => {}
+                }
+            }];
+        };
+        let (&count, base) = bases
+            .iter()
+            .next()
+            .map(|(count, productions)| (count, base(productions)))
+            .expect("Base production of a vector.");
+        let min = Literal::usize_unsuffixed(count);
+
+        // The recursive production is split at the self reference to the
+        // symbols before, between and after the references.
+        let rhs = recursive.rhs_assign();
+        let self_pos = rhs
+            .iter()
+            .position(|a| a.symbol == symbol)
+            .expect("Self reference in a recursive production.");
+        let item_pos = rhs
+            .iter()
+            .position(|a| a.symbol != symbol && self.grammar.symbol_has_content(a.symbol))
+            .expect("Element reference in a recursive production.");
+        let item: syn::Expr = parse_quote!(item);
+        let part = |range: std::ops::Range<usize>| -> Vec<TokenStream> {
+            let mut values = std::iter::once(&item);
+            rhs[range]
+                .iter()
+                .filter_map(|a| self.symbol(a.symbol, &mut values))
+                .collect()
+        };
+        let single = if count == 1 {
+            self.production(base, std::slice::from_ref(&item))
+        } else {
+            vec![]
+        };
+        let same = |rest: &[TokenStream]| {
+            quote!(#(#single)*).to_string() == quote!(#(#rest)*).to_string()
+        };
+
+        if self_pos < item_pos {
+            // Left recursion: A: prefix A middle B suffix;
+            let prefix = part(0..self_pos);
+            let rest = part(self_pos + 1..rhs.len());
+            let mut body = vec![];
+            if !prefix.is_empty() {
+                body.push(quote! {
+                    for _ in #min..node.len() {
+                        #(#prefix)*
+                    }
+                });
+            }
+            body.push(if count > 1 {
+                let base = self.production(base, &elements(quote!(), count));
+                quote! {
+                    #(#base)*
+                    for item in &node[#min..] {
+                        #(#rest)*
+                    }
+                }
+            } else if same(&rest) {
+                quote! {
+                    for item in node {
+                        #(#single)*
+                    }
+                }
+            } else {
+                quote! {
+                    for (idx, item) in node.iter().enumerate() {
+                        if idx == 0 {
+                            #(#single)*
+                        } else {
+                            #(#rest)*
+                        }
+                    }
+                }
+            });
+            body
+        } else {
+            // Right recursion: A: prefix B middle A suffix;
+            // Elements are collected in reverse, with the base elements first,
+            // unless the rule is created for a repetition with the `right`
+            // modifier.
+            let in_order = self.grammar.symbol_to_nonterm(symbol).in_order;
+            let rest = part(0..self_pos);
+            let suffix = part(self_pos + 1..rhs.len());
+            let iter = if in_order {
+                quote!(node.iter())
+            } else {
+                quote!(node.iter().rev())
+            };
+            let mut body = vec![if count > 1 && in_order {
+                let base = self.production(base, &elements(quote!(node.len() - #min +), count));
+                quote! {
+                    for item in &node[..node.len() - #min] {
+                        #(#rest)*
+                    }
+                    #(#base)*
+                }
+            } else if count > 1 {
+                let base = self.production(base, &elements(quote!(), count));
+                quote! {
+                    for item in node[#min..].iter().rev() {
+                        #(#rest)*
+                    }
+                    #(#base)*
+                }
+            } else if same(&rest) {
+                quote! {
+                    for item in #iter {
+                        #(#single)*
+                    }
+                }
+            } else {
+                quote! {
+                    for (idx, item) in #iter.enumerate() {
+                        if idx + 1 == node.len() {
+                            #(#single)*
+                        } else {
+                            #(#rest)*
+                        }
+                    }
+                }
+            }];
+            if !suffix.is_empty() {
+                body.push(quote! {
+                    for _ in #min..node.len() {
+                        #(#suffix)*
+                    }
+                });
+            }
+            body
+        }
+    }
+}
+
+/// Creates the module printing the AST types inferred from the grammar.
+pub(crate) fn unparse_mod(
+    grammar: &Grammar,
+    types: &SymbolTypes,
+    settings: &Settings,
+) -> syn::Item {
+    // Function names are snake case symbol names. Names clashing with the
+    // names of previous symbols get a numeric suffix.
+    let mut functions = BTreeMap::new();
+    let mut names = BTreeSet::new();
+    let term_len = grammar.terminals.len();
+    let symbols = grammar
+        .terminals
+        .iter()
+        .filter(|t| t.has_content && t.reachable.get())
+        .map(|t| (grammar.term_to_symbol_index(t.idx), &t.name))
+        .chain(
+            grammar
+                .nonterminals()
+                .into_iter()
+                .filter(|nt| nt.reachable.get())
+                .map(|nt| (nt.idx.symbol_index(term_len), &nt.name)),
+        );
+    for (symbol, name) in symbols {
+        let snake = to_snake_case(name);
+        let mut function = format!("unparse_{snake}");
+        let mut idx = 1;
+        while !names.insert(function.clone()) {
+            idx += 1;
+            function = format!("unparse_{snake}_{idx}");
+        }
+        functions.insert(symbol, format_ident!("{function}"));
+    }
+
+    let items = UnparseGenerator {
+        grammar,
+        types,
+        settings,
+        functions,
+    }
+    .items();
+    parse_quote! {
+        /// Printing of the AST types back to the source text. Generated by
+        /// rustemo on each run, do not edit.
+        pub mod unparse {
+            use super::*;
+            use rustemo::{Unparse, Unparser};
+            #(#items)*
+        }
+    }
+}
@@ -0,0 +1,496 @@
+//! Generating `Visitor`, `VisitorMut` and `Fold` traits for the AST types of
+//! the default builder.
+//!
+//! The traits follow the shape of the types in the actions file: enums,
+//! structs, `Option`, `Vec` and `Box` of other types from the file. For new
+//! actions these are the types inferred from the grammar while manually
+//! changed types are visited as they are. Each type has a trait method whose
+//! default implementation calls a `walk_*` (`fold_*` for `Fold`) function
+//! which visits the inner values. Types without inner values of other types
+//! (e.g. terminals) are leaves.
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse_quote;
+
+use crate::grammar::types::to_snake_case;
+
+/// The name of the generated module in the parser file.
+const VISITOR_MOD: &str = "visitor";
+
+/// Types of the actions file which are not AST types.
+const NON_AST_TYPES: [&str; 4] = ["Input", "Ctx", "Token", "AstArena"];
+
+/// A value containing a type from the actions file. Types are given by the
+/// names used in the visitor methods.
+enum Inner {
+    Type(String),
+    Boxed(Box<Inner>),
+    Optional(Box<Inner>),
+    Vector(Box<Inner>),
+}
+
+enum Shape {
+    Leaf,
+    /// A type alias of the inner value.
+    Alias(Inner),
+    /// Named fields with their inner values. Fields without inner values are
+    /// `None`. `base` is the struct wrapped in `ValSpan` if location info is
+    /// used.
+    Struct {
+        fields: Vec<(syn::Ident, Option<Inner>)>,
+        base: Option<syn::Ident>,
+    },
+    /// Variants with unnamed fields and the inner value of the first field.
+    /// Other variants are `None`. Names used for the remaining fields, e.g. the
+    /// span with location info, are given last.
+    Enum(Vec<(syn::Ident, Option<Inner>, Vec<syn::Ident>)>),
+}
+
+struct VisitedType {
+    name: String,
+    /// The name used in the visitor methods.
+    method: String,
+    shape: Shape,
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Visit,
+    VisitMut,
+    Fold,
+}
+
+impl Mode {
+    fn method(self, name: &str) -> syn::Ident {
+        match self {
+            Mode::Visit => format_ident!("visit_{name}"),
+            Mode::VisitMut => format_ident!("visit_{name}_mut"),
+            Mode::Fold => format_ident!("fold_{name}"),
+        }
+    }
+
+    fn walk(self, name: &str) -> syn::Ident {
+        match self {
+            Mode::Visit => format_ident!("walk_{name}"),
+            Mode::VisitMut => format_ident!("walk_{name}_mut"),
+            Mode::Fold => format_ident!("fold_{name}"),
+        }
+    }
+
+    fn trait_ident(self) -> syn::Ident {
+        match self {
+            Mode::Visit => format_ident!("Visitor"),
+            Mode::VisitMut => format_ident!("VisitorMut"),
+            Mode::Fold => format_ident!("Fold"),
+        }
+    }
+
+    fn param(self, type_name: &str) -> syn::Type {
+        let ty = format_ident!("{type_name}");
+        match self {
+            Mode::Visit => parse_quote! { &#ty },
+            Mode::VisitMut => parse_quote! { &mut #ty },
+            Mode::Fold => parse_quote! { #ty },
+        }
+    }
+
+    fn ret(self, type_name: &str) -> syn::ReturnType {
+        let ty = format_ident!("{type_name}");
+        match self {
+            Mode::Visit | Mode::VisitMut => syn::ReturnType::Default,
+            Mode::Fold => parse_quote! { -> #ty },
+        }
+    }
+
+    fn receiver(self) -> syn::Ident {
+        match self {
+            Mode::Visit | Mode::VisitMut => format_ident!("visitor"),
+            Mode::Fold => format_ident!("folder"),
+        }
+    }
+
+    /// Visits or folds the inner value given by `value` expression. For
+    /// visitors the expression is a reference to the value.
+    fn inner(self, inner: &Inner, value: TokenStream) -> TokenStream {
+        let receiver = self.receiver();
+        match (self, inner) {
+            (_, Inner::Type(name)) => {
+                let method = self.method(name);
+                quote! { #receiver.#method(#value) }
+            }
+            // References to boxes are coerced to the references of the boxed
+            // types.
+            (Mode::Visit | Mode::VisitMut, Inner::Boxed(inner))
+                if matches!(**inner, Inner::Type(_)) =>
+            {
+                self.inner(inner, value)
+            }
+            (Mode::Visit, Inner::Boxed(inner)) => self.inner(inner, quote! { &**#value }),
+            (Mode::VisitMut, Inner::Boxed(inner)) => self.inner(inner, quote! { &mut **#value }),
+            (Mode::Fold, Inner::Boxed(inner)) => {
+                let value = if matches!(**inner, Inner::Type(_)) {
+                    quote! { *#value }
+                } else {
+                    quote! { (*#value) }
+                };
+                let inner = self.inner(inner, value);
+                quote! { Box::new(#inner) }
+            }
+            (Mode::Visit | Mode::VisitMut, Inner::Optional(inner)) => {
+                let inner = visit_stmt(self, inner, quote! { item });
+                quote! {
+                    if let Some(item) = #value {
+                        #inner
+                    }
+                }
+            }
+            (Mode::Fold, Inner::Optional(inner)) => {
+                let inner = self.inner(inner, quote! { item });
+                quote! { #value.map(|item| #inner) }
+            }
+            (Mode::Visit | Mode::VisitMut, Inner::Vector(inner)) => {
+                let inner = visit_stmt(self, inner, quote! { item });
+                quote! {
+                    for item in #value {
+                        #inner
+                    }
+                }
+            }
+            (Mode::Fold, Inner::Vector(inner)) => {
+                let inner = self.inner(inner, quote! { item });
+                quote! { #value.into_iter().map(|item| #inner).collect() }
+            }
+        }
+    }
+}
+
+/// Visits the inner value as a statement.
+fn visit_stmt(mode: Mode, inner: &Inner, value: TokenStream) -> TokenStream {
+    let visit = mode.inner(inner, value);
+    match inner {
+        Inner::Optional(_) | Inner::Vector(_) => visit,
+        _ => quote! { #visit; },
+    }
+}
+
+/// The generic type `wrapper<T>` given by its name, e.g. `Box`, and its type
+/// argument.
+pub(super) fn generic(ty: &syn::Type) -> Option<(String, Option<&syn::Type>)> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() || path.path.segments.len() != 1 {
+        return None;
+    }
+    let segment = &path.path.segments[0];
+    let arg = match &segment.arguments {
+        syn::PathArguments::None => None,
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some((segment.ident.to_string(), arg))
+}
+
+/// The inner value of the given type if it contains any of the `visited`
+/// types.
+fn inner(ty: &syn::Type, visited: &BTreeMap<String, String>) -> Option<Inner> {
+    match generic(ty)? {
+        (name, None) => visited.get(&name).cloned().map(Inner::Type),
+        (name, Some(arg)) => {
+            let wrap = match name.as_str() {
+                "Box" => Inner::Boxed,
+                "Option" => Inner::Optional,
+                "Vec" => Inner::Vector,
+                _ => return None,
+            };
+            Some(wrap(Box::new(inner(arg, visited)?)))
+        }
+    }
+}
+
+/// Collects the types defined in the actions file.
+fn visited_types(items: &[syn::Item]) -> Vec<VisitedType> {
+    let structs = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(s) if s.generics.params.is_empty() => Some(s),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Structs wrapped in `ValSpan` are visited through their wrapper types.
+    let spanned = |ty: &syn::Type| match generic(ty)? {
+        (name, Some(arg)) if name == "ValSpan" => {
+            let (base, _) = generic(arg)?;
+            structs.iter().copied().find(|s| s.ident == base)
+        }
+        _ => None,
+    };
+    let bases = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Type(t) => spanned(&t.ty).map(|s| s.ident.to_string()),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+
+    // Method names are snake case type names. Names clashing with the names of
+    // previous types get a numeric suffix.
+    let mut visited = BTreeMap::new();
+    let mut methods = BTreeSet::new();
+    for name in items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(s) if s.generics.params.is_empty() => Some(s.ident.to_string()),
+            syn::Item::Enum(e) if e.generics.params.is_empty() => Some(e.ident.to_string()),
+            syn::Item::Type(t) if t.generics.params.is_empty() => Some(t.ident.to_string()),
+            _ => None,
+        })
+        .filter(|name| !NON_AST_TYPES.contains(&name.as_str()) && !bases.contains(name))
+    {
+        let snake = to_snake_case(&name);
+        let mut method = snake.clone();
+        let mut idx = 1;
+        while !methods.insert(method.clone()) {
+            idx += 1;
+            method = format!("{snake}_{idx}");
+        }
+        visited.insert(name, method);
+    }
+
+    let struct_shape = |s: &syn::ItemStruct, base: Option<syn::Ident>| match &s.fields {
+        syn::Fields::Named(fields) => Shape::Struct {
+            fields: fields
+                .named
+                .iter()
+                .map(|f| (f.ident.clone().unwrap(), inner(&f.ty, &visited)))
+                .collect(),
+            base,
+        },
+        _ => Shape::Leaf,
+    };
+
+    items
+        .iter()
+        .filter_map(|item| {
+            let (name, shape) = match item {
+                syn::Item::Struct(s) => (&s.ident, struct_shape(s, None)),
+                syn::Item::Enum(e) => (
+                    &e.ident,
+                    Shape::Enum(
+                        e.variants
+                            .iter()
+                            .map(|v| match &v.fields {
+                                syn::Fields::Unnamed(fields) => (
+                                    v.ident.clone(),
+                                    inner(&fields.unnamed[0].ty, &visited),
+                                    fields
+                                        .unnamed
+                                        .iter()
+                                        .enumerate()
+                                        .skip(1)
+                                        .map(|(idx, f)| match generic(&f.ty) {
+                                            Some((name, None)) if name == "SourceSpan" => {
+                                                format_ident!("span")
+                                            }
+                                            _ => format_ident!("field{idx}"),
+                                        })
+                                        .collect(),
+                                ),
+                                _ => (v.ident.clone(), None, vec![]),
+                            })
+                            .collect(),
+                    ),
+                ),
+                syn::Item::Type(t) => (
+                    &t.ident,
+                    match spanned(&t.ty) {
+                        Some(s) => struct_shape(s, Some(s.ident.clone())),
+                        None => inner(&t.ty, &visited).map_or(Shape::Leaf, Shape::Alias),
+                    },
+                ),
+                _ => return None,
+            };
+            let name = name.to_string();
+            let method = visited.get(&name)?.clone();
+            Some(VisitedType {
+                name,
+                method,
+                shape,
+            })
+        })
+        .map(|ty| match ty.shape {
+            Shape::Struct { ref fields, .. } if fields.iter().all(|(_, i)| i.is_none()) => {
+                VisitedType {
+                    shape: Shape::Leaf,
+                    ..ty
+                }
+            }
+            Shape::Enum(ref variants) if variants.iter().all(|(_, i, _)| i.is_none()) => {
+                VisitedType {
+                    shape: Shape::Leaf,
+                    ..ty
+                }
+            }
+            _ => ty,
+        })
+        .collect()
+}
+
+/// The body of the walk function of the given type.
+fn walk_body(mode: Mode, ty: &VisitedType) -> TokenStream {
+    let type_ident = format_ident!("{}", ty.name);
+    match &ty.shape {
+        Shape::Leaf => unreachable!(),
+        // Boxed values are folded in place to avoid a new allocation.
+        Shape::Alias(Inner::Boxed(inner)) if matches!(mode, Mode::Fold) => {
+            let value = if matches!(**inner, Inner::Type(_)) {
+                quote! { *node }
+            } else {
+                quote! { (*node) }
+            };
+            let value = mode.inner(inner, value);
+            quote! {
+                let mut node = node;
+                *node = #value;
+                node
+            }
+        }
+        Shape::Alias(inner) => mode.inner(inner, quote! { node }),
+        Shape::Struct { fields, base } => {
+            let values = fields.iter().map(|(field, inner)| match (mode, inner) {
+                (Mode::Visit, Some(inner)) => visit_stmt(mode, inner, quote! { &node.#field }),
+                (Mode::VisitMut, Some(inner)) => {
+                    visit_stmt(mode, inner, quote! { &mut node.#field })
+                }
+                (Mode::Fold, Some(inner)) => {
+                    let value = mode.inner(inner, quote! { node.#field });
+                    quote! { #field: #value, }
+                }
+                (Mode::Visit | Mode::VisitMut, None) => quote! {},
+                (Mode::Fold, None) => quote! { #field: node.#field, },
+            });
+            match (mode, base) {
+                (Mode::Visit | Mode::VisitMut, _) => quote! { #(#values)* },
+                (Mode::Fold, Some(base)) => quote! {
+                    let span = node.span;
+                    let node = node.into_value();
+                    #type_ident::new(#base { #(#values)* }, span)
+                },
+                (Mode::Fold, None) => quote! { #type_ident { #(#values)* } },
+            }
+        }
+        Shape::Enum(variants) => {
+            let mut arms = variants
+                .iter()
+                .filter_map(|(variant, inner, rest)| {
+                    let inner = inner.as_ref()?;
+                    let value = mode.inner(inner, quote! { inner });
+                    Some(match mode {
+                        Mode::Visit | Mode::VisitMut => {
+                            let rest = rest.iter().map(|_| quote! { _ });
+                            let pattern = quote! { #type_ident::#variant(inner, #(#rest),*) };
+                            match inner {
+                                Inner::Optional(_) | Inner::Vector(_) => {
+                                    quote! { #pattern => { #value } }
+                                }
+                                _ => quote! { #pattern => #value, },
+                            }
+                        }
+                        Mode::Fold => {
+                            quote! {
+                                #type_ident::#variant(inner, #(#rest),*) =>
+                                    #type_ident::#variant(#value, #(#rest),*),
+                            }
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            if variants.iter().any(|(_, inner, _)| inner.is_none()) {
+                arms.push(match mode {
+                    Mode::Visit | Mode::VisitMut => quote! { _ => {} },
+                    Mode::Fold => quote! { node => node, },
+                });
+            }
+            quote! {
+                match node {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
+/// Creates the trait and the walk functions for the given mode.
+fn visitor_items(mode: Mode, types: &[VisitedType]) -> Vec<syn::Item> {
+    let trait_ident = mode.trait_ident();
+    let receiver = mode.receiver();
+    let mut methods: Vec<syn::TraitItem> = vec![];
+    let mut walks: Vec<syn::Item> = vec![];
+    for ty in types {
+        let method = mode.method(&ty.method);
+        let walk = mode.walk(&ty.method);
+        let param = mode.param(&ty.name);
+        let ret = mode.ret(&ty.name);
+        methods.push(match (&ty.shape, mode) {
+            (Shape::Leaf, Mode::Visit | Mode::VisitMut) => parse_quote! {
+                fn #method(&mut self, _node: #param) {}
+            },
+            (Shape::Leaf, Mode::Fold) => parse_quote! {
+                fn #method(&mut self, node: #param) #ret {
+                    node
+                }
+            },
+            _ => parse_quote! {
+                fn #method(&mut self, node: #param) #ret {
+                    #walk(self, node)
+                }
+            },
+        });
+        if !matches!(ty.shape, Shape::Leaf) {
+            let body = walk_body(mode, ty);
+            walks.push(parse_quote! {
+                pub fn #walk<V: #trait_ident + ?Sized>(#receiver: &mut V, node: #param) #ret {
+                    #body
+                }
+            });
+        }
+    }
+    let doc = match mode {
+        Mode::Visit => " Traverses the AST by reference.",
+        Mode::VisitMut => " Traverses the AST by mutable reference.",
+        Mode::Fold => " Rewrites the AST by consuming and rebuilding nodes.",
+    };
+    let mut items: Vec<syn::Item> = vec![parse_quote! {
+        #[doc = #doc]
+        pub trait #trait_ident {
+            #(#methods)*
+        }
+    }];
+    items.extend(walks);
+    items
+}
+
+/// Creates the `visitor` module for the types defined by the given items of
+/// the actions file.
+/// The visitor module for the types of the given actions module. The module is
+/// placed in the parser file next to the actions module.
+pub(crate) fn visitor_mod(items: &[syn::Item], actions_mod: &syn::Ident) -> syn::Item {
+    let types = visited_types(items);
+    let items = [Mode::Visit, Mode::VisitMut, Mode::Fold]
+        .into_iter()
+        .flat_map(|mode| visitor_items(mode, &types));
+    let ident = format_ident!("{VISITOR_MOD}");
+    parse_quote! {
+        /// Visitors for the AST types.
+        pub mod #ident {
+            use super::#actions_mod::*;
+            #(#items)*
+        }
+    }
+}
@@ -144,6 +144,8 @@ impl<'g, 's> PartGenerator<'g, 's> for ArrayPartGenerator {
 
         let longest_match = format_ident!("{}", generator.settings.lexical_disamb_longest_match);
         let grammar_order = format_ident!("{}", generator.settings.lexical_disamb_grammar_order);
+        let error_reporting_fns = generator.error_reporting_fns();
+        let greedy_fns = generator.greedy_fns();
         ast.push(parse_quote! {
             impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for #parser_definition {
                 fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
@@ -164,6 +166,8 @@ impl<'g, 's> PartGenerator<'g, 's> for ArrayPartGenerator {
                 fn grammar_order() -> bool {
                     #grammar_order
                 }
+                #(#error_reporting_fns)*
+                #(#greedy_fns)*
             }
         });
 
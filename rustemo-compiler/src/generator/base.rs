@@ -1,6 +1,7 @@
 use std::iter::repeat_n;
 
-use quote::format_ident;
+use quote::{format_ident, quote};
+use rustemo::ModeAction;
 use syn::parse_quote;
 
 use crate::{
@@ -10,7 +11,11 @@ use crate::{
     BuilderType, LexerType, ParserAlgo,
 };
 
-use super::{action_name, actions::generate_parser_actions, ParserGenerator, PartGenerator};
+use super::{
+    action_name,
+    actions::{arena_field, existing_parser_actions, generate_parser_actions, visitor_mod},
+    ParserGenerator, PartGenerator, DEDENT, INDENT, NEWLINE,
+};
 
 pub(crate) struct BasePartGenerator {}
 
@@ -42,6 +47,18 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                 use rustemo::once_cell::sync::Lazy;
                 use rustemo::StringLexer;
             });
+            if generator.settings.indentation {
+                imports.push(parse_quote! {
+                    use rustemo::IndentationTokens;
+                });
+            }
+            if generator.grammar.has_lexical_modes() {
+                imports.extend::<Vec<syn::Stmt>>(parse_quote! {
+                    use rustemo::TokenMode;
+                    #[allow(unused_imports)]
+                    use rustemo::ModeAction;
+                });
+            }
         }
 
         imports.push(parse_quote! {
@@ -100,8 +117,14 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         }
         let actions_file = &generator.actions_file;
 
+        // Spans make AST values large so the variants may differ in size.
+        let allow_large = generator
+            .settings
+            .builder_loc_info
+            .then(|| quote! { #[allow(clippy::large_enum_variant)] });
         ast.push(parse_quote! {
             #[derive(Debug)]
+            #allow_large
             pub enum Symbol {
                 Terminal(Terminal),
                 NonTerminal(NonTerminal)
@@ -205,6 +228,16 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             use ProdKind as PK;
         });
 
+        if generator.settings.coverage {
+            ast.push(parse_quote! {
+                impl From<ProdKind> for usize {
+                    fn from(p: ProdKind) -> Self {
+                        p as usize
+                    }
+                }
+            });
+        }
+
         let display_arms: Vec<syn::Arm> = generator
             .grammar
             .productions()
@@ -339,13 +372,28 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         let parser = &generator.parser;
         let parser_definition = &generator.parser_definition;
         let mut ast: Vec<syn::Stmt> = vec![];
+        // User state type
+        let user_state: Option<syn::Type> = match &generator.settings.user_state_type {
+            Some(user_state_type) => {
+                let user_state_type: syn::Type = syn::parse_str(user_state_type)?;
+                ast.push(parse_quote! {
+                    pub type UserState = #user_state_type;
+                });
+                Some(parse_quote! { UserState })
+            }
+            None => None,
+        };
+        let user_state_param: Vec<syn::Type> = user_state.into_iter().collect();
+
         // Context type
         ast.push(match generator.settings.parser_algo {
             ParserAlgo::LR => parse_quote! {
-                pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
+                pub(crate) type Context<'i, I> =
+                    LRContext<'i, I, State, TokenKind #(, #user_state_param)*>;
             },
             ParserAlgo::GLR => parse_quote! {
-                pub(crate) type Context<'i, I> = GssHead<'i, I, State, TokenKind>;
+                pub(crate) type Context<'i, I> =
+                    GssHead<'i, I, State, TokenKind #(, #user_state_param)*>;
             },
         });
 
@@ -358,9 +406,42 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         let skip_ws = generator.settings.skip_ws && !generator.grammar.has_layout();
 
         let lexer_instance: syn::Expr = match generator.settings.lexer_type {
-            LexerType::Default => parse_quote! {
-                StringLexer::new(#skip_ws, &RECOGNIZERS)
-            },
+            LexerType::Default => {
+                let mut lexer: syn::Expr = parse_quote! {
+                    StringLexer::new(#skip_ws, &RECOGNIZERS)
+                };
+                if let Some(token_filter) = &generator.settings.token_filter {
+                    let token_filter: syn::Path = syn::parse_str(token_filter)?;
+                    lexer = parse_quote! {
+                        #lexer.with_token_filter(#token_filter)
+                    };
+                }
+                if generator.settings.indentation {
+                    let newline: syn::Expr = if generator
+                        .grammar
+                        .terminals
+                        .iter()
+                        .any(|term| term.name == NEWLINE)
+                    {
+                        parse_quote! { Some(TokenKind::NEWLINE) }
+                    } else {
+                        parse_quote! { None }
+                    };
+                    lexer = parse_quote! {
+                        #lexer.with_indentation(IndentationTokens {
+                            indent: TokenKind::INDENT,
+                            dedent: TokenKind::DEDENT,
+                            newline: #newline,
+                        })
+                    };
+                }
+                if generator.grammar.has_lexical_modes() {
+                    lexer = parse_quote! {
+                        #lexer.with_modes(&TOKEN_MODES)
+                    };
+                }
+                lexer
+            }
             LexerType::Custom => parse_quote! {
                 lexer
             },
@@ -436,7 +517,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         } else {
             parse_quote! {
                 GlrParser<'i, State, L, ProdKind, TokenKind, NonTermKind,
-                          #parser_definition, I, B>
+                          #parser_definition, I, B #(, #user_state_param)*>
             }
         };
         ast.push(parse_quote! {
@@ -511,6 +592,10 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             }
         });
 
+        if generator.settings.coverage {
+            ast.extend(coverage(generator));
+        }
+
         Ok(ast)
     }
 
@@ -520,13 +605,27 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             return Ok(ast);
         }
 
+        // Indentation tokens are produced by the lexer so their recognizers
+        // never match.
+        let (synthetic_variant, synthetic_arm): (Vec<syn::Variant>, Vec<syn::Arm>) =
+            if generator.settings.indentation {
+                (
+                    vec![parse_quote! { Synthetic }],
+                    vec![parse_quote! {
+                        TokenRecognizer(_, Recognizer::Synthetic) => None,
+                    }],
+                )
+            } else {
+                (vec![], vec![])
+            };
         ast.push(parse_quote! {
             #[allow(dead_code)]
             #[derive(Debug)]
             pub enum Recognizer {
                 Stop,
                 StrMatch(&'static str),
-                RegexMatch(Lazy<Regex>)
+                RegexMatch(Lazy<Regex>),
+                #(#synthetic_variant)*
             }
         });
         ast.push(parse_quote! {
@@ -586,6 +685,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                 None
                             }
                         },
+                        #(#synthetic_arm)*
                     }
                 }
             }
@@ -598,6 +698,10 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                 let token_kind = format_ident!("{}", &term.name);
                 if term.name == "STOP" {
                    parse_quote! { TokenRecognizer(TokenKind::STOP, Recognizer::Stop) }
+                } else if generator.settings.indentation
+                    && [INDENT, DEDENT, NEWLINE].contains(&term.name.as_str())
+                {
+                    parse_quote! { TokenRecognizer(TokenKind::#token_kind, Recognizer::Synthetic) }
                 } else {
                     match &term.recognizer {
                         Some(r) => match r {
@@ -630,6 +734,36 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             ];
         });
 
+        if generator.grammar.has_lexical_modes() {
+            let token_modes: Vec<syn::Expr> = generator
+                .grammar
+                .terminals
+                .iter()
+                .map(|term| {
+                    // STOP is recognized in all modes.
+                    let mode: syn::Expr = if term.name == "STOP" {
+                        parse_quote! { None }
+                    } else {
+                        let mode = term.mode;
+                        parse_quote! { Some(#mode) }
+                    };
+                    let action: syn::Expr = match term.mode_action {
+                        Some(ModeAction::Push(mode)) => {
+                            parse_quote! { Some(ModeAction::Push(#mode)) }
+                        }
+                        Some(ModeAction::Pop) => parse_quote! { Some(ModeAction::Pop) },
+                        None => parse_quote! { None },
+                    };
+                    parse_quote! { TokenMode { mode: #mode, action: #action } }
+                })
+                .collect();
+            ast.push(parse_quote! {
+                pub(crate) static TOKEN_MODES: [TokenMode; TERMINAL_COUNT] = [
+                    #(#token_modes,)*
+                ];
+            });
+        }
+
         Ok(ast)
     }
 
@@ -640,35 +774,80 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         }
 
         // Generate actions
-        if generator.settings.actions {
-            generate_parser_actions(generator)?;
-        }
+        let actions = if generator.settings.actions {
+            Some(generate_parser_actions(generator)?)
+        } else {
+            None
+        };
 
         let actions_file = &generator.actions_file;
         let root_symbol = &generator.root_symbol;
         let context_var = format_ident!("context");
 
+        // Actions of typed terminals may fail. The first error is kept and
+        // returned to the parser while further actions are skipped.
+        let has_typed_terminals = generator.grammar.terminals[1..]
+            .iter()
+            .any(|t| t.reachable.get() && t.value_type.is_some());
+        let error_field = has_typed_terminals.then(|| quote! { error: Option<rustemo::Error>, });
+        let error_init = has_typed_terminals.then(|| quote! { error: None, });
+        let error_check: Option<syn::Stmt> = has_typed_terminals.then(|| {
+            parse_quote! {
+                if self.error.is_some() {
+                    return;
+                }
+            }
+        });
+        let take_error: Option<syn::ImplItem> = has_typed_terminals.then(|| {
+            parse_quote! {
+                fn take_error(&mut self) -> Option<rustemo::Error> {
+                    self.error.take()
+                }
+            }
+        });
+
+        // Recursive values are allocated in the arena which is returned
+        // together with the root.
+        let arena = generator.settings.builder_arena;
+        let arena_decl = arena.then(|| quote! { arena: #actions_file::AstArena, });
+        let arena_init = arena.then(|| quote! { arena: Default::default(), });
+        let (output, result): (syn::Type, syn::Expr) = if arena {
+            (
+                parse_quote! { (#actions_file::#root_symbol, #actions_file::AstArena) },
+                parse_quote! { (r, std::mem::take(&mut self.arena)) },
+            )
+        } else {
+            (
+                parse_quote! { #actions_file::#root_symbol },
+                parse_quote! { r },
+            )
+        };
+
         ast.extend::<Vec<syn::Stmt>>(parse_quote! {
             pub struct DefaultBuilder {
                 res_stack: Vec<Symbol>,
+                #error_field
+                #arena_decl
             }
 
             impl DefaultBuilder {
                 #[allow(dead_code)]
                 pub fn new() -> Self {
                     Self {
-                        res_stack: vec![]
+                        res_stack: vec![],
+                        #error_init
+                        #arena_init
                     }
                 }
             }
 
             impl Builder for DefaultBuilder
             {
-                type Output = #actions_file::#root_symbol;
+                type Output = #output;
 
                 fn get_result(&mut self) -> Self::Output {
                     match self.res_stack.pop().unwrap() {
-                        Symbol::NonTerminal(NonTerminal::#root_symbol(r)) => r,
+                        Symbol::NonTerminal(NonTerminal::#root_symbol(r)) => #result,
                         _ => panic!("Invalid result on the parse stack!"),
                     }
                 }
@@ -681,10 +860,20 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             .map(|terminal| {
                 let action = format_ident!("{}", to_snake_case(&terminal.name));
                 let term = format_ident!("{}", terminal.name);
-                if let Some(Recognizer::StrConst(_)) = terminal.recognizer {
+                if !terminal.has_content {
                     parse_quote! {
                         TokenKind::#term => Terminal::#term
                     }
+                } else if terminal.value_type.is_some() {
+                    parse_quote! {
+                        TokenKind::#term => match #actions_file::#action(context, token) {
+                            Ok(value) => Terminal::#term(value),
+                            Err(error) => {
+                                self.error = Some(error);
+                                return;
+                            }
+                        }
+                    }
                 } else {
                     parse_quote! {
                         TokenKind::#term => Terminal::#term(#actions_file::#action(context, token))
@@ -720,9 +909,11 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                     None => production.rhs.len(),
                 };
                 let rhs_len = production.rhs.len();
-                let choice = &generator.types.as_ref().unwrap().get_type(
-                        nonterminal.idx.symbol_index(generator.grammar.terminals.len()))
-                                       .choices[production.ntidx];
+                let ty = generator.types.as_ref().unwrap().get_type(
+                        nonterminal.idx.symbol_index(generator.grammar.terminals.len()));
+                let choice = &ty.choices[production.ntidx];
+                let arena_arg: Option<syn::Expr> = (arena && ty.boxes(choice))
+                    .then(|| parse_quote! { &mut self.arena });
                 let action = format_ident!("{}", action_name(nonterminal, choice));
 
                 let prod_kind = generator.prod_kind_ident(production);
@@ -786,7 +977,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
 
                         let params = |len| -> Vec<syn::Expr> {
                             let mut param_count = 0usize;
-                            production.rhs_with_content(generator.grammar).iter()
+                            arena_arg.iter().cloned().chain(production.rhs_with_content(generator.grammar).iter()
                                 .map( |assignment| {
                                     if assignment.idx < len {
                                         let param = format_ident! { "p{}", param_count };
@@ -794,8 +985,11 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                         parse_quote! { #param }
                                     } else {
                                         let ty = generator.types.as_ref().unwrap().get_type(assignment.symbol);
-                                        if let SymbolTypeKind::Ref { ref recursive, .. } = ty.kind {
-                                            if recursive.get() {
+                                        if let SymbolTypeKind::Ref { ref recursive, ref ref_type } = ty.kind {
+                                            if recursive.get() && arena {
+                                                let field = arena_field(ref_type);
+                                                parse_quote! { self.arena.#field.alloc(None) }
+                                            } else if recursive.get() {
                                                 parse_quote! { Box::new(None) }
                                             } else {
                                                 parse_quote! { None }
@@ -804,7 +998,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                             parse_quote! { None }
                                         }
                                     }
-                                }).collect()
+                                })).collect()
                         };
 
                         let match_stmt = |match_lhs, match_expr, params: Vec<syn::Expr>, len| -> syn::Expr {
@@ -888,6 +1082,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                     &mut self,
                     #context_var: &Context<'i, Input>,
                     token: Token<'i, Input, TokenKind>) {
+                    #error_check
                     let val = match token.kind {
                         TokenKind::STOP => panic!("Cannot shift STOP token!"),
                         #(#shift_match_arms),*
@@ -900,15 +1095,28 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                     #context_var: &Context<'i, Input>,
                     prod: ProdKind,
                     prod_len: usize) {
+                    #error_check
                     let prod = match prod {
                         #(#reduce_match_arms),*
                     };
                     self.res_stack.push(Symbol::NonTerminal(prod));
                 }
 
+                #take_error
+
             }
         });
 
+        // Visitors follow the types of the actions module and are regenerated
+        // with the parser.
+        if generator.settings.visitors {
+            let actions = match actions {
+                Some(actions) => actions,
+                None => existing_parser_actions(generator)?,
+            };
+            ast.push(syn::Stmt::Item(visitor_mod(&actions.items, actions_file)));
+        }
+
         Ok(ast)
     }
 
@@ -916,3 +1124,81 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         unimplemented!("Delegate not defined!")
     }
 }
+
+/// Grammar information and the parser method used to record grammar coverage.
+fn coverage(generator: &ParserGenerator) -> Vec<syn::Stmt> {
+    let line = |span: Option<rustemo::SourceSpan>| {
+        span.and_then(|s| s.start.line_col)
+            .map_or(0, |line_col| line_col.line)
+    };
+    let terminals: Vec<syn::Expr> = generator
+        .grammar
+        .terminals
+        .iter()
+        .map(|term| {
+            let name = &term.name;
+            let line = line(term.span);
+            parse_quote! {
+                CoverageItem { name: #name, text: #name, line: #line }
+            }
+        })
+        .collect();
+    let productions: Vec<syn::Expr> = generator
+        .grammar
+        .productions()
+        .iter()
+        .map(|&prod| {
+            let name = &prod.nonterminal(generator.grammar).name;
+            let text = prod.to_string(generator.grammar);
+            let line = line(prod.span(generator.grammar));
+            parse_quote! {
+                CoverageItem { name: #name, text: #text, line: #line }
+            }
+        })
+        .collect();
+    let grammar_file = &generator.grammar_file;
+    let actions = generator
+        .table
+        .states
+        .iter()
+        .map(|state| {
+            state
+                .actions
+                .iter()
+                .filter(|actions| !actions.is_empty())
+                .count()
+        })
+        .sum::<usize>();
+    let states = generator.table.states.len();
+    let parser = &generator.parser;
+
+    parse_quote! {
+        use rustemo::{Coverage, CoverageInfo, CoverageItem};
+
+        pub static COVERAGE_INFO: CoverageInfo = CoverageInfo {
+            grammar_file: #grammar_file,
+            states: #states,
+            actions: #actions,
+            terminals: &[#(#terminals),*],
+            productions: &[#(#productions),*],
+        };
+
+        #[allow(dead_code)]
+        impl<'i, I, L, B> #parser <'i, I, L, B>
+        where
+            I: InputT + ?Sized + Debug,
+            L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
+            B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind>
+        {
+            /// Records grammar coverage of all following parses in the given
+            /// coverage.
+            pub fn with_coverage(
+                mut self,
+                coverage: std::rc::Rc<std::cell::RefCell<Coverage>>,
+            ) -> Self {
+                self.0.set_coverage(coverage);
+                self
+            }
+        }
+    }
+}
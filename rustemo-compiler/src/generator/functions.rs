@@ -197,6 +197,8 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
 
         let longest_match = format_ident!("{}", generator.settings.lexical_disamb_longest_match);
         let grammar_order = format_ident!("{}", generator.settings.lexical_disamb_grammar_order);
+        let error_reporting_fns = generator.error_reporting_fns();
+        let greedy_fns = generator.greedy_fns();
         ast.push(parse_quote! {
             impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for #parser_definition {
                 fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
@@ -214,6 +216,8 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
                 fn grammar_order() -> bool {
                     #grammar_order
                 }
+                #(#error_reporting_fns)*
+                #(#greedy_fns)*
             }
         });
 
@@ -3,7 +3,7 @@ mod arrays;
 mod base;
 mod functions;
 
-use quote::format_ident;
+use quote::{format_ident, quote, ToTokens};
 use rustemo::{Parser, WARN};
 use std::{
     fs,
@@ -12,10 +12,13 @@ use std::{
 use syn::{parse_quote, Ident};
 use yansi::Paint;
 
+use self::actions::BUILTIN_VALUE_TYPES;
 use crate::{
+    diagnostics::{filter_warnings, print_warnings, Code, Diagnostic},
     error::{Error, Result},
-    index::{StateIndex, TermIndex},
-    lang::rustemo::RustemoParser,
+    index::{StateIndex, StateVec, TermIndex},
+    lang::{rustemo::RustemoParser, rustemo_actions::File},
+    lint::grammar_warnings,
     settings::{BuilderType, GeneratorTableType, LexerType, Settings},
     table::{Action, LRTable},
 };
@@ -23,9 +26,10 @@ use crate::{grammar::builder::GrammarBuilder, ParserAlgo};
 use crate::{
     grammar::{
         types::{to_pascal_case, to_snake_case, Choice, SymbolTypes},
-        Grammar, NonTerminal, Production,
+        Grammar, NonTerminal, Production, Terminal,
     },
     index::NonTermIndex,
+    lang::rustemo_actions::Recognizer,
 };
 
 /// Generator for parser implementation parts. Different types can implement
@@ -58,6 +62,12 @@ trait PartGenerator<'g, 's> {
     }
 }
 
+/// Names of the terminals produced by the default lexer when indentation
+/// tracking is enabled.
+pub(crate) const INDENT: &str = "INDENT";
+pub(crate) const DEDENT: &str = "DEDENT";
+pub(crate) const NEWLINE: &str = "NEWLINE";
+
 /// Main entry point for the parser generator.
 pub fn generate_parser(
     grammar_path: &Path,
@@ -78,36 +88,55 @@ pub fn generate_parser(
 
     let mut parser = RustemoParser::new();
     let file = parser.parse_file(grammar_path)?;
-    let grammar: Grammar = GrammarBuilder::new().try_from_file(file, Some(grammar_path))?;
+    let src = fs::read_to_string(grammar_path)?;
+    let file_name = grammar_path.to_string_lossy();
+    let (grammar, warnings) = build_grammar(file, Some(grammar_path), &src, settings)?;
+    print_warnings(&filter_warnings(warnings, settings)?, settings);
+    let table = LRTable::new(&grammar, settings).map_err(|e| e.with_grammar(&file_name, &src))?;
+    println!("Terminals: {}", grammar.terminals.len());
+    println!("Non-terminals: {}", grammar.nonterminals().len());
+    println!("Productions: {}", grammar.productions().len());
+    println!("States: {}", table.states.len());
 
-    // Check recognizers definition. If default string lexer is used all
-    // recognizers must be defined. If custom lexer is used no recognizer should
-    // be defined.
-    if let LexerType::Default = settings.lexer_type {
-        for term in &grammar.terminals {
-            if term.idx != TermIndex(0) && term.recognizer.is_none() {
-                return Err(Error::Error(format!(
-                    "Recognizer not defined for terminal '{}'.",
-                    term.name
-                )));
-            }
-        }
+    if settings.print_table {
+        println!("LR TABLE:");
+        println!("{table}");
     }
 
-    let table = LRTable::new(&grammar, settings)?;
     if settings.dot {
         let dot_file = grammar_path.with_extension("dot");
         println!("Writting dot file: {:?}", &dot_file);
         fs::write(dot_file, table.to_dot())?;
     }
+    if settings.error_states {
+        let error_states_file = grammar_path.with_extension("error_states");
+        println!("Writing error states file: {:?}", &error_states_file);
+        fs::write(error_states_file, table.error_states())?;
+    }
+
+    // Custom syntax error messages are given in an optional `.messages` file
+    // beside the grammar.
+    let messages_file = grammar_path.with_extension("messages");
+    let error_messages = if messages_file.exists() {
+        println!("Reading error messages file: {:?}", &messages_file);
+        let messages = table.error_messages(&messages_file)?;
+        let warnings = table.missing_error_messages(&messages, &messages_file.to_string_lossy());
+        print_warnings(&filter_warnings(warnings, settings)?, settings);
+        Some(messages)
+    } else {
+        None
+    };
 
     if let ParserAlgo::LR = settings.parser_algo {
         let conflicts = table.get_conflicts();
         if !conflicts.is_empty() {
             println!("{}", "\nCONFLICTS:".paint(WARN));
             table.print_conflicts_report(&conflicts);
-            return Err(Error::Error(
-                "Grammar is not deterministic. There are conflicts.".to_string(),
+            return Err(Error::from(
+                table
+                    .conflicts_diagnostic(&conflicts)
+                    .with_file(file_name.as_ref())
+                    .with_source(&src),
             ));
         }
     }
@@ -118,6 +147,7 @@ pub fn generate_parser(
         out_dir_actions.to_owned(),
         &grammar,
         table,
+        error_messages,
         settings,
     )?;
 
@@ -125,11 +155,220 @@ pub fn generate_parser(
     Ok(())
 }
 
+/// Checks the grammar without generating the parser. Returns the warnings
+/// found in the grammar.
+pub(crate) fn check_grammar_file(
+    grammar_path: &Path,
+    settings: &Settings,
+) -> Result<Vec<Diagnostic>> {
+    let file = RustemoParser::new().parse_file(grammar_path)?;
+    let src = fs::read_to_string(grammar_path)?;
+    let file_name = grammar_path.to_string_lossy();
+    let (grammar, warnings) = build_grammar(file, Some(grammar_path), &src, settings)?;
+    let table = LRTable::new(&grammar, settings).map_err(|e| e.with_grammar(&file_name, &src))?;
+    check_conflicts(&table, &file_name, &src, settings)?;
+    filter_warnings(warnings, settings)
+}
+
+/// Builds the grammar from the parsed grammar file and checks it. Returns the
+/// grammar and its warnings. `src` is the grammar source used in reports.
+fn build_grammar(
+    file: File,
+    path: Option<&Path>,
+    src: &str,
+    settings: &Settings,
+) -> Result<(Grammar, Vec<Diagnostic>)> {
+    let file_name = path.map_or("<str>".into(), |p| p.to_string_lossy());
+    let mut grammar = GrammarBuilder::new()
+        .try_from_file(file, path)
+        .map_err(|e| Error::from(e).with_grammar(&file_name, src))?;
+    check_grammar(&mut grammar, &file_name, settings)
+        .map_err(|e| e.with_grammar(&file_name, src))?;
+    let warnings = grammar_warnings(&grammar, &file_name, src, settings);
+    Ok((grammar, warnings))
+}
+
+/// Returns an error if the grammar used for LR parsing has conflicts.
+fn check_conflicts(table: &LRTable, file: &str, src: &str, settings: &Settings) -> Result<()> {
+    if let ParserAlgo::LR = settings.parser_algo {
+        let conflicts = table.get_conflicts();
+        if !conflicts.is_empty() {
+            return Err(Error::from(
+                table
+                    .conflicts_diagnostic(&conflicts)
+                    .with_file(file)
+                    .with_source(src),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks the grammar against the settings used for parser generation.
+fn check_grammar(grammar: &mut Grammar, file: &str, settings: &Settings) -> Result<()> {
+    let error = |code, message: String, span| -> Result<()> {
+        Err(Diagnostic::new(code, message)
+            .with_file(file)
+            .with_span(span)
+            .into())
+    };
+    // Check recognizers definition. If default string lexer is used all
+    // recognizers must be defined. If custom lexer is used no recognizer should
+    // be defined.
+    // If indentation tracking is enabled, indentation terminals are produced
+    // by the lexer and must not have recognizers.
+    if settings.indentation {
+        for name in [INDENT, DEDENT] {
+            if !grammar.terminals.iter().any(|term| term.name == name) {
+                return error(
+                    Code::MissingIndentationTerminal,
+                    format!("Terminal '{name}' must be defined when indentation is enabled."),
+                    None,
+                );
+            }
+        }
+    }
+    let is_indentation_term =
+        |name: &str| settings.indentation && [INDENT, DEDENT, NEWLINE].contains(&name);
+    // Indentation tokens are always empty.
+    for term in grammar.terminals.iter_mut() {
+        if is_indentation_term(&term.name) {
+            term.has_content = false;
+        }
+    }
+    if let LexerType::Default = settings.lexer_type {
+        for term in &grammar.terminals {
+            if is_indentation_term(&term.name) {
+                if term.recognizer.is_some() {
+                    return error(
+                        Code::InvalidRecognizer,
+                        format!(
+                            "Indentation terminal '{}' must not have a recognizer.",
+                            term.name
+                        ),
+                        term.span,
+                    );
+                }
+            } else if term.idx != TermIndex(0) && term.recognizer.is_none() {
+                return error(
+                    Code::InvalidRecognizer,
+                    format!("Recognizer not defined for terminal '{}'.", term.name),
+                    term.span,
+                );
+            }
+        }
+    }
+
+    for term in &grammar.terminals {
+        let Some(value_type) = &term.value_type else {
+            continue;
+        };
+        if !term.has_content {
+            return error(
+                Code::InvalidTerminalType,
+                format!(
+                    "Terminal '{}' has no content and can't have a type.",
+                    term.name
+                ),
+                term.span,
+            );
+        }
+        if !BUILTIN_VALUE_TYPES.contains(&value_type.as_str())
+            && syn::parse_str::<syn::Type>(value_type).is_err()
+        {
+            return error(
+                Code::InvalidTerminalType,
+                format!("Invalid type '{value_type}' of terminal '{}'.", term.name),
+                term.span,
+            );
+        }
+    }
+
+    // Derives are given as strings and must be valid Rust paths.
+    for (derive, span) in settings.derives.iter().map(|derive| (derive, None)).chain(
+        grammar
+            .nonterminals
+            .iter()
+            .flat_map(|nt| nt.derives.iter().map(|derive| (derive, nt.span))),
+    ) {
+        if syn::parse_str::<syn::Path>(derive).is_err() {
+            error(
+                Code::InvalidDerive,
+                format!("Invalid derive '{derive}'."),
+                span,
+            )?;
+        }
+    }
+
+    // Values referenced by arena ids can't be reached by the printer.
+    if settings.unparse && settings.builder_arena {
+        return Err(Error::Error(
+            "Unparse is not supported with arena allocation.".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Generates the parser and actions source code from the given grammar string
+/// without touching the file system. `name` is the base name of the parser
+/// module. Actions are generated only for the default builder.
+pub(crate) fn generate_parser_source(
+    name: &str,
+    grammar_str: &str,
+    settings: &Settings,
+) -> Result<ParserSource> {
+    let file = RustemoParser::new().parse(grammar_str)?;
+    let (grammar, warnings) = build_grammar(file, None, grammar_str, settings)?;
+    print_warnings(&filter_warnings(warnings, settings)?, settings);
+    let table =
+        LRTable::new(&grammar, settings).map_err(|e| e.with_grammar("<str>", grammar_str))?;
+    check_conflicts(&table, "<str>", grammar_str, settings)?;
+
+    // Actions are not written to the file system but returned.
+    let settings = settings.clone().actions(false);
+    let grammar_path = PathBuf::from(name).with_extension("rustemo");
+    let generator = ParserGenerator::new(
+        &grammar_path,
+        PathBuf::new(),
+        PathBuf::new(),
+        &grammar,
+        table,
+        None,
+        &settings,
+    )?;
+
+    let actions = if let BuilderType::Default = settings.builder_type {
+        Some(
+            actions::parser_actions(&generator, None)
+                .0
+                .to_token_stream()
+                .to_string(),
+        )
+    } else {
+        None
+    };
+    Ok(ParserSource {
+        parser: generator.generate_file()?.to_token_stream().to_string(),
+        actions,
+    })
+}
+
+/// Source code of the parser generated by [Settings::process_grammar_str].
+#[derive(Debug)]
+pub struct ParserSource {
+    /// The parser module source.
+    pub parser: String,
+    /// The actions module source. Generated only for the default builder.
+    pub actions: Option<String>,
+}
+
 /// A generator for the parser code.
 // Some fields are used in parse_quote macros, that's why dead code is allowed.
 #[allow(dead_code)]
 struct ParserGenerator<'g, 's> {
     file_name: String,
+    grammar_file: String,
     root_symbol: Ident,
     parser: Ident,
     layout_parser: Ident,
@@ -141,6 +380,7 @@ struct ParserGenerator<'g, 's> {
     out_dir: PathBuf,
     out_dir_actions: PathBuf,
     table: LRTable<'g, 's>,
+    error_messages: Option<StateVec<Option<String>>>,
     settings: &'s Settings,
     input_type: syn::Type,
     part_generator: Box<dyn PartGenerator<'g, 's>>,
@@ -154,6 +394,7 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         out_dir_actions: PathBuf,
         grammar: &'g Grammar,
         table: LRTable<'g, 's>,
+        error_messages: Option<StateVec<Option<String>>>,
         settings: &'s Settings,
     ) -> Result<Self> {
         let file_name = grammar_path
@@ -195,6 +436,7 @@ impl<'g, 's> ParserGenerator<'g, 's> {
 
         Ok(Self {
             file_name: file_name.to_string(),
+            grammar_file: grammar_path.to_string_lossy().into_owned(),
             root_symbol,
             parser,
             layout_parser,
@@ -206,6 +448,7 @@ impl<'g, 's> ParserGenerator<'g, 's> {
             out_dir,
             out_dir_actions,
             table,
+            error_messages,
             settings,
             input_type,
             part_generator,
@@ -214,6 +457,24 @@ impl<'g, 's> ParserGenerator<'g, 's> {
     }
 
     fn generate(&self, out_dir: &Path) -> Result<()> {
+        let file = self.generate_file()?;
+
+        std::fs::create_dir_all(out_dir).map_err(|e| {
+            Error::Error(format!(
+                "Cannot create directories for path '{out_dir:?}': {e:?}."
+            ))
+        })?;
+
+        let out_file = out_dir.join(&self.file_name).with_extension("rs");
+        println!("Writing parser file {out_file:?}");
+        std::fs::write(&out_file, prettyplease::unparse(&file)).map_err(|e| {
+            Error::Error(format!("Cannot write parser file '{out_file:?}': {e:?}."))
+        })?;
+
+        Ok(())
+    }
+
+    fn generate_file(&self) -> Result<syn::File> {
         let mut ast: Vec<syn::Stmt> = vec![];
         ast.extend(self.part_generator.header(self)?);
         ast.extend(self.part_generator.parser_header(self)?);
@@ -224,12 +485,6 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         ast.extend(self.part_generator.lexer_definition(self)?);
         ast.extend(self.part_generator.builder(self)?);
 
-        std::fs::create_dir_all(out_dir).map_err(|e| {
-            Error::Error(format!(
-                "Cannot create directories for path '{out_dir:?}': {e:?}."
-            ))
-        })?;
-
         let mut file: syn::File = parse_quote!();
 
         file.items.extend(ast.into_iter().map(|s| match s {
@@ -237,13 +492,7 @@ impl<'g, 's> ParserGenerator<'g, 's> {
             _ => panic!("Invalid item."),
         }));
 
-        let out_file = out_dir.join(&self.file_name).with_extension("rs");
-        println!("Writing parser file {out_file:?}");
-        std::fs::write(&out_file, prettyplease::unparse(&file)).map_err(|e| {
-            Error::Error(format!("Cannot write parser file '{out_file:?}': {e:?}."))
-        })?;
-
-        Ok(())
+        Ok(file)
     }
 
     fn prod_kind(&self, prod: &Production) -> String {
@@ -278,6 +527,194 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         )
     }
 
+    /// Overrides of [`ParserDefinition`](rustemo::ParserDefinition) methods
+    /// providing terminal display names, custom error messages and brackets.
+    /// Generated only if the grammar defines any.
+    fn error_reporting_fns(&self) -> Vec<syn::ImplItemMethod> {
+        let mut fns = vec![];
+        let displays = self
+            .grammar
+            .terminals
+            .iter()
+            .filter_map(|term| {
+                term.display.as_ref().map(|display| {
+                    let term_kind = self.term_kind_ident(term.idx);
+                    quote! { TokenKind::#term_kind => Some(#display) }
+                })
+            })
+            .collect::<Vec<_>>();
+        if !displays.is_empty() {
+            fns.push(parse_quote! {
+                fn token_kind_display(&self, token_kind: TokenKind) -> Option<&'static str> {
+                    match token_kind {
+                        #(#displays),*,
+                        _ => None,
+                    }
+                }
+            });
+        }
+        let messages = self
+            .error_messages
+            .iter()
+            .flat_map(|messages| messages.iter().enumerate())
+            .filter_map(|(state, message)| {
+                message.as_ref().map(|message| {
+                    let state_kind = self.state_kind_ident(StateIndex(state));
+                    quote! { State::#state_kind => Some(#message) }
+                })
+            })
+            .collect::<Vec<_>>();
+        if !messages.is_empty() {
+            fns.push(parse_quote! {
+                fn error_message(&self, state: State) -> Option<&'static str> {
+                    match state {
+                        #(#messages),*,
+                        _ => None,
+                    }
+                }
+            });
+        }
+        let delimiters = self
+            .table
+            .states
+            .iter()
+            .filter(|state| self.grammar.is_term(state.symbol))
+            .filter_map(|state| {
+                let term = self.grammar.symbol_to_term(state.symbol);
+                let delimiter = match self.delimiter(term)? {
+                    (closing, true) => {
+                        let term_kind = self.term_kind_ident(closing.idx);
+                        quote! { rustemo::Delimiter::Open(TokenKind::#term_kind) }
+                    }
+                    (closing, false) => {
+                        let term_kind = self.term_kind_ident(closing.idx);
+                        quote! { rustemo::Delimiter::Close(TokenKind::#term_kind) }
+                    }
+                };
+                let state_kind = self.state_kind_ident(state.idx);
+                Some(quote! { State::#state_kind => Some(#delimiter) })
+            })
+            .collect::<Vec<_>>();
+        if !delimiters.is_empty() {
+            fns.push(parse_quote! {
+                fn delimiter(&self, state: State) -> Option<rustemo::Delimiter<TokenKind>> {
+                    match state {
+                        #(#delimiters),*,
+                        _ => None,
+                    }
+                }
+            });
+        }
+        fns
+    }
+
+    /// The closing bracket for the given bracket terminal, e.g. `')'` for
+    /// `'('` or `')'`, and whether the given terminal is the opening one.
+    /// Brackets without a matching pair in the grammar are skipped.
+    fn delimiter(&self, term: &'g Terminal) -> Option<(&'g Terminal, bool)> {
+        const BRACKETS: [(&str, &str); 3] = [("(", ")"), ("[", "]"), ("{", "}")];
+        let content = |term: &Terminal| match &term.recognizer {
+            Some(Recognizer::StrConst(content)) => Some(content.as_str().to_owned()),
+            _ => None,
+        };
+        let find = |content_str: &str| {
+            self.grammar
+                .terminals
+                .iter()
+                .find(|term| content(term).as_deref() == Some(content_str))
+        };
+        let term_content = content(term)?;
+        BRACKETS.iter().find_map(|(open, close)| {
+            if term_content == *open {
+                Some((find(close)?, true))
+            } else if term_content == *close {
+                find(open)?;
+                Some((term, false))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Override of [`ParserDefinition::greedy`](rustemo::ParserDefinition)
+    /// for states where greedy repetitions continue. Generated only for GLR
+    /// and if the grammar uses greedy repetitions.
+    fn greedy_fns(&self) -> Vec<syn::ImplItemMethod> {
+        if !matches!(self.settings.parser_algo, ParserAlgo::GLR) {
+            return vec![];
+        }
+        let greedy = self
+            .table
+            .states
+            .iter()
+            .flat_map(|state| {
+                let state_kind = self.state_kind_ident(state.idx);
+                state.greedy.iter().map(move |(&term, prods)| {
+                    let term_kind = self.term_kind_ident(term);
+                    let prod_kinds = prods.iter().map(|&prod| {
+                        let prod_kind = self.prod_kind_ident(&self.grammar.productions[prod]);
+                        quote! { ProdKind::#prod_kind }
+                    });
+                    quote! { (State::#state_kind, TokenKind::#term_kind, #(#prod_kinds)|*) }
+                })
+            })
+            .collect::<Vec<_>>();
+        // Stops not depending on the non-terminal are generated as a single
+        // pattern.
+        let mut stops = vec![];
+        let mut nonterm_stops = vec![];
+        for state in self
+            .table
+            .states
+            .iter()
+            .filter(|state| !state.greedy_continues.is_empty())
+        {
+            let state_kind = self.state_kind_ident(state.idx);
+            let terms = state.greedy_continues.iter().map(|&term| {
+                let term_kind = self.term_kind_ident(term);
+                quote! { TokenKind::#term_kind }
+            });
+            let pattern = quote! { (State::#state_kind, #(#terms)|*) };
+            if state.greedy_nonterms.is_empty() {
+                stops.push(pattern);
+            } else {
+                let nonterms = state.greedy_nonterms.iter().map(|&nonterm| {
+                    let nonterm_kind = self.nonterm_kind_ident(nonterm);
+                    quote! { NonTermKind::#nonterm_kind }
+                });
+                nonterm_stops.push(quote! { #pattern => !matches!(nonterm, #(#nonterms)|*) });
+            }
+        }
+
+        let mut fns = vec![];
+        if !greedy.is_empty() {
+            fns.push(parse_quote! {
+                fn greedy(&self, state: State, token: TokenKind, prod: ProdKind) -> bool {
+                    matches!((state, token, prod), #(#greedy)|*)
+                }
+            });
+        }
+        if !nonterm_stops.is_empty() {
+            let stops = (!stops.is_empty()).then(|| quote! { #(#stops)|* => true, });
+            fns.push(parse_quote! {
+                fn greedy_stop(&self, state: State, token: TokenKind, nonterm: NonTermKind) -> bool {
+                    match (state, token) {
+                        #stops
+                        #(#nonterm_stops,)*
+                        _ => false,
+                    }
+                }
+            });
+        } else if !stops.is_empty() {
+            fns.push(parse_quote! {
+                fn greedy_stop(&self, state: State, token: TokenKind, _nonterm: NonTermKind) -> bool {
+                    matches!((state, token), #(#stops)|*)
+                }
+            });
+        }
+        fns
+    }
+
     fn action_to_syntax(&self, action: &Option<Action>) -> syn::Expr {
         match action {
             Some(action) => match action {
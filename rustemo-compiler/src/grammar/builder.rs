@@ -4,13 +4,15 @@ use std::{
     path::Path,
 };
 
-use rustemo::{Result, ValSpan};
+use rustemo::{ModeAction, Result, SourceSpan, ValSpan};
 
 use crate::{
-    grammar::{Grammar, DEFAULT_PRIORITY},
+    diagnostics::{Code, Diagnostic},
+    grammar::{Grammar, DEFAULT_PRIORITY, INITIAL_MODE},
     index::{NonTermIndex, NonTermVec, ProdIndex, ProdVec, SymbolIndex, TermIndex, TermVec},
     lang::rustemo_actions::{
-        self, ConstVal, File, GrammarRule, GrammarSymbol, GrammarSymbolRef, Name, Recognizer,
+        self, ConstVal, File, GrammarRule, GrammarSymbol, GrammarSymbolRef, Name, Precedence,
+        PrecedenceAssoc, ProdMetaDatas, ProductionGroup, Recognizer, RepetitionOperator,
         RepetitionOperatorOp, TermMetaDatas,
     },
 };
@@ -43,6 +45,12 @@ pub(crate) struct GrammarBuilder {
     next_nonterm_idx: NonTermIndex,
     next_prod_idx: ProdIndex,
     start_rule_name: String,
+    rule_names: BTreeSet<String>,
+    /// Priority and associativity of terminals and `prec` symbols given in
+    /// the precedence block.
+    precedence: BTreeMap<String, (u32, Associativity)>,
+    /// Names of lexical modes. The first is the initial mode.
+    lexical_modes: Vec<String>,
 }
 
 impl GrammarBuilder {
@@ -57,6 +65,9 @@ impl GrammarBuilder {
             next_nonterm_idx: NonTermIndex(0),
             next_prod_idx: ProdIndex(0),
             start_rule_name: "".into(),
+            rule_names: BTreeSet::new(),
+            precedence: BTreeMap::new(),
+            lexical_modes: vec![INITIAL_MODE.into()],
         }
     }
 
@@ -96,9 +107,34 @@ impl GrammarBuilder {
             },
         );
 
+        // Collect lexical modes before terminals as terminals may enter modes
+        // defined after them.
+        for mode in file.lexical_modes.iter().flatten() {
+            if self.lexical_modes.contains(mode.name.as_ref()) {
+                error(
+                    Code::InvalidMode,
+                    format!("Lexical mode '{}' is already defined.", mode.name),
+                    &self.file,
+                    mode.name.span,
+                )?
+            }
+            self.lexical_modes.push(mode.name.as_ref().clone());
+        }
+
         // Collect grammar terminals
         if let Some(grammar_terminals) = file.terminal_rules {
-            self.collect_terminals(grammar_terminals)?;
+            self.collect_terminals(grammar_terminals, 0)?;
+        }
+        for (idx, mode) in file.lexical_modes.into_iter().flatten().enumerate() {
+            self.collect_terminals(mode.terminal_rules, idx + 1)?;
+        }
+        self.collect_terminals_matches();
+
+        if let Some(precedence) = file.precedence {
+            self.collect_precedence(
+                precedence,
+                file.grammar_rules.as_deref().unwrap_or_default(),
+            )?;
         }
 
         if let Some(rules) = file.grammar_rules {
@@ -125,6 +161,7 @@ impl GrammarBuilder {
                 .map(|x| SymbolIndex(term_len + x.idx.0)),
             start_index: (term_len + self.nonterminals.get(&self.start_rule_name).unwrap().idx.0)
                 .into(),
+            lexical_modes: self.lexical_modes,
             stop_index: 0.into(),
             term_by_name: self
                 .terminals
@@ -155,13 +192,49 @@ impl GrammarBuilder {
         Ok(grammar)
     }
 
+    /// Collects terminals of the given lexical mode.
     fn collect_terminals(
         &mut self,
         grammar_terminals: Vec<rustemo_actions::TerminalRule>,
+        mode: usize,
     ) -> Result<()> {
         for mut terminal in grammar_terminals {
             let term_idx = self.get_term_idx();
             self.check_identifier(&terminal.name)?;
+            let span = terminal.name.span;
+            // User meta-data `push` and `pop` of other types are kept.
+            let push = match terminal.meta.get("push") {
+                Some(ConstVal::String(_)) => terminal.meta.remove("push"),
+                _ => None,
+            };
+            let pop = match terminal.meta.get("pop") {
+                Some(ConstVal::Bool(pop)) if **pop => terminal.meta.remove("pop"),
+                _ => None,
+            };
+            let mode_action = match (push, pop) {
+                (Some(ConstVal::String(name)), None) => {
+                    match self.lexical_modes.iter().position(|m| m == name.as_ref()) {
+                        Some(mode) => Some(ModeAction::Push(mode)),
+                        None => error(
+                            Code::InvalidMode,
+                            format!("Lexical mode '{name}' is not defined."),
+                            &self.file,
+                            name.span,
+                        )?,
+                    }
+                }
+                (None, Some(_)) => Some(ModeAction::Pop),
+                (None, None) => None,
+                _ => error(
+                    Code::InvalidMode,
+                    format!(
+                        "Terminal '{}' can't both push and pop lexical modes.",
+                        terminal.name
+                    ),
+                    &self.file,
+                    span,
+                )?,
+            };
             self.terminals.insert(
                 terminal.name.as_ref().to_string(),
                 Terminal {
@@ -181,10 +254,11 @@ impl GrammarBuilder {
                     prio: if let Some(ConstVal::Int(prio)) = terminal.meta.remove("priority") {
                         let p = prio.clone().into();
                         if p > 99 {
-                            err!(
+                            error(
+                                Code::InvalidPriority,
                                 "Priority must be <=99.".to_owned(),
-                                Some(self.file.clone()),
-                                prio.span
+                                &self.file,
+                                prio.span,
                             )?
                         } else {
                             p
@@ -199,22 +273,114 @@ impl GrammarBuilder {
                     } else {
                         Associativity::None
                     },
+                    display: if let Some(ConstVal::String(display)) =
+                        terminal.meta.remove("display")
+                    {
+                        Some(display.into())
+                    } else {
+                        None
+                    },
+                    value_type: if let Some(ConstVal::String(value_type)) =
+                        terminal.meta.remove("type")
+                    {
+                        Some(value_type.into())
+                    } else {
+                        None
+                    },
+                    mode,
+                    mode_action,
                     meta: terminal.meta,
                     reachable: false.into(),
+                    span,
                 },
             );
         }
+        Ok(())
+    }
 
+    fn collect_terminals_matches(&mut self) {
         for terminal in self.terminals.values() {
             // Collect each terminal which uses a string match recognizer
             // Those can be used as inline terminals in productions.
             if let Some(Recognizer::StrConst(m)) = &terminal.recognizer {
+                // If the same match is used in multiple lexical modes the
+                // terminal of the first mode is used.
+                if let Some((name, _)) = self.terminals_matches.get(m.as_ref()) {
+                    if self.terminals[name].mode < terminal.mode {
+                        continue;
+                    }
+                }
                 self.terminals_matches
                     .insert((*m).as_ref().into(), (terminal.name.clone(), terminal.idx));
             }
         }
         log!("Terminal matches: {:?}", self.terminals_matches);
+    }
 
+    /// Assigns priority and associativity of each level of the precedence
+    /// block to its symbols. Each level has a higher priority than the
+    /// previous one, starting above the default priority.
+    fn collect_precedence(&mut self, precedence: Precedence, rules: &[GrammarRule]) -> Result<()> {
+        for (level, precedence_level) in precedence.into_iter().enumerate() {
+            let prio = DEFAULT_PRIORITY + level as u32 + 1;
+            let assoc = match precedence_level.assoc {
+                Some(PrecedenceAssoc::Left | PrecedenceAssoc::Reduce) => Associativity::Left,
+                Some(PrecedenceAssoc::Right | PrecedenceAssoc::Shift) => Associativity::Right,
+                None => Associativity::None,
+            };
+            for symbol in precedence_level.symbols {
+                let (name, span) = match symbol {
+                    GrammarSymbol::Name(name) => {
+                        if rules.iter().any(|rule| rule.name.as_ref() == name.as_ref()) {
+                            error(
+                                Code::InvalidPrecedence,
+                                format!("Precedence can't be given for rule '{name}'."),
+                                &self.file,
+                                name.span,
+                            )?
+                        }
+                        (name.as_ref().clone(), name.span)
+                    }
+                    GrammarSymbol::StrConst(mtch) => {
+                        match self.terminals_matches.get(mtch.as_ref()) {
+                            Some((name, _)) => (name.clone(), mtch.span),
+                            None => error(
+                                Code::UndefinedTerminal,
+                                format!(
+                                    concat!(
+                                        "Terminal \"{}\" used in the precedence block ",
+                                        "is not defined in the 'terminals' section."
+                                    ),
+                                    mtch
+                                ),
+                                &self.file,
+                                mtch.span,
+                            )?,
+                        }
+                    }
+                };
+                if prio > 99 {
+                    error(
+                        Code::InvalidPriority,
+                        "Too many precedence levels. Priority must be <=99.".to_owned(),
+                        &self.file,
+                        span,
+                    )?
+                }
+                if self
+                    .precedence
+                    .insert(name.clone(), (prio, assoc))
+                    .is_some()
+                {
+                    error(
+                        Code::InvalidPrecedence,
+                        format!("Precedence of '{name}' is given more than once."),
+                        &self.file,
+                        span,
+                    )?
+                }
+            }
+        }
         Ok(())
     }
 
@@ -228,7 +394,10 @@ impl GrammarBuilder {
                 name: "EMPTY".to_string(),
                 productions: vec![],
                 annotation: None,
+                derives: vec![],
                 reachable: false.into(),
+                span: None,
+                in_order: false,
             },
         );
 
@@ -241,6 +410,8 @@ impl GrammarBuilder {
             self.create_aug_nt_and_production("AUGL", layout_rule.name.as_ref());
         }
 
+        self.rule_names = rules.iter().map(|r| r.name.as_ref().clone()).collect();
+
         for rule in rules {
             self.check_identifier(&rule.name)?;
             // Create new nonterm index if needed
@@ -251,99 +422,17 @@ impl GrammarBuilder {
                 nt_idx = self.get_nonterm_idx();
             }
 
-            // Gather productions, create indexes. Transform RHS to mark
-            // resolving references. Desugar regex-like references.
             for (prod_ntidx, production) in rule.rhs.into_iter().enumerate() {
-                let mut desugar_productions: Vec<Production> = vec![];
-                let prod_idx = self.get_prod_idx();
-
-                let mut new_production = Production {
-                    idx: prod_idx,
-                    nonterminal: nt_idx,
-                    ntidx: prod_ntidx,
-                    rhs: production
-                        .assignments
-                        .into_iter()
-                        // Remove EMPTY from production RHS
-                        .filter(|assignment| {
-                            use rustemo_actions::Assignment;
-                            !matches!(assignment, Assignment::GrammarSymbolRef(
-                                        GrammarSymbolRef {
-                                            gsymbol:
-                                                Some(GrammarSymbol::Name(name)),
-                                            ..
-                                        },
-                                    ) if name.as_ref() == "EMPTY")
-                        })
-                        // Map all RHS elements to Assignments
-                        .map(|assignment| -> Result<ResolvingAssignment> {
-                            use rustemo_actions::Assignment::*;
-                            let is_bool = matches! { assignment, BoolAssignment(_) };
-                            match assignment {
-                                PlainAssignment(mut assign) | BoolAssignment(mut assign) => {
-                                    self.check_identifier(&assign.name)?;
-                                    self.desugar_regex(
-                                        &mut assign.gsymref,
-                                        &mut desugar_productions,
-                                    )?;
-                                    Ok(ResolvingAssignment {
-                                        name: Some(assign.name),
-                                        symbol: ResolvingSymbolIndex {
-                                            index: None,
-                                            symbol: assign.gsymref.gsymbol.unwrap(),
-                                        },
-                                        is_bool,
-                                    })
-                                }
-                                GrammarSymbolRef(mut reference) => {
-                                    self.desugar_regex(&mut reference, &mut desugar_productions)?;
-                                    Ok(ResolvingAssignment {
-                                        name: None,
-                                        symbol: ResolvingSymbolIndex {
-                                            index: None,
-                                            symbol: reference.gsymbol.unwrap(),
-                                        },
-                                        is_bool: false,
-                                    })
-                                }
-                            }
-                        })
-                        .collect::<Result<Vec<_>>>()?,
-                    meta: production.meta,
-                    ..Production::default()
-                };
-
-                // Inherit meta-data from Rule.
-                for (key, data) in &rule.meta {
-                    if !new_production.meta.contains_key(key) {
-                        new_production.meta.insert(key.clone(), data.clone());
-                    }
-                }
-
-                // Map meta-data to production fields for easier access
-                if let Some(ConstVal::Int(prio)) = new_production.meta.remove("priority") {
-                    new_production.prio = prio.into();
-                }
-
-                if let Some(ConstVal::String(kind)) = new_production.meta.remove("kind") {
-                    new_production.kind = Some(kind.into());
-                }
-
-                if new_production.meta.remove("left").is_some() {
-                    new_production.assoc = Associativity::Left;
-                }
-                if new_production.meta.remove("right").is_some() {
-                    new_production.assoc = Associativity::Right;
-                }
-                if new_production.meta.remove("nops").is_some() {
-                    new_production.nops = true;
-                }
-                if new_production.meta.remove("nopse").is_some() {
-                    new_production.nopse = true;
-                }
-
-                self.productions.push(new_production);
-                self.productions.extend(desugar_productions);
+                let mut productions: Vec<Production> = vec![];
+                let (prod_idx, derives) = self.create_production(
+                    nt_idx,
+                    prod_ntidx,
+                    production,
+                    &rule.name,
+                    &rule.meta,
+                    &mut productions,
+                )?;
+                self.productions.extend(productions);
                 let nonterminal = self
                     .nonterminals
                     .entry(rule.name.as_ref().into())
@@ -351,14 +440,179 @@ impl GrammarBuilder {
                         idx: nt_idx,
                         name: rule.name.as_ref().into(),
                         annotation: rule.annotation.as_ref().map(|a| a.as_ref().into()),
+                        span: rule.name.span,
                         ..Default::default()
                     });
                 nonterminal.productions.push(prod_idx);
+                for derive in derives {
+                    if !nonterminal.derives.contains(&derive) {
+                        nonterminal.derives.push(derive);
+                    }
+                }
             }
         }
         Ok(())
     }
 
+    /// Creates a production of the non-terminal `nt_idx` from the given
+    /// production of the rule `rule`. The production is pushed to
+    /// `productions` followed by the productions created by desugaring. Returns
+    /// the index of the production and the derives from its meta-data.
+    fn create_production(
+        &mut self,
+        nt_idx: NonTermIndex,
+        prod_ntidx: usize,
+        production: rustemo_actions::Production,
+        rule: &Name,
+        rule_meta: &ProdMetaDatas,
+        productions: &mut Vec<Production>,
+    ) -> Result<(ProdIndex, Vec<String>)> {
+        // Gather productions, create indexes. Transform RHS to mark
+        // resolving references. Desugar regex-like references.
+        let mut desugar_productions: Vec<Production> = vec![];
+        let prod_idx = self.get_prod_idx();
+
+        let mut new_production = Production {
+            idx: prod_idx,
+            nonterminal: nt_idx,
+            ntidx: prod_ntidx,
+            rhs: production
+                .assignments
+                .into_iter()
+                // Remove EMPTY from production RHS
+                .filter(|assignment| {
+                    use rustemo_actions::Assignment;
+                    !matches!(assignment, Assignment::GrammarSymbolRef(
+                                GrammarSymbolRef {
+                                    gsymbol:
+                                        Some(GrammarSymbol::Name(name)),
+                                    ..
+                                },
+                            ) if name.as_ref() == "EMPTY")
+                })
+                // Map all RHS elements to Assignments
+                .map(|assignment| -> Result<ResolvingAssignment> {
+                    use rustemo_actions::Assignment::*;
+                    let is_bool = matches! { assignment, BoolAssignment(_) };
+                    match assignment {
+                        PlainAssignment(mut assign) | BoolAssignment(mut assign) => {
+                            self.check_identifier(&assign.name)?;
+                            self.desugar_regex(
+                                &mut assign.gsymref,
+                                rule,
+                                &mut desugar_productions,
+                            )?;
+                            Ok(ResolvingAssignment {
+                                name: Some(assign.name),
+                                symbol: ResolvingSymbolIndex {
+                                    index: None,
+                                    symbol: assign.gsymref.gsymbol.unwrap(),
+                                },
+                                is_bool,
+                            })
+                        }
+                        GrammarSymbolRef(mut reference) => {
+                            self.desugar_regex(&mut reference, rule, &mut desugar_productions)?;
+                            Ok(ResolvingAssignment {
+                                name: None,
+                                symbol: ResolvingSymbolIndex {
+                                    index: None,
+                                    symbol: reference.gsymbol.unwrap(),
+                                },
+                                is_bool: false,
+                            })
+                        }
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?,
+            meta: production.meta,
+            ..Production::default()
+        };
+
+        // Inherit meta-data from Rule.
+        for (key, data) in rule_meta {
+            if !new_production.meta.contains_key(key) {
+                new_production.meta.insert(key.clone(), data.clone());
+            }
+        }
+
+        // Inherit priority and associativity from the precedence block
+        // through the `prec` symbol or the last terminal of the production.
+        // Explicit priority and associativity override the inherited ones.
+        // User meta-data `prec` which is not a name is kept.
+        let prec = match new_production.meta.get("prec") {
+            Some(ConstVal::String(_)) => new_production.meta.remove("prec"),
+            _ => None,
+        };
+        let precedence = match prec {
+            Some(ConstVal::String(name)) => {
+                Some(*self.precedence.get(name.as_ref()).ok_or_else(|| {
+                    error::<()>(
+                        Code::InvalidPrecedence,
+                        format!("Symbol '{name}' is not given in the precedence block."),
+                        &self.file,
+                        name.span,
+                    )
+                    .unwrap_err()
+                })?)
+            }
+            _ => new_production
+                .rhs
+                .iter()
+                .rev()
+                .find_map(|assign| match &assign.symbol.symbol {
+                    GrammarSymbol::Name(name) if self.terminals.contains_key(name.as_ref()) => {
+                        Some(name.as_ref().clone())
+                    }
+                    GrammarSymbol::StrConst(mtch) => self
+                        .terminals_matches
+                        .get(mtch.as_ref())
+                        .map(|(name, _)| name.clone()),
+                    _ => None,
+                })
+                .and_then(|name| self.precedence.get(&name).copied()),
+        };
+        if let Some((prio, assoc)) = precedence {
+            new_production.prio = prio;
+            new_production.assoc = assoc;
+        }
+
+        // Map meta-data to production fields for easier access
+        if let Some(ConstVal::Int(prio)) = new_production.meta.remove("priority") {
+            new_production.prio = prio.into();
+        }
+
+        if let Some(ConstVal::String(kind)) = new_production.meta.remove("kind") {
+            new_production.kind = Some(kind.into());
+        }
+
+        if new_production.meta.remove("left").is_some() {
+            new_production.assoc = Associativity::Left;
+        }
+        if new_production.meta.remove("right").is_some() {
+            new_production.assoc = Associativity::Right;
+        }
+        if new_production.meta.remove("nops").is_some() {
+            new_production.nops = true;
+        }
+        if new_production.meta.remove("nopse").is_some() {
+            new_production.nopse = true;
+        }
+        let derives = match new_production.meta.remove("derive") {
+            Some(ConstVal::String(derives)) => derives
+                .as_ref()
+                .split(',')
+                .map(|derive| derive.trim().to_string())
+                .filter(|derive| !derive.is_empty())
+                .collect(),
+            _ => vec![],
+        };
+
+        productions.push(new_production);
+        productions.extend(desugar_productions);
+        Ok((prod_idx, derives))
+    }
+
     fn create_aug_nt_and_production(&mut self, nt_name: &str, rhs_rule_name: &str) {
         // Augmented non-terminal and production.
         let nt_idx = self.get_nonterm_idx();
@@ -391,99 +645,226 @@ impl GrammarBuilder {
     }
 
     /// Support for regex-like syntax sugar. E.g: A+, A*, A? and greedy
-    /// variants with ! suffix: A*!...
+    /// variants with ! suffix: A*!... Parenthesized groups are replaced by
+    /// new non-terminals named after the rule `rule` they are used in.
     fn desugar_regex(
         &mut self,
         gsymref: &mut GrammarSymbolRef,
+        rule: &Name,
         productions: &mut Vec<Production>,
     ) -> Result<()> {
-        fn nt_name(name: &Name, rep_op: &RepetitionOperatorOp) -> Name {
-            Name::new(
-                format!(
-                    "{}{}",
-                    &name,
-                    match rep_op {
-                        RepetitionOperatorOp::ZeroOrMore => "0",
-                        RepetitionOperatorOp::ZeroOrMoreGreedy => "0Greedy",
-                        RepetitionOperatorOp::OneOrMore => "1",
-                        RepetitionOperatorOp::OneOrMoreGreedy => "1Greedy",
-                        RepetitionOperatorOp::Optional => "Opt",
-                        RepetitionOperatorOp::OptionalGreedy => "OptGreedy",
-                    }
-                ),
-                name.span,
-            )
+        // Names of groups may end with an index, e.g. `CallGroup2`, so numeric
+        // operator names are separated from them, e.g. `CallGroup2_1`.
+        fn nt_name(name: &Name, rep_op: &RepetitionOperatorOp, suffix: &str, group: bool) -> Name {
+            let op = match rep_op {
+                RepetitionOperatorOp::ZeroOrMore => "0",
+                RepetitionOperatorOp::ZeroOrMoreGreedy => "0Greedy",
+                RepetitionOperatorOp::OneOrMore => "1",
+                RepetitionOperatorOp::OneOrMoreGreedy => "1Greedy",
+                RepetitionOperatorOp::Optional => "Opt",
+                RepetitionOperatorOp::OptionalGreedy => "OptGreedy",
+            };
+            let separator = if group
+                && name.ends_with(|c: char| c.is_ascii_digit())
+                && op.starts_with(|c: char| c.is_ascii_digit())
+            {
+                "_"
+            } else {
+                ""
+            };
+            Name::new(format!("{name}{separator}{op}{suffix}"), name.span)
+        }
+
+        let group = gsymref.production_group.is_some();
+        if let Some(production_group) = gsymref.production_group.take() {
+            let name = self.create_group(rule, production_group, productions)?;
+            gsymref.gsymbol = Some(GrammarSymbol::Name(name));
         }
 
         if let Some(ref op) = gsymref.repetition_op {
-            let modifiers = &op.rep_modifiers;
-            let modifier = if let Some(modifiers) = modifiers {
-                assert!(
-                    modifiers.len() == 1,
-                    "Separator modifier is supported only!"
-                );
-                Some(&modifiers[0])
-            } else {
-                None
-            };
-            // TODO: This unwrap may fail in case of production groups use
-            // which is still unimplemented but allowed by the grammar.
+            let repetition = self.repetition(op)?;
             let ref_type = match gsymref
                 .gsymbol
                 .as_ref()
-                .expect("Parenthesized groups are not implemented!")
+                .expect("Groups are desugared to names.")
             {
                 GrammarSymbol::Name(ref name) => name.clone(),
                 GrammarSymbol::StrConst(ref mtch) => {
                     if let Some(term) = self.terminals_matches.get(mtch.as_ref()) {
                         ValSpan::new(term.0.clone(), mtch.span)
                     } else {
-                        return err!(
+                        return error(
+                            Code::UndefinedTerminal,
                             format!(
                                 r#"Terminal "{}" is not defined in the terminals section."#,
                                 mtch
                             ),
-                            Some(self.file.clone()),
-                            mtch.span
+                            &self.file,
+                            mtch.span,
                         );
                     }
                 }
             };
 
+            use RepetitionOperatorOp::*;
+            let greedy = matches!(
+                op.rep_op,
+                ZeroOrMoreGreedy | OneOrMoreGreedy | OptionalGreedy
+            );
+            let name = nt_name(&ref_type, &op.rep_op, &repetition.suffix(), group);
             match op.rep_op {
-                RepetitionOperatorOp::ZeroOrMore => {
-                    let one_name = nt_name(&ref_type, &RepetitionOperatorOp::OneOrMore);
+                ZeroOrMore | ZeroOrMoreGreedy => {
+                    let one_op = if greedy { OneOrMoreGreedy } else { OneOrMore };
+                    let one_name = nt_name(&ref_type, &one_op, &repetition.suffix(), group);
                     if !self.nonterminals.contains_key(one_name.as_ref()) {
-                        self.create_one(one_name.clone(), &ref_type, &modifier, productions);
+                        let plain_name =
+                            nt_name(&ref_type, &one_op, &repetition.plain_suffix(), group);
+                        self.create_one(
+                            one_name.clone(),
+                            &plain_name,
+                            &ref_type,
+                            &repetition,
+                            productions,
+                        );
+                        self.mark_greedy(&one_name, greedy, productions);
+                        self.mark_greedy(&plain_name, greedy, productions);
                     }
-                    let name = nt_name(&ref_type, &op.rep_op);
                     if !self.nonterminals.contains_key(name.as_ref()) {
                         self.create_zero(name.clone(), &one_name, productions);
+                        self.mark_greedy(&name, greedy, productions);
                     }
-                    gsymref.gsymbol = Some(GrammarSymbol::Name(name))
                 }
-                RepetitionOperatorOp::OneOrMore => {
-                    let name = nt_name(&ref_type, &op.rep_op);
+                OneOrMore | OneOrMoreGreedy => {
                     if !self.nonterminals.contains_key(name.as_ref()) {
-                        self.create_one(name.clone(), &ref_type, &modifier, productions);
+                        let plain_name =
+                            nt_name(&ref_type, &op.rep_op, &repetition.plain_suffix(), group);
+                        self.create_one(
+                            name.clone(),
+                            &plain_name,
+                            &ref_type,
+                            &repetition,
+                            productions,
+                        );
+                        self.mark_greedy(&name, greedy, productions);
+                        self.mark_greedy(&plain_name, greedy, productions);
                     }
-                    gsymref.gsymbol = Some(GrammarSymbol::Name(name))
                 }
-                RepetitionOperatorOp::Optional => {
-                    let name = nt_name(&ref_type, &op.rep_op);
+                Optional | OptionalGreedy => {
                     if !self.nonterminals.contains_key(name.as_ref()) {
                         self.create_optional(name.clone(), &ref_type, productions);
+                        self.mark_greedy(&name, greedy, productions);
                     }
-                    gsymref.gsymbol = Some(GrammarSymbol::Name(name))
                 }
-                RepetitionOperatorOp::OneOrMoreGreedy => todo!(),
-                RepetitionOperatorOp::ZeroOrMoreGreedy => todo!(),
-                RepetitionOperatorOp::OptionalGreedy => todo!(),
             }
+            gsymref.gsymbol = Some(GrammarSymbol::Name(name))
         }
         Ok(())
     }
 
+    /// Checks the modifiers of the repetition operator given in square
+    /// brackets, e.g. `A+[Comma, trailing, min: 2]`.
+    fn repetition(&self, op: &RepetitionOperator) -> Result<Repetition> {
+        let mut repetition = Repetition::default();
+        let modifiers = op.rep_modifiers.iter().flatten().collect::<Vec<_>>();
+        let error = |message: String, name: &Name| -> Result<Repetition> {
+            error(Code::InvalidRepetition, message, &self.file, name.span)
+        };
+        let mut trailing = None;
+        let mut recursion: Option<&Name> = None;
+        let mut min = None;
+        for modifier in &modifiers {
+            let name = &modifier.name;
+            match (name.as_str(), &modifier.value) {
+                ("min" | "max", Some(value)) => {
+                    let bound = if name.as_str() == "min" {
+                        min = Some(modifier);
+                        &mut repetition.min
+                    } else {
+                        &mut repetition.max
+                    };
+                    if bound.is_some() {
+                        return error(
+                            format!("Repetition modifier '{name}' is given more than once."),
+                            name,
+                        );
+                    }
+                    if *value.as_ref() == 0 {
+                        return error(
+                            format!("Repetition modifier '{name}' must be at least 1."),
+                            name,
+                        );
+                    }
+                    *bound = Some(*value.as_ref());
+                }
+                ("min" | "max", None) => {
+                    return error(
+                        format!(
+                            "Repetition modifier '{name}' requires a value, e.g. '{name}: 2'."
+                        ),
+                        name,
+                    )
+                }
+                (_, Some(_)) => {
+                    return error(format!("Unknown repetition modifier '{name}'."), name)
+                }
+                ("trailing", None) => {
+                    trailing = Some(name);
+                    repetition.trailing = true;
+                }
+                ("left" | "right", None) => {
+                    if recursion.is_some_and(|r| r.as_str() != name.as_str()) {
+                        return error(
+                            "Repetition can't be both left and right recursive.".into(),
+                            name,
+                        );
+                    }
+                    recursion = Some(name);
+                    repetition.right = name.as_str() == "right";
+                }
+                (_, None) => {
+                    if repetition.separator.is_some() {
+                        return error(
+                            "Repetition separator is given more than once.".into(),
+                            name,
+                        );
+                    }
+                    repetition.separator = Some(name.clone());
+                }
+            }
+        }
+
+        use RepetitionOperatorOp::*;
+        if let (Optional | OptionalGreedy, Some(modifier)) = (&op.rep_op, modifiers.first()) {
+            return error(
+                "Repetition modifiers can't be used with the optional operator.".into(),
+                &modifier.name,
+            );
+        }
+        if let (ZeroOrMore | ZeroOrMoreGreedy, Some(min)) = (&op.rep_op, min) {
+            return error(
+                "Repetition modifier 'min' can be used only with the '+' operator.".into(),
+                &min.name,
+            );
+        }
+        if let Some(trailing) = trailing.filter(|_| repetition.separator.is_none()) {
+            return error(
+                "Trailing separator requires a separator, e.g. '[Comma, trailing]'.".into(),
+                trailing,
+            );
+        }
+        if let (Some(min_value), Some(max_value)) = (repetition.min, repetition.max) {
+            if min_value > max_value {
+                return error(
+                    "Repetition modifier 'min' is greater than 'max'.".into(),
+                    &min.unwrap().name,
+                );
+            }
+        }
+        // `min: 1` is the default.
+        repetition.min = repetition.min.filter(|&min| min > 1);
+        Ok(repetition)
+    }
+
     /// Inline terminals are those created by specifying string match directly
     /// as a part of a production. In such a case we should verify that the
     /// terminal with the same string match is defined and we should resolve
@@ -509,7 +890,8 @@ impl GrammarBuilder {
                                 .symbol_index(),
                         );
                     } else {
-                        err!(
+                        error(
+                            Code::UndefinedTerminal,
                             format!(
                                 concat!(
                                     "Terminal \"{}\" used in production \"{}\" ",
@@ -517,8 +899,8 @@ impl GrammarBuilder {
                                 ),
                                 mtch, production_str
                             ),
-                            Some(self.file.clone()),
-                            mtch.span
+                            &self.file,
+                            mtch.span,
                         )?
                     }
                 }
@@ -547,21 +929,28 @@ impl GrammarBuilder {
                                     .nonterminals
                                     .get(name.as_ref())
                                     .ok_or_else(|| {
-                                        let r: Result<()> = err!(
+                                        error::<()>(
+                                            Code::UndefinedSymbol,
                                             format!(
                                                 "Unexisting symbol '{}' in production '{}'.",
                                                 name, production_str
                                             ),
-                                            Some(self.file.clone()),
-                                            name.span
-                                        );
-                                        r.unwrap_err()
+                                            &self.file,
+                                            name.span,
+                                        )
+                                        .unwrap_err()
                                     })?
                                     .idx;
                                 if rhs_len == 1 && nt_idx == production.nonterminal {
-                                    err!(format!("Infinite recursion on symbol '{}' in production '{}'.",
-                                            name, production_str),
-                                             Some(self.file.clone()), name.span)?;
+                                    error(
+                                        Code::InfiniteRecursion,
+                                        format!(
+                                            "Infinite recursion on symbol '{}' in production '{}'.",
+                                            name, production_str
+                                        ),
+                                        &self.file,
+                                        name.span,
+                                    )?;
                                 }
                                 nt_idx.symbol_index(self.terminals.len())
                             }
@@ -584,6 +973,71 @@ impl GrammarBuilder {
         Ok(())
     }
 
+    /// Creates a non-terminal for the group used in the rule `rule` and returns
+    /// its name. Groups are named `<rule>Group`, `<rule>Group2`... in the order
+    /// of appearance, skipping the names of rules and terminals of the grammar.
+    fn create_group(
+        &mut self,
+        rule: &Name,
+        group: ProductionGroup,
+        productions: &mut Vec<Production>,
+    ) -> Result<Name> {
+        let name = (1..)
+            .map(|idx| match idx {
+                1 => format!("{rule}Group"),
+                _ => format!("{rule}Group{idx}"),
+            })
+            .find(|name| {
+                !self.rule_names.contains(name)
+                    && !self.nonterminals.contains_key(name)
+                    && !self.terminals.contains_key(name)
+            })
+            .unwrap();
+        let name = Name::new(name, rule.span);
+        let nt_idx = self.get_nonterm_idx();
+        // Register the non-terminal first so that nested groups get different
+        // names.
+        self.nonterminals.insert(
+            name.as_ref().clone(),
+            NonTerminal {
+                idx: nt_idx,
+                name: name.as_ref().clone(),
+                span: name.span,
+                ..Default::default()
+            },
+        );
+        for (prod_ntidx, production) in group.0.into_iter().enumerate() {
+            let (prod_idx, derives) = self.create_production(
+                nt_idx,
+                prod_ntidx,
+                production,
+                rule,
+                &ProdMetaDatas::new(),
+                productions,
+            )?;
+            let nonterminal = self.nonterminals.get_mut(name.as_ref()).unwrap();
+            nonterminal.productions.push(prod_idx);
+            for derive in derives {
+                if !nonterminal.derives.contains(&derive) {
+                    nonterminal.derives.push(derive);
+                }
+            }
+        }
+        Ok(name)
+    }
+
+    /// Marks the productions of the given desugared non-terminal, if created,
+    /// as a part of a greedy repetition.
+    fn mark_greedy(&self, name: &Name, greedy: bool, productions: &mut [Production]) {
+        if let Some(nt) = self.nonterminals.get(name.as_ref()).filter(|_| greedy) {
+            let nt_idx = nt.idx;
+            productions
+                .iter_mut()
+                .filter(|prod| prod.nonterminal == nt_idx)
+                .for_each(|prod| prod.greedy = true);
+        }
+    }
+
     fn create_optional(&mut self, name: Name, ref_name: &Name, productions: &mut Vec<Production>) {
         let nt_index = self.get_nonterm_idx();
         let nt = NonTerminal {
@@ -612,92 +1066,128 @@ impl GrammarBuilder {
                     prod_idx
                 })
                 .collect(),
+            span: name.span,
             ..Default::default()
         };
         self.nonterminals.insert(name.into(), nt);
     }
 
+    /// Creates the rule for one or more repetitions. The rule is left
+    /// recursive unless the `right` modifier is given, e.g. for `A+[Comma]`:
+    ///
+    ///     A1: A1 Comma A | A;
+    ///
+    /// Bounded repetitions have a production for each allowed number of
+    /// elements. Repetitions with a trailing separator use the rule named
+    /// `plain_name`, i.e. the rule without the trailing separator, if left
+    /// recursive, e.g. for `A+[Comma, trailing]`:
+    ///
+    ///     A1CommaTrailing: A1Comma | A1Comma Comma;
     fn create_one(
         &mut self,
         name: Name,
+        plain_name: &Name,
         ref_name: &Name,
-        modifier: &Option<&rustemo_actions::RepetitionModifier>,
+        repetition: &Repetition,
         productions: &mut Vec<Production>,
     ) {
-        let nt_idx = self.get_nonterm_idx();
-        let nt = NonTerminal {
-            idx: nt_idx,
-            name: name.as_ref().clone(),
-            annotation: Some("vec".into()),
-            productions: (0..2)
-                .map(|idx| {
-                    let prod_idx = self.get_prod_idx();
-                    if idx == 0 {
-                        productions.push(Production {
-                            idx: prod_idx,
-                            nonterminal: nt_idx,
-                            ntidx: idx,
-                            rhs: if modifier.is_none() {
-                                // without separator
-                                vec![resolving!(name.clone()), resolving!(ref_name.clone())]
-                            } else {
-                                // with separator.
-                                let sep = modifier.unwrap().clone();
-                                vec![
-                                    resolving!(name.clone()),
-                                    resolving!(sep),
-                                    resolving!(ref_name.clone()),
-                                ]
-                            },
-                            ..Default::default()
-                        });
-                    } else {
-                        productions.push(Production {
-                            idx: prod_idx,
-                            nonterminal: nt_idx,
-                            ntidx: idx,
-                            rhs: vec![resolving!(ref_name.clone())],
-                            ..Default::default()
-                        });
-                    }
-                    prod_idx
-                })
-                .collect(),
-            reachable: false.into(),
+        let separator = repetition.separator.as_ref();
+        let elements = |count: u32, trailing: bool| {
+            let mut rhs = vec![resolving!(ref_name.clone())];
+            for _ in 1..count {
+                rhs.extend(separator.map(|sep| resolving!(sep.clone())));
+                rhs.push(resolving!(ref_name.clone()));
+            }
+            if trailing {
+                rhs.extend(separator.map(|sep| resolving!(sep.clone())));
+            }
+            rhs
         };
-        self.nonterminals.insert(name.into(), nt);
+        let min = repetition.min.unwrap_or(1);
+        let mut rhss = vec![];
+        if let Some(max) = repetition.max {
+            for count in min..=max {
+                rhss.push(elements(count, false));
+                if repetition.trailing {
+                    rhss.push(elements(count, true));
+                }
+            }
+        } else if repetition.right {
+            let mut rhs = vec![resolving!(ref_name.clone())];
+            rhs.extend(separator.map(|sep| resolving!(sep.clone())));
+            rhs.push(resolving!(name.clone()));
+            rhss.push(rhs);
+            rhss.push(elements(min, false));
+            if repetition.trailing {
+                rhss.push(elements(min, true));
+            }
+        } else if repetition.trailing {
+            if !self.nonterminals.contains_key(plain_name.as_ref()) {
+                self.create_one(
+                    plain_name.clone(),
+                    plain_name,
+                    ref_name,
+                    &repetition.plain(),
+                    productions,
+                );
+            }
+            let separator = separator.expect("Trailing separator is checked.");
+            rhss.push(vec![resolving!(plain_name.clone())]);
+            rhss.push(vec![
+                resolving!(plain_name.clone()),
+                resolving!(separator.clone()),
+            ]);
+        } else {
+            let mut rhs = vec![resolving!(name.clone())];
+            rhs.extend(separator.map(|sep| resolving!(sep.clone())));
+            rhs.push(resolving!(ref_name.clone()));
+            rhss.push(rhs);
+            rhss.push(elements(min, false));
+        }
+        self.create_vec(name, rhss, repetition.right, productions);
     }
 
     fn create_zero(&mut self, name: Name, one_name: &Name, productions: &mut Vec<Production>) {
+        self.create_vec(
+            name,
+            vec![vec![resolving!(one_name.clone())], vec![]],
+            false,
+            productions,
+        );
+    }
+
+    /// Creates the rule of a repetition with the given productions.
+    fn create_vec(
+        &mut self,
+        name: Name,
+        rhss: Vec<Vec<ResolvingAssignment>>,
+        in_order: bool,
+        productions: &mut Vec<Production>,
+    ) {
         let nt_idx = self.get_nonterm_idx();
         let nt = NonTerminal {
             idx: nt_idx,
             name: name.as_ref().clone(),
             annotation: Some("vec".into()),
-            productions: (0..2)
-                .map(|idx| {
+            productions: rhss
+                .into_iter()
+                .enumerate()
+                .map(|(idx, rhs)| {
                     let prod_idx = self.get_prod_idx();
-                    if idx == 0 {
-                        productions.push(Production {
-                            idx: prod_idx,
-                            nonterminal: nt_idx,
-                            ntidx: idx,
-                            rhs: vec![resolving!(one_name.clone())],
-                            ..Default::default()
-                        });
-                    } else {
-                        productions.push(Production {
-                            idx: prod_idx,
-                            nonterminal: nt_idx,
-                            ntidx: idx,
-                            rhs: vec![],
-                            ..Default::default()
-                        });
-                    }
+                    productions.push(Production {
+                        idx: prod_idx,
+                        nonterminal: nt_idx,
+                        ntidx: idx,
+                        rhs,
+                        ..Default::default()
+                    });
                     prod_idx
                 })
                 .collect(),
+            derives: vec![],
             reachable: false.into(),
+            span: name.span,
+            in_order,
         };
         self.nonterminals.insert(name.into(), nt);
     }
@@ -705,16 +1195,85 @@ impl GrammarBuilder {
     fn check_identifier(&self, name: &ValSpan<String>) -> Result<()> {
         let result = syn::parse_str::<syn::Ident>(name.as_ref());
         if result.is_err() {
-            err!(
+            error(
+                Code::InvalidIdentifier,
                 format!("Can't use '{}' as a valid Rust identifier.", &name),
-                Some(self.file.clone()),
-                name.span
+                &self.file,
+                name.span,
             )?
         }
         Ok(())
     }
 }
 
+/// Modifiers of a repetition operator.
+#[derive(Debug, Default, Clone)]
+struct Repetition {
+    separator: Option<Name>,
+    /// An optional separator is allowed after the last element.
+    trailing: bool,
+    min: Option<u32>,
+    max: Option<u32>,
+    /// Right recursive expansion.
+    right: bool,
+}
+
+impl Repetition {
+    /// The suffix of the names of the rules created for the repetition, e.g.
+    /// `CommaTrailing` in `A1CommaTrailing`. Repetitions with only a separator
+    /// have no suffix, e.g. `A+[Comma]` creates `A1`.
+    fn suffix(&self) -> String {
+        if !self.trailing && self.min.is_none() && self.max.is_none() && !self.right {
+            return String::new();
+        }
+        self.modifiers()
+    }
+
+    /// The suffix of the rule without the trailing separator which is used by
+    /// the rule of the repetition, e.g. `Comma` in `A1Comma`. The separator is
+    /// always given so that the rule isn't shared with `A+`.
+    fn plain_suffix(&self) -> String {
+        self.plain().modifiers()
+    }
+
+    fn modifiers(&self) -> String {
+        let mut suffix = self
+            .separator
+            .as_ref()
+            .map(|sep| sep.as_ref().clone())
+            .unwrap_or_default();
+        if self.trailing {
+            suffix.push_str("Trailing");
+        }
+        if let Some(min) = self.min {
+            suffix.push_str(&format!("Min{min}"));
+        }
+        if let Some(max) = self.max {
+            suffix.push_str(&format!("Max{max}"));
+        }
+        if self.right {
+            suffix.push_str("Right");
+        }
+        suffix
+    }
+
+    /// The same repetition without the trailing separator.
+    fn plain(&self) -> Self {
+        Self {
+            trailing: false,
+            ..self.clone()
+        }
+    }
+}
+
+/// Creates the grammar error with the given code.
+fn error<T>(code: Code, message: String, file: &str, span: Option<SourceSpan>) -> Result<T> {
+    Diagnostic::new(code, message)
+        .with_file(file)
+        .with_span(span)
+        .into()
+}
+
 fn mark_reachable_symbols(grammar: &Grammar) {
     let mut visited = BTreeSet::<ProdIndex>::new();
 
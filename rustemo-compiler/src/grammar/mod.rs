@@ -3,10 +3,11 @@ use std::{
     collections::BTreeMap,
     fmt::Display,
     hash::{Hash, Hasher},
+    path::Path,
     str::FromStr,
 };
 
-use rustemo::{Error, Parser, Result};
+use rustemo::{Error, ModeAction, Parser, Result, SourceSpan};
 
 use crate::{
     index::{
@@ -45,6 +46,9 @@ pub struct Grammar {
     pub augmented_layout_index: Option<SymbolIndex>,
     /// An index of the start symbol. First non-terminal or terminal of the grammar.
     pub start_index: SymbolIndex,
+    /// Names of lexical modes in the order of definition. The first is the
+    /// initial mode.
+    pub lexical_modes: Vec<String>,
 }
 
 macro_rules! grammar_elem {
@@ -96,7 +100,24 @@ pub struct Terminal {
     /// Associativity used to decide shift/reduce conflict resolutions
     pub assoc: Associativity,
 
+    /// A user friendly name of the terminal used in error reports.
+    pub display: Option<String>,
+
+    /// The type of the terminal value given by `type` meta-data. Either a
+    /// built-in conversion (`int`, `float`, `bool`, `string`) or a Rust type
+    /// implementing `FromStr`.
+    pub value_type: Option<String>,
+
+    /// The index of the lexical mode in which the terminal is recognized.
+    pub mode: usize,
+
+    /// The change of lexical modes done when the terminal is shifted.
+    pub mode_action: Option<ModeAction>,
+
     pub meta: TermMetaDatas,
+
+    /// The location of the terminal name in the grammar file.
+    pub span: Option<SourceSpan>,
 }
 grammar_elem!(Terminal);
 
@@ -107,9 +128,22 @@ pub struct NonTerminal {
     pub annotation: Option<String>,
     pub productions: Vec<ProdIndex>,
 
+    /// Additional derives for the generated AST types given by `derive`
+    /// meta-data.
+    pub derives: Vec<String>,
+
     /// Is this non-terminal reachable from the start rule.
     /// Used to determine layout-only rules.
     pub reachable: Cell<bool>,
+
+    /// The location of the rule name in the grammar file. For rules created by
+    /// desugaring it is the location of the reference.
+    pub span: Option<SourceSpan>,
+
+    /// Elements of this right recursive `@vec` rule are collected in the input
+    /// order. Set for repetitions with the `right` modifier while right
+    /// recursive rules given in the grammar collect the elements in reverse.
+    pub in_order: bool,
 }
 grammar_elem!(NonTerminal);
 
@@ -157,7 +191,7 @@ impl Display for Grammar {
     }
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Associativity {
     #[default]
     None,
@@ -168,6 +202,9 @@ pub enum Associativity {
 pub type Priority = u32;
 pub const DEFAULT_PRIORITY: u32 = 10;
 
+/// The name of the lexical mode of terminals given outside of mode blocks.
+pub const INITIAL_MODE: &str = "INITIAL";
+
 #[derive(Debug)]
 pub struct Production {
     pub idx: ProdIndex,
@@ -180,6 +217,8 @@ pub struct Production {
     pub dynamic: bool,
     pub nops: bool,
     pub nopse: bool,
+    /// The production is a part of a greedy repetition (`*!`, `+!`, `?!`).
+    pub greedy: bool,
     pub meta: ProdMetaDatas,
 }
 grammar_elem!(Production);
@@ -199,6 +238,7 @@ impl Default for Production {
             dynamic: Default::default(),
             nops: Default::default(),
             nopse: Default::default(),
+            greedy: Default::default(),
             meta: Default::default(),
         }
     }
@@ -239,6 +279,16 @@ impl Production {
         res_symbol(&self.rhs[pos])
     }
 
+    /// The location of the production in the grammar file, i.e. the location
+    /// of its first symbol or of its rule if the production is empty.
+    pub fn span(&self, grammar: &Grammar) -> Option<SourceSpan> {
+        match self.rhs.first().map(|assign| &assign.symbol.symbol) {
+            Some(GrammarSymbol::Name(name)) => name.span,
+            Some(GrammarSymbol::StrConst(mtch)) => mtch.span,
+            None => self.nonterminal(grammar).span,
+        }
+    }
+
     #[inline]
     pub fn nonterminal<'a>(&self, grammar: &'a Grammar) -> &'a NonTerminal {
         &grammar.nonterminals[self.nonterminal]
@@ -333,18 +383,13 @@ impl Grammar {
             .try_from_file(RustemoParser::new().parse(grammar_str.as_ref())?, None)
     }
 
-    // /// Parses given file and constructs a Grammar instance
-    // /// FIXME: Return/move owned string from file content.
-    // pub fn from_file<F: AsRef<Path>>(file: F) -> Result<Self> {
-    //     use crate::rustemo_types::{NonTerminal, Symbol};
-    //     if let Symbol::NonTerminal(NonTerminal::PGFile(pgfile)) =
-    //         RustemoParser::parse_file(file)?
-    //     {
-    //         Ok(Self::from_pgfile(pgfile))
-    //     } else {
-    //         panic!("Invalid symbol from grammar parse!")
-    //     }
-    // }
+    /// Parses given file and constructs a Grammar instance. Imports are
+    /// resolved relative to the file.
+    pub fn from_file<F: AsRef<Path>>(file: F) -> Result<Self> {
+        let mut parser = RustemoParser::new();
+        let grammar_file = parser.parse_file(file.as_ref())?;
+        GrammarBuilder::new().try_from_file(grammar_file, Some(file.as_ref()))
+    }
 
     pub(crate) fn new_termvec<T: Clone>(&self, default: T) -> TermVec<T> {
         TermVec(vec![default; self.terminals.len()])
@@ -513,4 +558,10 @@ impl Grammar {
     pub fn has_layout(&self) -> bool {
         self.augmented_layout_index.is_some()
     }
+
+    /// Are lexical modes other than the initial mode defined.
+    #[inline]
+    pub fn has_lexical_modes(&self) -> bool {
+        self.lexical_modes.len() > 1
+    }
 }
@@ -1,10 +1,11 @@
 use crate::{
-    grammar::{Associativity, Grammar},
+    grammar::{Associativity, Grammar, INITIAL_MODE},
     index::ProdIndex,
     lang::rustemo_actions::Recognizer,
     output_cmp,
     utils::type_of,
 };
+use rustemo::ModeAction;
 
 #[test]
 fn grammar_from_string() {
@@ -204,6 +205,75 @@ fn productions_meta_data() {
     );
 }
 
+#[test]
+fn precedence_keywords_as_names() {
+    // Keywords of the precedence block and the `prec` meta-data are matched
+    // only where they can't be names.
+    let grammar: Grammar = r#"
+        S: precedence | A {prec: 3} | B {precise: 2} | A B {prec};
+        precedence: A+;
+        terminals
+        A: "a";
+        B: "b";
+        "#
+    .parse()
+    .unwrap();
+    assert!(grammar
+        .nonterminals
+        .iter()
+        .any(|nt| nt.name == "precedence"));
+
+    let user_meta = |prod: usize, name: &str| -> u32 {
+        match grammar.productions[ProdIndex(prod)].meta.get(name).unwrap() {
+            crate::lang::rustemo_actions::ConstVal::Int(i) => i.into(),
+            _ => panic!(),
+        }
+    };
+    assert_eq!(user_meta(2, "prec"), 3);
+    assert_eq!(user_meta(3, "precise"), 2);
+    assert_eq!(
+        grammar.productions[ProdIndex(4)].kind.as_deref(),
+        Some("prec")
+    );
+}
+
+#[test]
+fn lexical_mode_keywords_as_names() {
+    // Keywords of lexical modes are matched only where they can't be names.
+    let grammar: Grammar = r#"
+        S: modeline mode A B;
+        terminals
+        modeline: "m" {popular: 1};
+        mode: "o" {pop: 2, push: 3};
+        A: "a" {push Inner};
+        mode Inner {
+            B: "b" {pop};
+        }
+        "#
+    .parse()
+    .unwrap();
+    assert_eq!(grammar.lexical_modes, [INITIAL_MODE, "Inner"]);
+
+    let user_meta = |term: &str, name: &str| -> u32 {
+        match grammar.term_by_name(term).meta.get(name).unwrap() {
+            crate::lang::rustemo_actions::ConstVal::Int(i) => i.into(),
+            _ => panic!(),
+        }
+    };
+    assert_eq!(user_meta("modeline", "popular"), 1);
+    assert_eq!(user_meta("mode", "pop"), 2);
+    assert_eq!(user_meta("mode", "push"), 3);
+    assert!(grammar.term_by_name("mode").mode_action.is_none());
+    assert!(matches!(
+        grammar.term_by_name("A").mode_action,
+        Some(ModeAction::Push(1))
+    ));
+    assert!(matches!(
+        grammar.term_by_name("B").mode_action,
+        Some(ModeAction::Pop)
+    ));
+}
+
 // ANCHOR: meta-data-inheritance
 #[test]
 fn productions_meta_data_inheritance() {
@@ -384,3 +454,125 @@ fn invalid_names_3() {
         format!("{:#?}", grammar)
     );
 }
+
+#[test]
+fn regex_sugar_groups() {
+    let grammar: Grammar = r#"
+        Call: Name "(" (Arg ",")* Arg ")" (";" | ".")?;
+        Arg: name=Name "=" value=(Name | Num) | Num;
+        List: "[" ((Num ",")+ Num)? "]";
+        ListGroup: Name;
+        terminals
+        Name: /\w+/;
+        Num: /\d+/;
+        OpenParen: "(";
+        CloseParen: ")";
+        OpenList: "[";
+        CloseList: "]";
+        Comma: ",";
+        Semi: ";";
+        Dot: ".";
+        Equals: "=";
+        "#
+    .parse()
+    .unwrap();
+    output_cmp!(
+        "src/grammar/tests/regex_sugar_groups.expected",
+        format!("{grammar}")
+    );
+}
+
+#[test]
+fn regex_sugar_groups_names() {
+    // The repetition of the second group and the 21st group must have
+    // different names.
+    let groups = (1..=21)
+        .map(|idx| if idx == 2 { "(A B)+" } else { "(A B)" })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let grammar: Grammar = format!(
+        r#"
+        S: {groups};
+        terminals
+        A: "a";
+        B: "b";
+        "#
+    )
+    .parse()
+    .unwrap();
+    let s = grammar.nonterm_by_name("S").productions(&grammar)[0];
+    let names = grammar.symbol_names(s.rhs_symbols());
+    assert_eq!(names.len(), 21);
+    assert_eq!(names[..3], ["SGroup", "SGroup2_1", "SGroup3"]);
+    assert_eq!(names[20], "SGroup21");
+    assert_eq!(
+        grammar.nonterm_by_name("SGroup2_1").productions(&grammar)[1].to_string(&grammar),
+        "SGroup2_1: SGroup2"
+    );
+}
+
+#[test]
+fn regex_sugar_modifiers() {
+    let grammar: Grammar = r#"
+        S: A+ A*[Comma, trailing] A+[Comma, min: 2, max: 3]
+           A+[min: 3] A*[Comma, right, trailing];
+        terminals
+        A: "a";
+        Comma: ",";
+        "#
+    .parse()
+    .unwrap();
+    output_cmp!(
+        "src/grammar/tests/regex_sugar_modifiers.expected",
+        format!("{grammar}")
+    );
+}
+
+#[test]
+fn regex_sugar_modifiers_errors() {
+    for (modifiers, message) in [
+        (
+            "A+[Comma, Semi]",
+            "Repetition separator is given more than once.",
+        ),
+        ("A+[trailing]", "Trailing separator requires a separator"),
+        (
+            "A+[left, right]",
+            "Repetition can't be both left and right recursive.",
+        ),
+        ("A+[min]", "Repetition modifier 'min' requires a value"),
+        (
+            "A+[min: 0]",
+            "Repetition modifier 'min' must be at least 1.",
+        ),
+        (
+            "A+[min: 3, max: 2]",
+            "Repetition modifier 'min' is greater than 'max'.",
+        ),
+        (
+            "A+[min: 2, min: 3]",
+            "Repetition modifier 'min' is given more than once.",
+        ),
+        ("A+[sep: 2]", "Unknown repetition modifier 'sep'."),
+        (
+            "A*[min: 2]",
+            "Repetition modifier 'min' can be used only with the '+' operator.",
+        ),
+        (
+            "A?[Comma]",
+            "Repetition modifiers can't be used with the optional operator.",
+        ),
+    ] {
+        let grammar = format!(
+            r#"
+            S: {modifiers};
+            terminals
+            A: "a";
+            Comma: ",";
+            Semi: ";";
+            "#
+        );
+        let error = grammar.parse::<Grammar>().unwrap_err().to_string();
+        assert!(error.contains(message), "{modifiers}: {error}");
+    }
+}
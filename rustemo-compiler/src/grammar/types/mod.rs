@@ -58,6 +58,48 @@ impl SymbolTypes {
         &self.symbol_types[idx]
     }
 
+    /// Names of the types whose values are boxed to break recursion, in the
+    /// order of the first reference.
+    pub(crate) fn boxed_types(&self) -> Vec<String> {
+        let mut boxed: Vec<String> = vec![];
+        let mut add = |ref_type: &String, recursive: &Cell<bool>| {
+            if recursive.get() && !boxed.contains(ref_type) {
+                boxed.push(ref_type.clone());
+            }
+        };
+        for ty in &self.symbol_types {
+            match &ty.kind {
+                SymbolTypeKind::Vec {
+                    ref_type,
+                    recursive,
+                } => {
+                    add(ref_type, recursive);
+                    continue;
+                }
+                SymbolTypeKind::Ref {
+                    ref_type,
+                    recursive,
+                } => add(ref_type, recursive),
+                _ => (),
+            }
+            for choice in &ty.choices {
+                match &choice.kind {
+                    ChoiceKind::Ref {
+                        ref_type,
+                        recursive,
+                    } => add(ref_type, recursive),
+                    ChoiceKind::Struct { fields, .. } => {
+                        for field in fields {
+                            add(&field.ref_type, &field.recursive);
+                        }
+                    }
+                    ChoiceKind::Empty | ChoiceKind::Plain => (),
+                }
+            }
+        }
+        boxed
+    }
+
     /// Returns a vector of all types inferred from the provided grammar.
     fn symbol_types(grammar: &Grammar, start_symbol: String) -> SymbolVec<SymbolType> {
         let mut types = SymbolVec::new();
@@ -186,104 +228,91 @@ impl SymbolTypes {
     /// A: A B | B; or A: A B | B | EMPTY; ---> A is Vec<B>
     /// A: <Whatever> ... | EMPTY; ---> A optional Enum
     /// ```
-    fn get_type_kind(nt: &NonTerminal, choices: &Vec<Choice>) -> SymbolTypeKind {
+    fn get_type_kind(nt: &NonTerminal, choices: &[Choice]) -> SymbolTypeKind {
         let type_name = &nt.name;
-        struct Match {
-            no_match: bool,
-
-            empty: bool,
-            single: Option<String>,
-            recurse: Option<String>,
-        }
-
-        let mut m = Match {
-            no_match: false,
-            empty: false,
-            single: None,
-            recurse: None,
-        };
-
-        // For zero/one-or-more, and optional pattern recognition
-        for choice in choices {
-            match &choice.kind {
-                ChoiceKind::Empty => m.empty = true,
-                ChoiceKind::Struct { fields, .. } => match &fields[..] {
-                    [a] => {
-                        if m.single.is_none() {
-                            m.single = Some(a.ref_type.clone())
-                        } else {
-                            m.no_match = true
-                        }
-                    }
-                    [a, b] => {
-                        if m.recurse.is_none() {
-                            if a.ref_type == *type_name && b.ref_type != *type_name {
-                                m.recurse = Some(b.ref_type.clone())
-                            } else if b.ref_type == *type_name && a.ref_type != *type_name {
-                                m.recurse = Some(a.ref_type.clone())
-                            } else {
-                                m.no_match = true
-                            }
-                        } else {
-                            m.no_match = true
-                        }
-                    }
-                    _ => m.no_match = true,
-                },
-                ChoiceKind::Ref { ref_type, .. } => m.single = Some(ref_type.clone()),
-                ChoiceKind::Plain => m.no_match = true,
+        let vec = matches! { nt.annotation, Some(ref action) if action == "vec" };
+        if vec {
+            if let Some(ref_type) = Self::vec_ref_type(type_name, choices) {
+                return SymbolTypeKind::Vec {
+                    ref_type,
+                    recursive: Cell::new(false),
+                };
             }
         }
 
-        let choices_noe = choices
+        let empty = choices.iter().any(|c| matches! {c.kind, ChoiceKind::Empty});
+        let mut choices_noe = choices
             .iter()
             .filter(|c| !matches! {c.kind, ChoiceKind::Empty})
             .collect::<Vec<_>>();
 
-        match m {
-            // A: A B | B | EMPTY; or
-            // A: A B | B;
-            Match {
-                single: Some(single),
-                recurse: Some(recurse),
-                no_match: false,
-                ..
-            } if single == recurse
-                && matches! { nt.annotation, Some(ref action) if action == "vec" } =>
-            {
-                SymbolTypeKind::Vec {
-                    ref_type: single,
-                    recursive: Cell::new(false),
+        // A vector rule referencing the same symbol in all productions, e.g.
+        // `@vec A: A1 | A1 Comma;`, is that symbol.
+        if vec {
+            if let Some(ChoiceKind::Ref { ref_type, .. }) = choices_noe.first().map(|c| &c.kind) {
+                if choices_noe.iter().all(
+                    |c| matches! {&c.kind, ChoiceKind::Ref { ref_type: r, .. } if r == ref_type},
+                ) {
+                    choices_noe.truncate(1);
                 }
             }
-            Match { empty, .. } => {
-                if choices_noe.len() == 1 && !matches! {choices_noe[0].kind, ChoiceKind::Plain} {
-                    // Promote
-                    match &choices_noe[0].kind {
-                        ChoiceKind::Ref { ref_type, .. } => SymbolTypeKind::Ref {
-                            ref_type: ref_type.to_string(),
-                            recursive: Cell::new(false),
-                        },
-                        ChoiceKind::Struct { .. } => SymbolTypeKind::Struct {
-                            type_name: if empty {
-                                has_empty_type_name(type_name)
-                            } else {
-                                type_name.clone()
-                            },
-                        },
-                        ChoiceKind::Plain | ChoiceKind::Empty => unreachable!(),
-                    }
+        }
+
+        if choices_noe.len() == 1 && !matches! {choices_noe[0].kind, ChoiceKind::Plain} {
+            // Promote
+            match &choices_noe[0].kind {
+                ChoiceKind::Ref { ref_type, .. } => SymbolTypeKind::Ref {
+                    ref_type: ref_type.to_string(),
+                    recursive: Cell::new(false),
+                },
+                ChoiceKind::Struct { .. } => SymbolTypeKind::Struct {
+                    type_name: if empty {
+                        has_empty_type_name(type_name)
+                    } else {
+                        type_name.clone()
+                    },
+                },
+                ChoiceKind::Plain | ChoiceKind::Empty => unreachable!(),
+            }
+        } else {
+            SymbolTypeKind::Enum {
+                type_name: if empty {
+                    has_empty_type_name(type_name)
                 } else {
-                    SymbolTypeKind::Enum {
-                        type_name: if empty {
-                            has_empty_type_name(type_name)
-                        } else {
-                            type_name.clone()
-                        },
-                    }
+                    type_name.clone()
+                },
+            }
+        }
+    }
+
+    /// The element type of a rule annotated with `@vec`. All productions must
+    /// reference only the element type and at most once the rule itself, and
+    /// some production must either reference the rule or have several
+    /// elements, e.g. `A: A B | B;` or `A: B B | B;`.
+    fn vec_ref_type(type_name: &String, choices: &[Choice]) -> Option<String> {
+        let mut ref_type: Option<&String> = None;
+        let mut repeated = false;
+        for choice in choices {
+            let refs = match &choice.kind {
+                ChoiceKind::Empty => continue,
+                ChoiceKind::Plain => return None,
+                ChoiceKind::Ref { ref_type, .. } => vec![ref_type],
+                ChoiceKind::Struct { fields, .. } => fields.iter().map(|f| &f.ref_type).collect(),
+            };
+            let recursions = refs.iter().filter(|&&r| r == type_name).count();
+            let elements = refs.len() - recursions;
+            if recursions > 1 || elements == 0 {
+                return None;
+            }
+            repeated |= recursions == 1 || elements > 1;
+            for element in refs.into_iter().filter(|&r| r != type_name) {
+                match ref_type {
+                    Some(ref_type) if ref_type != element => return None,
+                    _ => ref_type = Some(element),
                 }
             }
         }
+        ref_type.filter(|_| repeated).cloned()
     }
 
     /// Flags recursive types by performing a DFS over the types reference graph.
@@ -397,6 +426,18 @@ pub(crate) struct SymbolType {
     pub optional: bool,
 }
 
+impl SymbolType {
+    /// Does the action of the given choice box recursive values.
+    pub(crate) fn boxes(&self, choice: &Choice) -> bool {
+        match (&self.kind, &choice.kind) {
+            (_, ChoiceKind::Empty | ChoiceKind::Plain) => false,
+            (SymbolTypeKind::Vec { recursive, .. }, _) => recursive.get(),
+            (_, ChoiceKind::Ref { recursive, .. }) => recursive.get(),
+            (_, ChoiceKind::Struct { fields, .. }) => fields.iter().any(|f| f.recursive.get()),
+        }
+    }
+}
+
 /// Type kinds derived from grammar rules. Used to auto-generate AST types.
 #[derive(Debug)]
 pub(crate) enum SymbolTypeKind {
@@ -0,0 +1,446 @@
+//! Import of Bison and yacc grammars.
+//!
+//! Tokens are taken from `%token` and precedence declarations, and rules from
+//! the rules section. Each precedence declaration (`%left`, `%right`,
+//! `%nonassoc` and `%precedence`) gives a priority higher than the previous
+//! one, starting above the default priority. As in Bison, the precedence of a
+//! production is given by `%prec` or by its last terminal. Rustemo has no
+//! non-associativity so `%nonassoc` gives only the priority and a `TODO`
+//! comment is added to the grammar.
+//!
+//! Character literals and string aliases are given as string matches. Other
+//! tokens are terminals without a recognizer. Alternatives using the `error`
+//! token are dropped and reported in a `TODO` comment before their rule.
+use std::collections::{HashMap, HashSet};
+
+use crate::grammar::DEFAULT_PRIORITY;
+
+use super::{
+    identifier, quote, ImportedGrammar, Production, Recognizer, Result, Rule, Scanner, Symbol,
+};
+
+/// Characters allowed in Bison identifiers besides alphanumerics and `_`.
+const IDENT_CHARS: &str = ".-";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BisonSymbol {
+    Name(String),
+    /// A character literal or a string.
+    Literal(String),
+}
+
+#[derive(Debug, Default)]
+struct Alternative {
+    symbols: Vec<BisonSymbol>,
+    prec: Option<BisonSymbol>,
+    error: bool,
+}
+
+#[derive(Debug, Default)]
+struct Bison {
+    /// Tokens in the order of declaration.
+    tokens: Vec<BisonSymbol>,
+    /// Token names by their string aliases.
+    aliases: HashMap<String, String>,
+    /// Precedence level and associativity of tokens.
+    precedence: HashMap<BisonSymbol, (u32, Option<&'static str>)>,
+    /// Tokens declared by `%nonassoc`.
+    nonassoc: Vec<BisonSymbol>,
+    start: Option<String>,
+    rules: Vec<(String, Vec<Alternative>)>,
+}
+
+pub(crate) fn import(src: &str) -> Result<ImportedGrammar> {
+    let mut bison = Bison::default();
+    let mut scanner = Scanner::new(src);
+    bison.declarations(&mut scanner)?;
+    bison.rules(&mut scanner)?;
+    Ok(bison.grammar())
+}
+
+impl Bison {
+    fn declarations(&mut self, s: &mut Scanner) -> Result<()> {
+        let mut level = 0;
+        loop {
+            s.skip_ws();
+            if s.is_eof() {
+                return Err(s.error("Expected \"%%\""));
+            }
+            if s.eat("%%") {
+                return Ok(());
+            }
+            if s.eat("%{") {
+                match s.rest().find("%}") {
+                    Some(end) => s.pos += end + 2,
+                    None => return Err(s.error("Unterminated prologue")),
+                }
+                continue;
+            }
+            if !s.eat("%") {
+                return Err(s.error("Expected a declaration"));
+            }
+            let directive = s.ident("-_").unwrap_or_default();
+            match directive.as_str() {
+                "token" => {
+                    self.symbols(s, None)?;
+                }
+                "left" | "right" | "nonassoc" | "precedence" => {
+                    level += 1;
+                    let assoc = match directive.as_str() {
+                        "left" => Some("left"),
+                        "right" => Some("right"),
+                        _ => None,
+                    };
+                    let symbols = self.symbols(s, Some((level, assoc)))?;
+                    if directive == "nonassoc" {
+                        self.nonassoc.extend(symbols);
+                    }
+                }
+                "start" => {
+                    s.skip_ws();
+                    self.start = s.ident(IDENT_CHARS);
+                }
+                _ => skip_directive(s)?,
+            }
+        }
+    }
+
+    /// Symbols of a token or a precedence declaration. Returns the declared
+    /// symbols.
+    fn symbols(
+        &mut self,
+        s: &mut Scanner,
+        precedence: Option<(u32, Option<&'static str>)>,
+    ) -> Result<Vec<BisonSymbol>> {
+        let mut symbols = vec![];
+        loop {
+            s.skip_ws();
+            let symbol = match s.peek() {
+                Some('<') => {
+                    skip_tag(s)?;
+                    continue;
+                }
+                Some('\'' | '"') => {
+                    let literal = s.quoted()?;
+                    self.literal(literal)
+                }
+                Some(c) if c.is_ascii_digit() => {
+                    // The token number.
+                    while s.peek().is_some_and(|c| c.is_ascii_digit()) {
+                        s.bump();
+                    }
+                    continue;
+                }
+                _ => match s.ident(IDENT_CHARS) {
+                    Some(name) => {
+                        s.skip_ws();
+                        while s.peek().is_some_and(|c| c.is_ascii_digit()) {
+                            s.bump();
+                        }
+                        s.skip_ws();
+                        if s.peek() == Some('"') {
+                            let alias = s.quoted()?;
+                            self.aliases.insert(alias, name.clone());
+                        }
+                        BisonSymbol::Name(name)
+                    }
+                    None => return Ok(symbols),
+                },
+            };
+            symbols.push(symbol.clone());
+            match precedence {
+                Some(precedence) => {
+                    self.precedence.insert(symbol, precedence);
+                }
+                None => {
+                    if !self.tokens.contains(&symbol) {
+                        self.tokens.push(symbol);
+                    }
+                }
+            }
+        }
+    }
+
+    fn rules(&mut self, s: &mut Scanner) -> Result<()> {
+        loop {
+            s.skip_ws();
+            if s.is_eof() || s.eat("%%") {
+                return Ok(());
+            }
+            let lhs = s
+                .ident(IDENT_CHARS)
+                .ok_or_else(|| s.error("Expected a rule"))?;
+            skip_named_ref(s)?;
+            s.expect(":")?;
+            let alternatives = self.alternatives(s)?;
+            match self.rules.iter_mut().find(|(name, _)| *name == lhs) {
+                Some((_, alts)) => alts.extend(alternatives),
+                None => self.rules.push((lhs, alternatives)),
+            }
+        }
+    }
+
+    fn alternatives(&mut self, s: &mut Scanner) -> Result<Vec<Alternative>> {
+        let mut alternatives = vec![Alternative::default()];
+        loop {
+            s.skip_ws();
+            let alternative = alternatives.last_mut().unwrap();
+            match s.peek() {
+                None => break,
+                Some(';') => {
+                    s.bump();
+                    break;
+                }
+                Some('|') => {
+                    s.bump();
+                    alternatives.push(Alternative::default());
+                }
+                Some('{') => s.skip_block()?,
+                Some('<') => skip_tag(s)?,
+                Some('\'' | '"') => {
+                    let literal = s.quoted()?;
+                    alternative.symbols.push(self.literal(literal));
+                }
+                Some('%') => {
+                    if s.starts_with("%%") {
+                        break;
+                    }
+                    s.bump();
+                    match s.ident("-_").as_deref() {
+                        Some("prec") => {
+                            s.skip_ws();
+                            alternative.prec = Some(if let Some('\'' | '"') = s.peek() {
+                                let literal = s.quoted()?;
+                                self.literal(literal)
+                            } else {
+                                BisonSymbol::Name(
+                                    s.ident(IDENT_CHARS)
+                                        .ok_or_else(|| s.error("Expected a token"))?,
+                                )
+                            });
+                        }
+                        Some("empty") => (),
+                        // `%dprec`, `%merge` and `%expect` with an argument.
+                        _ => {
+                            s.skip_ws();
+                            if s.peek() == Some('<') {
+                                skip_tag(s)?;
+                            } else {
+                                s.ident("");
+                            }
+                        }
+                    }
+                }
+                Some(_) => {
+                    let start = s.pos;
+                    let name = s
+                        .ident(IDENT_CHARS)
+                        .ok_or_else(|| s.error("Unexpected character"))?;
+                    skip_named_ref(s)?;
+                    s.skip_ws();
+                    // The terminating `;` is optional.
+                    if s.peek() == Some(':') {
+                        s.pos = start;
+                        break;
+                    }
+                    if name == "error" {
+                        alternative.error = true;
+                    }
+                    alternative.symbols.push(BisonSymbol::Name(name));
+                }
+            }
+        }
+        Ok(alternatives)
+    }
+
+    fn literal(&self, literal: String) -> BisonSymbol {
+        match self.aliases.get(&literal) {
+            Some(name) => BisonSymbol::Name(name.clone()),
+            None => BisonSymbol::Literal(literal),
+        }
+    }
+
+    fn grammar(mut self) -> ImportedGrammar {
+        if let Some(start) = &self.start {
+            if let Some(idx) = self.rules.iter().position(|(name, _)| name == start) {
+                let rule = self.rules.remove(idx);
+                self.rules.insert(0, rule);
+            }
+        }
+        let nonterminals = self
+            .rules
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<HashSet<_>>();
+
+        // Terminals in the order of declaration followed by undeclared
+        // terminals in the order of usage.
+        let mut tokens = self.tokens.clone();
+        for symbol in self
+            .rules
+            .iter()
+            .flat_map(|(_, alts)| alts.iter().filter(|alt| !alt.error))
+            .flat_map(|alt| &alt.symbols)
+        {
+            let terminal = match symbol {
+                BisonSymbol::Name(name) => !nonterminals.contains(name),
+                BisonSymbol::Literal(_) => true,
+            };
+            if terminal && !tokens.contains(symbol) {
+                tokens.push(symbol.clone());
+            }
+        }
+
+        let mut grammar = ImportedGrammar::default();
+        let mut names = HashMap::new();
+        for name in &nonterminals {
+            let ident = identifier(name);
+            grammar.reserve(&ident);
+            names.insert(name.clone(), ident);
+        }
+        for token in &tokens {
+            if let BisonSymbol::Name(name) = token {
+                let ident = grammar.unique(&identifier(name));
+                names.insert(name.clone(), ident);
+            }
+        }
+        let aliases = self
+            .aliases
+            .iter()
+            .map(|(alias, name)| (name.clone(), alias.clone()))
+            .collect::<HashMap<_, _>>();
+        for token in &tokens {
+            match token {
+                BisonSymbol::Name(name) => grammar.add_terminal(
+                    &names[name],
+                    aliases
+                        .get(name)
+                        .map(|alias| Recognizer::Str(alias.clone())),
+                ),
+                BisonSymbol::Literal(literal) => {
+                    grammar.string_terminal(literal);
+                }
+            }
+        }
+
+        if !self.nonassoc.is_empty() {
+            grammar.notes.push(format!(
+                "`%nonassoc {}` is imported as a priority only. Rustemo has no \
+                 non-associativity so conflicts it resolves must be resolved in the grammar.",
+                self.nonassoc
+                    .iter()
+                    .map(bison_symbol)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ));
+        }
+        grammar.rules = self
+            .rules
+            .iter()
+            .map(|(name, alts)| Rule {
+                name: names[name].clone(),
+                productions: alts
+                    .iter()
+                    .filter(|alt| !alt.error)
+                    .map(|alt| self.production(alt, &nonterminals, &names, &aliases))
+                    .collect(),
+                notes: alts
+                    .iter()
+                    .filter(|alt| alt.error)
+                    .map(|alt| {
+                        format!(
+                            "Alternative `{}` is dropped. Rustemo has no `error` token.",
+                            alt.symbols
+                                .iter()
+                                .map(bison_symbol)
+                                .collect::<Vec<_>>()
+                                .join(" ")
+                        )
+                    })
+                    .collect(),
+            })
+            .collect();
+        grammar
+    }
+
+    fn production(
+        &self,
+        alt: &Alternative,
+        nonterminals: &HashSet<String>,
+        names: &HashMap<String, String>,
+        aliases: &HashMap<String, String>,
+    ) -> Production {
+        let symbols = alt
+            .symbols
+            .iter()
+            .map(|symbol| match symbol {
+                BisonSymbol::Name(name) => match aliases.get(name) {
+                    Some(alias) if !nonterminals.contains(name) => Symbol::Str(alias.clone()),
+                    _ => Symbol::Ref(names[name].clone()),
+                },
+                BisonSymbol::Literal(literal) => Symbol::Str(literal.clone()),
+            })
+            .collect();
+        let prec = alt.prec.as_ref().or_else(|| {
+            alt.symbols.iter().rev().find(|symbol| match symbol {
+                BisonSymbol::Name(name) => !nonterminals.contains(name),
+                BisonSymbol::Literal(_) => true,
+            })
+        });
+        let mut meta = vec![];
+        if let Some((level, assoc)) = prec.and_then(|prec| self.precedence.get(prec)) {
+            meta.push((DEFAULT_PRIORITY + level).to_string());
+            meta.extend(assoc.map(String::from));
+        }
+        Production { symbols, meta }
+    }
+}
+
+/// The symbol as given in the Bison grammar.
+fn bison_symbol(symbol: &BisonSymbol) -> String {
+    match symbol {
+        BisonSymbol::Name(name) => name.clone(),
+        BisonSymbol::Literal(literal) => quote(literal),
+    }
+}
+
+/// Skips arguments of a declaration which is not imported.
+fn skip_directive(s: &mut Scanner) -> Result<()> {
+    loop {
+        s.skip_ws();
+        match s.peek() {
+            None | Some('%') => return Ok(()),
+            Some('{') => s.skip_block()?,
+            Some('<') => skip_tag(s)?,
+            Some('\'' | '"') => {
+                s.quoted()?;
+            }
+            Some(_) => {
+                s.bump();
+            }
+        }
+    }
+}
+
+/// Skips a type tag, e.g. `<int>`.
+fn skip_tag(s: &mut Scanner) -> Result<()> {
+    match s.rest().find('>') {
+        Some(end) => {
+            s.pos += end + 1;
+            Ok(())
+        }
+        None => Err(s.error("Unterminated tag")),
+    }
+}
+
+/// Skips a named reference, e.g. `exp[left]`.
+fn skip_named_ref(s: &mut Scanner) -> Result<()> {
+    s.skip_ws();
+    if s.peek() == Some('[') {
+        match s.rest().find(']') {
+            Some(end) => s.pos += end + 1,
+            None => return Err(s.error("Unterminated named reference")),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,758 @@
+//! Import of LALRPOP grammars.
+//!
+//! Nonterminals are converted to rules with the first public nonterminal as
+//! the start rule. Named symbols, e.g. `<e:Expr>`, are given as assignments.
+//! Macros are expanded for each distinct invocation, e.g. `Comma<Expr>` gives
+//! the rule `CommaExpr`. A nonterminal matching only a single string or regex
+//! becomes a terminal. Separated lists with an optional trailing separator, as
+//! in the usual `Comma` macro, are given by the `trailing` repetition modifier.
+//!
+//! Precedence given by `#[precedence(level="N")]` and `#[assoc(side="left")]`
+//! attributes is mapped to priorities and associativity of productions. Lower
+//! levels bind tighter and thus get higher priorities.
+//!
+//! String matches renamed in the `match` block take the recognizer of the
+//! match. Tokens of an `extern` block are terminals without a recognizer.
+//! Locations (`@L`, `@R`) and error recovery (`!`) are dropped.
+use std::collections::HashMap;
+
+use crate::grammar::DEFAULT_PRIORITY;
+
+use super::{
+    identifier, ImportedGrammar, Production, Recognizer, Result, Rule, Scanner, Symbol, Terminal,
+};
+
+#[derive(Debug, Clone)]
+enum LalrpopSymbol {
+    Name(String),
+    Str(String),
+    Regex(String),
+    Group(Vec<LalrpopSymbol>),
+    Repeat(Box<LalrpopSymbol>, char),
+    Named(String, Box<LalrpopSymbol>),
+    Macro(String, Vec<LalrpopSymbol>),
+}
+
+#[derive(Debug, Default)]
+struct Alternative {
+    symbols: Vec<LalrpopSymbol>,
+    level: Option<u32>,
+    assoc: Option<&'static str>,
+}
+
+#[derive(Debug)]
+struct Nonterminal {
+    name: String,
+    public: bool,
+    params: Vec<String>,
+    alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug, Default)]
+struct Lalrpop {
+    nonterminals: Vec<Nonterminal>,
+    /// Recognizers of string matches renamed in the `match` block.
+    renamed: HashMap<String, Recognizer>,
+    /// Tokens of the `extern` block.
+    extern_tokens: Vec<String>,
+}
+
+pub(crate) fn import(src: &str) -> Result<ImportedGrammar> {
+    let mut lalrpop = Lalrpop::default();
+    lalrpop.parse(&mut Scanner::new(src))?;
+    Ok(Converter::new(&lalrpop).convert())
+}
+
+impl Lalrpop {
+    fn parse(&mut self, s: &mut Scanner) -> Result<()> {
+        loop {
+            s.skip_ws();
+            if s.is_eof() {
+                return Ok(());
+            }
+            if s.starts_with("#[") {
+                attribute(s)?;
+                continue;
+            }
+            let start = s.pos;
+            let ident = s.ident("").ok_or_else(|| s.error("Unexpected character"))?;
+            match ident.as_str() {
+                "use" | "grammar" => {
+                    s.skip_code(";")?;
+                    s.bump();
+                }
+                "extern" => self.extern_block(s)?,
+                "match" => self.match_block(s)?,
+                "pub" => {
+                    s.skip_ws();
+                    self.nonterminal(s, true)?;
+                }
+                _ => {
+                    s.pos = start;
+                    self.nonterminal(s, false)?;
+                }
+            }
+        }
+    }
+
+    /// Collects tokens given as `"token" => pattern` in the `extern` block.
+    fn extern_block(&mut self, s: &mut Scanner) -> Result<()> {
+        s.skip_ws();
+        let start = s.pos;
+        s.skip_block()?;
+        let mut block = Scanner::new(&s.src[start..s.pos]);
+        loop {
+            block.skip_ws();
+            match block.peek() {
+                None => return Ok(()),
+                Some('"') => {
+                    let token = block.quoted()?;
+                    block.skip_ws();
+                    if block.starts_with("=>") && !self.extern_tokens.contains(&token) {
+                        self.extern_tokens.push(token);
+                    }
+                }
+                Some('\'') => block.skip_code_literal(),
+                Some(_) => {
+                    block.bump();
+                }
+            }
+        }
+    }
+
+    /// Collects renamed matches, e.g. `r"[0-9]+" => "NUM"`, of the `match`
+    /// block and its `else` blocks.
+    fn match_block(&mut self, s: &mut Scanner) -> Result<()> {
+        loop {
+            s.expect("{")?;
+            loop {
+                s.skip_ws();
+                if s.eat("}") {
+                    break;
+                }
+                let recognizer = match s.peek() {
+                    Some('"') => Some(Recognizer::Str(s.quoted()?)),
+                    Some('r') if matches!(s.peek2(), Some('"' | '#')) => {
+                        Some(Recognizer::Regex(raw_string(s)?))
+                    }
+                    Some('_') => {
+                        s.bump();
+                        None
+                    }
+                    _ => return Err(s.error("Expected a match")),
+                };
+                s.skip_ws();
+                if s.eat("=>") {
+                    s.skip_ws();
+                    match s.peek() {
+                        Some('"') => {
+                            let name = s.quoted()?;
+                            if let Some(recognizer) = recognizer {
+                                self.renamed.insert(name, recognizer);
+                            }
+                        }
+                        _ => {
+                            s.ident("");
+                        }
+                    }
+                }
+                s.skip_ws();
+                s.eat(",");
+            }
+            s.skip_ws();
+            let start = s.pos;
+            if s.ident("").as_deref() != Some("else") {
+                s.pos = start;
+                return Ok(());
+            }
+        }
+    }
+
+    fn nonterminal(&mut self, s: &mut Scanner, public: bool) -> Result<()> {
+        let name = s
+            .ident("")
+            .ok_or_else(|| s.error("Expected a nonterminal"))?;
+        let mut params = vec![];
+        if s.eat("<") {
+            loop {
+                s.skip_ws();
+                if s.eat(">") {
+                    break;
+                }
+                params.push(s.ident("").ok_or_else(|| s.error("Expected a parameter"))?);
+                s.skip_ws();
+                s.eat(",");
+            }
+        }
+        s.skip_ws();
+        if s.eat(":") {
+            skip_type(s)?;
+        }
+        s.expect("=")?;
+        s.skip_ws();
+        let mut alternatives = vec![];
+        if s.eat("{") {
+            let mut level = None;
+            let mut assoc = None;
+            loop {
+                s.skip_ws();
+                if s.eat("}") {
+                    break;
+                }
+                let mut alternative = alternative(s, "},")?;
+                // Precedence applies to the following alternatives as well.
+                if alternative.level.is_some() {
+                    level = alternative.level;
+                    assoc = alternative.assoc;
+                } else {
+                    alternative.level = level;
+                    alternative.assoc = assoc;
+                }
+                alternatives.push(alternative);
+                s.skip_ws();
+                s.eat(",");
+            }
+            s.skip_ws();
+            s.eat(";");
+        } else {
+            alternatives.push(alternative(s, ";")?);
+            s.bump();
+        }
+        self.nonterminals.push(Nonterminal {
+            name,
+            public,
+            params,
+            alternatives,
+        });
+        Ok(())
+    }
+}
+
+/// An alternative terminated by one of the given characters. The terminating
+/// character is not consumed.
+fn alternative(s: &mut Scanner, until: &str) -> Result<Alternative> {
+    let mut alt = Alternative::default();
+    loop {
+        s.skip_ws();
+        if !s.starts_with("#[") {
+            break;
+        }
+        let attr = attribute(s)?;
+        let value = || {
+            attr.split('"')
+                .nth(1)
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default()
+        };
+        if attr.starts_with("precedence") {
+            alt.level = value().parse().ok();
+        } else if attr.starts_with("assoc") {
+            alt.assoc = match value().as_str() {
+                "left" => Some("left"),
+                "right" => Some("right"),
+                _ => None,
+            };
+        }
+    }
+    alt.symbols = symbols(s, &format!("{until}="))?;
+    s.skip_ws();
+    let start = s.pos;
+    if s.ident("").as_deref() == Some("if") {
+        // A macro condition.
+        s.skip_code(&format!("{until}="))?;
+    } else {
+        s.pos = start;
+    }
+    if s.eat("=>") {
+        if !s.eat("?") && s.eat("@") {
+            s.ident("");
+        }
+        s.skip_code(until)?;
+    }
+    s.skip_ws();
+    if !s.peek().is_some_and(|c| until.contains(c)) {
+        return Err(s.error("Unexpected character"));
+    }
+    Ok(alt)
+}
+
+/// A sequence of symbols terminated by one of the given characters.
+fn symbols(s: &mut Scanner, until: &str) -> Result<Vec<LalrpopSymbol>> {
+    let mut symbols = vec![];
+    loop {
+        s.skip_ws();
+        let Some(c) = s.peek() else {
+            return Ok(symbols);
+        };
+        if until.contains(c) {
+            return Ok(symbols);
+        }
+        let start = s.pos;
+        if s.ident("").as_deref() == Some("if") {
+            s.pos = start;
+            return Ok(symbols);
+        }
+        s.pos = start;
+        if let Some(symbol) = symbol(s)? {
+            symbols.push(symbol);
+        }
+    }
+}
+
+/// A symbol with postfix operators. Locations and error recovery give no
+/// symbol.
+fn symbol(s: &mut Scanner) -> Result<Option<LalrpopSymbol>> {
+    let mut symbol = match s.peek() {
+        Some('<') => {
+            s.bump();
+            s.skip_ws();
+            let start = s.pos;
+            let mut name = None;
+            if let Some(mut ident) = s.ident("") {
+                if ident == "mut" {
+                    s.skip_ws();
+                    ident = s.ident("").unwrap_or_default();
+                }
+                s.skip_ws();
+                if s.peek() == Some(':') && s.peek2() != Some(':') {
+                    s.bump();
+                    name = Some(ident);
+                } else {
+                    s.pos = start;
+                }
+            }
+            let mut inner = symbols(s, ">")?;
+            s.expect(">")?;
+            let inner = match inner.len() {
+                // E.g. `<@L>`.
+                0 => return Ok(None),
+                1 => inner.remove(0),
+                _ => LalrpopSymbol::Group(inner),
+            };
+            match name {
+                Some(name) => LalrpopSymbol::Named(name, Box::new(inner)),
+                None => inner,
+            }
+        }
+        Some('(') => {
+            s.bump();
+            let inner = symbols(s, ")")?;
+            s.expect(")")?;
+            LalrpopSymbol::Group(inner)
+        }
+        Some('"') => LalrpopSymbol::Str(s.quoted()?),
+        Some('r') if matches!(s.peek2(), Some('"' | '#')) => LalrpopSymbol::Regex(raw_string(s)?),
+        Some('@') => {
+            s.bump();
+            s.ident("");
+            return Ok(None);
+        }
+        Some('!') => {
+            s.bump();
+            return Ok(None);
+        }
+        _ => {
+            let name = s.ident("").ok_or_else(|| s.error("Unexpected character"))?;
+            // A macro invocation is given without space before `<`.
+            if s.eat("<") {
+                let mut args = vec![];
+                loop {
+                    let mut arg = symbols(s, ",>")?;
+                    args.push(if arg.len() == 1 {
+                        arg.remove(0)
+                    } else {
+                        LalrpopSymbol::Group(arg)
+                    });
+                    if s.eat(">") {
+                        break;
+                    }
+                    s.expect(",")?;
+                }
+                LalrpopSymbol::Macro(name, args)
+            } else {
+                LalrpopSymbol::Name(name)
+            }
+        }
+    };
+    while let Some(op @ ('*' | '+' | '?')) = s.peek() {
+        s.bump();
+        symbol = LalrpopSymbol::Repeat(Box::new(symbol), op);
+    }
+    Ok(Some(symbol))
+}
+
+/// A raw string, e.g. `r"[0-9]+"` or `r#"\d+"#`.
+fn raw_string(s: &mut Scanner) -> Result<String> {
+    s.bump();
+    let mut hashes = 0;
+    while s.eat("#") {
+        hashes += 1;
+    }
+    s.expect("\"")?;
+    let end = format!("\"{}", "#".repeat(hashes));
+    match s.rest().find(&end) {
+        Some(len) => {
+            let value = s.rest()[..len].to_string();
+            s.pos += len + end.len();
+            Ok(value)
+        }
+        None => Err(s.error("Unterminated raw string")),
+    }
+}
+
+/// An attribute, e.g. `#[inline]`. Returns the content between brackets.
+fn attribute(s: &mut Scanner) -> Result<String> {
+    s.eat("#");
+    let start = s.pos + 1;
+    s.skip_block()?;
+    Ok(s.src[start..s.pos - 1].trim().to_string())
+}
+
+/// Skips the type of a nonterminal up to the `=` sign.
+fn skip_type(s: &mut Scanner) -> Result<()> {
+    let mut depth = 0;
+    loop {
+        s.skip_ws();
+        match s.peek() {
+            None => return Err(s.error("Expected \"=\"")),
+            Some('=') if depth == 0 && s.peek2() != Some('>') => return Ok(()),
+            Some('<' | '(' | '[') => depth += 1,
+            Some('>' | ')' | ']') => depth -= 1,
+            Some('-') if s.peek2() == Some('>') => {
+                s.bump();
+            }
+            Some('\'') => {
+                s.skip_code_literal();
+                continue;
+            }
+            _ => (),
+        }
+        s.bump();
+    }
+}
+
+/// Converts LALRPOP nonterminals to Rustemo rules.
+struct Converter<'l> {
+    lalrpop: &'l Lalrpop,
+    grammar: ImportedGrammar,
+    names: HashMap<String, String>,
+    /// Names of nonterminals converted to terminals.
+    terminals: HashMap<String, String>,
+    /// Rules of the macro invocations by the invocation.
+    macros: HashMap<String, String>,
+    regexes: HashMap<String, String>,
+    /// Rules of macro invocations. Given after other rules.
+    macro_rules: Vec<Rule>,
+}
+
+impl<'l> Converter<'l> {
+    fn new(lalrpop: &'l Lalrpop) -> Self {
+        Self {
+            lalrpop,
+            grammar: ImportedGrammar::default(),
+            names: HashMap::new(),
+            terminals: HashMap::new(),
+            macros: HashMap::new(),
+            regexes: HashMap::new(),
+            macro_rules: vec![],
+        }
+    }
+
+    fn convert(mut self) -> ImportedGrammar {
+        let mut nonterminals = self
+            .lalrpop
+            .nonterminals
+            .iter()
+            .filter(|nt| nt.params.is_empty())
+            .collect::<Vec<_>>();
+        if let Some(idx) = nonterminals.iter().position(|nt| nt.public) {
+            let start = nonterminals.remove(idx);
+            nonterminals.insert(0, start);
+        }
+        for nt in &nonterminals {
+            let ident = identifier(&nt.name);
+            self.grammar.reserve(&ident);
+            self.names.insert(nt.name.clone(), ident);
+        }
+
+        // Nonterminals matching a single string or regex become terminals.
+        let mut rules = vec![];
+        for nt in nonterminals {
+            let name = self.names[&nt.name].clone();
+            match terminal(nt) {
+                Some(LalrpopSymbol::Str(s)) if !nt.public => {
+                    let s = s.clone();
+                    if self.grammar.string_terminals.contains_key(&s) {
+                        rules.push(nt);
+                    } else {
+                        self.terminals.insert(nt.name.clone(), name.clone());
+                        let recognizer = self.string_recognizer(&s);
+                        self.grammar.add_terminal(&name, recognizer);
+                        self.grammar.string_terminals.insert(s, name);
+                    }
+                }
+                Some(LalrpopSymbol::Regex(regex)) if !nt.public => {
+                    self.terminals.insert(nt.name.clone(), name.clone());
+                    self.regexes.insert(regex.clone(), name.clone());
+                    self.grammar
+                        .add_terminal(&name, Some(Recognizer::Regex(regex.clone())));
+                }
+                _ => rules.push(nt),
+            }
+        }
+        for token in &self.lalrpop.extern_tokens {
+            if !self.grammar.string_terminals.contains_key(token) {
+                let name = self.grammar.unique(&super::terminal_name(token));
+                self.grammar.add_terminal(&name, None);
+                self.grammar.string_terminals.insert(token.clone(), name);
+            }
+        }
+
+        for nt in rules {
+            let name = self.names[&nt.name].clone();
+            let productions = self.productions(nt, &HashMap::new());
+            self.grammar.rules.push(Rule {
+                name,
+                productions,
+                ..Default::default()
+            });
+        }
+        let macro_rules = std::mem::take(&mut self.macro_rules);
+        self.grammar.rules.extend(macro_rules);
+        self.grammar
+    }
+
+    /// Productions of the nonterminal with macro parameters substituted by the
+    /// given arguments.
+    fn productions(
+        &mut self,
+        nt: &Nonterminal,
+        args: &HashMap<String, LalrpopSymbol>,
+    ) -> Vec<Production> {
+        let max_level = nt.alternatives.iter().filter_map(|alt| alt.level).max();
+        nt.alternatives
+            .iter()
+            .map(|alt| {
+                let mut meta = vec![];
+                if let (Some(level), Some(max_level)) = (alt.level, max_level) {
+                    meta.push((DEFAULT_PRIORITY + max_level - level + 1).to_string());
+                    meta.extend(alt.assoc.map(String::from));
+                }
+                let symbols = alt
+                    .symbols
+                    .iter()
+                    .map(|symbol| self.symbol(symbol, args))
+                    .collect();
+                Production {
+                    symbols: self.separated(symbols),
+                    meta,
+                }
+            })
+            .collect()
+    }
+
+    fn symbol(&mut self, symbol: &LalrpopSymbol, args: &HashMap<String, LalrpopSymbol>) -> Symbol {
+        match symbol {
+            LalrpopSymbol::Name(name) => match args.get(name) {
+                Some(arg) => self.symbol(&arg.clone(), &HashMap::new()),
+                None => Symbol::Ref(
+                    self.terminals
+                        .get(name)
+                        .or_else(|| self.names.get(name))
+                        .cloned()
+                        .unwrap_or_else(|| identifier(name)),
+                ),
+            },
+            LalrpopSymbol::Str(s) => {
+                if !self.grammar.string_terminals.contains_key(s) {
+                    let name = self.grammar.unique(&super::terminal_name(s));
+                    let recognizer = self.string_recognizer(s);
+                    self.grammar.add_terminal(&name, recognizer);
+                    self.grammar.string_terminals.insert(s.clone(), name);
+                }
+                let name = &self.grammar.string_terminals[s];
+                match self.grammar.terminals.iter().find(|t| t.name == *name) {
+                    Some(Terminal {
+                        recognizer: Some(Recognizer::Str(_)),
+                        ..
+                    }) => Symbol::Str(s.clone()),
+                    _ => Symbol::Ref(name.clone()),
+                }
+            }
+            LalrpopSymbol::Regex(regex) => {
+                let name = match self.regexes.get(regex) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self.grammar.unique("Regex");
+                        self.grammar
+                            .add_terminal(&name, Some(Recognizer::Regex(regex.clone())));
+                        self.regexes.insert(regex.clone(), name.clone());
+                        name
+                    }
+                };
+                Symbol::Ref(name)
+            }
+            LalrpopSymbol::Group(symbols) => Symbol::Group(vec![Production {
+                symbols: symbols.iter().map(|s| self.symbol(s, args)).collect(),
+                meta: vec![],
+            }]),
+            LalrpopSymbol::Repeat(symbol, op) => {
+                Symbol::Repeat(Box::new(self.symbol(symbol, args)), *op)
+            }
+            LalrpopSymbol::Named(name, symbol) => {
+                Symbol::Assign(identifier(name), Box::new(self.symbol(symbol, args)))
+            }
+            LalrpopSymbol::Macro(name, macro_args) => {
+                let macro_args = macro_args
+                    .iter()
+                    .map(|arg| substitute(arg, args))
+                    .collect::<Vec<_>>();
+                Symbol::Ref(self.macro_rule(name, &macro_args))
+            }
+        }
+    }
+
+    /// A separated list with an optional trailing separator, written in
+    /// LALRPOP as `<v:(<T> ",")*> <e:T?>` (e.g. in the `Comma` macro), is
+    /// given as `T*[Comma, trailing]`. The direct translation is not LR(1).
+    fn separated(&self, symbols: Vec<Symbol>) -> Vec<Symbol> {
+        match self.separated_list(&symbols) {
+            Some(list) => vec![list],
+            None => symbols,
+        }
+    }
+
+    fn separated_list(&self, symbols: &[Symbol]) -> Option<Symbol> {
+        fn unassigned(symbol: &Symbol) -> &Symbol {
+            match symbol {
+                Symbol::Assign(_, symbol) => symbol,
+                symbol => symbol,
+            }
+        }
+        let [many, last] = symbols else {
+            return None;
+        };
+        let (Symbol::Repeat(group, '*'), Symbol::Repeat(last, '?')) =
+            (unassigned(many), unassigned(last))
+        else {
+            return None;
+        };
+        let Symbol::Group(group) = &**group else {
+            return None;
+        };
+        let [Production { symbols, meta }] = &group[..] else {
+            return None;
+        };
+        let [element, separator] = &symbols[..] else {
+            return None;
+        };
+        let element = unassigned(element);
+        if !meta.is_empty()
+            || !matches!(element, Symbol::Ref(_) | Symbol::Str(_))
+            || super::symbol(element) != super::symbol(unassigned(last))
+        {
+            return None;
+        }
+        let separator = match unassigned(separator) {
+            Symbol::Str(s) => self.grammar.string_terminals.get(s)?,
+            Symbol::Ref(name) => {
+                &self
+                    .grammar
+                    .terminals
+                    .iter()
+                    .find(|t| t.name == *name)?
+                    .name
+            }
+            _ => return None,
+        };
+        Some(Symbol::Separated(
+            Box::new(element.clone()),
+            separator.clone(),
+        ))
+    }
+
+    /// The name of the rule for the macro invocation. The rule is created on
+    /// the first invocation.
+    fn macro_rule(&mut self, name: &str, args: &[LalrpopSymbol]) -> String {
+        let key = format!("{name}{args:?}");
+        if let Some(rule) = self.macros.get(&key) {
+            return rule.clone();
+        }
+        let lalrpop = self.lalrpop;
+        let Some(nt) = lalrpop
+            .nonterminals
+            .iter()
+            .find(|nt| nt.name == name && !nt.params.is_empty())
+        else {
+            return identifier(name);
+        };
+        let suffix = args
+            .iter()
+            .map(|arg| match arg {
+                LalrpopSymbol::Name(name) => identifier(name),
+                LalrpopSymbol::Str(s) => super::terminal_name(s),
+                _ => String::new(),
+            })
+            .collect::<String>();
+        let rule_name = self.grammar.unique(&identifier(&format!("{name}{suffix}")));
+        self.macros.insert(key, rule_name.clone());
+        let params = nt
+            .params
+            .iter()
+            .cloned()
+            .zip(args.iter().cloned())
+            .collect::<HashMap<_, _>>();
+        let productions = self.productions(nt, &params);
+        self.macro_rules.push(Rule {
+            name: rule_name.clone(),
+            productions,
+            ..Default::default()
+        });
+        rule_name
+    }
+
+    fn string_recognizer(&self, s: &str) -> Option<Recognizer> {
+        if self.lalrpop.extern_tokens.iter().any(|token| token == s) {
+            return None;
+        }
+        Some(match self.lalrpop.renamed.get(s) {
+            Some(Recognizer::Str(s)) => Recognizer::Str(s.clone()),
+            Some(Recognizer::Regex(regex)) => Recognizer::Regex(regex.clone()),
+            None => Recognizer::Str(s.to_string()),
+        })
+    }
+}
+
+/// The single string or regex matched by the nonterminal.
+fn terminal(nt: &Nonterminal) -> Option<&LalrpopSymbol> {
+    match &nt.alternatives[..] {
+        [alt] => match &alt.symbols[..] {
+            [LalrpopSymbol::Named(_, symbol)] => Some(&**symbol),
+            [symbol] => Some(symbol),
+            _ => None,
+        },
+        _ => None,
+    }
+    .filter(|symbol| matches!(symbol, LalrpopSymbol::Str(_) | LalrpopSymbol::Regex(_)))
+}
+
+/// Substitutes macro parameters in the symbol.
+fn substitute(symbol: &LalrpopSymbol, args: &HashMap<String, LalrpopSymbol>) -> LalrpopSymbol {
+    match symbol {
+        LalrpopSymbol::Name(name) => args.get(name).cloned().unwrap_or_else(|| symbol.clone()),
+        LalrpopSymbol::Group(symbols) => {
+            LalrpopSymbol::Group(symbols.iter().map(|s| substitute(s, args)).collect())
+        }
+        LalrpopSymbol::Repeat(symbol, op) => {
+            LalrpopSymbol::Repeat(Box::new(substitute(symbol, args)), *op)
+        }
+        LalrpopSymbol::Named(name, symbol) => {
+            LalrpopSymbol::Named(name.clone(), Box::new(substitute(symbol, args)))
+        }
+        LalrpopSymbol::Macro(name, macro_args) => LalrpopSymbol::Macro(
+            name.clone(),
+            macro_args.iter().map(|arg| substitute(arg, args)).collect(),
+        ),
+        _ => symbol.clone(),
+    }
+}
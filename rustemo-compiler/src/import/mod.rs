@@ -0,0 +1,540 @@
+//! Import of grammars from other parser generators.
+//!
+//! Grammars given in [Bison](https://www.gnu.org/software/bison/)/yacc or
+//! [LALRPOP](https://github.com/lalrpop/lalrpop) syntax are converted to
+//! equivalent Rustemo grammars. Semantic actions, types and other
+//! generator-specific declarations are dropped as Rustemo builds the AST from
+//! the grammar itself.
+//!
+//! Precedence declarations are mapped to priorities and associativity of
+//! productions. String and character matches become terminals of the
+//! `terminals` section. Tokens provided by an external lexer are given as
+//! terminals without a recognizer.
+//!
+//! Parts of the grammar which couldn't be imported are reported by `TODO`
+//! comments in the produced grammar, e.g. terminals whose recognizers must be
+//! given or a custom lexer used.
+//!
+//! The produced grammar is checked by parsing it and is returned formatted (see
+//! [crate::formatter]).
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use clap::ValueEnum;
+
+use crate::{formatter, Error, Result};
+
+mod bison;
+mod lalrpop;
+
+/// The notation of the imported grammar.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    /// Bison or yacc grammar (`.y`, `.yy`).
+    BISON,
+    /// LALRPOP grammar (`.lalrpop`).
+    LALRPOP,
+}
+
+impl ImportFormat {
+    /// Deduces the format from the extension of the given file.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "y" | "yy" | "yacc" | "ypp" => Some(ImportFormat::BISON),
+            "lalrpop" => Some(ImportFormat::LALRPOP),
+            _ => None,
+        }
+    }
+}
+
+/// Converts the grammar given as a string to a Rustemo grammar.
+pub fn import_grammar(src: &str, format: ImportFormat) -> Result<String> {
+    let grammar = match format {
+        ImportFormat::BISON => bison::import(src)?,
+        ImportFormat::LALRPOP => lalrpop::import(src)?,
+    };
+    grammar.to_rustemo()
+}
+
+/// Converts the grammar from the given file to a Rustemo grammar. If the format
+/// is not given it is deduced from the file extension.
+pub fn import_file(path: &Path, format: Option<ImportFormat>) -> Result<String> {
+    let format = format
+        .or_else(|| ImportFormat::from_path(path))
+        .ok_or_else(|| {
+            Error::Error(format!(
+                "Can't deduce the grammar format from the file name \"{}\".",
+                path.display()
+            ))
+        })?;
+    import_grammar(&fs::read_to_string(path)?, format)
+}
+
+/// A grammar converted from other notation.
+#[derive(Debug, Default)]
+struct ImportedGrammar {
+    rules: Vec<Rule>,
+    terminals: Vec<Terminal>,
+    /// Terminal names by their string matches.
+    string_terminals: HashMap<String, String>,
+    /// All rule and terminal names.
+    names: HashSet<String>,
+    /// Notes about the whole grammar given as `TODO` comments.
+    notes: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct Rule {
+    name: String,
+    productions: Vec<Production>,
+    /// Notes given as `TODO` comments before the rule.
+    notes: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Production {
+    symbols: Vec<Symbol>,
+    /// Metadata, e.g. priority and associativity.
+    meta: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Symbol {
+    /// A reference to a rule or a terminal by name.
+    Ref(String),
+    /// A string match of a terminal.
+    Str(String),
+    Group(Vec<Production>),
+    /// A symbol with the repetition operator `*`, `+` or `?`.
+    Repeat(Box<Symbol>, char),
+    /// A possibly empty list of symbols separated by the given terminal with
+    /// an optional trailing separator, e.g. `Expr*[Comma, trailing]`.
+    Separated(Box<Symbol>, String),
+    /// A symbol with the assignment name.
+    Assign(String, Box<Symbol>),
+}
+
+#[derive(Debug)]
+struct Terminal {
+    name: String,
+    recognizer: Option<Recognizer>,
+}
+
+#[derive(Debug)]
+enum Recognizer {
+    Str(String),
+    Regex(String),
+}
+
+impl ImportedGrammar {
+    /// Reserves the name so that generated names don't clash with it.
+    fn reserve(&mut self, name: &str) {
+        self.names.insert(name.to_string());
+    }
+
+    /// A unique name based on the given name.
+    fn unique(&mut self, name: &str) -> String {
+        let mut unique = name.to_string();
+        let mut idx = 1;
+        while self.names.contains(&unique) {
+            idx += 1;
+            unique = format!("{name}{idx}");
+        }
+        self.reserve(&unique);
+        unique
+    }
+
+    /// Adds a terminal with the given name. The name must be reserved.
+    fn add_terminal(&mut self, name: &str, recognizer: Option<Recognizer>) {
+        if let Some(Recognizer::Str(s)) = &recognizer {
+            self.string_terminals
+                .entry(s.clone())
+                .or_insert_with(|| name.to_string());
+        }
+        self.terminals.push(Terminal {
+            name: name.to_string(),
+            recognizer,
+        });
+    }
+
+    /// The name of the terminal matching the given string. The terminal is
+    /// created if it doesn't exist.
+    fn string_terminal(&mut self, s: &str) -> String {
+        if let Some(name) = self.string_terminals.get(s) {
+            return name.clone();
+        }
+        let name = self.unique(&terminal_name(s));
+        self.add_terminal(&name, Some(Recognizer::Str(s.to_string())));
+        name
+    }
+
+    /// The grammar in Rustemo notation.
+    fn to_rustemo(&self) -> Result<String> {
+        let mut out = String::new();
+        if !self.notes.is_empty() {
+            out.push_str(&todo(&self.notes));
+            out.push('\n');
+        }
+        for rule in &self.rules {
+            out.push_str(&todo(&rule.notes));
+            out.push_str(&format!(
+                "{}: {};\n",
+                rule.name,
+                productions(&rule.productions, " | ")
+            ));
+        }
+        out.push_str("\nterminals\n\n");
+        for terminal in &self.terminals {
+            let recognizer = match &terminal.recognizer {
+                Some(Recognizer::Str(s)) => quote(s),
+                Some(Recognizer::Regex(regex)) => format!("/{}/", regex.replace('/', r"\/")),
+                None => {
+                    out.push_str(&todo(&[format!(
+                        "Terminal '{}' is provided by an external lexer. Define its \
+                         recognizer or use a custom lexer.",
+                        terminal.name
+                    )]));
+                    String::new()
+                }
+            };
+            out.push_str(&format!("{}: {recognizer};\n", terminal.name));
+        }
+        formatter::format_grammar(&out)
+    }
+}
+
+fn productions(productions: &[Production], separator: &str) -> String {
+    productions
+        .iter()
+        .map(|production| {
+            let mut text = if production.symbols.is_empty() {
+                "EMPTY".to_string()
+            } else {
+                production
+                    .symbols
+                    .iter()
+                    .map(symbol)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            if !production.meta.is_empty() {
+                text.push_str(&format!(" {{{}}}", production.meta.join(", ")));
+            }
+            text
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn symbol(symbol: &Symbol) -> String {
+    match symbol {
+        Symbol::Ref(name) => name.clone(),
+        Symbol::Str(s) => quote(s),
+        Symbol::Group(group) => format!("({})", productions(group, " | ")),
+        Symbol::Repeat(symbol, op) => format!("{}{op}", self::symbol(symbol)),
+        Symbol::Separated(symbol, separator) => {
+            format!("{}*[{separator}, trailing]", self::symbol(symbol))
+        }
+        Symbol::Assign(name, symbol) => format!("{name}={}", self::symbol(symbol)),
+    }
+}
+
+/// The given notes as `TODO` comments.
+fn todo(notes: &[String]) -> String {
+    notes
+        .iter()
+        .map(|note| format!("// TODO: {note}\n"))
+        .collect()
+}
+
+/// Quotes the string as a Rustemo string match.
+fn quote(s: &str) -> String {
+    format!(
+        "'{}'",
+        s.replace('\\', r"\\")
+            .replace('\'', r"\'")
+            .replace('\n', r"\n")
+            .replace('\t', r"\t")
+    )
+}
+
+/// Keywords of the Rustemo grammar language which can't be used as names.
+const KEYWORDS: &[&str] = &[
+    "terminals",
+    "import",
+    "as",
+    "left",
+    "right",
+    "reduce",
+    "shift",
+    "dynamic",
+    "nops",
+    "nopse",
+    "prefer",
+    "finish",
+    "nofinish",
+    "true",
+    "false",
+    "EMPTY",
+    "STOP",
+];
+
+/// A valid Rustemo name for the given name.
+fn identifier(name: &str) -> String {
+    let mut ident = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+/// A terminal name for the given string match. Keyword-like strings are
+/// capitalized, e.g. `while` gives `While`, while other strings are named
+/// after their characters, e.g. `<=` gives `LtEquals`.
+fn terminal_name(s: &str) -> String {
+    if s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return s
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                chars.next().unwrap().to_ascii_uppercase().to_string() + chars.as_str()
+            })
+            .collect();
+    }
+    let name = s
+        .chars()
+        .map(|c| match c {
+            '+' => "Plus".into(),
+            '-' => "Minus".into(),
+            '*' => "Star".into(),
+            '/' => "Slash".into(),
+            '%' => "Percent".into(),
+            '^' => "Caret".into(),
+            '=' => "Equals".into(),
+            '<' => "Lt".into(),
+            '>' => "Gt".into(),
+            '!' => "Bang".into(),
+            '?' => "Question".into(),
+            ':' => "Colon".into(),
+            ';' => "SemiColon".into(),
+            ',' => "Comma".into(),
+            '.' => "Dot".into(),
+            '(' => "OBracket".into(),
+            ')' => "CBracket".into(),
+            '[' => "OSBracket".into(),
+            ']' => "CSBracket".into(),
+            '{' => "OBrace".into(),
+            '}' => "CBrace".into(),
+            '|' => "Pipe".into(),
+            '&' => "Amp".into(),
+            '~' => "Tilde".into(),
+            '@' => "At".into(),
+            '#' => "Hash".into(),
+            '$' => "Dollar".into(),
+            '\'' => "Quote".into(),
+            '"' => "DQuote".into(),
+            '\\' => "Backslash".into(),
+            '`' => "Backtick".into(),
+            '_' => "Underscore".into(),
+            '\n' => "NewLine".into(),
+            c if c.is_ascii_alphanumeric() => c.to_ascii_uppercase().to_string(),
+            c => format!("Char{}", c as u32),
+        })
+        .collect::<String>();
+    identifier(&name)
+}
+
+/// A simple scanner over the source of the imported grammar.
+struct Scanner<'s> {
+    src: &'s str,
+    pos: usize,
+}
+
+impl<'s> Scanner<'s> {
+    fn new(src: &'s str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn is_eof(&self) -> bool {
+        self.pos >= self.src.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// The character after the next one.
+    fn peek2(&self) -> Option<char> {
+        self.rest().chars().nth(1)
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.rest().starts_with(s)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Result<()> {
+        self.skip_ws();
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected \"{s}\"")))
+        }
+    }
+
+    /// Skips whitespace and C-style comments.
+    fn skip_ws(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if self.starts_with("//") {
+                self.pos += self.rest().find('\n').unwrap_or(self.rest().len());
+            } else if self.starts_with("/*") {
+                self.pos += self.rest()[2..]
+                    .find("*/")
+                    .map(|end| end + 4)
+                    .unwrap_or(self.rest().len());
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// An identifier which may contain the given additional characters.
+    fn ident(&mut self, extra: &str) -> Option<String> {
+        let rest = self.rest();
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            return None;
+        }
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || extra.contains(c)))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        Some(rest[..len].to_string())
+    }
+
+    /// A string or a character literal delimited by the next character with
+    /// the usual escapes resolved.
+    fn quoted(&mut self) -> Result<String> {
+        let start = self.pos;
+        let quote = self.bump().unwrap();
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some(c) if c == quote => return Ok(value),
+                Some('\\') => value.push(match self.bump() {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('0') => '\0',
+                    Some(c) => c,
+                    None => break,
+                }),
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        self.pos = start;
+        Err(self.error("Unterminated string"))
+    }
+
+    /// Skips a string or a character literal in code. A single quote not
+    /// closed after one character is a Rust lifetime.
+    fn skip_code_literal(&mut self) {
+        let quote = self.bump().unwrap();
+        if quote == '\'' && self.peek() != Some('\\') && self.peek2() != Some('\'') {
+            return;
+        }
+        while let Some(c) = self.bump() {
+            if c == '\\' {
+                self.bump();
+            } else if c == quote {
+                break;
+            }
+        }
+    }
+
+    /// Skips code until one of the given characters outside of brackets,
+    /// strings and comments. The terminating character is not consumed.
+    fn skip_code(&mut self, until: &str) -> Result<()> {
+        let start = self.pos;
+        let mut depth = 0;
+        loop {
+            self.skip_ws();
+            let Some(c) = self.peek() else {
+                if until.is_empty() && depth == 0 {
+                    return Ok(());
+                }
+                self.pos = start;
+                return Err(self.error("Unterminated code"));
+            };
+            if depth == 0 && until.contains(c) {
+                return Ok(());
+            }
+            match c {
+                '"' | '\'' => self.skip_code_literal(),
+                '(' | '[' | '{' => {
+                    depth += 1;
+                    self.bump();
+                }
+                ')' | ']' | '}' => {
+                    if depth == 0 {
+                        return Err(self.error(&format!("Unexpected \"{c}\"")));
+                    }
+                    depth -= 1;
+                    self.bump();
+                    if depth == 0 && until.is_empty() {
+                        return Ok(());
+                    }
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    /// Skips a bracketed block of code starting at the current position.
+    fn skip_block(&mut self) -> Result<()> {
+        self.skip_code("")
+    }
+
+    fn error(&self, message: &str) -> Error {
+        let consumed = &self.src[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.len() - consumed.rfind('\n').map(|p| p + 1).unwrap_or(0) + 1;
+        Error::Error(format!("{message} at {line}:{column}."))
+    }
+}
@@ -5,7 +5,7 @@ use rustemo::{
     Result, Input as InputT, Lexer, Token, TokenRecognizer as TokenRecognizerT, Parser,
     ParserDefinition, State as StateT, Builder,
 };
-use rustemo::regex::Regex;
+use rustemo::fancy_regex::Regex;
 use rustemo::once_cell::sync::Lazy;
 use rustemo::StringLexer;
 use rustemo::LRBuilder;
@@ -16,18 +16,26 @@ use rustemo::Action::{self, Shift, Reduce, Accept};
 use rustemo::debug::{log, logn};
 #[allow(unused_imports)]
 #[cfg(debug_assertions)]
+use rustemo::colors::*;
+#[allow(unused_imports)]
+#[cfg(debug_assertions)]
 use rustemo::yansi::Paint;
 pub type Input = str;
-const STATE_COUNT: usize = 145usize;
+const STATE_COUNT: usize = 182usize;
 const MAX_RECOGNIZERS: usize = 15usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 44usize;
+const TERMINAL_COUNT: usize = 49usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
     #[default]
     STOP,
     Terminals,
+    PrecedenceKw,
+    Prec,
+    Mode,
+    Push,
+    Pop,
     Import,
     As,
     Colon,
@@ -81,6 +89,8 @@ impl From<TokenKind> for usize {
 #[derive(Clone, Copy, PartialEq)]
 pub enum ProdKind {
     FileP1,
+    PrecedenceOptP1,
+    PrecedenceOptP2,
     GrammarRule1P1,
     GrammarRule1P2,
     FileP2,
@@ -89,10 +99,26 @@ pub enum ProdKind {
     FileP3,
     TerminalRule1P1,
     TerminalRule1P2,
+    LexicalMode1P1,
+    LexicalMode1P2,
+    LexicalMode0P1,
+    LexicalMode0P2,
     FileP4,
     FileP5,
     ImportStmP1,
     ImportStmP2,
+    PrecedenceP1,
+    PrecedenceLevel1P1,
+    PrecedenceLevel1P2,
+    PrecedenceLevelP1,
+    PrecedenceAssocOptP1,
+    PrecedenceAssocOptP2,
+    GrammarSymbol1P1,
+    GrammarSymbol1P2,
+    PrecedenceAssocLeft,
+    PrecedenceAssocReduce,
+    PrecedenceAssocRight,
+    PrecedenceAssocShift,
     GrammarRuleP1,
     AnnotationOptP1,
     AnnotationOptP2,
@@ -103,6 +129,7 @@ pub enum ProdKind {
     Assignment1P1,
     Assignment1P2,
     ProductionP2,
+    LexicalModeP1,
     TerminalRuleP1,
     TerminalRuleP2,
     TerminalRuleP3,
@@ -115,8 +142,9 @@ pub enum ProdKind {
     ProdMetaDataNOPS,
     ProdMetaDataNOPSE,
     ProdMetaDataPriority,
-    ProdMetaDataP9,
+    ProdMetaDataPrec,
     ProdMetaDataP10,
+    ProdMetaDataP11,
     ProdMetaDatasP1,
     ProdMetaDatasP2,
     TermMetaDataPrefer,
@@ -128,7 +156,9 @@ pub enum ProdKind {
     TermMetaDataShift,
     TermMetaDataDynamic,
     TermMetaDataPriority,
-    TermMetaDataP10,
+    TermMetaDataPush,
+    TermMetaDataPop,
+    TermMetaDataP12,
     TermMetaDatasP1,
     TermMetaDatasP2,
     UserMetaDataP1,
@@ -160,6 +190,7 @@ pub enum ProdKind {
     RepetitionModifier1P1,
     RepetitionModifier1P2,
     RepetitionModifierP1,
+    RepetitionModifierP2,
     GrammarSymbolP1,
     GrammarSymbolP2,
     RecognizerP1,
@@ -186,19 +217,47 @@ use ProdKind as PK;
 impl std::fmt::Debug for ProdKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
-            ProdKind::FileP1 => "File: GrammarRule1",
+            ProdKind::FileP1 => "File: PrecedenceOpt GrammarRule1",
+            ProdKind::PrecedenceOptP1 => "PrecedenceOpt: Precedence",
+            ProdKind::PrecedenceOptP2 => "PrecedenceOpt: ",
             ProdKind::GrammarRule1P1 => "GrammarRule1: GrammarRule1 GrammarRule",
             ProdKind::GrammarRule1P2 => "GrammarRule1: GrammarRule",
-            ProdKind::FileP2 => "File: ImportStm1 GrammarRule1",
+            ProdKind::FileP2 => "File: ImportStm1 PrecedenceOpt GrammarRule1",
             ProdKind::ImportStm1P1 => "ImportStm1: ImportStm1 ImportStm",
             ProdKind::ImportStm1P2 => "ImportStm1: ImportStm",
-            ProdKind::FileP3 => "File: GrammarRule1 Terminals TerminalRule1",
+            ProdKind::FileP3 => {
+                "File: PrecedenceOpt GrammarRule1 Terminals TerminalRule1 LexicalMode0"
+            }
             ProdKind::TerminalRule1P1 => "TerminalRule1: TerminalRule1 TerminalRule",
             ProdKind::TerminalRule1P2 => "TerminalRule1: TerminalRule",
-            ProdKind::FileP4 => "File: ImportStm1 GrammarRule1 Terminals TerminalRule1",
-            ProdKind::FileP5 => "File: Terminals TerminalRule1",
+            ProdKind::LexicalMode1P1 => "LexicalMode1: LexicalMode1 LexicalMode",
+            ProdKind::LexicalMode1P2 => "LexicalMode1: LexicalMode",
+            ProdKind::LexicalMode0P1 => "LexicalMode0: LexicalMode1",
+            ProdKind::LexicalMode0P2 => "LexicalMode0: ",
+            ProdKind::FileP4 => {
+                "File: ImportStm1 PrecedenceOpt GrammarRule1 Terminals TerminalRule1 LexicalMode0"
+            }
+            ProdKind::FileP5 => "File: Terminals TerminalRule1 LexicalMode0",
             ProdKind::ImportStmP1 => "ImportStm: Import StrConst",
             ProdKind::ImportStmP2 => "ImportStm: Import StrConst As Name",
+            ProdKind::PrecedenceP1 => {
+                "Precedence: PrecedenceKw OBrace PrecedenceLevel1 CBrace"
+            }
+            ProdKind::PrecedenceLevel1P1 => {
+                "PrecedenceLevel1: PrecedenceLevel1 PrecedenceLevel"
+            }
+            ProdKind::PrecedenceLevel1P2 => "PrecedenceLevel1: PrecedenceLevel",
+            ProdKind::PrecedenceLevelP1 => {
+                "PrecedenceLevel: PrecedenceAssocOpt GrammarSymbol1 SemiColon"
+            }
+            ProdKind::PrecedenceAssocOptP1 => "PrecedenceAssocOpt: PrecedenceAssoc",
+            ProdKind::PrecedenceAssocOptP2 => "PrecedenceAssocOpt: ",
+            ProdKind::GrammarSymbol1P1 => "GrammarSymbol1: GrammarSymbol1 GrammarSymbol",
+            ProdKind::GrammarSymbol1P2 => "GrammarSymbol1: GrammarSymbol",
+            ProdKind::PrecedenceAssocLeft => "PrecedenceAssoc: Left",
+            ProdKind::PrecedenceAssocReduce => "PrecedenceAssoc: Reduce",
+            ProdKind::PrecedenceAssocRight => "PrecedenceAssoc: Right",
+            ProdKind::PrecedenceAssocShift => "PrecedenceAssoc: Shift",
             ProdKind::GrammarRuleP1 => {
                 "GrammarRule: AnnotationOpt Name Colon GrammarRuleRHS SemiColon"
             }
@@ -217,6 +276,9 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::ProductionP2 => {
                 "Production: Assignment1 OBrace ProdMetaDatas CBrace"
             }
+            ProdKind::LexicalModeP1 => {
+                "LexicalMode: Mode Name OBrace TerminalRule1 CBrace"
+            }
             ProdKind::TerminalRuleP1 => {
                 "TerminalRule: AnnotationOpt Name Colon Recognizer SemiColon"
             }
@@ -237,8 +299,9 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::ProdMetaDataNOPS => "ProdMetaData: NOPS",
             ProdKind::ProdMetaDataNOPSE => "ProdMetaData: NOPSE",
             ProdKind::ProdMetaDataPriority => "ProdMetaData: IntConst",
-            ProdKind::ProdMetaDataP9 => "ProdMetaData: UserMetaData",
-            ProdKind::ProdMetaDataP10 => "ProdMetaData: ProdKind",
+            ProdKind::ProdMetaDataPrec => "ProdMetaData: Prec Name",
+            ProdKind::ProdMetaDataP10 => "ProdMetaData: UserMetaData",
+            ProdKind::ProdMetaDataP11 => "ProdMetaData: ProdKind",
             ProdKind::ProdMetaDatasP1 => {
                 "ProdMetaDatas: ProdMetaDatas Comma ProdMetaData"
             }
@@ -252,7 +315,9 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::TermMetaDataShift => "TermMetaData: Shift",
             ProdKind::TermMetaDataDynamic => "TermMetaData: Dynamic",
             ProdKind::TermMetaDataPriority => "TermMetaData: IntConst",
-            ProdKind::TermMetaDataP10 => "TermMetaData: UserMetaData",
+            ProdKind::TermMetaDataPush => "TermMetaData: Push Name",
+            ProdKind::TermMetaDataPop => "TermMetaData: Pop",
+            ProdKind::TermMetaDataP12 => "TermMetaData: UserMetaData",
             ProdKind::TermMetaDatasP1 => {
                 "TermMetaDatas: TermMetaDatas Comma TermMetaData"
             }
@@ -312,6 +377,7 @@ impl std::fmt::Debug for ProdKind {
             }
             ProdKind::RepetitionModifier1P2 => "RepetitionModifier1: RepetitionModifier",
             ProdKind::RepetitionModifierP1 => "RepetitionModifier: Name",
+            ProdKind::RepetitionModifierP2 => "RepetitionModifier: Name Colon IntConst",
             ProdKind::GrammarSymbolP1 => "GrammarSymbol: Name",
             ProdKind::GrammarSymbolP2 => "GrammarSymbol: StrConst",
             ProdKind::RecognizerP1 => "Recognizer: StrConst",
@@ -345,15 +411,25 @@ pub enum NonTermKind {
     AUG,
     AUGL,
     File,
+    PrecedenceOpt,
     GrammarRule1,
     ImportStm1,
     TerminalRule1,
+    LexicalMode1,
+    LexicalMode0,
     ImportStm,
+    Precedence,
+    PrecedenceLevel1,
+    PrecedenceLevel,
+    PrecedenceAssocOpt,
+    GrammarSymbol1,
+    PrecedenceAssoc,
     GrammarRule,
     AnnotationOpt,
     GrammarRuleRHS,
     Production,
     Assignment1,
+    LexicalMode,
     TerminalRule,
     ProdMetaData,
     ProdMetaDatas,
@@ -390,6 +466,8 @@ impl From<ProdKind> for NonTermKind {
     fn from(prod: ProdKind) -> Self {
         match prod {
             ProdKind::FileP1 => NonTermKind::File,
+            ProdKind::PrecedenceOptP1 => NonTermKind::PrecedenceOpt,
+            ProdKind::PrecedenceOptP2 => NonTermKind::PrecedenceOpt,
             ProdKind::GrammarRule1P1 => NonTermKind::GrammarRule1,
             ProdKind::GrammarRule1P2 => NonTermKind::GrammarRule1,
             ProdKind::FileP2 => NonTermKind::File,
@@ -398,10 +476,26 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::FileP3 => NonTermKind::File,
             ProdKind::TerminalRule1P1 => NonTermKind::TerminalRule1,
             ProdKind::TerminalRule1P2 => NonTermKind::TerminalRule1,
+            ProdKind::LexicalMode1P1 => NonTermKind::LexicalMode1,
+            ProdKind::LexicalMode1P2 => NonTermKind::LexicalMode1,
+            ProdKind::LexicalMode0P1 => NonTermKind::LexicalMode0,
+            ProdKind::LexicalMode0P2 => NonTermKind::LexicalMode0,
             ProdKind::FileP4 => NonTermKind::File,
             ProdKind::FileP5 => NonTermKind::File,
             ProdKind::ImportStmP1 => NonTermKind::ImportStm,
             ProdKind::ImportStmP2 => NonTermKind::ImportStm,
+            ProdKind::PrecedenceP1 => NonTermKind::Precedence,
+            ProdKind::PrecedenceLevel1P1 => NonTermKind::PrecedenceLevel1,
+            ProdKind::PrecedenceLevel1P2 => NonTermKind::PrecedenceLevel1,
+            ProdKind::PrecedenceLevelP1 => NonTermKind::PrecedenceLevel,
+            ProdKind::PrecedenceAssocOptP1 => NonTermKind::PrecedenceAssocOpt,
+            ProdKind::PrecedenceAssocOptP2 => NonTermKind::PrecedenceAssocOpt,
+            ProdKind::GrammarSymbol1P1 => NonTermKind::GrammarSymbol1,
+            ProdKind::GrammarSymbol1P2 => NonTermKind::GrammarSymbol1,
+            ProdKind::PrecedenceAssocLeft => NonTermKind::PrecedenceAssoc,
+            ProdKind::PrecedenceAssocReduce => NonTermKind::PrecedenceAssoc,
+            ProdKind::PrecedenceAssocRight => NonTermKind::PrecedenceAssoc,
+            ProdKind::PrecedenceAssocShift => NonTermKind::PrecedenceAssoc,
             ProdKind::GrammarRuleP1 => NonTermKind::GrammarRule,
             ProdKind::AnnotationOptP1 => NonTermKind::AnnotationOpt,
             ProdKind::AnnotationOptP2 => NonTermKind::AnnotationOpt,
@@ -412,6 +506,7 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::Assignment1P1 => NonTermKind::Assignment1,
             ProdKind::Assignment1P2 => NonTermKind::Assignment1,
             ProdKind::ProductionP2 => NonTermKind::Production,
+            ProdKind::LexicalModeP1 => NonTermKind::LexicalMode,
             ProdKind::TerminalRuleP1 => NonTermKind::TerminalRule,
             ProdKind::TerminalRuleP2 => NonTermKind::TerminalRule,
             ProdKind::TerminalRuleP3 => NonTermKind::TerminalRule,
@@ -424,8 +519,9 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::ProdMetaDataNOPS => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataNOPSE => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataPriority => NonTermKind::ProdMetaData,
-            ProdKind::ProdMetaDataP9 => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataPrec => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataP10 => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataP11 => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDatasP1 => NonTermKind::ProdMetaDatas,
             ProdKind::ProdMetaDatasP2 => NonTermKind::ProdMetaDatas,
             ProdKind::TermMetaDataPrefer => NonTermKind::TermMetaData,
@@ -437,7 +533,9 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::TermMetaDataShift => NonTermKind::TermMetaData,
             ProdKind::TermMetaDataDynamic => NonTermKind::TermMetaData,
             ProdKind::TermMetaDataPriority => NonTermKind::TermMetaData,
-            ProdKind::TermMetaDataP10 => NonTermKind::TermMetaData,
+            ProdKind::TermMetaDataPush => NonTermKind::TermMetaData,
+            ProdKind::TermMetaDataPop => NonTermKind::TermMetaData,
+            ProdKind::TermMetaDataP12 => NonTermKind::TermMetaData,
             ProdKind::TermMetaDatasP1 => NonTermKind::TermMetaDatas,
             ProdKind::TermMetaDatasP2 => NonTermKind::TermMetaDatas,
             ProdKind::UserMetaDataP1 => NonTermKind::UserMetaData,
@@ -475,6 +573,7 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::RepetitionModifier1P1 => NonTermKind::RepetitionModifier1,
             ProdKind::RepetitionModifier1P2 => NonTermKind::RepetitionModifier1,
             ProdKind::RepetitionModifierP1 => NonTermKind::RepetitionModifier,
+            ProdKind::RepetitionModifierP2 => NonTermKind::RepetitionModifier,
             ProdKind::GrammarSymbolP1 => NonTermKind::GrammarSymbol,
             ProdKind::GrammarSymbolP2 => NonTermKind::GrammarSymbol,
             ProdKind::RecognizerP1 => NonTermKind::Recognizer,
@@ -505,153 +604,190 @@ pub enum State {
     #[default]
     AUGS0,
     TerminalsS1,
-    ImportS2,
-    AnnotationS3,
+    PrecedenceKwS2,
+    ImportS3,
     FileS4,
-    GrammarRule1S5,
+    PrecedenceOptS5,
     ImportStm1S6,
     ImportStmS7,
-    GrammarRuleS8,
-    AnnotationOptS9,
+    PrecedenceS8,
+    AnnotationS9,
     TerminalRule1S10,
     AnnotationOptS11,
     TerminalRuleS12,
-    StrConstS13,
-    TerminalsS14,
-    GrammarRuleS15,
-    GrammarRule1S16,
-    ImportStmS17,
-    NameS18,
-    TerminalRuleS19,
-    NameS20,
-    AsS21,
-    TerminalRule1S22,
-    TerminalsS23,
-    ColonS24,
-    OBraceS25,
-    ColonS26,
-    NameS27,
-    TerminalRule1S28,
-    OBracketS29,
-    NameS30,
-    StrConstS31,
-    GrammarRuleRHSS32,
-    ProductionS33,
-    Assignment1S34,
-    AssignmentS35,
-    PlainAssignmentS36,
-    BoolAssignmentS37,
-    ProductionGroupS38,
-    GrammarSymbolRefS39,
-    GrammarSymbolS40,
-    LeftS41,
-    RightS42,
-    ReduceS43,
-    ShiftS44,
-    DynamicS45,
-    NOPSS46,
-    NOPSES47,
+    OBraceS13,
+    StrConstS14,
+    GrammarRule1S15,
+    GrammarRuleS16,
+    AnnotationOptS17,
+    PrecedenceOptS18,
+    ImportStmS19,
+    ModeS20,
+    LexicalMode1S21,
+    LexicalMode0S22,
+    LexicalModeS23,
+    TerminalRuleS24,
+    NameS25,
+    LeftS26,
+    RightS27,
+    ReduceS28,
+    ShiftS29,
+    PrecedenceLevel1S30,
+    PrecedenceLevelS31,
+    PrecedenceAssocOptS32,
+    PrecedenceAssocS33,
+    AsS34,
+    TerminalsS35,
+    GrammarRuleS36,
+    NameS37,
+    GrammarRule1S38,
+    NameS39,
+    LexicalModeS40,
+    ColonS41,
+    CBraceS42,
+    PrecedenceLevelS43,
+    NameS44,
+    StrConstS45,
+    GrammarSymbol1S46,
+    GrammarSymbolS47,
     NameS48,
-    IntConstS49,
-    ProdMetaDataS50,
-    ProdMetaDatasS51,
-    UserMetaDataS52,
-    ProdKindS53,
+    TerminalRule1S49,
+    ColonS50,
+    OBraceS51,
+    TerminalsS52,
+    OBraceS53,
     SemiColonS54,
     OBraceS55,
     RegexTermS56,
     StrConstS57,
     RecognizerS58,
-    GrammarRuleRHSS59,
-    EqualsS60,
-    QEqualsS61,
-    SemiColonS62,
-    ChoiceS63,
-    OBraceS64,
-    AssignmentS65,
-    ZeroOrMoreS66,
-    ZeroOrMoreGreedyS67,
-    OneOrMoreS68,
-    OneOrMoreGreedyS69,
-    OptionalS70,
-    OptionalGreedyS71,
-    RepetitionOperatorOptS72,
-    RepetitionOperatorS73,
-    RepetitionOperatorOpS74,
-    RepetitionOperatorOptS75,
-    ColonS76,
-    CommaS77,
-    CBraceS78,
-    LeftS79,
-    RightS80,
-    ReduceS81,
-    ShiftS82,
-    DynamicS83,
-    PreferS84,
-    FinishS85,
-    NoFinishS86,
-    NameS87,
-    IntConstS88,
-    TermMetaDataS89,
-    TermMetaDatasS90,
-    UserMetaDataS91,
-    SemiColonS92,
-    OBraceS93,
-    CBracketS94,
-    NameS95,
-    GrammarSymbolRefS96,
-    GrammarSymbolRefS97,
-    ProductionS98,
-    ProdMetaDatasS99,
-    OSBracketS100,
-    RepetitionModifiersOptS101,
-    RepetitionModifiersS102,
-    IntConstS103,
-    FloatConstS104,
-    BoolConstS105,
-    StrConstS106,
-    ConstValS107,
-    ProdMetaDataS108,
-    ColonS109,
-    CommaS110,
-    CBraceS111,
-    TermMetaDatasS112,
-    CBraceS113,
-    NameS114,
-    RepetitionModifier1S115,
-    RepetitionModifierS116,
-    GrammarRuleRHSS117,
-    TermMetaDataS118,
-    SemiColonS119,
-    CBraceS120,
-    CommaS121,
-    CSBracketS122,
-    SemiColonS123,
-    SemiColonS124,
-    RepetitionModifierS125,
-    AUGLS126,
-    OCommentS127,
-    WSS128,
-    CommentLineS129,
-    LayoutS130,
-    LayoutItem1S131,
-    LayoutItem0S132,
-    LayoutItemS133,
-    CommentS134,
-    WSS135,
-    NotCommentS136,
-    CommentS137,
-    CorncsS138,
-    Cornc1S139,
-    Cornc0S140,
-    CorncS141,
-    LayoutItemS142,
-    CCommentS143,
-    CorncS144,
+    SemiColonS59,
+    GrammarSymbolS60,
+    LexicalMode0S61,
+    OBracketS62,
+    NameS63,
+    GrammarRuleRHSS64,
+    ProductionS65,
+    Assignment1S66,
+    AssignmentS67,
+    PlainAssignmentS68,
+    BoolAssignmentS69,
+    ProductionGroupS70,
+    GrammarSymbolRefS71,
+    GrammarSymbolS72,
+    PrecS73,
+    LeftS74,
+    RightS75,
+    ReduceS76,
+    ShiftS77,
+    DynamicS78,
+    NOPSS79,
+    NOPSES80,
+    NameS81,
+    IntConstS82,
+    ProdMetaDataS83,
+    ProdMetaDatasS84,
+    UserMetaDataS85,
+    ProdKindS86,
+    TerminalRule1S87,
+    TerminalRule1S88,
+    PushS89,
+    PopS90,
+    LeftS91,
+    RightS92,
+    ReduceS93,
+    ShiftS94,
+    DynamicS95,
+    PreferS96,
+    FinishS97,
+    NoFinishS98,
+    NameS99,
+    IntConstS100,
+    TermMetaDataS101,
+    TermMetaDatasS102,
+    UserMetaDataS103,
+    SemiColonS104,
+    OBraceS105,
+    GrammarRuleRHSS106,
+    EqualsS107,
+    QEqualsS108,
+    SemiColonS109,
+    ChoiceS110,
+    OBraceS111,
+    AssignmentS112,
+    ZeroOrMoreS113,
+    ZeroOrMoreGreedyS114,
+    OneOrMoreS115,
+    OneOrMoreGreedyS116,
+    OptionalS117,
+    OptionalGreedyS118,
+    RepetitionOperatorOptS119,
+    RepetitionOperatorS120,
+    RepetitionOperatorOpS121,
+    RepetitionOperatorOptS122,
+    NameS123,
+    ColonS124,
+    CommaS125,
+    CBraceS126,
+    LexicalMode0S127,
+    CBraceS128,
+    NameS129,
+    CommaS130,
+    CBraceS131,
+    TermMetaDatasS132,
+    CBracketS133,
+    GrammarSymbolRefS134,
+    GrammarSymbolRefS135,
+    ProductionS136,
+    ProdMetaDatasS137,
+    OSBracketS138,
+    RepetitionModifiersOptS139,
+    RepetitionModifiersS140,
+    IntConstS141,
+    FloatConstS142,
+    BoolConstS143,
+    StrConstS144,
+    ConstValS145,
+    ProdMetaDataS146,
+    ColonS147,
+    TermMetaDataS148,
+    SemiColonS149,
+    CBraceS150,
+    CBraceS151,
+    NameS152,
+    RepetitionModifier1S153,
+    RepetitionModifierS154,
+    GrammarRuleRHSS155,
+    SemiColonS156,
+    ColonS157,
+    CommaS158,
+    CSBracketS159,
+    SemiColonS160,
+    IntConstS161,
+    RepetitionModifierS162,
+    AUGLS163,
+    OCommentS164,
+    WSS165,
+    CommentLineS166,
+    LayoutS167,
+    LayoutItem1S168,
+    LayoutItem0S169,
+    LayoutItemS170,
+    CommentS171,
+    WSS172,
+    NotCommentS173,
+    CommentS174,
+    CorncsS175,
+    Cornc1S176,
+    Cornc0S177,
+    CorncS178,
+    LayoutItemS179,
+    CCommentS180,
+    CorncS181,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
-        Some(State::AUGLS126)
+        Some(State::AUGLS163)
     }
 }
 impl From<State> for usize {
@@ -664,149 +800,186 @@ impl std::fmt::Debug for State {
         let name = match self {
             State::AUGS0 => "0:AUG",
             State::TerminalsS1 => "1:Terminals",
-            State::ImportS2 => "2:Import",
-            State::AnnotationS3 => "3:Annotation",
+            State::PrecedenceKwS2 => "2:PrecedenceKw",
+            State::ImportS3 => "3:Import",
             State::FileS4 => "4:File",
-            State::GrammarRule1S5 => "5:GrammarRule1",
+            State::PrecedenceOptS5 => "5:PrecedenceOpt",
             State::ImportStm1S6 => "6:ImportStm1",
             State::ImportStmS7 => "7:ImportStm",
-            State::GrammarRuleS8 => "8:GrammarRule",
-            State::AnnotationOptS9 => "9:AnnotationOpt",
+            State::PrecedenceS8 => "8:Precedence",
+            State::AnnotationS9 => "9:Annotation",
             State::TerminalRule1S10 => "10:TerminalRule1",
             State::AnnotationOptS11 => "11:AnnotationOpt",
             State::TerminalRuleS12 => "12:TerminalRule",
-            State::StrConstS13 => "13:StrConst",
-            State::TerminalsS14 => "14:Terminals",
-            State::GrammarRuleS15 => "15:GrammarRule",
-            State::GrammarRule1S16 => "16:GrammarRule1",
-            State::ImportStmS17 => "17:ImportStm",
-            State::NameS18 => "18:Name",
-            State::TerminalRuleS19 => "19:TerminalRule",
-            State::NameS20 => "20:Name",
-            State::AsS21 => "21:As",
-            State::TerminalRule1S22 => "22:TerminalRule1",
-            State::TerminalsS23 => "23:Terminals",
-            State::ColonS24 => "24:Colon",
-            State::OBraceS25 => "25:OBrace",
-            State::ColonS26 => "26:Colon",
-            State::NameS27 => "27:Name",
-            State::TerminalRule1S28 => "28:TerminalRule1",
-            State::OBracketS29 => "29:OBracket",
-            State::NameS30 => "30:Name",
-            State::StrConstS31 => "31:StrConst",
-            State::GrammarRuleRHSS32 => "32:GrammarRuleRHS",
-            State::ProductionS33 => "33:Production",
-            State::Assignment1S34 => "34:Assignment1",
-            State::AssignmentS35 => "35:Assignment",
-            State::PlainAssignmentS36 => "36:PlainAssignment",
-            State::BoolAssignmentS37 => "37:BoolAssignment",
-            State::ProductionGroupS38 => "38:ProductionGroup",
-            State::GrammarSymbolRefS39 => "39:GrammarSymbolRef",
-            State::GrammarSymbolS40 => "40:GrammarSymbol",
-            State::LeftS41 => "41:Left",
-            State::RightS42 => "42:Right",
-            State::ReduceS43 => "43:Reduce",
-            State::ShiftS44 => "44:Shift",
-            State::DynamicS45 => "45:Dynamic",
-            State::NOPSS46 => "46:NOPS",
-            State::NOPSES47 => "47:NOPSE",
+            State::OBraceS13 => "13:OBrace",
+            State::StrConstS14 => "14:StrConst",
+            State::GrammarRule1S15 => "15:GrammarRule1",
+            State::GrammarRuleS16 => "16:GrammarRule",
+            State::AnnotationOptS17 => "17:AnnotationOpt",
+            State::PrecedenceOptS18 => "18:PrecedenceOpt",
+            State::ImportStmS19 => "19:ImportStm",
+            State::ModeS20 => "20:Mode",
+            State::LexicalMode1S21 => "21:LexicalMode1",
+            State::LexicalMode0S22 => "22:LexicalMode0",
+            State::LexicalModeS23 => "23:LexicalMode",
+            State::TerminalRuleS24 => "24:TerminalRule",
+            State::NameS25 => "25:Name",
+            State::LeftS26 => "26:Left",
+            State::RightS27 => "27:Right",
+            State::ReduceS28 => "28:Reduce",
+            State::ShiftS29 => "29:Shift",
+            State::PrecedenceLevel1S30 => "30:PrecedenceLevel1",
+            State::PrecedenceLevelS31 => "31:PrecedenceLevel",
+            State::PrecedenceAssocOptS32 => "32:PrecedenceAssocOpt",
+            State::PrecedenceAssocS33 => "33:PrecedenceAssoc",
+            State::AsS34 => "34:As",
+            State::TerminalsS35 => "35:Terminals",
+            State::GrammarRuleS36 => "36:GrammarRule",
+            State::NameS37 => "37:Name",
+            State::GrammarRule1S38 => "38:GrammarRule1",
+            State::NameS39 => "39:Name",
+            State::LexicalModeS40 => "40:LexicalMode",
+            State::ColonS41 => "41:Colon",
+            State::CBraceS42 => "42:CBrace",
+            State::PrecedenceLevelS43 => "43:PrecedenceLevel",
+            State::NameS44 => "44:Name",
+            State::StrConstS45 => "45:StrConst",
+            State::GrammarSymbol1S46 => "46:GrammarSymbol1",
+            State::GrammarSymbolS47 => "47:GrammarSymbol",
             State::NameS48 => "48:Name",
-            State::IntConstS49 => "49:IntConst",
-            State::ProdMetaDataS50 => "50:ProdMetaData",
-            State::ProdMetaDatasS51 => "51:ProdMetaDatas",
-            State::UserMetaDataS52 => "52:UserMetaData",
-            State::ProdKindS53 => "53:ProdKind",
+            State::TerminalRule1S49 => "49:TerminalRule1",
+            State::ColonS50 => "50:Colon",
+            State::OBraceS51 => "51:OBrace",
+            State::TerminalsS52 => "52:Terminals",
+            State::OBraceS53 => "53:OBrace",
             State::SemiColonS54 => "54:SemiColon",
             State::OBraceS55 => "55:OBrace",
             State::RegexTermS56 => "56:RegexTerm",
             State::StrConstS57 => "57:StrConst",
             State::RecognizerS58 => "58:Recognizer",
-            State::GrammarRuleRHSS59 => "59:GrammarRuleRHS",
-            State::EqualsS60 => "60:Equals",
-            State::QEqualsS61 => "61:QEquals",
-            State::SemiColonS62 => "62:SemiColon",
-            State::ChoiceS63 => "63:Choice",
-            State::OBraceS64 => "64:OBrace",
-            State::AssignmentS65 => "65:Assignment",
-            State::ZeroOrMoreS66 => "66:ZeroOrMore",
-            State::ZeroOrMoreGreedyS67 => "67:ZeroOrMoreGreedy",
-            State::OneOrMoreS68 => "68:OneOrMore",
-            State::OneOrMoreGreedyS69 => "69:OneOrMoreGreedy",
-            State::OptionalS70 => "70:Optional",
-            State::OptionalGreedyS71 => "71:OptionalGreedy",
-            State::RepetitionOperatorOptS72 => "72:RepetitionOperatorOpt",
-            State::RepetitionOperatorS73 => "73:RepetitionOperator",
-            State::RepetitionOperatorOpS74 => "74:RepetitionOperatorOp",
-            State::RepetitionOperatorOptS75 => "75:RepetitionOperatorOpt",
-            State::ColonS76 => "76:Colon",
-            State::CommaS77 => "77:Comma",
-            State::CBraceS78 => "78:CBrace",
-            State::LeftS79 => "79:Left",
-            State::RightS80 => "80:Right",
-            State::ReduceS81 => "81:Reduce",
-            State::ShiftS82 => "82:Shift",
-            State::DynamicS83 => "83:Dynamic",
-            State::PreferS84 => "84:Prefer",
-            State::FinishS85 => "85:Finish",
-            State::NoFinishS86 => "86:NoFinish",
-            State::NameS87 => "87:Name",
-            State::IntConstS88 => "88:IntConst",
-            State::TermMetaDataS89 => "89:TermMetaData",
-            State::TermMetaDatasS90 => "90:TermMetaDatas",
-            State::UserMetaDataS91 => "91:UserMetaData",
-            State::SemiColonS92 => "92:SemiColon",
-            State::OBraceS93 => "93:OBrace",
-            State::CBracketS94 => "94:CBracket",
-            State::NameS95 => "95:Name",
-            State::GrammarSymbolRefS96 => "96:GrammarSymbolRef",
-            State::GrammarSymbolRefS97 => "97:GrammarSymbolRef",
-            State::ProductionS98 => "98:Production",
-            State::ProdMetaDatasS99 => "99:ProdMetaDatas",
-            State::OSBracketS100 => "100:OSBracket",
-            State::RepetitionModifiersOptS101 => "101:RepetitionModifiersOpt",
-            State::RepetitionModifiersS102 => "102:RepetitionModifiers",
-            State::IntConstS103 => "103:IntConst",
-            State::FloatConstS104 => "104:FloatConst",
-            State::BoolConstS105 => "105:BoolConst",
-            State::StrConstS106 => "106:StrConst",
-            State::ConstValS107 => "107:ConstVal",
-            State::ProdMetaDataS108 => "108:ProdMetaData",
-            State::ColonS109 => "109:Colon",
-            State::CommaS110 => "110:Comma",
-            State::CBraceS111 => "111:CBrace",
-            State::TermMetaDatasS112 => "112:TermMetaDatas",
-            State::CBraceS113 => "113:CBrace",
-            State::NameS114 => "114:Name",
-            State::RepetitionModifier1S115 => "115:RepetitionModifier1",
-            State::RepetitionModifierS116 => "116:RepetitionModifier",
-            State::GrammarRuleRHSS117 => "117:GrammarRuleRHS",
-            State::TermMetaDataS118 => "118:TermMetaData",
-            State::SemiColonS119 => "119:SemiColon",
-            State::CBraceS120 => "120:CBrace",
-            State::CommaS121 => "121:Comma",
-            State::CSBracketS122 => "122:CSBracket",
-            State::SemiColonS123 => "123:SemiColon",
-            State::SemiColonS124 => "124:SemiColon",
-            State::RepetitionModifierS125 => "125:RepetitionModifier",
-            State::AUGLS126 => "126:AUGL",
-            State::OCommentS127 => "127:OComment",
-            State::WSS128 => "128:WS",
-            State::CommentLineS129 => "129:CommentLine",
-            State::LayoutS130 => "130:Layout",
-            State::LayoutItem1S131 => "131:LayoutItem1",
-            State::LayoutItem0S132 => "132:LayoutItem0",
-            State::LayoutItemS133 => "133:LayoutItem",
-            State::CommentS134 => "134:Comment",
-            State::WSS135 => "135:WS",
-            State::NotCommentS136 => "136:NotComment",
-            State::CommentS137 => "137:Comment",
-            State::CorncsS138 => "138:Corncs",
-            State::Cornc1S139 => "139:Cornc1",
-            State::Cornc0S140 => "140:Cornc0",
-            State::CorncS141 => "141:Cornc",
-            State::LayoutItemS142 => "142:LayoutItem",
-            State::CCommentS143 => "143:CComment",
-            State::CorncS144 => "144:Cornc",
+            State::SemiColonS59 => "59:SemiColon",
+            State::GrammarSymbolS60 => "60:GrammarSymbol",
+            State::LexicalMode0S61 => "61:LexicalMode0",
+            State::OBracketS62 => "62:OBracket",
+            State::NameS63 => "63:Name",
+            State::GrammarRuleRHSS64 => "64:GrammarRuleRHS",
+            State::ProductionS65 => "65:Production",
+            State::Assignment1S66 => "66:Assignment1",
+            State::AssignmentS67 => "67:Assignment",
+            State::PlainAssignmentS68 => "68:PlainAssignment",
+            State::BoolAssignmentS69 => "69:BoolAssignment",
+            State::ProductionGroupS70 => "70:ProductionGroup",
+            State::GrammarSymbolRefS71 => "71:GrammarSymbolRef",
+            State::GrammarSymbolS72 => "72:GrammarSymbol",
+            State::PrecS73 => "73:Prec",
+            State::LeftS74 => "74:Left",
+            State::RightS75 => "75:Right",
+            State::ReduceS76 => "76:Reduce",
+            State::ShiftS77 => "77:Shift",
+            State::DynamicS78 => "78:Dynamic",
+            State::NOPSS79 => "79:NOPS",
+            State::NOPSES80 => "80:NOPSE",
+            State::NameS81 => "81:Name",
+            State::IntConstS82 => "82:IntConst",
+            State::ProdMetaDataS83 => "83:ProdMetaData",
+            State::ProdMetaDatasS84 => "84:ProdMetaDatas",
+            State::UserMetaDataS85 => "85:UserMetaData",
+            State::ProdKindS86 => "86:ProdKind",
+            State::TerminalRule1S87 => "87:TerminalRule1",
+            State::TerminalRule1S88 => "88:TerminalRule1",
+            State::PushS89 => "89:Push",
+            State::PopS90 => "90:Pop",
+            State::LeftS91 => "91:Left",
+            State::RightS92 => "92:Right",
+            State::ReduceS93 => "93:Reduce",
+            State::ShiftS94 => "94:Shift",
+            State::DynamicS95 => "95:Dynamic",
+            State::PreferS96 => "96:Prefer",
+            State::FinishS97 => "97:Finish",
+            State::NoFinishS98 => "98:NoFinish",
+            State::NameS99 => "99:Name",
+            State::IntConstS100 => "100:IntConst",
+            State::TermMetaDataS101 => "101:TermMetaData",
+            State::TermMetaDatasS102 => "102:TermMetaDatas",
+            State::UserMetaDataS103 => "103:UserMetaData",
+            State::SemiColonS104 => "104:SemiColon",
+            State::OBraceS105 => "105:OBrace",
+            State::GrammarRuleRHSS106 => "106:GrammarRuleRHS",
+            State::EqualsS107 => "107:Equals",
+            State::QEqualsS108 => "108:QEquals",
+            State::SemiColonS109 => "109:SemiColon",
+            State::ChoiceS110 => "110:Choice",
+            State::OBraceS111 => "111:OBrace",
+            State::AssignmentS112 => "112:Assignment",
+            State::ZeroOrMoreS113 => "113:ZeroOrMore",
+            State::ZeroOrMoreGreedyS114 => "114:ZeroOrMoreGreedy",
+            State::OneOrMoreS115 => "115:OneOrMore",
+            State::OneOrMoreGreedyS116 => "116:OneOrMoreGreedy",
+            State::OptionalS117 => "117:Optional",
+            State::OptionalGreedyS118 => "118:OptionalGreedy",
+            State::RepetitionOperatorOptS119 => "119:RepetitionOperatorOpt",
+            State::RepetitionOperatorS120 => "120:RepetitionOperator",
+            State::RepetitionOperatorOpS121 => "121:RepetitionOperatorOp",
+            State::RepetitionOperatorOptS122 => "122:RepetitionOperatorOpt",
+            State::NameS123 => "123:Name",
+            State::ColonS124 => "124:Colon",
+            State::CommaS125 => "125:Comma",
+            State::CBraceS126 => "126:CBrace",
+            State::LexicalMode0S127 => "127:LexicalMode0",
+            State::CBraceS128 => "128:CBrace",
+            State::NameS129 => "129:Name",
+            State::CommaS130 => "130:Comma",
+            State::CBraceS131 => "131:CBrace",
+            State::TermMetaDatasS132 => "132:TermMetaDatas",
+            State::CBracketS133 => "133:CBracket",
+            State::GrammarSymbolRefS134 => "134:GrammarSymbolRef",
+            State::GrammarSymbolRefS135 => "135:GrammarSymbolRef",
+            State::ProductionS136 => "136:Production",
+            State::ProdMetaDatasS137 => "137:ProdMetaDatas",
+            State::OSBracketS138 => "138:OSBracket",
+            State::RepetitionModifiersOptS139 => "139:RepetitionModifiersOpt",
+            State::RepetitionModifiersS140 => "140:RepetitionModifiers",
+            State::IntConstS141 => "141:IntConst",
+            State::FloatConstS142 => "142:FloatConst",
+            State::BoolConstS143 => "143:BoolConst",
+            State::StrConstS144 => "144:StrConst",
+            State::ConstValS145 => "145:ConstVal",
+            State::ProdMetaDataS146 => "146:ProdMetaData",
+            State::ColonS147 => "147:Colon",
+            State::TermMetaDataS148 => "148:TermMetaData",
+            State::SemiColonS149 => "149:SemiColon",
+            State::CBraceS150 => "150:CBrace",
+            State::CBraceS151 => "151:CBrace",
+            State::NameS152 => "152:Name",
+            State::RepetitionModifier1S153 => "153:RepetitionModifier1",
+            State::RepetitionModifierS154 => "154:RepetitionModifier",
+            State::GrammarRuleRHSS155 => "155:GrammarRuleRHS",
+            State::SemiColonS156 => "156:SemiColon",
+            State::ColonS157 => "157:Colon",
+            State::CommaS158 => "158:Comma",
+            State::CSBracketS159 => "159:CSBracket",
+            State::SemiColonS160 => "160:SemiColon",
+            State::IntConstS161 => "161:IntConst",
+            State::RepetitionModifierS162 => "162:RepetitionModifier",
+            State::AUGLS163 => "163:AUGL",
+            State::OCommentS164 => "164:OComment",
+            State::WSS165 => "165:WS",
+            State::CommentLineS166 => "166:CommentLine",
+            State::LayoutS167 => "167:Layout",
+            State::LayoutItem1S168 => "168:LayoutItem1",
+            State::LayoutItem0S169 => "169:LayoutItem0",
+            State::LayoutItemS170 => "170:LayoutItem",
+            State::CommentS171 => "171:Comment",
+            State::WSS172 => "172:WS",
+            State::NotCommentS173 => "173:NotComment",
+            State::CommentS174 => "174:Comment",
+            State::CorncsS175 => "175:Corncs",
+            State::Cornc1S176 => "176:Cornc1",
+            State::Cornc0S177 => "177:Cornc0",
+            State::CorncS178 => "178:Cornc",
+            State::LayoutItemS179 => "179:LayoutItem",
+            State::CCommentS180 => "180:CComment",
+            State::CorncS181 => "181:Cornc",
         };
         write!(f, "{name}")
     }
@@ -820,6 +993,11 @@ pub enum Symbol {
 #[derive(Debug)]
 pub enum Terminal {
     Terminals,
+    PrecedenceKw(rustemo_actions::PrecedenceKw),
+    Prec(rustemo_actions::Prec),
+    Mode(rustemo_actions::Mode),
+    Push(rustemo_actions::Push),
+    Pop(rustemo_actions::Pop),
     Import,
     As,
     Colon,
@@ -861,15 +1039,25 @@ pub enum Terminal {
 #[derive(Debug)]
 pub enum NonTerminal {
     File(rustemo_actions::File),
+    PrecedenceOpt(rustemo_actions::PrecedenceOpt),
     GrammarRule1(rustemo_actions::GrammarRule1),
     ImportStm1(rustemo_actions::ImportStm1),
     TerminalRule1(rustemo_actions::TerminalRule1),
+    LexicalMode1(rustemo_actions::LexicalMode1),
+    LexicalMode0(rustemo_actions::LexicalMode0),
     ImportStm(rustemo_actions::ImportStm),
+    Precedence(rustemo_actions::Precedence),
+    PrecedenceLevel1(rustemo_actions::PrecedenceLevel1),
+    PrecedenceLevel(rustemo_actions::PrecedenceLevel),
+    PrecedenceAssocOpt(rustemo_actions::PrecedenceAssocOpt),
+    GrammarSymbol1(rustemo_actions::GrammarSymbol1),
+    PrecedenceAssoc(rustemo_actions::PrecedenceAssoc),
     GrammarRule(rustemo_actions::GrammarRule),
     AnnotationOpt(rustemo_actions::AnnotationOpt),
     GrammarRuleRHS(rustemo_actions::GrammarRuleRHS),
     Production(rustemo_actions::Production),
     Assignment1(rustemo_actions::Assignment1),
+    LexicalMode(rustemo_actions::LexicalMode),
     TerminalRule(rustemo_actions::TerminalRule),
     ProdMetaData(rustemo_actions::ProdMetaData),
     ProdMetaDatas(rustemo_actions::ProdMetaDatas),
@@ -902,28 +1090,29 @@ pub struct RustemoParserDefinition {
 fn action_aug_s0(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Terminals => Vec::from(&[Shift(State::TerminalsS1)]),
-        TK::Import => Vec::from(&[Shift(State::ImportS2)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::PrecedenceKw => Vec::from(&[Shift(State::PrecedenceKwS2)]),
+        TK::Import => Vec::from(&[Shift(State::ImportS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::PrecedenceOptP2, 0usize)]),
         _ => vec![],
     }
 }
 fn action_terminals_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
-fn action_import_s2(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_precedencekw_s2(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS13)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS13)]),
         _ => vec![],
     }
 }
-fn action_annotation_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_import_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP1, 1usize)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS14)]),
         _ => vec![],
     }
 }
@@ -933,208 +1122,318 @@ fn action_file_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_grammarrule1_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_precedenceopt_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP1, 1usize)]),
-        TK::Terminals => Vec::from(&[Shift(State::TerminalsS14)]),
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
 fn action_importstm1_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Import => Vec::from(&[Shift(State::ImportS2)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::PrecedenceKw => Vec::from(&[Shift(State::PrecedenceKwS2)]),
+        TK::Import => Vec::from(&[Shift(State::ImportS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::PrecedenceOptP2, 0usize)]),
         _ => vec![],
     }
 }
 fn action_importstm_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::PrecedenceKw => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
         TK::Import => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_grammarrule_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_precedence_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
-        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceOptP1, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::PrecedenceOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_annotationopt_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_annotation_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS18)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP1, 1usize)]),
         _ => vec![],
     }
 }
 fn action_terminalrule1_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP5, 2usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalMode0P2, 0usize)]),
+        TK::Mode => Vec::from(&[Shift(State::ModeS20)]),
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
 fn action_annotationopt_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS20)]),
+        TK::Name => Vec::from(&[Shift(State::NameS25)]),
         _ => vec![],
     }
 }
 fn action_terminalrule_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_obrace_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Left => Vec::from(&[Shift(State::LeftS26)]),
+        TK::Right => Vec::from(&[Shift(State::RightS27)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS28)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS29)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocOptP2, 0usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::PrecedenceKw => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
         TK::Import => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
-        TK::As => Vec::from(&[Shift(State::AsS21)]),
+        TK::As => Vec::from(&[Shift(State::AsS34)]),
         TK::Name => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_terminals_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrule1_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FileP1, 2usize)]),
+        TK::Terminals => Vec::from(&[Shift(State::TerminalsS35)]),
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
-fn action_grammarrule_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrule_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
-        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_annotationopt_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS37)]),
         _ => vec![],
     }
 }
-fn action_grammarrule1_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_precedenceopt_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP2, 2usize)]),
-        TK::Terminals => Vec::from(&[Shift(State::TerminalsS23)]),
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
-fn action_importstm_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_importstm_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::PrecedenceKw => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
         TK::Import => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_name_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_mode_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS39)]),
+        _ => vec![],
+    }
+}
+fn action_lexicalmode1_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalMode0P1, 1usize)]),
+        TK::Mode => Vec::from(&[Shift(State::ModeS20)]),
+        _ => vec![],
+    }
+}
+fn action_lexicalmode0_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FileP5, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_lexicalmode_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS24)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS25)]),
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalMode1P2, 1usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::LexicalMode1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_terminalrule_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_name_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS26)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS41)]),
         _ => vec![],
     }
 }
-fn action_as_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_left_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS27)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocLeft, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocLeft, 1usize)]),
         _ => vec![],
     }
 }
-fn action_terminalrule1_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_right_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP3, 3usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocRight, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocRight, 1usize)]),
         _ => vec![],
     }
 }
-fn action_terminals_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_reduce_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocReduce, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocReduce, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_shift_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocShift, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocShift, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_precedencelevel1_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS42)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS26)]),
+        TK::Right => Vec::from(&[Shift(State::RightS27)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS28)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS29)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocOptP2, 0usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_precedencelevel_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CBrace => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        TK::Left => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        TK::Right => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        TK::Reduce => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        TK::Shift => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceLevel1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_precedenceassocopt_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS44)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
         _ => vec![],
     }
 }
-fn action_colon_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_precedenceassoc_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceAssocOptP1, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceAssocOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_obrace_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_as_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS41)]),
-        TK::Right => Vec::from(&[Shift(State::RightS42)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS43)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS44)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS45)]),
-        TK::NOPS => Vec::from(&[Shift(State::NOPSS46)]),
-        TK::NOPSE => Vec::from(&[Shift(State::NOPSES47)]),
         TK::Name => Vec::from(&[Shift(State::NameS48)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS49)]),
         _ => vec![],
     }
 }
-fn action_colon_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminals_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS54)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS55)]),
-        TK::RegexTerm => Vec::from(&[Shift(State::RegexTermS56)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS57)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
-fn action_name_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrule_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Import => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_name_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS50)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS51)]),
         _ => vec![],
     }
 }
-fn action_terminalrule1_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrule1_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP4, 4usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FileP2, 3usize)]),
+        TK::Terminals => Vec::from(&[Shift(State::TerminalsS52)]),
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
+        _ => vec![],
+    }
+}
+fn action_name_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS53)]),
+        _ => vec![],
+    }
+}
+fn action_lexicalmode_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalMode1P1, 2usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::LexicalMode1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS54)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS55)]),
+        TK::RegexTerm => Vec::from(&[Shift(State::RegexTermS56)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS57)]),
         _ => vec![],
     }
 }
-fn action_obracket_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceP1, 4usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::PrecedenceP1, 4usize)]),
         _ => vec![],
     }
 }
-fn action_name_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_precedencelevel_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CBrace => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        TK::Left => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        TK::Right => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        TK::Reduce => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        TK::Shift => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceLevel1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_name_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
@@ -1147,14 +1446,12 @@ fn action_name_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::OneOrMoreGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::Optional => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::OptionalGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::Equals => Vec::from(&[Shift(State::EqualsS60)]),
-        TK::QEquals => Vec::from(&[Shift(State::QEqualsS61)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
@@ -1172,14 +1469,187 @@ fn action_strconst_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_grammarrulerhs_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbol1_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS59)]),
+        TK::Name => Vec::from(&[Shift(State::NameS44)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
+        _ => vec![],
+    }
+}
+fn action_grammarsymbol_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbol1P2, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarSymbol1P2, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbol1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_name_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::PrecedenceKw => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Import => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_terminalrule1_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalMode0P2, 0usize)]),
+        TK::Mode => Vec::from(&[Shift(State::ModeS20)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
+        TK::Name => Vec::from(&[Shift(State::NameS63)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Prec => Vec::from(&[Shift(State::PrecS73)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS74)]),
+        TK::Right => Vec::from(&[Shift(State::RightS75)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS76)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS77)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS78)]),
+        TK::NOPS => Vec::from(&[Shift(State::NOPSS79)]),
+        TK::NOPSE => Vec::from(&[Shift(State::NOPSES80)]),
+        TK::Name => Vec::from(&[Shift(State::NameS81)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS82)]),
+        _ => vec![],
+    }
+}
+fn action_terminals_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Push => Vec::from(&[Shift(State::PushS89)]),
+        TK::Pop => Vec::from(&[Shift(State::PopS90)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS91)]),
+        TK::Right => Vec::from(&[Shift(State::RightS92)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS93)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS94)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS95)]),
+        TK::Prefer => Vec::from(&[Shift(State::PreferS96)]),
+        TK::Finish => Vec::from(&[Shift(State::FinishS97)]),
+        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS98)]),
+        TK::Name => Vec::from(&[Shift(State::NameS99)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS100)]),
+        _ => vec![],
+    }
+}
+fn action_regexterm_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_recognizer_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS104)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS105)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CBrace => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        TK::Left => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        TK::Right => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        TK::Reduce => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        TK::Shift => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::PrecedenceLevelP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_grammarsymbol_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbol1P1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarSymbol1P1, 2usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbol1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_lexicalmode0_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FileP3, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_obracket_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
+        TK::Name => Vec::from(&[Shift(State::NameS63)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
+        _ => vec![],
+    }
+}
+fn action_name_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::OBracket => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::ZeroOrMore => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::OneOrMore => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::Optional => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::OptionalGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS107)]),
+        TK::QEquals => Vec::from(&[Shift(State::QEqualsS108)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_grammarrulerhs_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS62)]),
-        TK::Choice => Vec::from(&[Shift(State::ChoiceS63)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS109)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS110)]),
         _ => vec![],
     }
 }
-fn action_production_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_production_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarRuleRHSP2, 1usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::GrammarRuleRHSP2, 1usize)]),
@@ -1187,19 +1657,19 @@ fn action_production_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_assignment1_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment1_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::ProductionP1, 1usize)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS64)]),
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS111)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
         TK::CBracket => Vec::from(&[Reduce(PK::ProductionP1, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::ProductionP1, 1usize)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Name => Vec::from(&[Shift(State::NameS63)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
         _ => vec![],
     }
 }
-fn action_assignment_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
@@ -1211,7 +1681,7 @@ fn action_assignment_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_plainassignment_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_plainassignment_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
@@ -1223,7 +1693,7 @@ fn action_plainassignment_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKi
         _ => vec![],
     }
 }
-fn action_boolassignment_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_boolassignment_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
@@ -1235,25 +1705,25 @@ fn action_boolassignment_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKin
         _ => vec![],
     }
 }
-fn action_productiongroup_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_productiongroup_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
-        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS66)]),
-        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS67)]),
-        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS68)]),
-        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS69)]),
-        TK::Optional => Vec::from(&[Shift(State::OptionalS70)]),
-        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS71)]),
+        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS113)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS114)]),
+        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS115)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS116)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS117)]),
+        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS118)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_grammarsymbolref_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbolref_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
@@ -1265,229 +1735,339 @@ fn action_grammarsymbolref_s39(token_kind: TokenKind) -> Vec<Action<State, ProdK
         _ => vec![],
     }
 }
-fn action_grammarsymbol_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbol_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
-        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS66)]),
-        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS67)]),
-        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS68)]),
-        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS69)]),
-        TK::Optional => Vec::from(&[Shift(State::OptionalS70)]),
-        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS71)]),
+        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS113)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS114)]),
+        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS115)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS116)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS117)]),
+        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS118)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_left_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prec_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS123)]),
+        _ => vec![],
+    }
+}
+fn action_left_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataLeft, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataLeft, 1usize)]),
         _ => vec![],
     }
 }
-fn action_right_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_right_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataRight, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataRight, 1usize)]),
         _ => vec![],
     }
 }
-fn action_reduce_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_reduce_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataReduce, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataReduce, 1usize)]),
         _ => vec![],
     }
 }
-fn action_shift_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_shift_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataShift, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataShift, 1usize)]),
         _ => vec![],
     }
 }
-fn action_dynamic_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dynamic_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataDynamic, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataDynamic, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nops_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nops_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataNOPS, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataNOPS, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nopse_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nopse_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataNOPSE, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataNOPSE, 1usize)]),
         _ => vec![],
     }
 }
-fn action_name_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS76)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS124)]),
         TK::Comma => Vec::from(&[Reduce(PK::ProdKindP1, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdKindP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_intconst_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_intconst_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataPriority, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataPriority, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prodmetadata_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadata_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDatasP2, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDatasP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prodmetadatas_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadatas_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS77)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS78)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS125)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS126)]),
         _ => vec![],
     }
 }
-fn action_usermetadata_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_usermetadata_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP9, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP9, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP10, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP10, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prodkind_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodkind_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP10, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP10, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP11, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP11, 1usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule1_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalMode0P2, 0usize)]),
+        TK::Mode => Vec::from(&[Shift(State::ModeS20)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
-fn action_obrace_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule1_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS79)]),
-        TK::Right => Vec::from(&[Shift(State::RightS80)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS81)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS82)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS83)]),
-        TK::Prefer => Vec::from(&[Shift(State::PreferS84)]),
-        TK::Finish => Vec::from(&[Shift(State::FinishS85)]),
-        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS86)]),
-        TK::Name => Vec::from(&[Shift(State::NameS87)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS88)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS128)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS9)]),
         _ => vec![],
     }
 }
-fn action_regexterm_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_push_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
-        TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
+        TK::Name => Vec::from(&[Shift(State::NameS129)]),
         _ => vec![],
     }
 }
-fn action_strconst_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_pop_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
-        TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPop, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPop, 1usize)]),
         _ => vec![],
     }
 }
-fn action_recognizer_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_left_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS92)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS93)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataLeft, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataLeft, 1usize)]),
         _ => vec![],
     }
 }
-fn action_grammarrulerhs_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_right_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CBracket => Vec::from(&[Shift(State::CBracketS94)]),
-        TK::Choice => Vec::from(&[Shift(State::ChoiceS63)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataRight, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataRight, 1usize)]),
         _ => vec![],
     }
 }
-fn action_equals_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_reduce_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS95)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataReduce, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataReduce, 1usize)]),
         _ => vec![],
     }
 }
-fn action_qequals_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_shift_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS95)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataShift, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataShift, 1usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dynamic_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
-        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataDynamic, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataDynamic, 1usize)]),
         _ => vec![],
     }
 }
-fn action_choice_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prefer_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPrefer, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPrefer, 1usize)]),
         _ => vec![],
     }
 }
-fn action_obrace_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_finish_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS41)]),
-        TK::Right => Vec::from(&[Shift(State::RightS42)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS43)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS44)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS45)]),
-        TK::NOPS => Vec::from(&[Shift(State::NOPSS46)]),
-        TK::NOPSE => Vec::from(&[Shift(State::NOPSES47)]),
-        TK::Name => Vec::from(&[Shift(State::NameS48)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS49)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataFinish, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataFinish, 1usize)]),
         _ => vec![],
     }
 }
-fn action_assignment_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nofinish_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
-        TK::OBrace => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
-        TK::OBracket => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
-        TK::CBracket => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
-        TK::Choice => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
-        TK::StrConst => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataNoFinish, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataNoFinish, 1usize)]),
         _ => vec![],
     }
 }
-fn action_zeroormore_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS124)]),
+        _ => vec![],
+    }
+}
+fn action_intconst_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPriority, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPriority, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_termmetadata_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDatasP2, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDatasP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_termmetadatas_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Shift(State::CommaS130)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS131)]),
+        _ => vec![],
+    }
+}
+fn action_usermetadata_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataP12, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataP12, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Push => Vec::from(&[Shift(State::PushS89)]),
+        TK::Pop => Vec::from(&[Shift(State::PopS90)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS91)]),
+        TK::Right => Vec::from(&[Shift(State::RightS92)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS93)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS94)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS95)]),
+        TK::Prefer => Vec::from(&[Shift(State::PreferS96)]),
+        TK::Finish => Vec::from(&[Shift(State::FinishS97)]),
+        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS98)]),
+        TK::Name => Vec::from(&[Shift(State::NameS99)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS100)]),
+        _ => vec![],
+    }
+}
+fn action_grammarrulerhs_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CBracket => Vec::from(&[Shift(State::CBracketS133)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS110)]),
+        _ => vec![],
+    }
+}
+fn action_equals_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
+        TK::Name => Vec::from(&[Shift(State::NameS44)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
+        _ => vec![],
+    }
+}
+fn action_qequals_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
+        TK::Name => Vec::from(&[Shift(State::NameS44)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
+        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_choice_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
+        TK::Name => Vec::from(&[Shift(State::NameS63)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Prec => Vec::from(&[Shift(State::PrecS73)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS74)]),
+        TK::Right => Vec::from(&[Shift(State::RightS75)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS76)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS77)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS78)]),
+        TK::NOPS => Vec::from(&[Shift(State::NOPSS79)]),
+        TK::NOPSE => Vec::from(&[Shift(State::NOPSES80)]),
+        TK::Name => Vec::from(&[Shift(State::NameS81)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS82)]),
+        _ => vec![],
+    }
+}
+fn action_assignment_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::OBracket => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_zeroormore_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
@@ -1498,7 +2078,7 @@ fn action_zeroormore_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_zeroormoregreedy_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_zeroormoregreedy_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMoreGreedy, 1usize)])
@@ -1527,7 +2107,7 @@ fn action_zeroormoregreedy_s67(token_kind: TokenKind) -> Vec<Action<State, ProdK
         _ => vec![],
     }
 }
-fn action_oneormore_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_oneormore_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
@@ -1540,7 +2120,7 @@ fn action_oneormore_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_oneormoregreedy_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_oneormoregreedy_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMoreGreedy, 1usize)])
@@ -1567,7 +2147,7 @@ fn action_oneormoregreedy_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKi
         _ => vec![],
     }
 }
-fn action_optional_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optional_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
@@ -1580,7 +2160,7 @@ fn action_optional_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_optionalgreedy_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optionalgreedy_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOptionalGreedy, 1usize)])
@@ -1607,7 +2187,7 @@ fn action_optionalgreedy_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKin
         _ => vec![],
     }
 }
-fn action_repetitionoperatoropt_s72(
+fn action_repetitionoperatoropt_s119(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1621,7 +2201,9 @@ fn action_repetitionoperatoropt_s72(
         _ => vec![],
     }
 }
-fn action_repetitionoperator_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_repetitionoperator_s120(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
@@ -1633,7 +2215,7 @@ fn action_repetitionoperator_s73(token_kind: TokenKind) -> Vec<Action<State, Pro
         _ => vec![],
     }
 }
-fn action_repetitionoperatorop_s74(
+fn action_repetitionoperatorop_s121(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1641,14 +2223,14 @@ fn action_repetitionoperatorop_s74(
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
-        TK::OSBracket => Vec::from(&[Shift(State::OSBracketS100)]),
+        TK::OSBracket => Vec::from(&[Shift(State::OSBracketS138)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionoperatoropt_s75(
+fn action_repetitionoperatoropt_s122(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1662,149 +2244,94 @@ fn action_repetitionoperatoropt_s75(
         _ => vec![],
     }
 }
-fn action_colon_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS103)]),
-        TK::FloatConst => Vec::from(&[Shift(State::FloatConstS104)]),
-        TK::BoolConst => Vec::from(&[Shift(State::BoolConstS105)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS106)]),
-        _ => vec![],
-    }
-}
-fn action_comma_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS41)]),
-        TK::Right => Vec::from(&[Shift(State::RightS42)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS43)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS44)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS45)]),
-        TK::NOPS => Vec::from(&[Shift(State::NOPSS46)]),
-        TK::NOPSE => Vec::from(&[Shift(State::NOPSES47)]),
-        TK::Name => Vec::from(&[Shift(State::NameS48)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS49)]),
-        _ => vec![],
-    }
-}
-fn action_cbrace_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS109)]),
-        _ => vec![],
-    }
-}
-fn action_left_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataLeft, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataLeft, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_right_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataRight, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataRight, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_reduce_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataReduce, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataReduce, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_shift_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataShift, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataShift, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_dynamic_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataDynamic, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataDynamic, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_prefer_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPrefer, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPrefer, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataPrec, 2usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataPrec, 2usize)]),
         _ => vec![],
     }
 }
-fn action_finish_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataFinish, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataFinish, 1usize)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS141)]),
+        TK::FloatConst => Vec::from(&[Shift(State::FloatConstS142)]),
+        TK::BoolConst => Vec::from(&[Shift(State::BoolConstS143)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS144)]),
         _ => vec![],
     }
 }
-fn action_nofinish_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s125(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataNoFinish, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataNoFinish, 1usize)]),
+        TK::Prec => Vec::from(&[Shift(State::PrecS73)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS74)]),
+        TK::Right => Vec::from(&[Shift(State::RightS75)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS76)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS77)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS78)]),
+        TK::NOPS => Vec::from(&[Shift(State::NOPSS79)]),
+        TK::NOPSE => Vec::from(&[Shift(State::NOPSES80)]),
+        TK::Name => Vec::from(&[Shift(State::NameS81)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS82)]),
         _ => vec![],
     }
 }
-fn action_name_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS76)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS147)]),
         _ => vec![],
     }
 }
-fn action_intconst_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_lexicalmode0_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPriority, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPriority, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FileP4, 6usize)]),
         _ => vec![],
     }
 }
-fn action_termmetadata_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDatasP2, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDatasP2, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::LexicalModeP1, 5usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::LexicalModeP1, 5usize)]),
         _ => vec![],
     }
 }
-fn action_termmetadatas_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS110)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS111)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPush, 2usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPush, 2usize)]),
         _ => vec![],
     }
 }
-fn action_usermetadata_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataP10, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataP10, 1usize)]),
+        TK::Push => Vec::from(&[Shift(State::PushS89)]),
+        TK::Pop => Vec::from(&[Shift(State::PopS90)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS91)]),
+        TK::Right => Vec::from(&[Shift(State::RightS92)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS93)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS94)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS95)]),
+        TK::Prefer => Vec::from(&[Shift(State::PreferS96)]),
+        TK::Finish => Vec::from(&[Shift(State::FinishS97)]),
+        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS98)]),
+        TK::Name => Vec::from(&[Shift(State::NameS99)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS100)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS149)]),
         _ => vec![],
     }
 }
-fn action_obrace_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_termmetadatas_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS79)]),
-        TK::Right => Vec::from(&[Shift(State::RightS80)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS81)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS82)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS83)]),
-        TK::Prefer => Vec::from(&[Shift(State::PreferS84)]),
-        TK::Finish => Vec::from(&[Shift(State::FinishS85)]),
-        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS86)]),
-        TK::Name => Vec::from(&[Shift(State::NameS87)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS88)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS130)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS150)]),
         _ => vec![],
     }
 }
-fn action_cbracket_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbracket_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
@@ -1822,25 +2349,7 @@ fn action_cbracket_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_name_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::OBracket => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::CBracket => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::Choice => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::ZeroOrMore => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::ZeroOrMoreGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::OneOrMore => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::OneOrMoreGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::Optional => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::OptionalGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_grammarsymbolref_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbolref_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
@@ -1852,7 +2361,7 @@ fn action_grammarsymbolref_s96(token_kind: TokenKind) -> Vec<Action<State, ProdK
         _ => vec![],
     }
 }
-fn action_grammarsymbolref_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbolref_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
@@ -1864,7 +2373,7 @@ fn action_grammarsymbolref_s97(token_kind: TokenKind) -> Vec<Action<State, ProdK
         _ => vec![],
     }
 }
-fn action_production_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_production_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarRuleRHSP1, 3usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::GrammarRuleRHSP1, 3usize)]),
@@ -1872,20 +2381,20 @@ fn action_production_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_prodmetadatas_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadatas_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS77)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS113)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS125)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS151)]),
         _ => vec![],
     }
 }
-fn action_osbracket_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_osbracket_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS114)]),
+        TK::Name => Vec::from(&[Shift(State::NameS152)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifiersopt_s101(
+fn action_repetitionmodifiersopt_s139(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1899,7 +2408,7 @@ fn action_repetitionmodifiersopt_s101(
         _ => vec![],
     }
 }
-fn action_repetitionmodifiers_s102(
+fn action_repetitionmodifiers_s140(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1913,85 +2422,80 @@ fn action_repetitionmodifiers_s102(
         _ => vec![],
     }
 }
-fn action_intconst_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_intconst_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP1, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_floatconst_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_floatconst_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP2, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_boolconst_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_boolconst_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP3, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP4, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_constval_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_constval_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::UserMetaDataP1, 3usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::UserMetaDataP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_prodmetadata_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadata_s146(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDatasP1, 3usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDatasP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_colon_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s147(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS62)]),
+        TK::Name => Vec::from(&[Shift(State::NameS63)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS45)]),
         _ => vec![],
     }
 }
-fn action_comma_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_termmetadata_s148(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS79)]),
-        TK::Right => Vec::from(&[Shift(State::RightS80)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS81)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS82)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS83)]),
-        TK::Prefer => Vec::from(&[Shift(State::PreferS84)]),
-        TK::Finish => Vec::from(&[Shift(State::FinishS85)]),
-        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS86)]),
-        TK::Name => Vec::from(&[Shift(State::NameS87)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS88)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDatasP1, 3usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDatasP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s149(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS119)]),
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
         _ => vec![],
     }
 }
-fn action_termmetadatas_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s150(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS110)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS120)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS156)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s151(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::ProductionP2, 4usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::ProductionP2, 4usize)]),
@@ -1999,23 +2503,24 @@ fn action_cbrace_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_name_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s152(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS157)]),
         TK::Comma => Vec::from(&[Reduce(PK::RepetitionModifierP1, 1usize)]),
         TK::CSBracket => Vec::from(&[Reduce(PK::RepetitionModifierP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifier1_s115(
+fn action_repetitionmodifier1_s153(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS121)]),
-        TK::CSBracket => Vec::from(&[Shift(State::CSBracketS122)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS158)]),
+        TK::CSBracket => Vec::from(&[Shift(State::CSBracketS159)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifier_s116(
+fn action_repetitionmodifier_s154(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -2024,41 +2529,36 @@ fn action_repetitionmodifier_s116(
         _ => vec![],
     }
 }
-fn action_grammarrulerhs_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS123)]),
-        TK::Choice => Vec::from(&[Shift(State::ChoiceS63)]),
-        _ => vec![],
-    }
-}
-fn action_termmetadata_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrulerhs_s155(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::TermMetaDatasP1, 3usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDatasP1, 3usize)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS160)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS110)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s156(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
+        TK::Mode => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s157(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS124)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS161)]),
         _ => vec![],
     }
 }
-fn action_comma_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s158(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS114)]),
+        TK::Name => Vec::from(&[Shift(State::NameS152)]),
         _ => vec![],
     }
 }
-fn action_csbracket_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_csbracket_s159(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
@@ -2070,7 +2570,7 @@ fn action_csbracket_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_semicolon_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s160(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP2, 8usize)]),
         TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP2, 8usize)]),
@@ -2079,15 +2579,14 @@ fn action_semicolon_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_semicolon_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_intconst_s161(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::RepetitionModifierP2, 3usize)]),
+        TK::CSBracket => Vec::from(&[Reduce(PK::RepetitionModifierP2, 3usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifier_s125(
+fn action_repetitionmodifier_s162(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -2096,26 +2595,26 @@ fn action_repetitionmodifier_s125(
         _ => vec![],
     }
 }
-fn action_augl_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_augl_s163(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
-        TK::WS => Vec::from(&[Shift(State::WSS128)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS164)]),
+        TK::WS => Vec::from(&[Shift(State::WSS165)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS166)]),
         _ => vec![],
     }
 }
-fn action_ocomment_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ocomment_s164(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS164)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc0P2, 0usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS135)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS136)]),
+        TK::WS => Vec::from(&[Shift(State::WSS172)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS166)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS173)]),
         _ => vec![],
     }
 }
-fn action_ws_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ws_s165(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
@@ -2124,7 +2623,7 @@ fn action_ws_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_commentline_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_commentline_s166(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
@@ -2135,28 +2634,28 @@ fn action_commentline_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>
         _ => vec![],
     }
 }
-fn action_layout_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layout_s167(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Accept]),
         _ => vec![],
     }
 }
-fn action_layoutitem1_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem1_s168(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
-        TK::WS => Vec::from(&[Shift(State::WSS128)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS164)]),
+        TK::WS => Vec::from(&[Shift(State::WSS165)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS166)]),
         _ => vec![],
     }
 }
-fn action_layoutitem0_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem0_s169(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_layoutitem_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem_s170(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
@@ -2165,7 +2664,7 @@ fn action_layoutitem_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_comment_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comment_s171(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
@@ -2174,7 +2673,7 @@ fn action_comment_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_ws_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ws_s172(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
@@ -2184,7 +2683,7 @@ fn action_ws_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_notcomment_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_notcomment_s173(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
@@ -2194,7 +2693,7 @@ fn action_notcomment_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_comment_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comment_s174(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
@@ -2204,29 +2703,29 @@ fn action_comment_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_corncs_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_corncs_s175(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CComment => Vec::from(&[Shift(State::CCommentS143)]),
+        TK::CComment => Vec::from(&[Shift(State::CCommentS180)]),
         _ => vec![],
     }
 }
-fn action_cornc1_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc1_s176(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS164)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc0P1, 1usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS135)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS136)]),
+        TK::WS => Vec::from(&[Shift(State::WSS172)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS166)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS173)]),
         _ => vec![],
     }
 }
-fn action_cornc0_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc0_s177(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CComment => Vec::from(&[Reduce(PK::CorncsP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc_s178(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
@@ -2236,7 +2735,7 @@ fn action_cornc_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_layoutitem_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem_s179(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
@@ -2245,7 +2744,7 @@ fn action_layoutitem_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_ccomment_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ccomment_s180(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
@@ -2256,7 +2755,7 @@ fn action_ccomment_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_cornc_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc_s181(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
@@ -2269,11 +2768,10 @@ fn action_cornc_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
 fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
         NonTermKind::File => State::FileS4,
-        NonTermKind::GrammarRule1 => State::GrammarRule1S5,
+        NonTermKind::PrecedenceOpt => State::PrecedenceOptS5,
         NonTermKind::ImportStm1 => State::ImportStm1S6,
         NonTermKind::ImportStm => State::ImportStmS7,
-        NonTermKind::GrammarRule => State::GrammarRuleS8,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::Precedence => State::PrecedenceS8,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -2295,24 +2793,24 @@ fn goto_terminals_s1(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
-fn goto_grammarrule1_s5(nonterm_kind: NonTermKind) -> State {
+fn goto_precedenceopt_s5(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRule => State::GrammarRuleS15,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::GrammarRule1 => State::GrammarRule1S15,
+        NonTermKind::GrammarRule => State::GrammarRuleS16,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS17,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GrammarRule1S5
+                State::PrecedenceOptS5
             )
         }
     }
 }
 fn goto_importstm1_s6(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRule1 => State::GrammarRule1S16,
-        NonTermKind::ImportStm => State::ImportStmS17,
-        NonTermKind::GrammarRule => State::GrammarRuleS8,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::PrecedenceOpt => State::PrecedenceOptS18,
+        NonTermKind::ImportStm => State::ImportStmS19,
+        NonTermKind::Precedence => State::PrecedenceS8,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -2323,8 +2821,11 @@ fn goto_importstm1_s6(nonterm_kind: NonTermKind) -> State {
 }
 fn goto_terminalrule1_s10(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
+        NonTermKind::LexicalMode1 => State::LexicalMode1S21,
+        NonTermKind::LexicalMode0 => State::LexicalMode0S22,
         NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS19,
+        NonTermKind::LexicalMode => State::LexicalModeS23,
+        NonTermKind::TerminalRule => State::TerminalRuleS24,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -2333,178 +2834,207 @@ fn goto_terminalrule1_s10(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
-fn goto_terminals_s14(nonterm_kind: NonTermKind) -> State {
+fn goto_obrace_s13(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TerminalRule1 => State::TerminalRule1S22,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS12,
+        NonTermKind::PrecedenceLevel1 => State::PrecedenceLevel1S30,
+        NonTermKind::PrecedenceLevel => State::PrecedenceLevelS31,
+        NonTermKind::PrecedenceAssocOpt => State::PrecedenceAssocOptS32,
+        NonTermKind::PrecedenceAssoc => State::PrecedenceAssocS33,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalsS14
+                State::OBraceS13
             )
         }
     }
 }
-fn goto_grammarrule1_s16(nonterm_kind: NonTermKind) -> State {
+fn goto_grammarrule1_s15(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRule => State::GrammarRuleS15,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::GrammarRule => State::GrammarRuleS36,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS17,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GrammarRule1S16
+                State::GrammarRule1S15
             )
         }
     }
 }
-fn goto_terminalrule1_s22(nonterm_kind: NonTermKind) -> State {
+fn goto_precedenceopt_s18(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS19,
+        NonTermKind::GrammarRule1 => State::GrammarRule1S38,
+        NonTermKind::GrammarRule => State::GrammarRuleS16,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS17,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PrecedenceOptS18
+            )
+        }
+    }
+}
+fn goto_lexicalmode1_s21(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LexicalMode => State::LexicalModeS40,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LexicalMode1S21
+            )
+        }
+    }
+}
+fn goto_precedencelevel1_s30(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PrecedenceLevel => State::PrecedenceLevelS43,
+        NonTermKind::PrecedenceAssocOpt => State::PrecedenceAssocOptS32,
+        NonTermKind::PrecedenceAssoc => State::PrecedenceAssocS33,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PrecedenceLevel1S30
+            )
+        }
+    }
+}
+fn goto_precedenceassocopt_s32(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GrammarSymbol1 => State::GrammarSymbol1S46,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS47,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalRule1S22
+                State::PrecedenceAssocOptS32
             )
         }
     }
 }
-fn goto_terminals_s23(nonterm_kind: NonTermKind) -> State {
+fn goto_terminals_s35(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TerminalRule1 => State::TerminalRule1S28,
+        NonTermKind::TerminalRule1 => State::TerminalRule1S49,
         NonTermKind::AnnotationOpt => State::AnnotationOptS11,
         NonTermKind::TerminalRule => State::TerminalRuleS12,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalsS23
+                State::TerminalsS35
             )
         }
     }
 }
-fn goto_colon_s24(nonterm_kind: NonTermKind) -> State {
+fn goto_grammarrule1_s38(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS32,
-        NonTermKind::Production => State::ProductionS33,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::GrammarRule => State::GrammarRuleS36,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS17,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS24
+                State::GrammarRule1S38
             )
         }
     }
 }
-fn goto_obrace_s25(nonterm_kind: NonTermKind) -> State {
+fn goto_colon_s41(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProdMetaData => State::ProdMetaDataS50,
-        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS51,
-        NonTermKind::UserMetaData => State::UserMetaDataS52,
-        NonTermKind::ProdKind => State::ProdKindS53,
+        NonTermKind::Recognizer => State::RecognizerS58,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS25
+                State::ColonS41
             )
         }
     }
 }
-fn goto_colon_s26(nonterm_kind: NonTermKind) -> State {
+fn goto_grammarsymbol1_s46(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Recognizer => State::RecognizerS58,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS60,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS26
+                State::GrammarSymbol1S46
             )
         }
     }
 }
-fn goto_terminalrule1_s28(nonterm_kind: NonTermKind) -> State {
+fn goto_terminalrule1_s49(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
+        NonTermKind::LexicalMode1 => State::LexicalMode1S21,
+        NonTermKind::LexicalMode0 => State::LexicalMode0S61,
         NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS19,
+        NonTermKind::LexicalMode => State::LexicalModeS23,
+        NonTermKind::TerminalRule => State::TerminalRuleS24,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalRule1S28
+                State::TerminalRule1S49
             )
         }
     }
 }
-fn goto_obracket_s29(nonterm_kind: NonTermKind) -> State {
+fn goto_colon_s50(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS59,
-        NonTermKind::Production => State::ProductionS33,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS64,
+        NonTermKind::Production => State::ProductionS65,
+        NonTermKind::Assignment1 => State::Assignment1S66,
+        NonTermKind::Assignment => State::AssignmentS67,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS68,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS69,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS71,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBracketS29
+                State::ColonS50
             )
         }
     }
 }
-fn goto_assignment1_s34(nonterm_kind: NonTermKind) -> State {
+fn goto_obrace_s51(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Assignment => State::AssignmentS65,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::ProdMetaData => State::ProdMetaDataS83,
+        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS84,
+        NonTermKind::UserMetaData => State::UserMetaDataS85,
+        NonTermKind::ProdKind => State::ProdKindS86,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Assignment1S34
+                State::OBraceS51
             )
         }
     }
 }
-fn goto_productiongroup_s38(nonterm_kind: NonTermKind) -> State {
+fn goto_terminals_s52(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS72,
-        NonTermKind::RepetitionOperator => State::RepetitionOperatorS73,
-        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS74,
+        NonTermKind::TerminalRule1 => State::TerminalRule1S87,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
+        NonTermKind::TerminalRule => State::TerminalRuleS12,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ProductionGroupS38
+                State::TerminalsS52
             )
         }
     }
 }
-fn goto_grammarsymbol_s40(nonterm_kind: NonTermKind) -> State {
+fn goto_obrace_s53(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS75,
-        NonTermKind::RepetitionOperator => State::RepetitionOperatorS73,
-        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS74,
+        NonTermKind::TerminalRule1 => State::TerminalRule1S88,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
+        NonTermKind::TerminalRule => State::TerminalRuleS12,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GrammarSymbolS40
+                State::OBraceS53
             )
         }
     }
 }
 fn goto_obrace_s55(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TermMetaData => State::TermMetaDataS89,
-        NonTermKind::TermMetaDatas => State::TermMetaDatasS90,
-        NonTermKind::UserMetaData => State::UserMetaDataS91,
+        NonTermKind::TermMetaData => State::TermMetaDataS101,
+        NonTermKind::TermMetaDatas => State::TermMetaDatasS102,
+        NonTermKind::UserMetaData => State::UserMetaDataS103,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -2513,217 +3043,305 @@ fn goto_obrace_s55(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
-fn goto_equals_s60(nonterm_kind: NonTermKind) -> State {
+fn goto_obracket_s62(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS106,
+        NonTermKind::Production => State::ProductionS65,
+        NonTermKind::Assignment1 => State::Assignment1S66,
+        NonTermKind::Assignment => State::AssignmentS67,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS68,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS69,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS71,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OBracketS62
+            )
+        }
+    }
+}
+fn goto_assignment1_s66(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Assignment => State::AssignmentS112,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS68,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS69,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS71,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::Assignment1S66
+            )
+        }
+    }
+}
+fn goto_productiongroup_s70(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS119,
+        NonTermKind::RepetitionOperator => State::RepetitionOperatorS120,
+        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS121,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ProductionGroupS70
+            )
+        }
+    }
+}
+fn goto_grammarsymbol_s72(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS122,
+        NonTermKind::RepetitionOperator => State::RepetitionOperatorS120,
+        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS121,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GrammarSymbolS72
+            )
+        }
+    }
+}
+fn goto_terminalrule1_s87(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LexicalMode1 => State::LexicalMode1S21,
+        NonTermKind::LexicalMode0 => State::LexicalMode0S127,
+        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
+        NonTermKind::LexicalMode => State::LexicalModeS23,
+        NonTermKind::TerminalRule => State::TerminalRuleS24,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TerminalRule1S87
+            )
+        }
+    }
+}
+fn goto_terminalrule1_s88(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
+        NonTermKind::TerminalRule => State::TerminalRuleS24,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TerminalRule1S88
+            )
+        }
+    }
+}
+fn goto_obrace_s105(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS96,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::TermMetaData => State::TermMetaDataS101,
+        NonTermKind::TermMetaDatas => State::TermMetaDatasS132,
+        NonTermKind::UserMetaData => State::UserMetaDataS103,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsS60
+                State::OBraceS105
             )
         }
     }
 }
-fn goto_qequals_s61(nonterm_kind: NonTermKind) -> State {
+fn goto_equals_s107(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS97,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS134,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::QEqualsS61
+                State::EqualsS107
             )
         }
     }
 }
-fn goto_choice_s63(nonterm_kind: NonTermKind) -> State {
+fn goto_qequals_s108(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Production => State::ProductionS98,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS135,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ChoiceS63
+                State::QEqualsS108
             )
         }
     }
 }
-fn goto_obrace_s64(nonterm_kind: NonTermKind) -> State {
+fn goto_choice_s110(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProdMetaData => State::ProdMetaDataS50,
-        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS99,
-        NonTermKind::UserMetaData => State::UserMetaDataS52,
-        NonTermKind::ProdKind => State::ProdKindS53,
+        NonTermKind::Production => State::ProductionS136,
+        NonTermKind::Assignment1 => State::Assignment1S66,
+        NonTermKind::Assignment => State::AssignmentS67,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS68,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS69,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS71,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS64
+                State::ChoiceS110
             )
         }
     }
 }
-fn goto_repetitionoperatorop_s74(nonterm_kind: NonTermKind) -> State {
+fn goto_obrace_s111(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionModifiersOpt => State::RepetitionModifiersOptS101,
-        NonTermKind::RepetitionModifiers => State::RepetitionModifiersS102,
+        NonTermKind::ProdMetaData => State::ProdMetaDataS83,
+        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS137,
+        NonTermKind::UserMetaData => State::UserMetaDataS85,
+        NonTermKind::ProdKind => State::ProdKindS86,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::RepetitionOperatorOpS74
+                State::OBraceS111
             )
         }
     }
 }
-fn goto_colon_s76(nonterm_kind: NonTermKind) -> State {
+fn goto_repetitionoperatorop_s121(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ConstVal => State::ConstValS107,
+        NonTermKind::RepetitionModifiersOpt => State::RepetitionModifiersOptS139,
+        NonTermKind::RepetitionModifiers => State::RepetitionModifiersS140,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS76
+                State::RepetitionOperatorOpS121
             )
         }
     }
 }
-fn goto_comma_s77(nonterm_kind: NonTermKind) -> State {
+fn goto_colon_s124(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProdMetaData => State::ProdMetaDataS108,
-        NonTermKind::UserMetaData => State::UserMetaDataS52,
-        NonTermKind::ProdKind => State::ProdKindS53,
+        NonTermKind::ConstVal => State::ConstValS145,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS77
+                State::ColonS124
             )
         }
     }
 }
-fn goto_obrace_s93(nonterm_kind: NonTermKind) -> State {
+fn goto_comma_s125(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TermMetaData => State::TermMetaDataS89,
-        NonTermKind::TermMetaDatas => State::TermMetaDatasS112,
-        NonTermKind::UserMetaData => State::UserMetaDataS91,
+        NonTermKind::ProdMetaData => State::ProdMetaDataS146,
+        NonTermKind::UserMetaData => State::UserMetaDataS85,
+        NonTermKind::ProdKind => State::ProdKindS86,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS93
+                State::CommaS125
             )
         }
     }
 }
-fn goto_osbracket_s100(nonterm_kind: NonTermKind) -> State {
+fn goto_comma_s130(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionModifier1 => State::RepetitionModifier1S115,
-        NonTermKind::RepetitionModifier => State::RepetitionModifierS116,
+        NonTermKind::TermMetaData => State::TermMetaDataS148,
+        NonTermKind::UserMetaData => State::UserMetaDataS103,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OSBracketS100
+                State::CommaS130
             )
         }
     }
 }
-fn goto_colon_s109(nonterm_kind: NonTermKind) -> State {
+fn goto_osbracket_s138(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS117,
-        NonTermKind::Production => State::ProductionS33,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::RepetitionModifier1 => State::RepetitionModifier1S153,
+        NonTermKind::RepetitionModifier => State::RepetitionModifierS154,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS109
+                State::OSBracketS138
             )
         }
     }
 }
-fn goto_comma_s110(nonterm_kind: NonTermKind) -> State {
+fn goto_colon_s147(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TermMetaData => State::TermMetaDataS118,
-        NonTermKind::UserMetaData => State::UserMetaDataS91,
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS155,
+        NonTermKind::Production => State::ProductionS65,
+        NonTermKind::Assignment1 => State::Assignment1S66,
+        NonTermKind::Assignment => State::AssignmentS67,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS68,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS69,
+        NonTermKind::ProductionGroup => State::ProductionGroupS70,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS71,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS110
+                State::ColonS147
             )
         }
     }
 }
-fn goto_comma_s121(nonterm_kind: NonTermKind) -> State {
+fn goto_comma_s158(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionModifier => State::RepetitionModifierS125,
+        NonTermKind::RepetitionModifier => State::RepetitionModifierS162,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS121
+                State::CommaS158
             )
         }
     }
 }
-fn goto_augl_s126(nonterm_kind: NonTermKind) -> State {
+fn goto_augl_s163(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Layout => State::LayoutS130,
-        NonTermKind::LayoutItem1 => State::LayoutItem1S131,
-        NonTermKind::LayoutItem0 => State::LayoutItem0S132,
-        NonTermKind::LayoutItem => State::LayoutItemS133,
-        NonTermKind::Comment => State::CommentS134,
+        NonTermKind::Layout => State::LayoutS167,
+        NonTermKind::LayoutItem1 => State::LayoutItem1S168,
+        NonTermKind::LayoutItem0 => State::LayoutItem0S169,
+        NonTermKind::LayoutItem => State::LayoutItemS170,
+        NonTermKind::Comment => State::CommentS171,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGLS126
+                State::AUGLS163
             )
         }
     }
 }
-fn goto_ocomment_s127(nonterm_kind: NonTermKind) -> State {
+fn goto_ocomment_s164(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Comment => State::CommentS137,
-        NonTermKind::Corncs => State::CorncsS138,
-        NonTermKind::Cornc1 => State::Cornc1S139,
-        NonTermKind::Cornc0 => State::Cornc0S140,
-        NonTermKind::Cornc => State::CorncS141,
+        NonTermKind::Comment => State::CommentS174,
+        NonTermKind::Corncs => State::CorncsS175,
+        NonTermKind::Cornc1 => State::Cornc1S176,
+        NonTermKind::Cornc0 => State::Cornc0S177,
+        NonTermKind::Cornc => State::CorncS178,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OCommentS127
+                State::OCommentS164
             )
         }
     }
 }
-fn goto_layoutitem1_s131(nonterm_kind: NonTermKind) -> State {
+fn goto_layoutitem1_s168(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::LayoutItem => State::LayoutItemS142,
-        NonTermKind::Comment => State::CommentS134,
+        NonTermKind::LayoutItem => State::LayoutItemS179,
+        NonTermKind::Comment => State::CommentS171,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LayoutItem1S131
+                State::LayoutItem1S168
             )
         }
     }
 }
-fn goto_cornc1_s139(nonterm_kind: NonTermKind) -> State {
+fn goto_cornc1_s176(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Comment => State::CommentS137,
-        NonTermKind::Cornc => State::CorncS144,
+        NonTermKind::Comment => State::CommentS174,
+        NonTermKind::Cornc => State::CorncS181,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Cornc1S139
+                State::Cornc1S176
             )
         }
     }
@@ -2735,149 +3353,186 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
     actions: [
         action_aug_s0,
         action_terminals_s1,
-        action_import_s2,
-        action_annotation_s3,
+        action_precedencekw_s2,
+        action_import_s3,
         action_file_s4,
-        action_grammarrule1_s5,
+        action_precedenceopt_s5,
         action_importstm1_s6,
         action_importstm_s7,
-        action_grammarrule_s8,
-        action_annotationopt_s9,
+        action_precedence_s8,
+        action_annotation_s9,
         action_terminalrule1_s10,
         action_annotationopt_s11,
         action_terminalrule_s12,
-        action_strconst_s13,
-        action_terminals_s14,
-        action_grammarrule_s15,
-        action_grammarrule1_s16,
-        action_importstm_s17,
-        action_name_s18,
-        action_terminalrule_s19,
-        action_name_s20,
-        action_as_s21,
-        action_terminalrule1_s22,
-        action_terminals_s23,
-        action_colon_s24,
-        action_obrace_s25,
-        action_colon_s26,
-        action_name_s27,
-        action_terminalrule1_s28,
-        action_obracket_s29,
-        action_name_s30,
-        action_strconst_s31,
-        action_grammarrulerhs_s32,
-        action_production_s33,
-        action_assignment1_s34,
-        action_assignment_s35,
-        action_plainassignment_s36,
-        action_boolassignment_s37,
-        action_productiongroup_s38,
-        action_grammarsymbolref_s39,
-        action_grammarsymbol_s40,
-        action_left_s41,
-        action_right_s42,
-        action_reduce_s43,
-        action_shift_s44,
-        action_dynamic_s45,
-        action_nops_s46,
-        action_nopse_s47,
+        action_obrace_s13,
+        action_strconst_s14,
+        action_grammarrule1_s15,
+        action_grammarrule_s16,
+        action_annotationopt_s17,
+        action_precedenceopt_s18,
+        action_importstm_s19,
+        action_mode_s20,
+        action_lexicalmode1_s21,
+        action_lexicalmode0_s22,
+        action_lexicalmode_s23,
+        action_terminalrule_s24,
+        action_name_s25,
+        action_left_s26,
+        action_right_s27,
+        action_reduce_s28,
+        action_shift_s29,
+        action_precedencelevel1_s30,
+        action_precedencelevel_s31,
+        action_precedenceassocopt_s32,
+        action_precedenceassoc_s33,
+        action_as_s34,
+        action_terminals_s35,
+        action_grammarrule_s36,
+        action_name_s37,
+        action_grammarrule1_s38,
+        action_name_s39,
+        action_lexicalmode_s40,
+        action_colon_s41,
+        action_cbrace_s42,
+        action_precedencelevel_s43,
+        action_name_s44,
+        action_strconst_s45,
+        action_grammarsymbol1_s46,
+        action_grammarsymbol_s47,
         action_name_s48,
-        action_intconst_s49,
-        action_prodmetadata_s50,
-        action_prodmetadatas_s51,
-        action_usermetadata_s52,
-        action_prodkind_s53,
+        action_terminalrule1_s49,
+        action_colon_s50,
+        action_obrace_s51,
+        action_terminals_s52,
+        action_obrace_s53,
         action_semicolon_s54,
         action_obrace_s55,
         action_regexterm_s56,
         action_strconst_s57,
         action_recognizer_s58,
-        action_grammarrulerhs_s59,
-        action_equals_s60,
-        action_qequals_s61,
-        action_semicolon_s62,
-        action_choice_s63,
-        action_obrace_s64,
-        action_assignment_s65,
-        action_zeroormore_s66,
-        action_zeroormoregreedy_s67,
-        action_oneormore_s68,
-        action_oneormoregreedy_s69,
-        action_optional_s70,
-        action_optionalgreedy_s71,
-        action_repetitionoperatoropt_s72,
-        action_repetitionoperator_s73,
-        action_repetitionoperatorop_s74,
-        action_repetitionoperatoropt_s75,
-        action_colon_s76,
-        action_comma_s77,
-        action_cbrace_s78,
-        action_left_s79,
-        action_right_s80,
-        action_reduce_s81,
-        action_shift_s82,
-        action_dynamic_s83,
-        action_prefer_s84,
-        action_finish_s85,
-        action_nofinish_s86,
-        action_name_s87,
-        action_intconst_s88,
-        action_termmetadata_s89,
-        action_termmetadatas_s90,
-        action_usermetadata_s91,
-        action_semicolon_s92,
-        action_obrace_s93,
-        action_cbracket_s94,
-        action_name_s95,
-        action_grammarsymbolref_s96,
-        action_grammarsymbolref_s97,
-        action_production_s98,
-        action_prodmetadatas_s99,
-        action_osbracket_s100,
-        action_repetitionmodifiersopt_s101,
-        action_repetitionmodifiers_s102,
-        action_intconst_s103,
-        action_floatconst_s104,
-        action_boolconst_s105,
-        action_strconst_s106,
-        action_constval_s107,
-        action_prodmetadata_s108,
-        action_colon_s109,
-        action_comma_s110,
-        action_cbrace_s111,
-        action_termmetadatas_s112,
-        action_cbrace_s113,
-        action_name_s114,
-        action_repetitionmodifier1_s115,
-        action_repetitionmodifier_s116,
-        action_grammarrulerhs_s117,
-        action_termmetadata_s118,
-        action_semicolon_s119,
-        action_cbrace_s120,
-        action_comma_s121,
-        action_csbracket_s122,
-        action_semicolon_s123,
-        action_semicolon_s124,
-        action_repetitionmodifier_s125,
-        action_augl_s126,
-        action_ocomment_s127,
-        action_ws_s128,
-        action_commentline_s129,
-        action_layout_s130,
-        action_layoutitem1_s131,
-        action_layoutitem0_s132,
-        action_layoutitem_s133,
-        action_comment_s134,
-        action_ws_s135,
-        action_notcomment_s136,
-        action_comment_s137,
-        action_corncs_s138,
-        action_cornc1_s139,
-        action_cornc0_s140,
-        action_cornc_s141,
-        action_layoutitem_s142,
-        action_ccomment_s143,
-        action_cornc_s144,
+        action_semicolon_s59,
+        action_grammarsymbol_s60,
+        action_lexicalmode0_s61,
+        action_obracket_s62,
+        action_name_s63,
+        action_grammarrulerhs_s64,
+        action_production_s65,
+        action_assignment1_s66,
+        action_assignment_s67,
+        action_plainassignment_s68,
+        action_boolassignment_s69,
+        action_productiongroup_s70,
+        action_grammarsymbolref_s71,
+        action_grammarsymbol_s72,
+        action_prec_s73,
+        action_left_s74,
+        action_right_s75,
+        action_reduce_s76,
+        action_shift_s77,
+        action_dynamic_s78,
+        action_nops_s79,
+        action_nopse_s80,
+        action_name_s81,
+        action_intconst_s82,
+        action_prodmetadata_s83,
+        action_prodmetadatas_s84,
+        action_usermetadata_s85,
+        action_prodkind_s86,
+        action_terminalrule1_s87,
+        action_terminalrule1_s88,
+        action_push_s89,
+        action_pop_s90,
+        action_left_s91,
+        action_right_s92,
+        action_reduce_s93,
+        action_shift_s94,
+        action_dynamic_s95,
+        action_prefer_s96,
+        action_finish_s97,
+        action_nofinish_s98,
+        action_name_s99,
+        action_intconst_s100,
+        action_termmetadata_s101,
+        action_termmetadatas_s102,
+        action_usermetadata_s103,
+        action_semicolon_s104,
+        action_obrace_s105,
+        action_grammarrulerhs_s106,
+        action_equals_s107,
+        action_qequals_s108,
+        action_semicolon_s109,
+        action_choice_s110,
+        action_obrace_s111,
+        action_assignment_s112,
+        action_zeroormore_s113,
+        action_zeroormoregreedy_s114,
+        action_oneormore_s115,
+        action_oneormoregreedy_s116,
+        action_optional_s117,
+        action_optionalgreedy_s118,
+        action_repetitionoperatoropt_s119,
+        action_repetitionoperator_s120,
+        action_repetitionoperatorop_s121,
+        action_repetitionoperatoropt_s122,
+        action_name_s123,
+        action_colon_s124,
+        action_comma_s125,
+        action_cbrace_s126,
+        action_lexicalmode0_s127,
+        action_cbrace_s128,
+        action_name_s129,
+        action_comma_s130,
+        action_cbrace_s131,
+        action_termmetadatas_s132,
+        action_cbracket_s133,
+        action_grammarsymbolref_s134,
+        action_grammarsymbolref_s135,
+        action_production_s136,
+        action_prodmetadatas_s137,
+        action_osbracket_s138,
+        action_repetitionmodifiersopt_s139,
+        action_repetitionmodifiers_s140,
+        action_intconst_s141,
+        action_floatconst_s142,
+        action_boolconst_s143,
+        action_strconst_s144,
+        action_constval_s145,
+        action_prodmetadata_s146,
+        action_colon_s147,
+        action_termmetadata_s148,
+        action_semicolon_s149,
+        action_cbrace_s150,
+        action_cbrace_s151,
+        action_name_s152,
+        action_repetitionmodifier1_s153,
+        action_repetitionmodifier_s154,
+        action_grammarrulerhs_s155,
+        action_semicolon_s156,
+        action_colon_s157,
+        action_comma_s158,
+        action_csbracket_s159,
+        action_semicolon_s160,
+        action_intconst_s161,
+        action_repetitionmodifier_s162,
+        action_augl_s163,
+        action_ocomment_s164,
+        action_ws_s165,
+        action_commentline_s166,
+        action_layout_s167,
+        action_layoutitem1_s168,
+        action_layoutitem0_s169,
+        action_layoutitem_s170,
+        action_comment_s171,
+        action_ws_s172,
+        action_notcomment_s173,
+        action_comment_s174,
+        action_corncs_s175,
+        action_cornc1_s176,
+        action_cornc0_s177,
+        action_cornc_s178,
+        action_layoutitem_s179,
+        action_ccomment_s180,
+        action_cornc_s181,
     ],
     gotos: [
         goto_aug_s0,
@@ -2885,7 +3540,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_grammarrule1_s5,
+        goto_precedenceopt_s5,
         goto_importstm1_s6,
         goto_invalid,
         goto_invalid,
@@ -2893,71 +3548,71 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_terminalrule1_s10,
         goto_invalid,
         goto_invalid,
+        goto_obrace_s13,
         goto_invalid,
-        goto_terminals_s14,
+        goto_grammarrule1_s15,
         goto_invalid,
-        goto_grammarrule1_s16,
         goto_invalid,
+        goto_precedenceopt_s18,
         goto_invalid,
         goto_invalid,
+        goto_lexicalmode1_s21,
         goto_invalid,
         goto_invalid,
-        goto_terminalrule1_s22,
-        goto_terminals_s23,
-        goto_colon_s24,
-        goto_obrace_s25,
-        goto_colon_s26,
         goto_invalid,
-        goto_terminalrule1_s28,
-        goto_obracket_s29,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_assignment1_s34,
         goto_invalid,
+        goto_precedencelevel1_s30,
         goto_invalid,
+        goto_precedenceassocopt_s32,
         goto_invalid,
-        goto_productiongroup_s38,
         goto_invalid,
-        goto_grammarsymbol_s40,
+        goto_terminals_s35,
         goto_invalid,
         goto_invalid,
+        goto_grammarrule1_s38,
         goto_invalid,
         goto_invalid,
+        goto_colon_s41,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_grammarsymbol1_s46,
         goto_invalid,
         goto_invalid,
+        goto_terminalrule1_s49,
+        goto_colon_s50,
+        goto_obrace_s51,
+        goto_terminals_s52,
+        goto_obrace_s53,
         goto_invalid,
+        goto_obrace_s55,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_obrace_s55,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_obracket_s62,
         goto_invalid,
-        goto_equals_s60,
-        goto_qequals_s61,
         goto_invalid,
-        goto_choice_s63,
-        goto_obrace_s64,
         goto_invalid,
+        goto_assignment1_s66,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_productiongroup_s70,
         goto_invalid,
+        goto_grammarsymbol_s72,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_repetitionoperatorop_s74,
         goto_invalid,
-        goto_colon_s76,
-        goto_comma_s77,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -2967,30 +3622,33 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_terminalrule1_s87,
+        goto_terminalrule1_s88,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_obrace_s93,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_osbracket_s100,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_obrace_s105,
         goto_invalid,
+        goto_equals_s107,
+        goto_qequals_s108,
         goto_invalid,
+        goto_choice_s110,
+        goto_obrace_s111,
         goto_invalid,
         goto_invalid,
-        goto_colon_s109,
-        goto_comma_s110,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -2998,38 +3656,73 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_repetitionoperatorop_s121,
         goto_invalid,
         goto_invalid,
+        goto_colon_s124,
+        goto_comma_s125,
         goto_invalid,
-        goto_comma_s121,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_comma_s130,
         goto_invalid,
-        goto_augl_s126,
-        goto_ocomment_s127,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_layoutitem1_s131,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_osbracket_s138,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_cornc1_s139,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_colon_s147,
         goto_invalid,
-    ],
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s158,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_augl_s163,
+        goto_ocomment_s164,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_layoutitem1_s168,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_cornc1_s176,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+    ],
     token_kinds: [
         [
             Some((TK::Terminals, true)),
             Some((TK::Import, true)),
+            Some((TK::PrecedenceKw, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3042,7 +3735,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::Name, false)),
@@ -3062,7 +3754,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::StrConst, false)),
+            Some((TK::OBrace, true)),
             None,
             None,
             None,
@@ -3079,7 +3771,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -3113,8 +3805,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3128,9 +3818,12 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
+            None,
         ],
         [
             Some((TK::Import, true)),
+            Some((TK::PrecedenceKw, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3144,10 +3837,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::Import, true)),
+            Some((TK::PrecedenceKw, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3161,11 +3854,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3179,6 +3869,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
+            None,
         ],
         [
             Some((TK::Name, false)),
@@ -3199,6 +3891,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3212,7 +3905,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::Name, false)),
@@ -3233,6 +3925,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::STOP, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Mode, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3245,16 +3939,14 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
-            None,
         ],
         [
-            Some((TK::Import, true)),
-            Some((TK::As, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
-            None,
-            None,
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -3266,6 +3958,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
+            Some((TK::Import, true)),
+            Some((TK::As, true)),
+            Some((TK::PrecedenceKw, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3278,9 +3973,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
-            None,
-            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -3317,9 +4009,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Import, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3334,8 +4026,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Colon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
             None,
             None,
             None,
@@ -3351,7 +4043,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
+            Some((TK::Import, true)),
+            Some((TK::PrecedenceKw, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3365,10 +4058,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
-            Some((TK::Colon, true)),
+            Some((TK::Name, false)),
             None,
             None,
             None,
@@ -3385,8 +4077,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
             None,
             None,
             None,
@@ -3402,9 +4094,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::STOP, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3419,8 +4111,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
             None,
             None,
             None,
@@ -3436,11 +4128,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
+            Some((TK::STOP, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Mode, false)),
             Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
+            Some((TK::Annotation, false)),
             None,
             None,
             None,
@@ -3453,27 +4145,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::NOPSE, true)),
-            Some((TK::Left, true)),
-            Some((TK::NOPS, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
-            None,
-            None,
-            None,
+            Some((TK::Colon, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::RegexTerm, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -3487,9 +4162,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Import, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3504,9 +4179,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3521,7 +4196,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
             Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
@@ -3536,46 +4210,13 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::QEquals, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
-            Some((TK::Equals, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-        ],
-        [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
             Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::Choice, true)),
-            None,
-            None,
             None,
             None,
             None,
@@ -3589,13 +4230,13 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -3606,11 +4247,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::CBrace, true)),
             Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
@@ -3623,11 +4264,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
             Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
@@ -3638,18 +4274,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
             None,
             None,
             None,
@@ -3657,11 +4281,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
             Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
@@ -3672,35 +4291,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
             None,
             None,
             None,
@@ -3708,25 +4298,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
             Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -3742,8 +4315,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
             None,
             None,
             None,
@@ -3759,8 +4332,688 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::RegexTerm, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Import, true)),
+            Some((TK::PrecedenceKw, false)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::NOPSE, true)),
+            Some((TK::Left, true)),
+            Some((TK::NOPS, true)),
+            Some((TK::Prec, false)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Mode, false)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NoFinish, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Prefer, true)),
+            Some((TK::Finish, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::Push, false)),
+            Some((TK::Pop, false)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::QEquals, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Equals, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -3947,6 +5200,23 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CBrace, true)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3963,16 +5233,220 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::NoFinish, true)),
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Prefer, true)),
-            Some((TK::Finish, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::Left, true)),
             Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3980,8 +5454,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -3997,8 +5471,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -4014,8 +5488,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::STOP, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Mode, false)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
             None,
             None,
             None,
@@ -4026,6 +5503,20 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::NoFinish, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Prefer, true)),
+            Some((TK::Finish, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::Push, false)),
+            Some((TK::Pop, false)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -4123,6 +5614,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::NOPSE, true)),
             Some((TK::Left, true)),
             Some((TK::NOPS, true)),
+            Some((TK::Prec, false)),
             Some((TK::Name, false)),
             Some((TK::IntConst, false)),
             None,
@@ -4130,7 +5622,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::SemiColon, true)),
@@ -4319,142 +5810,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
         ],
-        [
-            Some((TK::IntConst, false)),
-            Some((TK::FloatConst, false)),
-            Some((TK::BoolConst, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::NOPSE, true)),
-            Some((TK::Left, true)),
-            Some((TK::NOPS, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Colon, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
         [
             Some((TK::Comma, true)),
             Some((TK::CBrace, true)),
@@ -4473,10 +5828,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
+            Some((TK::IntConst, false)),
+            Some((TK::FloatConst, false)),
+            Some((TK::BoolConst, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4490,16 +5845,16 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::NOPSE, true)),
+            Some((TK::Left, true)),
+            Some((TK::NOPS, true)),
+            Some((TK::Prec, false)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -4524,25 +5879,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::STOP, false)),
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -4558,8 +5896,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::STOP, true)),
+            Some((TK::Mode, false)),
             None,
             None,
             None,
@@ -4592,12 +5930,24 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
+            Some((TK::NoFinish, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Prefer, true)),
+            Some((TK::Finish, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::Push, false)),
+            Some((TK::Pop, false)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::IntConst, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
             None,
             None,
             None,
@@ -4607,18 +5957,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::NoFinish, true)),
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Prefer, true)),
-            Some((TK::Finish, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::Left, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -4626,19 +5964,19 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         ],
@@ -4898,25 +6236,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::NoFinish, true)),
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Prefer, true)),
-            Some((TK::Finish, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::Left, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            None,
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -4932,11 +6253,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
+            Some((TK::STOP, true)),
             Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
+            Some((TK::Mode, false)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
             None,
             None,
             None,
@@ -4950,8 +6271,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::SemiColon, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
+            None,
+            None,
             None,
             None,
             None,
@@ -4966,9 +6287,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CSBracket, true)),
-            None,
+            Some((TK::SemiColon, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4983,6 +6304,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
+            Some((TK::Colon, true)),
             Some((TK::Comma, true)),
             Some((TK::CSBracket, true)),
             None,
@@ -4997,7 +6319,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -5017,8 +6338,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::Choice, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CSBracket, true)),
             None,
             None,
             None,
@@ -5034,8 +6355,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -5052,6 +6373,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::STOP, true)),
+            Some((TK::CBrace, true)),
+            Some((TK::Mode, false)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -5064,11 +6387,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
-            None,
         ],
         [
-            Some((TK::SemiColon, true)),
+            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -5136,9 +6457,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::Comma, true)),
+            Some((TK::CSBracket, true)),
+            None,
             None,
             None,
             None,
@@ -5511,6 +6832,27 @@ for RustemoParserDefinition {
     fn grammar_order() -> bool {
         true
     }
+    fn delimiter(&self, state: State) -> Option<rustemo::Delimiter<TokenKind>> {
+        match state {
+            State::OBraceS13 => Some(rustemo::Delimiter::Open(TokenKind::CBrace)),
+            State::CBraceS42 => Some(rustemo::Delimiter::Close(TokenKind::CBrace)),
+            State::OBraceS51 => Some(rustemo::Delimiter::Open(TokenKind::CBrace)),
+            State::OBraceS53 => Some(rustemo::Delimiter::Open(TokenKind::CBrace)),
+            State::OBraceS55 => Some(rustemo::Delimiter::Open(TokenKind::CBrace)),
+            State::OBracketS62 => Some(rustemo::Delimiter::Open(TokenKind::CBracket)),
+            State::OBraceS105 => Some(rustemo::Delimiter::Open(TokenKind::CBrace)),
+            State::OBraceS111 => Some(rustemo::Delimiter::Open(TokenKind::CBrace)),
+            State::CBraceS126 => Some(rustemo::Delimiter::Close(TokenKind::CBrace)),
+            State::CBraceS128 => Some(rustemo::Delimiter::Close(TokenKind::CBrace)),
+            State::CBraceS131 => Some(rustemo::Delimiter::Close(TokenKind::CBrace)),
+            State::CBracketS133 => Some(rustemo::Delimiter::Close(TokenKind::CBracket)),
+            State::OSBracketS138 => Some(rustemo::Delimiter::Open(TokenKind::CSBracket)),
+            State::CBraceS150 => Some(rustemo::Delimiter::Close(TokenKind::CBrace)),
+            State::CBraceS151 => Some(rustemo::Delimiter::Close(TokenKind::CBrace)),
+            State::CSBracketS159 => Some(rustemo::Delimiter::Close(TokenKind::CSBracket)),
+            _ => None,
+        }
+    }
 }
 pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
 pub struct RustemoParser<
@@ -5598,38 +6940,38 @@ impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
         match &self {
             #[allow(unused_variables)]
             TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                logn!("{} {:?} -- ", "    Recognizing".paint(LOG), token_kind);
                 if input.starts_with(s) {
-                    log!("{}", "recognized".bold().green());
+                    log!("{}", "recognized".paint(LOG_BOLD));
                     Some(s)
                 } else {
-                    log!("{}", "not recognized".red());
+                    log!("{}", "not recognized".paint(WARN));
                     None
                 }
             }
             #[allow(unused_variables)]
             TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                logn!("{} {:?} -- ", "    Recognizing".paint(LOG), token_kind);
                 let match_str = r.find(input);
                 match match_str {
-                    Some(x) => {
+                    Ok(Some(x)) => {
                         let x_str = x.as_str();
-                        log!("{} '{}'", "recognized".bold().green(), x_str);
+                        log!("{} '{}'", "recognized".paint(LOG_BOLD), x_str);
                         Some(x_str)
                     }
                     _ => {
-                        log!("{}", "not recognized".red());
+                        log!("{}", "not recognized".paint(WARN));
                         None
                     }
                 }
             }
             TokenRecognizer(_, Recognizer::Stop) => {
-                logn!("{} STOP -- ", "    Recognizing".green());
+                logn!("{} STOP -- ", "    Recognizing".paint(LOG));
                 if input.is_empty() {
-                    log!("{}", "recognized".bold().green());
+                    log!("{}", "recognized".paint(LOG_BOLD));
                     Some("")
                 } else {
-                    log!("{}", "not recognized".red());
+                    log!("{}", "not recognized".paint(WARN));
                     None
                 }
             }
@@ -5639,6 +6981,36 @@ impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
 pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
     TokenRecognizer(TokenKind::Terminals, Recognizer::StrMatch("terminals")),
+    TokenRecognizer(
+        TokenKind::PrecedenceKw,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "precedence\\b(?!\\s*:)")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Prec,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "prec\\b(?!\\s*[:,}])")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Mode,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "mode\\b(?!\\s*:)")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Push,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "push\\b(?!\\s*[:,}])")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Pop,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "pop\\b(?!\\s*:)")).unwrap() }),
+        ),
+    ),
     TokenRecognizer(TokenKind::Import, Recognizer::StrMatch("import")),
     TokenRecognizer(TokenKind::As, Recognizer::StrMatch("as")),
     TokenRecognizer(TokenKind::Colon, Recognizer::StrMatch(":")),
@@ -5776,6 +7148,13 @@ for DefaultBuilder {
         let val = match token.kind {
             TokenKind::STOP => panic!("Cannot shift STOP token!"),
             TokenKind::Terminals => Terminal::Terminals,
+            TokenKind::PrecedenceKw => {
+                Terminal::PrecedenceKw(rustemo_actions::precedence_kw(context, token))
+            }
+            TokenKind::Prec => Terminal::Prec(rustemo_actions::prec(context, token)),
+            TokenKind::Mode => Terminal::Mode(rustemo_actions::mode(context, token)),
+            TokenKind::Push => Terminal::Push(rustemo_actions::push(context, token)),
+            TokenKind::Pop => Terminal::Pop(rustemo_actions::pop(context, token)),
             TokenKind::Import => Terminal::Import,
             TokenKind::As => Terminal::As,
             TokenKind::Colon => Terminal::Colon,
@@ -5837,211 +7216,463 @@ for DefaultBuilder {
     ) {
         let prod = match prod {
             ProdKind::FileP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::PrecedenceOpt(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
+                    ) => NonTerminal::File(rustemo_actions::file_c1(context, p0, p1)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PrecedenceOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Precedence(p0)) => {
+                        NonTerminal::PrecedenceOpt(
+                            rustemo_actions::precedence_opt_precedence(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PrecedenceOptP2 => {
+                NonTerminal::PrecedenceOpt(
+                    rustemo_actions::precedence_opt_empty(context),
+                )
+            }
+            ProdKind::GrammarRule1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRule(p1)),
+                    ) => {
+                        NonTerminal::GrammarRule1(
+                            rustemo_actions::grammar_rule1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GrammarRule1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::GrammarRule(p0)) => {
+                        NonTerminal::GrammarRule1(
+                            rustemo_actions::grammar_rule1_grammar_rule(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
+                        Symbol::NonTerminal(NonTerminal::PrecedenceOpt(p1)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p2)),
+                    ) => NonTerminal::File(rustemo_actions::file_c2(context, p0, p1, p2)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ImportStm1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
+                        Symbol::NonTerminal(NonTerminal::ImportStm(p1)),
+                    ) => {
+                        NonTerminal::ImportStm1(
+                            rustemo_actions::import_stm1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ImportStm1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ImportStm(p0)) => {
+                        NonTerminal::ImportStm1(
+                            rustemo_actions::import_stm1_import_stm(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::PrecedenceOpt(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p2)),
+                        Symbol::NonTerminal(NonTerminal::LexicalMode0(p3)),
+                    ) => {
+                        NonTerminal::File(
+                            rustemo_actions::file_c3(context, p0, p1, p2, p3),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TerminalRule1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p0)),
+                        Symbol::NonTerminal(NonTerminal::TerminalRule(p1)),
+                    ) => {
+                        NonTerminal::TerminalRule1(
+                            rustemo_actions::terminal_rule1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TerminalRule1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TerminalRule(p0)) => {
+                        NonTerminal::TerminalRule1(
+                            rustemo_actions::terminal_rule1_terminal_rule(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::LexicalMode1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::LexicalMode1(p0)),
+                        Symbol::NonTerminal(NonTerminal::LexicalMode(p1)),
+                    ) => {
+                        NonTerminal::LexicalMode1(
+                            rustemo_actions::lexical_mode1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::LexicalMode1P2 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)) => {
-                        NonTerminal::File(
-                            rustemo_actions::file_grammar_rule1(context, p0),
+                    Symbol::NonTerminal(NonTerminal::LexicalMode(p0)) => {
+                        NonTerminal::LexicalMode1(
+                            rustemo_actions::lexical_mode1_lexical_mode(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GrammarRule1P1 => {
+            ProdKind::LexicalMode0P1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)),
-                        Symbol::NonTerminal(NonTerminal::GrammarRule(p1)),
-                    ) => {
-                        NonTerminal::GrammarRule1(
-                            rustemo_actions::grammar_rule1_c1(context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::LexicalMode1(p0)) => {
+                        NonTerminal::LexicalMode0(
+                            rustemo_actions::lexical_mode0_lexical_mode1(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GrammarRule1P2 => {
+            ProdKind::LexicalMode0P2 => {
+                NonTerminal::LexicalMode0(rustemo_actions::lexical_mode0_empty(context))
+            }
+            ProdKind::FileP4 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 6usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::GrammarRule(p0)) => {
-                        NonTerminal::GrammarRule1(
-                            rustemo_actions::grammar_rule1_grammar_rule(context, p0),
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
+                        Symbol::NonTerminal(NonTerminal::PrecedenceOpt(p1)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p2)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p3)),
+                        Symbol::NonTerminal(NonTerminal::LexicalMode0(p4)),
+                    ) => {
+                        NonTerminal::File(
+                            rustemo_actions::file_c4(context, p0, p1, p2, p3, p4),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP2 => {
+            ProdKind::FileP5 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
-                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
-                    ) => NonTerminal::File(rustemo_actions::file_c2(context, p0, p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p0)),
+                        Symbol::NonTerminal(NonTerminal::LexicalMode0(p1)),
+                    ) => NonTerminal::File(rustemo_actions::file_c5(context, p0, p1)),
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStm1P1 => {
+            ProdKind::ImportStmP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
-                        Symbol::NonTerminal(NonTerminal::ImportStm(p1)),
-                    ) => {
-                        NonTerminal::ImportStm1(
-                            rustemo_actions::import_stm1_c1(context, p0, p1),
+                    (_, Symbol::Terminal(Terminal::StrConst(p0))) => {
+                        NonTerminal::ImportStm(
+                            rustemo_actions::import_stm_c1(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStm1P2 => {
+            ProdKind::ImportStmP2 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 4usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::ImportStm(p0)) => {
-                        NonTerminal::ImportStm1(
-                            rustemo_actions::import_stm1_import_stm(context, p0),
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(p0)),
+                        _,
+                        Symbol::Terminal(Terminal::Name(p1)),
+                    ) => {
+                        NonTerminal::ImportStm(
+                            rustemo_actions::import_stm_c2(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP3 => {
+            ProdKind::PrecedenceP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 4usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)),
+                        Symbol::Terminal(Terminal::PrecedenceKw(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::PrecedenceLevel1(p1)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p1)),
-                    ) => NonTerminal::File(rustemo_actions::file_c3(context, p0, p1)),
+                    ) => {
+                        NonTerminal::Precedence(
+                            rustemo_actions::precedence_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::TerminalRule1P1 => {
+            ProdKind::PrecedenceLevel1P1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p0)),
-                        Symbol::NonTerminal(NonTerminal::TerminalRule(p1)),
+                        Symbol::NonTerminal(NonTerminal::PrecedenceLevel1(p0)),
+                        Symbol::NonTerminal(NonTerminal::PrecedenceLevel(p1)),
                     ) => {
-                        NonTerminal::TerminalRule1(
-                            rustemo_actions::terminal_rule1_c1(context, p0, p1),
+                        NonTerminal::PrecedenceLevel1(
+                            rustemo_actions::precedence_level1_c1(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::TerminalRule1P2 => {
+            ProdKind::PrecedenceLevel1P2 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::TerminalRule(p0)) => {
-                        NonTerminal::TerminalRule1(
-                            rustemo_actions::terminal_rule1_terminal_rule(context, p0),
+                    Symbol::NonTerminal(NonTerminal::PrecedenceLevel(p0)) => {
+                        NonTerminal::PrecedenceLevel1(
+                            rustemo_actions::precedence_level1_precedence_level(
+                                context,
+                                p0,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP4 => {
+            ProdKind::PrecedenceLevelP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
-                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
+                        Symbol::NonTerminal(NonTerminal::PrecedenceAssocOpt(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarSymbol1(p1)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p2)),
-                    ) => NonTerminal::File(rustemo_actions::file_c4(context, p0, p1, p2)),
+                    ) => {
+                        NonTerminal::PrecedenceLevel(
+                            rustemo_actions::precedence_level_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP5 => {
+            ProdKind::PrecedenceAssocOptP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::TerminalRule1(p0))) => {
-                        NonTerminal::File(
-                            rustemo_actions::file_terminal_rule1(context, p0),
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::PrecedenceAssoc(p0)) => {
+                        NonTerminal::PrecedenceAssocOpt(
+                            rustemo_actions::precedence_assoc_opt_precedence_assoc(
+                                context,
+                                p0,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStmP1 => {
+            ProdKind::PrecedenceAssocOptP2 => {
+                NonTerminal::PrecedenceAssocOpt(
+                    rustemo_actions::precedence_assoc_opt_empty(context),
+                )
+            }
+            ProdKind::GrammarSymbol1P1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::Terminal(Terminal::StrConst(p0))) => {
-                        NonTerminal::ImportStm(
-                            rustemo_actions::import_stm_c1(context, p0),
+                    (
+                        Symbol::NonTerminal(NonTerminal::GrammarSymbol1(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarSymbol(p1)),
+                    ) => {
+                        NonTerminal::GrammarSymbol1(
+                            rustemo_actions::grammar_symbol1_c1(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStmP2 => {
+            ProdKind::GrammarSymbol1P2 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (
-                        _,
-                        Symbol::Terminal(Terminal::StrConst(p0)),
-                        _,
-                        Symbol::Terminal(Terminal::Name(p1)),
-                    ) => {
-                        NonTerminal::ImportStm(
-                            rustemo_actions::import_stm_c2(context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::GrammarSymbol(p0)) => {
+                        NonTerminal::GrammarSymbol1(
+                            rustemo_actions::grammar_symbol1_grammar_symbol(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::PrecedenceAssocLeft => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::PrecedenceAssoc(
+                    rustemo_actions::precedence_assoc_left(context),
+                )
+            }
+            ProdKind::PrecedenceAssocReduce => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::PrecedenceAssoc(
+                    rustemo_actions::precedence_assoc_reduce(context),
+                )
+            }
+            ProdKind::PrecedenceAssocRight => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::PrecedenceAssoc(
+                    rustemo_actions::precedence_assoc_right(context),
+                )
+            }
+            ProdKind::PrecedenceAssocShift => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::PrecedenceAssoc(
+                    rustemo_actions::precedence_assoc_shift(context),
+                )
+            }
             ProdKind::GrammarRuleP1 => {
                 let mut i = self
                     .res_stack
@@ -6220,6 +7851,32 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::LexicalModeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Mode(p0)),
+                        Symbol::Terminal(Terminal::Name(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p2)),
+                        _,
+                    ) => {
+                        NonTerminal::LexicalMode(
+                            rustemo_actions::lexical_mode_c1(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::TerminalRuleP1 => {
                 let mut i = self
                     .res_stack
@@ -6399,7 +8056,24 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ProdMetaDataP9 => {
+            ProdKind::ProdMetaDataPrec => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Prec(p0)),
+                        Symbol::Terminal(Terminal::Name(p1)),
+                    ) => {
+                        NonTerminal::ProdMetaData(
+                            rustemo_actions::prod_meta_data_prec(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ProdMetaDataP10 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
@@ -6413,7 +8087,7 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ProdMetaDataP10 => {
+            ProdKind::ProdMetaDataP11 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
@@ -6539,7 +8213,38 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::TermMetaDataP10 => {
+            ProdKind::TermMetaDataPush => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Push(p0)),
+                        Symbol::Terminal(Terminal::Name(p1)),
+                    ) => {
+                        NonTerminal::TermMetaData(
+                            rustemo_actions::term_meta_data_push(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TermMetaDataPop => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::Pop(p0)) => {
+                        NonTerminal::TermMetaData(
+                            rustemo_actions::term_meta_data_pop(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TermMetaDataP12 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
@@ -6980,6 +8685,24 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::RepetitionModifierP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Name(p0)),
+                        _,
+                        Symbol::Terminal(Terminal::IntConst(p1)),
+                    ) => {
+                        NonTerminal::RepetitionModifier(
+                            rustemo_actions::repetition_modifier_c2(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::GrammarSymbolP1 => {
                 let mut i = self
                     .res_stack
@@ -45,51 +45,88 @@ pub type Annotation = ValSpan<String>;
 pub fn annotation(ctx: &Ctx, token: Token) -> Annotation {
     Annotation::new(token.value[1..].into(), Some(ctx.span()))
 }
+/// Keywords matched by regexes carry no value.
+pub type PrecedenceKw = ();
+pub fn precedence_kw(_ctx: &Ctx, _token: Token) -> PrecedenceKw {}
+pub type Prec = ();
+pub fn prec(_ctx: &Ctx, _token: Token) -> Prec {}
+pub type Mode = ();
+pub fn mode(_ctx: &Ctx, _token: Token) -> Mode {}
+pub type Push = ();
+pub fn push(_ctx: &Ctx, _token: Token) -> Push {}
+pub type Pop = ();
+pub fn pop(_ctx: &Ctx, _token: Token) -> Pop {}
 #[derive(Debug, Clone, Default)]
 pub struct File {
     pub imports: Option<Imports>,
+    pub precedence: Option<Precedence>,
     pub grammar_rules: Option<GrammarRules>,
     pub terminal_rules: Option<TerminalRules>,
+    pub lexical_modes: Option<LexicalModes>,
 }
-pub fn file_grammar_rule1(_ctx: &Ctx, grammar_rule1: GrammarRule1) -> File {
+pub fn file_c1(
+    _ctx: &Ctx,
+    precedence: PrecedenceOpt,
+    grammar_rules: GrammarRules,
+) -> File {
     File {
-        grammar_rules: Some(grammar_rule1),
+        precedence,
+        grammar_rules: Some(grammar_rules),
         ..Default::default()
     }
 }
-pub fn file_c2(_ctx: &Ctx, imports: Imports, grammar_rules: GrammarRules) -> File {
+pub fn file_c2(
+    _ctx: &Ctx,
+    imports: Imports,
+    precedence: PrecedenceOpt,
+    grammar_rules: GrammarRules,
+) -> File {
     File {
         imports: Some(imports),
+        precedence,
         grammar_rules: Some(grammar_rules),
-        terminal_rules: None,
+        ..Default::default()
     }
 }
 pub fn file_c3(
     _ctx: &Ctx,
+    precedence: PrecedenceOpt,
     grammar_rules: GrammarRules,
     terminal_rules: TerminalRules,
+    lexical_modes: LexicalModesOpt,
 ) -> File {
     File {
+        precedence,
         grammar_rules: Some(grammar_rules),
         terminal_rules: Some(terminal_rules),
+        lexical_modes,
         imports: None,
     }
 }
 pub fn file_c4(
     _ctx: &Ctx,
     imports: Imports,
+    precedence: PrecedenceOpt,
     grammar_rules: GrammarRules,
     terminal_rules: TerminalRules,
+    lexical_modes: LexicalModesOpt,
 ) -> File {
     File {
         imports: Some(imports),
+        precedence,
         grammar_rules: Some(grammar_rules),
         terminal_rules: Some(terminal_rules),
+        lexical_modes,
     }
 }
-pub fn file_terminal_rule1(_ctx: &Ctx, terminal_rule1: TerminalRule1) -> File {
+pub fn file_c5(
+    _ctx: &Ctx,
+    terminal_rules: TerminalRules,
+    lexical_modes: LexicalModesOpt,
+) -> File {
     File {
-        terminal_rules: Some(terminal_rule1),
+        terminal_rules: Some(terminal_rules),
+        lexical_modes,
         ..Default::default()
     }
 }
@@ -120,6 +157,93 @@ pub fn import_stm_c2(_ctx: &Ctx, path: StrConst, name: Name) -> ImportStm {
         name: Some(name),
     }
 }
+pub type PrecedenceOpt = Option<Precedence>;
+pub fn precedence_opt_precedence(_ctx: &Ctx, precedence: Precedence) -> PrecedenceOpt {
+    Some(precedence)
+}
+pub fn precedence_opt_empty(_ctx: &Ctx) -> PrecedenceOpt {
+    None
+}
+/// Precedence levels from the lowest to the highest.
+pub type Precedence = PrecedenceLevel1;
+pub fn precedence_c1(
+    _ctx: &Ctx,
+    _precedence_kw: PrecedenceKw,
+    precedence_level1: PrecedenceLevel1,
+) -> Precedence {
+    precedence_level1
+}
+pub type PrecedenceLevel1 = Vec<PrecedenceLevel>;
+pub fn precedence_level1_c1(
+    _ctx: &Ctx,
+    mut precedence_level1: PrecedenceLevel1,
+    precedence_level: PrecedenceLevel,
+) -> PrecedenceLevel1 {
+    precedence_level1.push(precedence_level);
+    precedence_level1
+}
+pub fn precedence_level1_precedence_level(
+    _ctx: &Ctx,
+    precedence_level: PrecedenceLevel,
+) -> PrecedenceLevel1 {
+    vec![precedence_level]
+}
+#[derive(Debug, Clone)]
+pub struct PrecedenceLevel {
+    pub assoc: PrecedenceAssocOpt,
+    pub symbols: GrammarSymbol1,
+}
+pub fn precedence_level_c1(
+    _ctx: &Ctx,
+    assoc: PrecedenceAssocOpt,
+    symbols: GrammarSymbol1,
+) -> PrecedenceLevel {
+    PrecedenceLevel { assoc, symbols }
+}
+pub type PrecedenceAssocOpt = Option<PrecedenceAssoc>;
+pub fn precedence_assoc_opt_precedence_assoc(
+    _ctx: &Ctx,
+    precedence_assoc: PrecedenceAssoc,
+) -> PrecedenceAssocOpt {
+    Some(precedence_assoc)
+}
+pub fn precedence_assoc_opt_empty(_ctx: &Ctx) -> PrecedenceAssocOpt {
+    None
+}
+pub type GrammarSymbol1 = Vec<GrammarSymbol>;
+pub fn grammar_symbol1_c1(
+    _ctx: &Ctx,
+    mut grammar_symbol1: GrammarSymbol1,
+    grammar_symbol: GrammarSymbol,
+) -> GrammarSymbol1 {
+    grammar_symbol1.push(grammar_symbol);
+    grammar_symbol1
+}
+pub fn grammar_symbol1_grammar_symbol(
+    _ctx: &Ctx,
+    grammar_symbol: GrammarSymbol,
+) -> GrammarSymbol1 {
+    vec![grammar_symbol]
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrecedenceAssoc {
+    Left,
+    Reduce,
+    Right,
+    Shift,
+}
+pub fn precedence_assoc_left(_ctx: &Ctx) -> PrecedenceAssoc {
+    PrecedenceAssoc::Left
+}
+pub fn precedence_assoc_reduce(_ctx: &Ctx) -> PrecedenceAssoc {
+    PrecedenceAssoc::Reduce
+}
+pub fn precedence_assoc_right(_ctx: &Ctx) -> PrecedenceAssoc {
+    PrecedenceAssoc::Right
+}
+pub fn precedence_assoc_shift(_ctx: &Ctx) -> PrecedenceAssoc {
+    PrecedenceAssoc::Shift
+}
 pub type GrammarRule1 = Vec<GrammarRule>;
 pub type GrammarRules = GrammarRule1;
 pub fn grammar_rule1_c1(
@@ -226,6 +350,43 @@ pub fn terminal_rule1_terminal_rule(
 ) -> TerminalRule1 {
     vec![terminal_rule]
 }
+pub type LexicalMode1 = Vec<LexicalMode>;
+pub type LexicalModes = LexicalMode1;
+pub fn lexical_mode1_c1(
+    _ctx: &Ctx,
+    mut lexical_mode1: LexicalMode1,
+    lexical_mode: LexicalMode,
+) -> LexicalMode1 {
+    lexical_mode1.push(lexical_mode);
+    lexical_mode1
+}
+pub fn lexical_mode1_lexical_mode(_ctx: &Ctx, lexical_mode: LexicalMode) -> LexicalMode1 {
+    vec![lexical_mode]
+}
+pub type LexicalMode0 = Option<LexicalMode1>;
+pub type LexicalModesOpt = LexicalMode0;
+pub fn lexical_mode0_lexical_mode1(_ctx: &Ctx, lexical_mode1: LexicalMode1) -> LexicalMode0 {
+    Some(lexical_mode1)
+}
+pub fn lexical_mode0_empty(_ctx: &Ctx) -> LexicalMode0 {
+    None
+}
+#[derive(Debug, Clone)]
+pub struct LexicalMode {
+    pub name: Name,
+    pub terminal_rules: TerminalRules,
+}
+pub fn lexical_mode_c1(
+    _ctx: &Ctx,
+    _mode: Mode,
+    name: Name,
+    terminal_rules: TerminalRules,
+) -> LexicalMode {
+    LexicalMode {
+        name,
+        terminal_rules,
+    }
+}
 #[derive(Debug, Clone)]
 pub struct TerminalRule {
     pub name: Name,
@@ -310,6 +471,9 @@ pub fn prod_meta_data_nopse(_ctx: &Ctx) -> ProdMetaData {
 pub fn prod_meta_data_priority(_ctx: &Ctx, prio: IntConst) -> ProdMetaData {
     ProdMetaData::from([("priority".into(), ConstVal::Int(prio))])
 }
+pub fn prod_meta_data_prec(_ctx: &Ctx, _prec: Prec, name: Name) -> ProdMetaData {
+    ProdMetaData::from([("prec".into(), ConstVal::String(name))])
+}
 pub fn prod_meta_data_user_meta_data(_ctx: &Ctx, user: UserMetaData) -> ProdMetaData {
     ProdMetaData::from([(user.name.into(), user.value)])
 }
@@ -356,6 +520,12 @@ pub fn term_meta_data_dynamic(_ctx: &Ctx) -> TermMetaData {
 pub fn term_meta_data_priority(_ctx: &Ctx, prio: IntConst) -> TermMetaData {
     TermMetaData::from([("priority".into(), ConstVal::Int(prio))])
 }
+pub fn term_meta_data_push(_ctx: &Ctx, _push: Push, name: Name) -> TermMetaData {
+    TermMetaData::from([("push".into(), ConstVal::String(name))])
+}
+pub fn term_meta_data_pop(_ctx: &Ctx, _pop: Pop) -> TermMetaData {
+    TermMetaData::from([("pop".into(), ConstVal::Bool(true.into()))])
+}
 pub fn term_meta_data_user_meta_data(_ctx: &Ctx, user: UserMetaData) -> TermMetaData {
     TermMetaData::from([(user.name.into(), user.value)])
 }
@@ -461,7 +631,6 @@ pub fn bool_assignment_c1(
     BoolAssignment { name, gsymref }
 }
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct ProductionGroup(pub GrammarRuleRHS);
 pub fn production_group_c1(
     _ctx: &Ctx,
@@ -473,7 +642,6 @@ pub fn production_group_c1(
 pub struct GrammarSymbolRef {
     pub gsymbol: Option<GrammarSymbol>,
     pub repetition_op: RepetitionOperatorOpt,
-    #[allow(dead_code)]
     pub production_group: Option<ProductionGroup>,
 }
 pub fn grammar_symbol_ref_c1(
@@ -582,9 +750,28 @@ pub fn repetition_modifier1_repetition_modifier(
 ) -> RepetitionModifier1 {
     vec![repetition_modifier]
 }
-pub type RepetitionModifier = Name;
+/// A repetition modifier given by a name (e.g. a separator or `trailing`)
+/// or by a name and a value (e.g. `min: 2`).
+#[derive(Debug, Clone)]
+pub struct RepetitionModifier {
+    pub name: Name,
+    pub value: Option<IntConst>,
+}
 pub fn repetition_modifier_name(_ctx: &Ctx, name: Name) -> RepetitionModifier {
-    name
+    RepetitionModifier {
+        name,
+        value: None,
+    }
+}
+pub fn repetition_modifier_c2(
+    _ctx: &Ctx,
+    name: Name,
+    value: IntConst,
+) -> RepetitionModifier {
+    RepetitionModifier {
+        name,
+        value: Some(value),
+    }
 }
 #[derive(Debug, Clone)]
 pub enum GrammarSymbol {
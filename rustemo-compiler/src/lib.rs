@@ -34,15 +34,22 @@
 #[macro_use]
 extern crate rustemo;
 
+pub mod diagnostics;
+pub mod export;
+pub mod formatter;
 pub mod grammar;
+pub mod import;
+pub mod sentences;
 pub mod utils;
 
+pub use crate::generator::ParserSource;
 pub use crate::settings::{
     process_crate_dir, process_dir, process_grammar, BuilderType, GeneratorTableType, LexerType,
     ParserAlgo, Settings,
 };
 pub use crate::table::TableType;
 
+pub use crate::diagnostics::{Code, Diagnostic, Severity};
 pub use crate::error::Error;
 pub use crate::error::Result;
 
@@ -53,5 +60,6 @@ mod error;
 mod generator;
 mod index;
 mod lang;
+mod lint;
 mod settings;
 mod table;
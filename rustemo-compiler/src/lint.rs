@@ -0,0 +1,348 @@
+//! Grammar analysis reporting symbols which are unused, unreachable,
+//! non-productive, shadowed or part of nullable cycles.
+use std::collections::{BTreeMap, BTreeSet};
+
+use rustemo::regex::Regex;
+
+use crate::{
+    diagnostics::{Code, Diagnostic},
+    generator::{DEDENT, INDENT, NEWLINE},
+    grammar::{Grammar, NonTerminal},
+    index::{NonTermIndex, SymbolIndex, TermIndex},
+    lang::rustemo_actions::Recognizer,
+    settings::Settings,
+};
+
+/// Finds warnings in the given grammar. `src` is the grammar source used to
+/// tell the rules given in the grammar from the rules created by the
+/// compiler (e.g. for repetitions).
+pub(crate) fn grammar_warnings(
+    grammar: &Grammar,
+    file: &str,
+    src: &str,
+    settings: &Settings,
+) -> Vec<Diagnostic> {
+    let lint = Lint { grammar, src };
+    let mut diagnostics = vec![];
+    lint.unused_terminals(settings, &mut diagnostics);
+    lint.unreachable_rules(&mut diagnostics);
+    lint.duplicate_matches(&mut diagnostics);
+    lint.non_productive_rules(&mut diagnostics);
+    lint.shadowed_terminals(settings, &mut diagnostics);
+    lint.nullable_cycles(&mut diagnostics);
+
+    diagnostics
+        .into_iter()
+        .map(|d| d.with_file(file).with_source(src))
+        .collect()
+}
+
+struct Lint<'g, 's> {
+    grammar: &'g Grammar,
+    src: &'s str,
+}
+
+impl Lint<'_, '_> {
+    fn unused_terminals(&self, settings: &Settings, diagnostics: &mut Vec<Diagnostic>) {
+        let (reachable_terms, _) = self.reachable_symbols();
+        // Indentation terminals are produced by the lexer and `KEYWORD` is used
+        // only for keyword matching.
+        let is_implicit_term = |name: &str| {
+            name == "KEYWORD" || settings.indentation && [INDENT, DEDENT, NEWLINE].contains(&name)
+        };
+        for term in self.grammar.terminals.iter().skip(1) {
+            if !reachable_terms.contains(&term.idx) && !is_implicit_term(&term.name) {
+                diagnostics.push(
+                    Diagnostic::new(
+                        Code::UnusedTerminal,
+                        format!("Terminal '{}' is not used in any production.", term.name),
+                    )
+                    .with_span(term.span),
+                );
+            }
+        }
+    }
+
+    fn unreachable_rules(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let (_, reachable_nonterms) = self.reachable_symbols();
+        for nt in self.grammar_rules() {
+            if !reachable_nonterms.contains(&nt.idx) {
+                diagnostics.push(
+                    Diagnostic::new(
+                        Code::UnreachableRule,
+                        format!("Rule '{}' is not reachable from the start rule.", nt.name),
+                    )
+                    .with_span(nt.span),
+                );
+            }
+        }
+    }
+
+    fn duplicate_matches(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let terminals = &self.grammar.terminals;
+        for (idx, term) in terminals.iter().enumerate() {
+            let Some(Recognizer::StrConst(mtch)) = &term.recognizer else {
+                continue;
+            };
+            // The same match may be used in different lexical modes.
+            let first = terminals.iter().take(idx).find(|t| {
+                t.mode == term.mode
+                    && matches!(&t.recognizer,
+                                Some(Recognizer::StrConst(m)) if m.as_ref() == mtch.as_ref())
+            });
+            if let Some(first) = first {
+                diagnostics.push(
+                    Diagnostic::new(
+                        Code::DuplicateMatch,
+                        format!(
+                            "Terminal '{}' uses the same string match as terminal '{}'.",
+                            term.name, first.name
+                        ),
+                    )
+                    .with_span(mtch.span.or(term.span))
+                    .with_label(first.span, format!("'{}' defined here", first.name))
+                    .with_note(format!(
+                        "Inline '{}' references resolve to only one of the terminals.",
+                        mtch.as_ref()
+                    )),
+                );
+            }
+        }
+    }
+
+    /// Rules which can't derive any string of terminals. Only the rules given
+    /// in the grammar are reported as the rules created by the compiler are
+    /// non-productive only if the rules they are created for are.
+    fn non_productive_rules(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let grammar = self.grammar;
+        let mut productive = BTreeSet::from([grammar.empty_index]);
+        let mut additions = true;
+        while additions {
+            additions = false;
+            for prod in &grammar.productions {
+                let nt = grammar.nonterm_to_symbol_index(prod.nonterminal);
+                if !productive.contains(&nt)
+                    && prod
+                        .rhs_symbols()
+                        .iter()
+                        .all(|s| grammar.is_term(*s) || productive.contains(s))
+                {
+                    productive.insert(nt);
+                    additions = true;
+                }
+            }
+        }
+        for nt in self.grammar_rules() {
+            if !productive.contains(&grammar.nonterm_to_symbol_index(nt.idx)) {
+                diagnostics.push(
+                    Diagnostic::new(
+                        Code::NonProductiveRule,
+                        format!("Rule '{}' can't derive any string of terminals.", nt.name),
+                    )
+                    .with_span(nt.span)
+                    .with_help("Add an alternative which ends the recursion."),
+                );
+            }
+        }
+    }
+
+    /// String match terminals which are fully matched by a regex terminal of the
+    /// same lexical mode which wins the lexical disambiguation. With the same
+    /// priorities, the string match wins if the most specific match strategy is
+    /// used.
+    fn shadowed_terminals(&self, settings: &Settings, diagnostics: &mut Vec<Diagnostic>) {
+        let regexes = self
+            .grammar
+            .terminals
+            .iter()
+            .filter_map(|term| match &term.recognizer {
+                Some(Recognizer::RegexTerm(regex)) => Regex::new(&format!("^(?:{})$", regex))
+                    .ok()
+                    .map(|regex| (term, regex)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for term in &self.grammar.terminals {
+            let Some(Recognizer::StrConst(mtch)) = &term.recognizer else {
+                continue;
+            };
+            let shadowing = regexes.iter().find(|(regex_term, regex)| {
+                regex_term.mode == term.mode
+                    && (regex_term.prio > term.prio
+                        || regex_term.prio == term.prio && !settings.lexical_disamb_most_specific)
+                    && regex.is_match(mtch.as_ref())
+            });
+            if let Some((regex_term, _)) = shadowing {
+                diagnostics.push(
+                    Diagnostic::new(
+                        Code::ShadowedTerminal,
+                        format!(
+                            "Terminal '{}' is shadowed by regex terminal '{}'.",
+                            term.name, regex_term.name
+                        ),
+                    )
+                    .with_span(mtch.span.or(term.span))
+                    .with_label(
+                        regex_term.span,
+                        format!("'{}' matches \"{}\"", regex_term.name, mtch.as_ref()),
+                    )
+                    .with_help(format!(
+                        "Give '{}' a priority higher than {}, e.g. '{{{}}}'.",
+                        term.name,
+                        regex_term.prio,
+                        regex_term.prio + 1
+                    )),
+                );
+            }
+        }
+    }
+
+    /// Rules which can derive themselves, i.e. `A =>+ A`. Such rules make the
+    /// grammar infinitely ambiguous. Each cycle is reported once.
+    fn nullable_cycles(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let grammar = self.grammar;
+        let nullable = self.nullable_symbols();
+        // `A -> B` if `B` is in a production of `A` and all other symbols of
+        // the production are nullable.
+        let mut derives: BTreeMap<NonTermIndex, BTreeSet<NonTermIndex>> = BTreeMap::new();
+        for prod in &grammar.productions {
+            let rhs = prod.rhs_symbols();
+            for (idx, symbol) in rhs.iter().enumerate() {
+                if grammar.is_nonterm(*symbol)
+                    && !grammar.is_empty(*symbol)
+                    && rhs
+                        .iter()
+                        .enumerate()
+                        .all(|(i, s)| i == idx || nullable.contains(s))
+                {
+                    derives
+                        .entry(prod.nonterminal)
+                        .or_default()
+                        .insert(grammar.symbol_to_nonterm_index(*symbol));
+                }
+            }
+        }
+        let reachable = |from: NonTermIndex| {
+            let mut visited = BTreeSet::new();
+            let mut stack = vec![from];
+            while let Some(nt) = stack.pop() {
+                for next in derives.get(&nt).into_iter().flatten() {
+                    if visited.insert(*next) {
+                        stack.push(*next);
+                    }
+                }
+            }
+            visited
+        };
+        let mut reported = BTreeSet::new();
+        for nt in &grammar.nonterminals {
+            if reported.contains(&nt.idx) {
+                continue;
+            }
+            let from_nt = reachable(nt.idx);
+            if !from_nt.contains(&nt.idx) {
+                continue;
+            }
+            // Rules on the same cycle.
+            let cycle = from_nt
+                .into_iter()
+                .filter(|other| reachable(*other).contains(&nt.idx))
+                .collect::<Vec<_>>();
+            reported.extend(cycle.iter().copied());
+            let cycle = cycle
+                .iter()
+                .map(|idx| &grammar.nonterminals[*idx])
+                .collect::<Vec<_>>();
+            let reported_nt = cycle
+                .iter()
+                .find(|nt| self.is_grammar_rule(nt))
+                .unwrap_or(&cycle[0]);
+            let mut diagnostic = Diagnostic::new(
+                Code::NullableCycle,
+                format!("Rule '{}' can derive itself.", reported_nt.name),
+            )
+            .with_span(reported_nt.span)
+            .with_note(format!(
+                "Rules in the cycle: {}.",
+                cycle
+                    .iter()
+                    .map(|nt| nt.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            for nt in cycle.iter().filter(|nt| nt.idx != reported_nt.idx) {
+                if self.is_grammar_rule(nt) {
+                    diagnostic =
+                        diagnostic.with_label(nt.span, format!("'{}' is in the cycle", nt.name));
+                }
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Symbols which can derive an empty string.
+    fn nullable_symbols(&self) -> BTreeSet<SymbolIndex> {
+        let grammar = self.grammar;
+        let mut nullable = BTreeSet::from([grammar.empty_index]);
+        let mut additions = true;
+        while additions {
+            additions = false;
+            for prod in &grammar.productions {
+                let nt = grammar.nonterm_to_symbol_index(prod.nonterminal);
+                if !nullable.contains(&nt)
+                    && prod.rhs_symbols().iter().all(|s| nullable.contains(s))
+                {
+                    nullable.insert(nt);
+                    additions = true;
+                }
+            }
+        }
+        nullable
+    }
+
+    /// Symbols reachable from the start rule or the `Layout` rule.
+    fn reachable_symbols(&self) -> (BTreeSet<TermIndex>, BTreeSet<NonTermIndex>) {
+        let grammar = self.grammar;
+        let mut terms = BTreeSet::new();
+        let mut nonterms = BTreeSet::new();
+        let mut stack: Vec<NonTermIndex> = [
+            Some(grammar.augmented_index),
+            grammar.augmented_layout_index,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|idx| grammar.symbol_to_nonterm_index(idx))
+        .collect();
+        while let Some(nonterm) = stack.pop() {
+            if !nonterms.insert(nonterm) {
+                continue;
+            }
+            for prod in grammar.nonterminals[nonterm].productions(grammar) {
+                for symbol in prod.rhs_symbols() {
+                    if grammar.is_nonterm(symbol) {
+                        stack.push(grammar.symbol_to_nonterm_index(symbol));
+                    } else {
+                        terms.insert(grammar.symbol_to_term_index(symbol));
+                    }
+                }
+            }
+        }
+        (terms, nonterms)
+    }
+
+    /// Rules given in the grammar, i.e. not created by the compiler.
+    fn grammar_rules(&self) -> impl Iterator<Item = &NonTerminal> {
+        self.grammar
+            .nonterminals
+            .iter()
+            .filter(|nt| self.is_grammar_rule(nt))
+    }
+
+    /// Rules created by the compiler have the span of the reference they are
+    /// created for.
+    fn is_grammar_rule(&self, nt: &NonTerminal) -> bool {
+        nt.span
+            .and_then(|span| self.src.get(span.start.pos..span.end.pos))
+            .is_some_and(|name| name == nt.name)
+    }
+}
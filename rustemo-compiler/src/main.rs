@@ -1,11 +1,20 @@
 //! Rustemo compiler. Run `rcomp --help` for more information.
 
-use std::path::PathBuf;
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use clap::{ArgAction, Parser};
+use clap::{ArgAction, Parser, Subcommand};
 use rustemo::WARN;
 use rustemo_compiler::{
-    BuilderType, GeneratorTableType, LexerType, ParserAlgo, Settings, TableType,
+    export::{ExportFormat, ExportGrammar},
+    formatter,
+    grammar::Grammar,
+    import::{self, ImportFormat},
+    sentences::{Entropy, SentenceGenerator},
+    BuilderType, Code, GeneratorTableType, LexerType, ParserAlgo, Settings, TableType,
 };
 use yansi::Paint;
 
@@ -16,7 +25,11 @@ use yansi::Paint;
 #[cfg_attr(not(feature="bootstrap"),
            clap(version = concat!(env!("CARGO_PKG_VERSION"), env!("GIT_HASH"))))]
 #[clap(author, about, long_about = None)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Regenerate output actions file even if exists
     #[clap(short, long, action)]
     force: bool,
@@ -25,6 +38,14 @@ struct Cli {
     #[clap(long, action)]
     dot: bool,
 
+    /// List every error state of the parser in a .error_states file
+    #[clap(long, action)]
+    error_states: bool,
+
+    /// Update out of sync actions to match the grammar
+    #[clap(long, action)]
+    migrate_actions: bool,
+
     /// Do not generate actions
     #[clap(short, long, action)]
     noactions: bool,
@@ -34,8 +55,8 @@ struct Cli {
     trace: bool,
 
     /// Grammar file or directory to process
-    #[clap(value_parser, value_name="GRAMMAR FILE/DIR", value_hint = clap::ValueHint::AnyPath)]
-    grammar_file_or_dir: PathBuf,
+    #[clap(value_parser, value_name="GRAMMAR FILE/DIR", value_hint = clap::ValueHint::AnyPath, required = true)]
+    grammar_file_or_dir: Option<PathBuf>,
 
     /// Output root directory for the parser. Default is the same as input grammar file.
     #[clap(short, long, value_name="OUT DIR ROOT", value_hint = clap::ValueHint::DirPath)]
@@ -73,14 +94,43 @@ struct Cli {
     #[clap(short, long, default_value = "str")]
     input_type: String,
 
+    /// The type of the user state kept in the parsing context.
+    #[clap(long)]
+    user_state_type: Option<String>,
+
     /// Generated builder type.
     #[clap(short, long, value_enum, default_value_t)]
     builder_type: BuilderType,
 
     /// Should generated default AST builder types contain location/layout information
     /// This is only used for the default builder type.
+    #[clap(long, default_missing_value = "true", require_equals = true)]
+    builder_loc_info: Option<bool>,
+
+    /// Allocate recursive values of default AST types in an arena instead of
+    /// boxing them.
+    #[clap(long)]
+    builder_arena: bool,
+
+    /// Additional derive for generated default AST types. Can be repeated.
+    #[clap(long, value_name = "DERIVE")]
+    derive: Vec<String>,
+
+    /// Derive serde Serialize/Deserialize for generated default AST types.
+    #[clap(long)]
+    serde: bool,
+
+    /// Generate Visitor, VisitorMut and Fold traits for default AST types.
+    #[clap(long)]
+    visitors: bool,
+
+    /// Generate printing of default AST types back to the source text.
     #[clap(long)]
-    builder_loc_info: bool,
+    unparse: bool,
+
+    /// Generate grammar coverage support in the parser.
+    #[clap(long)]
+    coverage: bool,
 
     /// Lexical disambiguation using most specific match strategy.
     #[clap(long, default_missing_value = "true", require_equals = true)]
@@ -106,10 +156,31 @@ struct Cli {
     #[clap(long)]
     no_skip_ws: bool,
 
+    /// Should the default lexer produce INDENT/DEDENT/NEWLINE tokens.
+    #[clap(long)]
+    indentation: bool,
+
+    /// A path to the function used by the default lexer to filter recognized
+    /// tokens based on the parsing context.
+    #[clap(long)]
+    token_filter: Option<String>,
+
     /// Print LR table
     #[clap(long)]
     print_table: bool,
 
+    /// Report grammar warnings as errors
+    #[clap(long)]
+    deny_warnings: bool,
+
+    /// Don't report grammar warnings
+    #[clap(long)]
+    no_warnings: bool,
+
+    /// Don't report grammar warnings with the given code, e.g. W0001
+    #[clap(long, value_name = "CODE")]
+    allow: Vec<Code>,
+
     /// Exclude dirs containing these parts. Used with dir processing.
     #[clap(short, long, value_parser)]
     exclude: Vec<String>,
@@ -119,13 +190,175 @@ struct Cli {
     verbosity: u8,
 }
 
+#[derive(Subcommand)]
+enum Command {
+    /// Generate random sentences from the grammar
+    Sentences {
+        /// Grammar file
+        #[clap(value_parser, value_name="GRAMMAR FILE", value_hint = clap::ValueHint::FilePath)]
+        grammar_file: PathBuf,
+
+        /// The number of sentences to generate
+        #[clap(short = 'n', long, default_value_t = 10)]
+        count: usize,
+
+        /// Seed of the random choices. Random by default.
+        #[clap(long)]
+        seed: Option<u64>,
+
+        /// Derivation depth after which the shortest derivations are chosen
+        #[clap(long, default_value_t = 20)]
+        max_depth: usize,
+
+        /// The number of tokens after which the shortest derivations are chosen
+        #[clap(long, default_value_t = 100)]
+        max_tokens: usize,
+
+        /// Separator between tokens
+        #[clap(long, default_value = " ")]
+        separator: String,
+
+        /// Choose productions at random instead of preferring the least used
+        #[clap(long)]
+        no_coverage: bool,
+
+        /// Make sentences invalid by a single token mutation
+        #[clap(long)]
+        near_miss: bool,
+    },
+    /// Check the grammar for errors and warnings without generating the parser
+    Check {
+        /// Grammar file
+        #[clap(value_parser, value_name="GRAMMAR FILE", value_hint = clap::ValueHint::FilePath)]
+        grammar_file: PathBuf,
+
+        /// Parser algorithm. LR conflicts are errors for LR parsing.
+        #[clap(short, long, value_enum, default_value_t)]
+        parser_algo: ParserAlgo,
+
+        /// Should the default lexer produce INDENT/DEDENT/NEWLINE tokens.
+        #[clap(long)]
+        indentation: bool,
+
+        /// Report grammar warnings as errors
+        #[clap(long)]
+        deny_warnings: bool,
+
+        /// Don't report grammar warnings with the given code, e.g. W0001
+        #[clap(long, value_name = "CODE")]
+        allow: Vec<Code>,
+    },
+    /// Export the grammar to other notations
+    Export {
+        /// Grammar file
+        #[clap(value_parser, value_name="GRAMMAR FILE", value_hint = clap::ValueHint::FilePath)]
+        grammar_file: PathBuf,
+
+        /// The notation to export to
+        #[clap(short, long, value_enum, default_value_t)]
+        format: ExportFormat,
+
+        /// Export the grammar after desugaring of repetitions and groups
+        #[clap(long)]
+        desugared: bool,
+
+        /// Output file. Output directory for railroad diagrams. The output is
+        /// printed if not given.
+        #[clap(short, long, value_name = "OUTPUT", value_hint = clap::ValueHint::AnyPath)]
+        output: Option<PathBuf>,
+    },
+    /// Format grammar files in place
+    Fmt {
+        /// Grammar files
+        #[clap(value_parser, value_name="GRAMMAR FILE", value_hint = clap::ValueHint::FilePath, required = true)]
+        grammar_files: Vec<PathBuf>,
+
+        /// Don't change the files but report the files which are not formatted
+        #[clap(long)]
+        check: bool,
+    },
+    /// Import a grammar from other parser generators
+    Import {
+        /// Bison/yacc or LALRPOP grammar file
+        #[clap(value_parser, value_name="GRAMMAR FILE", value_hint = clap::ValueHint::FilePath)]
+        grammar_file: PathBuf,
+
+        /// The notation of the grammar. Deduced from the file extension if not
+        /// given
+        #[clap(short, long, value_enum)]
+        format: Option<ImportFormat>,
+
+        /// Output Rustemo grammar file. The grammar is printed if not given.
+        #[clap(short, long, value_name = "OUTPUT", value_hint = clap::ValueHint::AnyPath)]
+        output: Option<PathBuf>,
+    },
+}
+
 fn main() {
     let cli = Cli::parse();
 
+    if let Some(command) = cli.command {
+        let result = match command {
+            Command::Sentences {
+                grammar_file,
+                count,
+                seed,
+                max_depth,
+                max_tokens,
+                separator,
+                no_coverage,
+                near_miss,
+            } => sentences(grammar_file, count, seed, |generator| {
+                generator
+                    .max_depth(max_depth)
+                    .max_tokens(max_tokens)
+                    .separator(separator)
+                    .coverage(!no_coverage)
+                    .near_miss(near_miss)
+            }),
+            Command::Check {
+                grammar_file,
+                parser_algo,
+                indentation,
+                deny_warnings,
+                allow,
+            } => check(
+                grammar_file,
+                Settings::new()
+                    .parser_algo(parser_algo)
+                    .indentation(indentation)
+                    .warnings_as_errors(deny_warnings)
+                    .allow_warnings(allow),
+            ),
+            Command::Export {
+                grammar_file,
+                format,
+                desugared,
+                output,
+            } => export(grammar_file, format, desugared, output),
+            Command::Fmt {
+                grammar_files,
+                check,
+            } => fmt(grammar_files, check),
+            Command::Import {
+                grammar_file,
+                format,
+                output,
+            } => import(grammar_file, format, output),
+        };
+        if let Err(e) = result {
+            println!("{e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let mut settings = Settings::new()
         .force(cli.force)
         .dot(cli.dot)
+        .error_states(cli.error_states)
         .actions(!cli.noactions)
+        .migrate_actions(cli.migrate_actions)
         .trace(cli.trace)
         .exclude(cli.exclude)
         .prefer_shifts(cli.prefer_shifts)
@@ -133,15 +366,33 @@ fn main() {
         .fancy_regex(cli.fancy_regex)
         .partial_parse(cli.partial_parse)
         .skip_ws(!cli.no_skip_ws)
+        .indentation(cli.indentation)
         .table_type(cli.table_type)
         .print_table(cli.print_table)
         .parser_algo(cli.parser_algo)
         .generator_table_type(cli.generator_table_type)
         .lexer_type(cli.lexer_type)
         .builder_type(cli.builder_type)
-        .builder_loc_info(cli.builder_loc_info)
+        .builder_arena(cli.builder_arena)
+        .derives(cli.derive)
+        .serde(cli.serde)
+        .visitors(cli.visitors)
+        .unparse(cli.unparse)
+        .coverage(cli.coverage)
+        .warnings_as_errors(cli.deny_warnings)
+        .silence_warnings(cli.no_warnings)
+        .allow_warnings(cli.allow)
         .input_type(cli.input_type);
 
+    if let Some(user_state_type) = cli.user_state_type {
+        settings = settings.user_state_type(user_state_type)
+    }
+    if let Some(token_filter) = cli.token_filter {
+        settings = settings.token_filter(token_filter)
+    }
+    if let Some(loc_info) = cli.builder_loc_info {
+        settings = settings.builder_loc_info(loc_info)
+    }
     if let Some(most_specific) = cli.lexical_disamb_most_specific {
         settings = settings.lexical_disamb_most_specific(most_specific)
     }
@@ -159,10 +410,13 @@ fn main() {
         settings = settings.out_dir_actions_root(outdir_actions_root);
     }
 
-    let result = if cli.grammar_file_or_dir.is_file() {
-        settings.process_grammar(&cli.grammar_file_or_dir)
+    let grammar_file_or_dir = cli
+        .grammar_file_or_dir
+        .expect("Grammar file or dir is required.");
+    let result = if grammar_file_or_dir.is_file() {
+        settings.process_grammar(&grammar_file_or_dir)
     } else {
-        settings.root_dir(cli.grammar_file_or_dir).process_dir()
+        settings.root_dir(grammar_file_or_dir).process_dir()
     };
 
     if let Err(e) = result {
@@ -170,3 +424,111 @@ fn main() {
         println!("{}", "Parser(s) not generated.".paint(WARN));
     }
 }
+
+fn check(grammar_file: PathBuf, settings: Settings) -> rustemo_compiler::Result<()> {
+    let warnings = settings.check_grammar(&grammar_file)?;
+    for warning in &warnings {
+        println!("{warning}");
+    }
+    if warnings.is_empty() {
+        println!("No problems found.");
+    } else {
+        println!("{}", format!("{} warning(s).", warnings.len()).paint(WARN));
+    }
+    Ok(())
+}
+
+fn export(
+    grammar_file: PathBuf,
+    format: ExportFormat,
+    desugared: bool,
+    output: Option<PathBuf>,
+) -> rustemo_compiler::Result<()> {
+    let grammar = if desugared {
+        ExportGrammar::from_grammar(&Grammar::from_file(&grammar_file)?)
+    } else {
+        ExportGrammar::from_file(&grammar_file)?
+    };
+    let name = grammar_file
+        .file_stem()
+        .map_or("Grammar".into(), |stem| stem.to_string_lossy());
+    let text = match format {
+        ExportFormat::EBNF => grammar.to_ebnf(),
+        ExportFormat::W3C_EBNF => grammar.to_w3c_ebnf(),
+        ExportFormat::ANTLR => grammar.to_antlr(&name),
+        ExportFormat::RAILROAD => {
+            let output = output.ok_or_else(|| {
+                rustemo_compiler::Error::Error(
+                    "Output directory is required for railroad diagrams.".into(),
+                )
+            })?;
+            fs::create_dir_all(&output)?;
+            for (rule, svg) in grammar.to_railroad() {
+                fs::write(output.join(format!("{rule}.svg")), svg)?;
+            }
+            return Ok(());
+        }
+    };
+    match output {
+        Some(output) => fs::write(output, text)?,
+        None => print!("{text}"),
+    }
+    Ok(())
+}
+
+fn import(
+    grammar_file: PathBuf,
+    format: Option<ImportFormat>,
+    output: Option<PathBuf>,
+) -> rustemo_compiler::Result<()> {
+    let grammar = import::import_file(&grammar_file, format)?;
+    match output {
+        Some(output) => fs::write(output, grammar)?,
+        None => print!("{grammar}"),
+    }
+    Ok(())
+}
+
+fn fmt(grammar_files: Vec<PathBuf>, check: bool) -> rustemo_compiler::Result<()> {
+    let mut unformatted = 0;
+    for grammar_file in grammar_files {
+        let formatted = formatter::format_file(&grammar_file)?;
+        if fs::read_to_string(&grammar_file)? != formatted {
+            if check {
+                println!("Not formatted: {}", grammar_file.display());
+                unformatted += 1;
+            } else {
+                fs::write(&grammar_file, formatted)?;
+                println!("Formatted: {}", grammar_file.display());
+            }
+        }
+    }
+    if unformatted > 0 {
+        return Err(rustemo_compiler::Error::Error(format!(
+            "{unformatted} grammar file(s) not formatted."
+        )));
+    }
+    Ok(())
+}
+
+fn sentences(
+    grammar_file: PathBuf,
+    count: usize,
+    seed: Option<u64>,
+    configure: impl FnOnce(SentenceGenerator) -> SentenceGenerator,
+) -> rustemo_compiler::Result<()> {
+    let grammar = Grammar::from_file(&grammar_file)?;
+    let mut generator = configure(SentenceGenerator::new(&grammar)?);
+    let seed = seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    });
+    let mut entropy = Entropy::seed(seed);
+    for _ in 0..count {
+        println!("{}", generator.generate(&mut entropy)?);
+    }
+    let (used, total) = generator.production_coverage();
+    eprintln!("Seed: {seed}, productions covered: {used}/{total}");
+    Ok(())
+}
@@ -0,0 +1,501 @@
+//! Generating random sentences of the language described by a grammar.
+//!
+//! [SentenceGenerator] derives sentences from the start rule of a [Grammar].
+//! String match terminals are emitted as they are while regex terminals are
+//! sampled from their regexes. Choices are taken from an [Entropy] source
+//! which is either a seeded pseudo-random generator or a byte slice, e.g. the
+//! input given by a fuzzer:
+//!
+//! ```rust,ignore
+//! fuzz_target!(|data: &[u8]| {
+//!     let sentence = generator.generate(&mut Entropy::bytes(data)).unwrap();
+//!     let _ = MyParser::new().parse(&sentence);
+//! });
+//! ```
+use regex_syntax::hir::Hir;
+
+use crate::{
+    error::{Error, Result},
+    grammar::Grammar,
+    index::{NonTermVec, ProdIndex, ProdVec, SymbolIndex, TermIndex, TermVec},
+    lang::rustemo_actions::Recognizer,
+};
+
+mod regex;
+
+/// The source of the choices made while generating sentences.
+pub struct Entropy<'a> {
+    kind: EntropyKind<'a>,
+}
+
+enum EntropyKind<'a> {
+    Seed(u64),
+    Bytes(&'a [u8]),
+}
+
+impl Entropy<'_> {
+    /// Pseudo-random choices from the given seed. The same seed always yields
+    /// the same choices.
+    pub fn seed(seed: u64) -> Entropy<'static> {
+        Entropy {
+            kind: EntropyKind::Seed(seed),
+        }
+    }
+
+    /// Choices read from the given bytes. When the bytes are exhausted the
+    /// first alternative is always chosen.
+    pub fn bytes(data: &[u8]) -> Entropy<'_> {
+        Entropy {
+            kind: EntropyKind::Bytes(data),
+        }
+    }
+
+    /// Returns a number in the range `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        if n <= 1 {
+            return 0;
+        }
+        let value = match &mut self.kind {
+            EntropyKind::Seed(state) => {
+                // SplitMix64
+                *state = state.wrapping_add(0x9e3779b97f4a7c15);
+                let mut z = *state;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                z ^ (z >> 31)
+            }
+            EntropyKind::Bytes(data) => {
+                let len = if n <= 256 { 1 } else { 4 }.min(data.len());
+                let (value, rest) = data.split_at(len);
+                *data = rest;
+                value.iter().fold(0, |acc, &b| (acc << 8) | b as u64)
+            }
+        };
+        (value % n as u64) as usize
+    }
+}
+
+/// Generator of random sentences from a grammar.
+///
+/// Sentences are derived from the start rule up to the maximal derivation
+/// depth and the maximal number of tokens. When a bound is reached, the
+/// productions leading to the shortest derivations are chosen. By default,
+/// the least used productions are preferred so that the generated sentences
+/// cover the grammar.
+///
+/// Tokens are joined by the separator, a single space by default, so the
+/// generated sentences are meant for parsers which skip whitespace or have a
+/// layout. Sampled regex tokens which are equal to a string match terminal are
+/// resampled, but sentences may still be lexically ambiguous for the parser.
+pub struct SentenceGenerator<'g> {
+    grammar: &'g Grammar,
+    max_depth: usize,
+    max_tokens: usize,
+    separator: String,
+    coverage: bool,
+    near_miss: bool,
+    /// Minimal derivation height of each nonterminal. `None` if a nonterminal
+    /// can't derive a finite sentence.
+    heights: NonTermVec<Option<usize>>,
+    regexes: TermVec<Option<Hir>>,
+    /// Productions reachable from the start rule.
+    productions: Vec<ProdIndex>,
+    /// How many times each production was used.
+    uses: ProdVec<usize>,
+}
+
+/// How many times a sampled regex token equal to a string match is resampled.
+const MAX_RESAMPLES: usize = 10;
+
+impl<'g> SentenceGenerator<'g> {
+    pub fn new(grammar: &'g Grammar) -> Result<Self> {
+        let regexes = TermVec(
+            grammar
+                .terminals
+                .iter()
+                .map(|terminal| match &terminal.recognizer {
+                    Some(Recognizer::RegexTerm(r)) => regex::parse(r.as_ref()).map(Some),
+                    _ => Ok(None),
+                })
+                .collect::<Result<_>>()?,
+        );
+        let generator = Self {
+            grammar,
+            max_depth: 20,
+            max_tokens: 100,
+            separator: " ".into(),
+            coverage: true,
+            near_miss: false,
+            heights: Self::heights(grammar),
+            regexes,
+            productions: Self::reachable_productions(grammar),
+            uses: ProdVec(vec![0; grammar.productions.len()]),
+        };
+        let start = grammar.symbol_to_nonterm(grammar.start_index);
+        if generator.heights[start.idx].is_none() {
+            return Err(Error::Error(format!(
+                "Rule '{}' can't derive a finite sentence.",
+                start.name
+            )));
+        }
+        Ok(generator)
+    }
+
+    /// Sets the maximal derivation depth after which the shortest derivations
+    /// are chosen.
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Sets the number of tokens after which the shortest derivations are
+    /// chosen.
+    pub fn max_tokens(self, max_tokens: usize) -> Self {
+        Self { max_tokens, ..self }
+    }
+
+    /// Sets the separator used between tokens.
+    pub fn separator(self, separator: impl Into<String>) -> Self {
+        Self {
+            separator: separator.into(),
+            ..self
+        }
+    }
+
+    /// Should the least used productions be preferred.
+    pub fn coverage(self, coverage: bool) -> Self {
+        Self { coverage, ..self }
+    }
+
+    /// Should the generated sentences be made invalid by a single token
+    /// mutation. Mutations are not checked against the grammar so a mutated
+    /// sentence may still be valid.
+    pub fn near_miss(self, near_miss: bool) -> Self {
+        Self { near_miss, ..self }
+    }
+
+    /// Generates a sentence.
+    pub fn generate(&mut self, entropy: &mut Entropy) -> Result<String> {
+        let mut tokens = vec![];
+        self.derive(self.grammar.start_index, 0, entropy, &mut tokens)?;
+        if self.near_miss {
+            self.mutate(&mut tokens, entropy);
+        }
+        Ok(tokens.join(&self.separator))
+    }
+
+    /// The number of used productions and the number of all productions.
+    pub fn production_coverage(&self) -> (usize, usize) {
+        let used = self
+            .productions
+            .iter()
+            .filter(|&&p| self.uses[p] > 0)
+            .count();
+        (used, self.productions.len())
+    }
+
+    /// Productions reachable from the start rule which haven't been used in
+    /// the generated sentences.
+    pub fn unused_productions(&self) -> Vec<ProdIndex> {
+        self.productions
+            .iter()
+            .copied()
+            .filter(|&p| self.uses[p] == 0)
+            .collect()
+    }
+
+    fn reachable_productions(grammar: &Grammar) -> Vec<ProdIndex> {
+        let mut reachable = grammar.new_nontermvec(false);
+        let mut stack = vec![grammar.symbol_to_nonterm_index(grammar.start_index)];
+        while let Some(nonterminal) = stack.pop() {
+            if std::mem::replace(&mut reachable[nonterminal], true) {
+                continue;
+            }
+            for &production in &grammar.nonterminals[nonterminal].productions {
+                stack.extend(
+                    grammar
+                        .production_rhs_symbols(production)
+                        .into_iter()
+                        .filter(|&symbol| grammar.is_nonterm(symbol))
+                        .map(|symbol| grammar.symbol_to_nonterm_index(symbol)),
+                );
+            }
+        }
+        grammar
+            .productions()
+            .iter()
+            .filter(|p| reachable[p.nonterminal])
+            .map(|p| p.idx)
+            .collect()
+    }
+
+    /// Calculates minimal derivation heights of nonterminals.
+    fn heights(grammar: &Grammar) -> NonTermVec<Option<usize>> {
+        let mut heights = grammar.new_nontermvec(None);
+        heights[grammar.symbol_to_nonterm_index(grammar.empty_index)] = Some(0);
+        loop {
+            let mut changed = false;
+            for production in grammar.productions() {
+                let Some(height) = Self::production_height(grammar, &heights, production.idx)
+                else {
+                    continue;
+                };
+                let current = &mut heights[production.nonterminal];
+                if current.is_none_or(|current| height < current) {
+                    *current = Some(height);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break heights;
+            }
+        }
+    }
+
+    fn production_height(
+        grammar: &Grammar,
+        heights: &NonTermVec<Option<usize>>,
+        production: ProdIndex,
+    ) -> Option<usize> {
+        grammar
+            .production_rhs_symbols(production)
+            .into_iter()
+            .filter(|&symbol| grammar.is_nonterm(symbol))
+            .try_fold(0, |height, symbol| {
+                heights[grammar.symbol_to_nonterm_index(symbol)]
+                    .map(|symbol_height| height.max(symbol_height))
+            })
+            .map(|height| height + 1)
+    }
+
+    fn derive(
+        &mut self,
+        symbol: SymbolIndex,
+        depth: usize,
+        entropy: &mut Entropy,
+        tokens: &mut Vec<String>,
+    ) -> Result<()> {
+        if self.grammar.is_empty(symbol) {
+            return Ok(());
+        }
+        if self.grammar.is_term(symbol) {
+            let token = self.terminal(self.grammar.symbol_to_term_index(symbol), entropy)?;
+            tokens.push(token);
+            return Ok(());
+        }
+
+        let nonterminal = self.grammar.symbol_to_nonterm(symbol);
+        let productions = nonterminal
+            .productions
+            .iter()
+            .filter_map(|&production| {
+                Self::production_height(self.grammar, &self.heights, production)
+                    .map(|height| (production, height))
+            })
+            .collect::<Vec<_>>();
+        let min_height = productions.iter().map(|&(_, height)| height).min();
+        let bounded = depth >= self.max_depth || tokens.len() >= self.max_tokens;
+        let mut candidates = productions
+            .iter()
+            .filter(|&&(_, height)| {
+                if bounded {
+                    Some(height) == min_height
+                } else {
+                    depth + height <= self.max_depth
+                }
+            })
+            .map(|&(production, _)| production)
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            candidates = productions
+                .iter()
+                .filter(|&&(_, height)| Some(height) == min_height)
+                .map(|&(production, _)| production)
+                .collect();
+        }
+        if self.coverage {
+            let min_uses = candidates.iter().map(|&p| self.uses[p]).min();
+            candidates.retain(|&p| Some(self.uses[p]) == min_uses);
+        }
+        let production = candidates[entropy.below(candidates.len())];
+        self.uses[production] += 1;
+
+        for symbol in self.grammar.production_rhs_symbols(production) {
+            self.derive(symbol, depth + 1, entropy, tokens)?;
+        }
+        Ok(())
+    }
+
+    fn terminal(&self, terminal: TermIndex, entropy: &mut Entropy) -> Result<String> {
+        let term = &self.grammar.terminals[terminal];
+        match (&term.recognizer, &self.regexes[terminal]) {
+            (Some(Recognizer::StrConst(s)), _) => Ok(s.as_ref().clone()),
+            (_, Some(hir)) => {
+                let mut token = regex::sample(hir, entropy);
+                for _ in 0..MAX_RESAMPLES {
+                    if !token.is_empty() && !self.is_str_const(&token) {
+                        break;
+                    }
+                    token = regex::sample(hir, entropy);
+                }
+                Ok(token)
+            }
+            _ => Err(Error::Error(format!(
+                "Terminal '{}' has no recognizer.",
+                term.name
+            ))),
+        }
+    }
+
+    fn is_str_const(&self, token: &str) -> bool {
+        self.str_consts().any(|s| s == token)
+    }
+
+    fn str_consts(&self) -> impl Iterator<Item = &String> {
+        self.grammar
+            .terminals
+            .iter()
+            .filter_map(|terminal| match &terminal.recognizer {
+                Some(Recognizer::StrConst(s)) => Some(s.as_ref()),
+                _ => None,
+            })
+    }
+
+    /// Deletes, duplicates, swaps or inserts a token.
+    fn mutate(&self, tokens: &mut Vec<String>, entropy: &mut Entropy) {
+        let str_consts = self.str_consts().cloned().collect::<Vec<_>>();
+        let mutation = if tokens.is_empty() {
+            3
+        } else {
+            entropy.below(4)
+        };
+        match mutation {
+            0 => {
+                tokens.remove(entropy.below(tokens.len()));
+            }
+            1 => {
+                let idx = entropy.below(tokens.len());
+                tokens.insert(idx, tokens[idx].clone());
+            }
+            2 if tokens.len() > 1 => {
+                let idx = entropy.below(tokens.len() - 1);
+                tokens.swap(idx, idx + 1);
+            }
+            _ if !str_consts.is_empty() => {
+                let token = str_consts[entropy.below(str_consts.len())].clone();
+                tokens.insert(entropy.below(tokens.len() + 1), token);
+            }
+            _ => {
+                tokens.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Entropy, SentenceGenerator};
+    use crate::grammar::Grammar;
+
+    const GRAMMAR: &str = r#"
+        Program: Stmt+;
+        Stmt: 'print' Expr ';' | Name '=' Expr ';' | 'if' Expr '{' Stmt* '}';
+        Expr: Expr '+' Expr | '(' Expr ')' | Name | Number;
+
+        terminals
+        Name: /[a-z]+/;
+        Number: /\d+/;
+        KwPrint: 'print';
+        KwIf: 'if';
+        Plus: '+';
+        Assign: '=';
+        SemiColon: ';';
+        LParen: '(';
+        RParen: ')';
+        LBrace: '{';
+        RBrace: '}';
+        "#;
+
+    #[test]
+    fn sentences_seeded() {
+        let grammar = Grammar::from_str(GRAMMAR).unwrap();
+        let generate = || {
+            let mut generator = SentenceGenerator::new(&grammar).unwrap();
+            let mut entropy = Entropy::seed(7);
+            (0..10)
+                .map(|_| generator.generate(&mut entropy).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let sentences = generate();
+        assert_eq!(sentences, generate());
+        for sentence in &sentences {
+            assert!(sentence.ends_with(';') || sentence.ends_with('}'));
+            assert!(!sentence.contains("print print"));
+        }
+    }
+
+    #[test]
+    fn sentences_coverage() {
+        let grammar = Grammar::from_str(GRAMMAR).unwrap();
+        let mut generator = SentenceGenerator::new(&grammar).unwrap();
+        let mut entropy = Entropy::seed(1);
+        for _ in 0..10 {
+            generator.generate(&mut entropy).unwrap();
+        }
+        assert!(generator.unused_productions().is_empty());
+        let (used, total) = generator.production_coverage();
+        assert_eq!(used, total);
+    }
+
+    #[test]
+    fn sentences_bounds() {
+        let grammar = Grammar::from_str(GRAMMAR).unwrap();
+        let mut generator = SentenceGenerator::new(&grammar)
+            .unwrap()
+            .max_depth(0)
+            .max_tokens(0);
+        // Exhausted bytes always choose the first of the shortest derivations.
+        assert_eq!(
+            generator.generate(&mut Entropy::bytes(&[])).unwrap(),
+            "print a ;"
+        );
+        let mut generator = SentenceGenerator::new(&grammar).unwrap().max_tokens(20);
+        let mut entropy = Entropy::seed(3);
+        for _ in 0..20 {
+            let sentence = generator.generate(&mut entropy).unwrap();
+            assert!(sentence.split(' ').count() < 60, "{sentence}");
+        }
+    }
+
+    #[test]
+    fn sentences_near_miss() {
+        let grammar = Grammar::from_str(GRAMMAR).unwrap();
+        let mut valid = SentenceGenerator::new(&grammar).unwrap();
+        let mut invalid = SentenceGenerator::new(&grammar).unwrap().near_miss(true);
+        let differ = (0..10).any(|seed| {
+            valid.generate(&mut Entropy::seed(seed)).unwrap()
+                != invalid.generate(&mut Entropy::seed(seed)).unwrap()
+        });
+        assert!(differ);
+    }
+
+    #[test]
+    fn sentences_errors() {
+        let grammar = Grammar::from_str("A: 'a' A;\nterminals\nTa: 'a';").unwrap();
+        assert_eq!(
+            SentenceGenerator::new(&grammar).err().unwrap().to_string(),
+            "Error: Rule 'A' can't derive a finite sentence."
+        );
+
+        let grammar = Grammar::from_str("A: B;\nterminals\nB: ;").unwrap();
+        let mut generator = SentenceGenerator::new(&grammar).unwrap();
+        assert_eq!(
+            generator
+                .generate(&mut Entropy::seed(0))
+                .unwrap_err()
+                .to_string(),
+            "Error: Terminal 'B' has no recognizer."
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Sampling of strings matched by regex terminals.
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use super::Entropy;
+use crate::error::{Error, Result};
+
+/// Characters are preferably sampled from the printable ASCII range.
+const PRINTABLE: (u32, u32) = (0x20, 0x7e);
+
+/// The number of additional repetitions of unbounded repetitions.
+const MAX_EXTRA_REPETITIONS: u32 = 3;
+
+pub(crate) fn parse(regex: &str) -> Result<Hir> {
+    regex_syntax::parse(regex).map_err(|e| Error::Error(format!("Invalid regex '{regex}': {e}")))
+}
+
+/// Produces a random string matched by the given regex.
+pub(crate) fn sample(hir: &Hir, entropy: &mut Entropy) -> String {
+    let mut out = vec![];
+    sample_into(hir, entropy, &mut out);
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn sample_into(hir: &Hir, entropy: &mut Entropy, out: &mut Vec<u8>) {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => (),
+        HirKind::Literal(literal) => out.extend_from_slice(&literal.0),
+        HirKind::Class(Class::Unicode(class)) => {
+            let ranges = class
+                .ranges()
+                .iter()
+                .map(|r| (r.start() as u32, r.end() as u32))
+                .collect::<Vec<_>>();
+            if let Some(c) = sample_range(&ranges, entropy).and_then(char::from_u32) {
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            let ranges = class
+                .ranges()
+                .iter()
+                .map(|r| (r.start() as u32, r.end() as u32))
+                .collect::<Vec<_>>();
+            if let Some(b) = sample_range(&ranges, entropy) {
+                out.push(b as u8);
+            }
+        }
+        HirKind::Repetition(repetition) => {
+            let min = repetition.min;
+            let max = repetition
+                .max
+                .unwrap_or(min + MAX_EXTRA_REPETITIONS)
+                .min(min + MAX_EXTRA_REPETITIONS);
+            let count = min + entropy.below((max - min + 1) as usize) as u32;
+            for _ in 0..count {
+                sample_into(&repetition.sub, entropy, out);
+            }
+        }
+        HirKind::Capture(capture) => sample_into(&capture.sub, entropy, out),
+        HirKind::Concat(hirs) => hirs.iter().for_each(|hir| sample_into(hir, entropy, out)),
+        HirKind::Alternation(hirs) => sample_into(&hirs[entropy.below(hirs.len())], entropy, out),
+    }
+}
+
+/// Samples a value from the given inclusive ranges, preferring printable ASCII
+/// characters.
+fn sample_range(ranges: &[(u32, u32)], entropy: &mut Entropy) -> Option<u32> {
+    let printable = ranges
+        .iter()
+        .filter_map(|&(start, end)| {
+            let (start, end) = (start.max(PRINTABLE.0), end.min(PRINTABLE.1));
+            (start <= end).then_some((start, end))
+        })
+        .collect::<Vec<_>>();
+    let ranges = if printable.is_empty() {
+        ranges
+    } else {
+        &printable
+    };
+    let total = ranges
+        .iter()
+        .map(|(start, end)| (end - start + 1) as usize)
+        .sum::<usize>();
+    if total == 0 {
+        return None;
+    }
+    let mut idx = entropy.below(total);
+    for &(start, end) in ranges {
+        let len = (end - start + 1) as usize;
+        if idx < len {
+            return Some(start + idx as u32);
+        }
+        idx -= len;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, sample};
+    use crate::sentences::Entropy;
+
+    #[test]
+    fn regex_samples() {
+        let mut entropy = Entropy::seed(42);
+        for _ in 0..50 {
+            let number = sample(&parse(r"\d+(\.\d+)?").unwrap(), &mut entropy);
+            assert!(!number.is_empty() && number.split('.').count() <= 2);
+            assert!(number.chars().all(|c| c.is_ascii_digit() || c == '.'));
+
+            let id = sample(&parse(r"[a-zA-Z_][a-zA-Z_0-9]*").unwrap(), &mut entropy);
+            assert!(!id.starts_with(|c: char| c.is_ascii_digit()));
+
+            let string = sample(&parse(r#""[^"]*""#).unwrap(), &mut entropy);
+            assert!(string.len() >= 2 && string.matches('"').count() == 2);
+
+            let keyword = sample(&parse("if|else|while").unwrap(), &mut entropy);
+            assert!(["if", "else", "while"].contains(&keyword.as_str()));
+        }
+    }
+
+    #[test]
+    fn regex_invalid() {
+        assert!(parse("(?=a)")
+            .unwrap_err()
+            .to_string()
+            .starts_with("Error: Invalid regex '(?=a)'"));
+    }
+}
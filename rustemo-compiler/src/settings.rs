@@ -4,11 +4,15 @@ use clap::ValueEnum;
 use rustemo::LOG;
 use yansi::Paint;
 
+use crate::diagnostics::Code;
 use crate::table::TableType;
 use crate::{Error, Result};
 use std::path::{Path, PathBuf};
 
-use crate::generator::generate_parser;
+use crate::diagnostics::Diagnostic;
+use crate::generator::{
+    check_grammar_file, generate_parser, generate_parser_source, ParserSource,
+};
 
 /// The parsing algorithm used
 #[derive(Debug, Default, Clone, ValueEnum)]
@@ -95,8 +99,15 @@ pub struct Settings {
     pub(crate) lexer_type: LexerType,
     pub(crate) builder_type: BuilderType,
     pub(crate) builder_loc_info: bool,
+    pub(crate) builder_arena: bool,
+    pub(crate) derives: Vec<String>,
+    pub(crate) serde: bool,
+    pub(crate) visitors: bool,
+    pub(crate) unparse: bool,
+    pub(crate) coverage: bool,
     pub(crate) generator_table_type: GeneratorTableType,
     pub(crate) input_type: String,
+    pub(crate) user_state_type: Option<String>,
 
     pub(crate) lexical_disamb_most_specific: bool,
     pub(crate) lexical_disamb_longest_match: bool,
@@ -104,12 +115,21 @@ pub struct Settings {
 
     pub(crate) partial_parse: bool,
     pub(crate) skip_ws: bool,
+    pub(crate) token_filter: Option<String>,
+    pub(crate) indentation: bool,
 
     pub(crate) force: bool,
     force_explicit: bool,
+    pub(crate) migrate_actions: bool,
 
     pub(crate) dot: bool,
+    pub(crate) error_states: bool,
     pub(crate) fancy_regex: bool,
+
+    pub(crate) warnings_as_errors: bool,
+    pub(crate) silence_warnings: bool,
+    pub(crate) allowed_warnings: Vec<Code>,
+    pub(crate) cargo_warnings: bool,
 }
 
 impl Default for Settings {
@@ -135,19 +155,34 @@ impl Default for Settings {
             trace: false,
             lexer_type: Default::default(),
             builder_type: Default::default(),
-            builder_loc_info: false,
+            builder_loc_info: true,
+            builder_arena: false,
+            derives: vec![],
+            serde: false,
+            visitors: false,
+            unparse: false,
+            coverage: false,
             generator_table_type: Default::default(),
             input_type: "str".into(),
+            user_state_type: None,
             lexical_disamb_most_specific: true,
             lexical_disamb_longest_match: true,
             lexical_disamb_grammar_order: true,
             partial_parse: false,
             skip_ws: true,
+            token_filter: None,
+            indentation: false,
             force: true, // Overwriting actions by default
             force_explicit: false,
+            migrate_actions: false,
             exclude: vec![],
             dot: false,
+            error_states: false,
             fancy_regex: false,
+            warnings_as_errors: false,
+            silence_warnings: false,
+            allowed_warnings: vec![],
+            cargo_warnings: false,
         }
     }
 }
@@ -266,12 +301,61 @@ impl Settings {
     }
 
     /// Should generated default AST builder types contain location/layout information
-    /// This is only used if builder-type is default.
+    /// This is only used if builder-type is default. Enabled by default.
     pub fn builder_loc_info(mut self, builder_loc_info: bool) -> Self {
         self.builder_loc_info = builder_loc_info;
         self
     }
 
+    /// Should recursive values of AST types generated by the default builder
+    /// be allocated in the `AstArena` and referenced by `rustemo::Id` instead
+    /// of being boxed. The builder returns the arena together with the root of
+    /// the AST.
+    pub fn builder_arena(mut self, builder_arena: bool) -> Self {
+        self.builder_arena = builder_arena;
+        self
+    }
+
+    /// Additional derives for AST types generated by the default builder (e.g.
+    /// `PartialEq`, `Hash`). `Debug` and `Clone` are always derived.
+    pub fn derives(mut self, derives: Vec<String>) -> Self {
+        self.derives = derives;
+        self
+    }
+
+    /// Should AST types generated by the default builder derive
+    /// `serde::Serialize` and `serde::Deserialize`. The crate using the parser
+    /// must depend on `serde` with `derive` feature. If `builder_loc_info` is
+    /// used, `serde` feature of `rustemo` must be enabled.
+    pub fn serde(mut self, serde: bool) -> Self {
+        self.serde = serde;
+        self
+    }
+
+    /// Should `Visitor`, `VisitorMut` and `Fold` traits for AST types generated
+    /// by the default builder be generated in the `visitor` module of the
+    /// parser file.
+    pub fn visitors(mut self, visitors: bool) -> Self {
+        self.visitors = visitors;
+        self
+    }
+
+    /// Should the printer of AST types generated by the default builder back
+    /// to the source text be generated in the `unparse` module of the actions
+    /// file. The module is regenerated on each run.
+    pub fn unparse(mut self, unparse: bool) -> Self {
+        self.unparse = unparse;
+        self
+    }
+
+    /// Should the parser be generated with the support for recording grammar
+    /// coverage. If enabled, the parser module gets `COVERAGE_INFO` and the
+    /// parser gets `with_coverage` method.
+    pub fn coverage(mut self, coverage: bool) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
     /// Sets generator table type. The default is nested static arrays.
     pub fn generator_table_type(mut self, generator_table_type: GeneratorTableType) -> Self {
         self.generator_table_type = generator_table_type;
@@ -284,6 +368,16 @@ impl Settings {
         self
     }
 
+    /// Sets the type of the user state embedded in the parsing context. The
+    /// path is resolved from the generated parser module, e.g.
+    /// `super::SymbolTable`. The type must implement `Default` and, for GLR,
+    /// `Clone` and `Debug`. The user state is accessible in semantic actions
+    /// and lexers through `user_state`/`user_state_mut` context methods.
+    pub fn user_state_type(mut self, user_state_type: String) -> Self {
+        self.user_state_type = Some(user_state_type);
+        self
+    }
+
     /// Lexical disambiguation using most specific match strategy.
     pub fn lexical_disamb_most_specific(mut self, most_specific: bool) -> Self {
         self.lexical_disamb_most_specific = most_specific;
@@ -334,6 +428,26 @@ impl Settings {
         self
     }
 
+    /// A path to a function used to accept or reject tokens recognized by the
+    /// default lexer based on the parsing context. The path is resolved from
+    /// the generated parser module, e.g. `super::my_lexer::token_filter`. The
+    /// function must have the signature of [`rustemo::TokenFilter`]. Used
+    /// only in the default lexer.
+    pub fn token_filter(mut self, token_filter: String) -> Self {
+        self.token_filter = Some(token_filter);
+        self
+    }
+
+    /// Should the default lexer track indentation. `false` by default. If
+    /// enabled, the grammar must define `INDENT` and `DEDENT` terminals, and
+    /// optionally `NEWLINE` terminal, without recognizers. These tokens are
+    /// produced by the lexer at the start of lines based on the indentation.
+    /// Used only in the default lexer.
+    pub fn indentation(mut self, indentation: bool) -> Self {
+        self.indentation = indentation;
+        self
+    }
+
     /// Should actions be generated. `true` by default. Used only if default
     /// builder is used.
     pub fn actions(mut self, actions: bool) -> Self {
@@ -363,6 +477,15 @@ impl Settings {
         self
     }
 
+    /// When an existing actions file is out of sync with the grammar, rewrite
+    /// signatures of actions and fields/variants of types to match the grammar
+    /// while keeping the bodies of actions. Items no longer used by the parser
+    /// are moved to the `orphaned` module excluded from compilation.
+    pub fn migrate_actions(mut self, migrate_actions: bool) -> Self {
+        self.migrate_actions = migrate_actions;
+        self
+    }
+
     /// If this is set a .dot file with automata visualization will be produced during
     /// compiling.
     pub fn dot(mut self, dot: bool) -> Self {
@@ -370,6 +493,46 @@ impl Settings {
         self
     }
 
+    /// If this is set a `.error_states` file listing every state of the parser
+    /// where a syntax error can be detected will be produced during parser
+    /// generation. The file is in the `.messages` format and can be used as a
+    /// starting point for custom error messages.
+    ///
+    /// Custom error messages are always read from the file with the grammar
+    /// base name and the `.messages` extension beside the grammar if it
+    /// exists.
+    pub fn error_states(mut self, error_states: bool) -> Self {
+        self.error_states = error_states;
+        self
+    }
+
+    /// Should grammar warnings be reported as errors. The parser is not
+    /// generated if there are warnings.
+    pub fn warnings_as_errors(mut self, warnings_as_errors: bool) -> Self {
+        self.warnings_as_errors = warnings_as_errors;
+        self
+    }
+
+    /// Should all grammar warnings be silenced.
+    pub fn silence_warnings(mut self, silence_warnings: bool) -> Self {
+        self.silence_warnings = silence_warnings;
+        self
+    }
+
+    /// Grammar warnings with the given codes are not reported, e.g.
+    /// `vec![Code::UnusedTerminal]`.
+    pub fn allow_warnings(mut self, codes: Vec<Code>) -> Self {
+        self.allowed_warnings = codes;
+        self
+    }
+
+    /// Should grammar warnings be printed as `cargo:warning` lines. Use from
+    /// `build.rs` scripts to get the warnings reported by cargo.
+    pub fn cargo_warnings(mut self, cargo_warnings: bool) -> Self {
+        self.cargo_warnings = cargo_warnings;
+        self
+    }
+
     /// Recursively traverse the root dir and process each Rustemo grammar found.
     /// Used as the last call to the configured [Settings] value.
     pub fn process_dir(&self) -> Result<()> {
@@ -439,6 +602,22 @@ impl Settings {
         )
     }
 
+    /// Generates the parser from the given grammar string and returns its
+    /// source code instead of writing it to the file system. The `name` is
+    /// used as the base name of the parser, the same as the grammar file name
+    /// in [Settings::process_grammar]. Used by procedural macros.
+    pub fn process_grammar_str(&self, name: &str, grammar: &str) -> Result<ParserSource> {
+        generate_parser_source(name, grammar, self)
+    }
+
+    /// Checks the given grammar without generating the parser. Grammar errors,
+    /// including LR conflicts if LR parsing is used, are returned as errors.
+    /// Returns the warnings found in the grammar filtered by the warnings
+    /// settings.
+    pub fn check_grammar(&self, grammar_path: &Path) -> Result<Vec<Diagnostic>> {
+        check_grammar_file(grammar_path, self)
+    }
+
     /// Recursively visits dirs starting from the given `dir` and calls
     /// `visitor` for each Rustemo grammar found.
     fn visit_dirs(&self, dir: &Path, visitor: &dyn Fn(&Path) -> Result<()>) -> Result<()> {
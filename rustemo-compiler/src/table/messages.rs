@@ -0,0 +1,415 @@
+//! Custom syntax error messages for LR states.
+//!
+//! Messages are given in a `.messages` file in the spirit of Menhir's
+//! `.messages` files. Each entry consists of one or more sentences followed by
+//! a message. A sentence starts with the name of the start rule followed by a
+//! colon and a sequence of grammar symbols (terminals or non-terminals) which
+//! leads the parser from the start state to the state where the error is
+//! detected. Entries are separated by empty lines. Lines starting with `##` are
+//! comments.
+//!
+//! ```text
+//! ## Missing operand
+//! E: E Plus
+//! E: E Mul
+//! An expression is expected after the operator.
+//! ```
+use std::{collections::VecDeque, fs, path::Path};
+
+use crate::{
+    diagnostics::{Code, Diagnostic},
+    error::{Error, Result},
+    index::{StateIndex, StateVec, SymbolIndex, TermIndex},
+};
+
+use super::{Action, LRItem, LRTable};
+
+/// A placeholder used for messages in the generated error states listing.
+pub(crate) const MESSAGE_PLACEHOLDER: &str = "<YOUR SYNTAX ERROR MESSAGE HERE>";
+
+impl LRTable<'_, '_> {
+    /// Finds the shortest sequence of grammar symbols leading from the start
+    /// state to each state of the main parser.
+    fn state_sentences(&self) -> StateVec<Option<Vec<SymbolIndex>>> {
+        let mut sentences = StateVec(vec![None; self.states.len()]);
+        sentences[StateIndex(0)] = Some(vec![]);
+        let mut queue = VecDeque::from([StateIndex(0)]);
+        while let Some(state_idx) = queue.pop_front() {
+            for (symbol, target) in self.transitions(state_idx) {
+                if sentences[target].is_none() {
+                    let mut sentence = sentences[state_idx].clone().unwrap();
+                    sentence.push(symbol);
+                    sentences[target] = Some(sentence);
+                    queue.push_back(target);
+                }
+            }
+        }
+        sentences
+    }
+
+    /// Transitions from the given state, first over terminals and then over
+    /// non-terminals.
+    fn transitions(&self, state_idx: StateIndex) -> Vec<(SymbolIndex, StateIndex)> {
+        let state = &self.states[state_idx];
+        let shifts = state
+            .actions
+            .iter()
+            .enumerate()
+            .filter_map(|(term_idx, actions)| {
+                actions.iter().find_map(|action| match action {
+                    Action::Shift(target) => Some((
+                        self.grammar.term_to_symbol_index(TermIndex(term_idx)),
+                        *target,
+                    )),
+                    _ => None,
+                })
+            });
+        let gotos = state
+            .gotos
+            .iter()
+            .enumerate()
+            .filter_map(|(nonterm_idx, target)| {
+                target.map(|target| {
+                    (
+                        self.grammar.nonterm_to_symbol_index(nonterm_idx.into()),
+                        target,
+                    )
+                })
+            });
+        shifts.chain(gotos).collect()
+    }
+
+    /// Follows the given symbols starting from the start state.
+    fn follow_sentence(&self, symbols: &[SymbolIndex]) -> Option<StateIndex> {
+        symbols.iter().try_fold(StateIndex(0), |state_idx, symbol| {
+            self.transitions(state_idx)
+                .into_iter()
+                .find_map(|(s, target)| (s == *symbol).then_some(target))
+        })
+    }
+
+    fn sentence_to_string(&self, sentence: &[SymbolIndex]) -> String {
+        let mut result = format!("{}:", self.grammar.symbol_name(self.grammar.start_index));
+        for symbol in sentence {
+            result.push(' ');
+            result.push_str(&self.grammar.symbol_name(*symbol));
+        }
+        result
+    }
+
+    fn item_to_plain_string(&self, item: &LRItem) -> String {
+        let prod = &self.grammar.productions[item.prod];
+        let mut rhs = prod
+            .rhs_symbols()
+            .iter()
+            .map(|s| self.grammar.symbol_name(*s))
+            .collect::<Vec<_>>();
+        rhs.insert(item.position, ".".into());
+        format!(
+            "{}: {}",
+            self.grammar
+                .symbol_name(self.grammar.nonterm_to_symbol_index(prod.nonterminal)),
+            rhs.join(" ")
+        )
+    }
+
+    /// The states of the parser where a syntax error can be detected with the
+    /// shortest sentences leading to them and the expected terminals.
+    fn error_state_sentences(&self) -> Vec<(StateIndex, Vec<SymbolIndex>, Vec<&str>)> {
+        let mut sentences = self.state_sentences();
+        self.states
+            .iter()
+            .filter_map(|state| {
+                let sentence = sentences[state.idx].take()?;
+                let expected = state
+                    .actions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, actions)| !actions.is_empty())
+                    .map(|(term_idx, _)| self.grammar.terminals[TermIndex(term_idx)].name.as_str())
+                    .collect::<Vec<_>>();
+                // No error can occur if all terminals are expected.
+                (expected.len() < self.grammar.terminals.len())
+                    .then_some((state.idx, sentence, expected))
+            })
+            .collect()
+    }
+
+    /// Lists every state of the parser where a syntax error can be detected in
+    /// the `.messages` file format. The messages are placeholders which should
+    /// be replaced by the user.
+    pub fn error_states(&self) -> String {
+        let mut result = String::new();
+        for (state_idx, sentence, expected) in self.error_state_sentences() {
+            let state = &self.states[state_idx];
+            result.push_str(&format!(
+                "## State {}:{}\n",
+                state.idx,
+                self.grammar.symbol_name(state.symbol)
+            ));
+            for item in state.kernel_items() {
+                result.push_str(&format!("##   {}\n", self.item_to_plain_string(item)));
+            }
+            result.push_str(&format!("## Expected: {}\n", expected.join(", ")));
+            result.push_str(&self.sentence_to_string(&sentence));
+            result.push('\n');
+            result.push_str(MESSAGE_PLACEHOLDER);
+            result.push_str("\n\n");
+        }
+        result
+    }
+
+    /// Warnings for the states where a syntax error can be detected which have
+    /// no message in the given `.messages` file.
+    pub(crate) fn missing_error_messages(
+        &self,
+        messages: &StateVec<Option<String>>,
+        file: &str,
+    ) -> Vec<Diagnostic> {
+        self.error_state_sentences()
+            .into_iter()
+            .filter(|(state_idx, _, _)| messages[*state_idx].is_none())
+            .map(|(state_idx, sentence, _)| {
+                Diagnostic::new(
+                    Code::MissingErrorMessage,
+                    format!(
+                        "No message for the error state {state_idx} reached by '{}'.",
+                        self.sentence_to_string(&sentence)
+                    ),
+                )
+                .with_file(file)
+            })
+            .collect()
+    }
+
+    /// Loads custom error messages from the given `.messages` file and maps
+    /// them to LR states.
+    pub(crate) fn error_messages(&self, path: &Path) -> Result<StateVec<Option<String>>> {
+        let content = fs::read_to_string(path)?;
+        self.parse_error_messages(&content, &path.to_string_lossy())
+    }
+
+    fn parse_error_messages(&self, content: &str, file: &str) -> Result<StateVec<Option<String>>> {
+        let prefix = format!("{}:", self.grammar.symbol_name(self.grammar.start_index));
+        let mut messages: StateVec<Option<(String, usize)>> =
+            StateVec(vec![None; self.states.len()]);
+
+        // States of the current entry with the line of their sentence.
+        let mut states: Vec<(StateIndex, usize)> = vec![];
+        let mut message: Vec<&str> = vec![];
+
+        let mut finish_entry =
+            |states: &mut Vec<(StateIndex, usize)>, message: &mut Vec<&str>| -> Result<()> {
+                if states.is_empty() {
+                    return Ok(());
+                }
+                if message.is_empty() {
+                    return Err(Error::Error(format!(
+                        "{file}:{}: Message is missing.",
+                        states[0].1
+                    )));
+                }
+                let text = message.join("\n");
+                message.clear();
+                if text == MESSAGE_PLACEHOLDER {
+                    // Entries from the error states listing which are not
+                    // filled in yet.
+                    states.clear();
+                    return Ok(());
+                }
+                let entry_line = states[0].1;
+                for (state_idx, line) in states.drain(..) {
+                    // Sentences of the same entry may lead to the same state.
+                    if let Some((_, other_line)) = messages[state_idx]
+                        .as_ref()
+                        .filter(|(_, other_line)| *other_line != entry_line)
+                    {
+                        return Err(Error::Error(format!(
+                            "{file}:{line}: Sentence leads to the state {state_idx} \
+                         which already has a message given at line {other_line}."
+                        )));
+                    }
+                    messages[state_idx] = Some((text.clone(), entry_line));
+                }
+                Ok(())
+            };
+
+        for (line_no, line) in content.lines().enumerate() {
+            let line_no = line_no + 1;
+            let line = line.trim();
+            if line.starts_with("##") {
+                continue;
+            }
+            if line.is_empty() {
+                finish_entry(&mut states, &mut message)?;
+                continue;
+            }
+            if message.is_empty() && line.starts_with(&prefix) {
+                let symbols = line[prefix.len()..]
+                    .split_whitespace()
+                    .map(|name| {
+                        self.grammar
+                            .term_by_name
+                            .get(name)
+                            .or_else(|| self.grammar.nonterm_by_name.get(name))
+                            .copied()
+                            .ok_or_else(|| {
+                                Error::Error(format!("{file}:{line_no}: Unknown symbol '{name}'."))
+                            })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let state_idx = self.follow_sentence(&symbols).ok_or_else(|| {
+                    Error::Error(format!(
+                        "{file}:{line_no}: Sentence '{line}' is not a valid prefix."
+                    ))
+                })?;
+                states.push((state_idx, line_no));
+            } else if states.is_empty() {
+                return Err(Error::Error(format!(
+                    "{file}:{line_no}: Expected a sentence starting with '{prefix}'."
+                )));
+            } else {
+                message.push(line);
+            }
+        }
+        finish_entry(&mut states, &mut message)?;
+
+        Ok(StateVec(
+            messages
+                .0
+                .into_iter()
+                .map(|m| m.map(|(message, _)| message))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        diagnostics::Code, grammar::Grammar, output_cmp, settings::Settings, table::LRTable,
+    };
+
+    fn calc_grammar() -> Grammar {
+        r#"
+        E: E Plus E {left}
+         | LParen E RParen
+         | Num;
+
+        terminals
+        Plus: '+';
+        LParen: '(';
+        RParen: ')';
+        Num: /\d+/;
+        "#
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn error_states_listing() {
+        let grammar = calc_grammar();
+        let settings = Settings::default();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+
+        output_cmp!("src/table/error_states.expected", table.error_states());
+    }
+
+    #[test]
+    fn error_messages_to_states() {
+        let grammar = calc_grammar();
+        let settings = Settings::default();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+
+        let messages = table
+            .parse_error_messages(
+                "## Operand missing\n\
+                 E: E Plus\n\
+                 E: LParen\n\
+                 Expected an operand.\n\
+                 \n\
+                 E: LParen E\n\
+                 Unclosed parenthesis.\n",
+                "calc.messages",
+            )
+            .unwrap();
+        let state_of = |symbols: &[&str]| {
+            let symbols = symbols
+                .iter()
+                .map(|s| grammar.symbol_index(s))
+                .collect::<Vec<_>>();
+            table.follow_sentence(&symbols).unwrap()
+        };
+        assert_eq!(
+            messages[state_of(&["E", "Plus"])].as_deref(),
+            Some("Expected an operand.")
+        );
+        assert_eq!(
+            messages[state_of(&["LParen"])].as_deref(),
+            Some("Expected an operand.")
+        );
+        assert_eq!(
+            messages[state_of(&["LParen", "E"])].as_deref(),
+            Some("Unclosed parenthesis.")
+        );
+        assert_eq!(messages.iter().filter(|m| m.is_some()).count(), 3);
+    }
+
+    #[test]
+    fn error_messages_missing() {
+        let grammar = calc_grammar();
+        let settings = Settings::default();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+
+        let messages = table
+            .parse_error_messages("E: LParen\nExpected an operand.\n", "calc.messages")
+            .unwrap();
+        let warnings = table.missing_error_messages(&messages, "calc.messages");
+        assert_eq!(warnings.len(), table.error_state_sentences().len() - 1);
+        assert!(warnings
+            .iter()
+            .all(|warning| warning.code == Code::MissingErrorMessage));
+        assert_eq!(
+            warnings[0].to_locfile_str(),
+            "Warning[W0007] at calc.messages:\n\tNo message for the error state 0 reached by 'E:'."
+        );
+        assert!(!warnings
+            .iter()
+            .any(|warning| warning.report.message.contains("'E: LParen'")));
+    }
+
+    #[test]
+    fn error_messages_errors() {
+        let grammar = calc_grammar();
+        let settings = Settings::default();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+
+        let error = |content| {
+            table
+                .parse_error_messages(content, "calc.messages")
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("E: E Minus\nMessage."),
+            "Error: calc.messages:1: Unknown symbol 'Minus'."
+        );
+        assert_eq!(
+            error("E: E E\nMessage."),
+            "Error: calc.messages:1: Sentence 'E: E E' is not a valid prefix."
+        );
+        assert_eq!(
+            error("E: LParen\nFirst.\n\nE: LParen\nSecond."),
+            "Error: calc.messages:4: Sentence leads to the state 1 \
+             which already has a message given at line 1."
+        );
+        assert_eq!(
+            error("E: LParen\n\n"),
+            "Error: calc.messages:1: Message is missing."
+        );
+        assert_eq!(
+            error("Message."),
+            "Error: calc.messages:1: Expected a sentence starting with 'E:'."
+        );
+    }
+}
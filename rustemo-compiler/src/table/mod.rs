@@ -16,7 +16,8 @@ use yansi::Paint;
 
 use crate::{
     create_index,
-    error::{Error, Result},
+    diagnostics::{Code, Diagnostic},
+    error::Result,
     grammar::{Associativity, Priority, Terminal, DEFAULT_PRIORITY},
     index::{
         NonTermIndex, NonTermVec, ProdIndex, ProdVec, StateIndex, StateVec, SymbolIndex,
@@ -28,6 +29,8 @@ use crate::{
 
 use super::grammar::{res_symbol, Grammar};
 
+mod messages;
+
 #[derive(Debug, Clone)]
 pub enum Action {
     Shift(StateIndex),
@@ -90,6 +93,20 @@ pub struct LRState<'g> {
     /// location no further termininals should be tried.
     pub sorted_terminals: Vec<(TermIndex, bool)>,
 
+    /// Lookahead terminals for which a greedy repetition continues in this
+    /// state by a SHIFT, together with the productions whose reductions would
+    /// stop the repetition. GLR parser drops these reductions.
+    pub greedy: BTreeMap<TermIndex, BTreeSet<ProdIndex>>,
+
+    /// Lookahead terminals for which a greedy repetition, started before this
+    /// state, continues by a SHIFT.
+    pub greedy_continues: BTreeSet<TermIndex>,
+
+    /// Non-terminals continuing a greedy repetition started before this
+    /// state. For lookaheads in `greedy_continues` GLR parser drops the
+    /// reductions to other non-terminals starting in this state.
+    pub greedy_nonterms: BTreeSet<NonTermIndex>,
+
     /// Each production has a priority. We use this priority to resolve S/R and
     /// R/R conflicts. Since the Shift operation is executed over terminal symbol
     /// to resolve S/R we need terminal priority. But, the priority given for a
@@ -156,6 +173,9 @@ impl<'g> LRState<'g> {
             gotos: grammar.new_nontermvec(None),
             max_prior_for_term: BTreeMap::new(),
             sorted_terminals: Vec::new(),
+            greedy: BTreeMap::new(),
+            greedy_continues: BTreeSet::new(),
+            greedy_nonterms: BTreeSet::new(),
         }
     }
 
@@ -174,6 +194,9 @@ impl<'g> LRState<'g> {
             gotos: grammar.new_nontermvec(None),
             max_prior_for_term: BTreeMap::new(),
             sorted_terminals: Vec::new(),
+            greedy: BTreeMap::new(),
+            greedy_continues: BTreeSet::new(),
+            greedy_nonterms: BTreeSet::new(),
         }
     }
 
@@ -506,19 +529,12 @@ impl<'g, 's> LRTable<'g, 's> {
         );
         table.calculate_reductions();
 
+        log!("Find lookaheads continuing greedy repetitions.");
+        table.calculate_greedy();
+
         log!("Sort terminals for lexical disambiguation");
         table.sort_terminals();
 
-        println!("Terminals: {}", grammar.terminals.len());
-        println!("Non-terminals: {}", grammar.nonterminals().len());
-        println!("Productions: {}", grammar.productions().len());
-        println!("States: {}", table.states.len());
-
-        if settings.print_table {
-            println!("LR TABLE:");
-            println!("{table}");
-        }
-
         Ok(table)
     }
 
@@ -904,6 +920,92 @@ impl<'g, 's> LRTable<'g, 's> {
         }
     }
 
+    /// Finds, for each state, lookaheads for which an item of a greedy
+    /// repetition can be continued by a SHIFT and the reductions which would
+    /// stop the repetition for those lookaheads, so that GLR parser can drop
+    /// them. A repetition is stopped by an empty reduction of a greedy
+    /// production, which would skip the repetition, or by a reduction of a
+    /// production ending with the repetition, if the dot of the greedy item is
+    /// after the repetition start. Other reductions are unrelated to the
+    /// repetition and are kept. If an empty reduction of a non-greedy
+    /// production is possible for the lookahead it might be needed to continue
+    /// the repetition so no reduction is dropped.
+    ///
+    /// For states where a started greedy repetition continues, non-terminals
+    /// which continue it are found. Reductions to other non-terminals starting
+    /// in these states would stop the repetition.
+    fn calculate_greedy(&mut self) {
+        for state in &mut self.states {
+            for item in state.items.iter().filter(|item| {
+                self.grammar.productions[item.prod].greedy && item.position < item.prod_len
+            }) {
+                let repetition = self
+                    .grammar
+                    .nonterm_to_symbol_index(self.grammar.productions[item.prod].nonterminal);
+                let rest = &self.grammar.production_rhs_symbols(item.prod)[item.position..];
+                for first in firsts(self.grammar, &self.first_sets, rest) {
+                    if !self.grammar.is_term(first) {
+                        continue;
+                    }
+                    let term = self.grammar.symbol_to_term_index(first);
+                    let actions = &state.actions[term];
+                    if !actions.iter().any(|a| matches!(a, Action::Shift(_))) {
+                        continue;
+                    }
+                    if item.position > 0 {
+                        state.greedy_continues.insert(term);
+                    }
+                    if actions.iter().any(|a| {
+                        matches!(a, Action::Reduce(prod, 0)
+                                     if !self.grammar.productions[*prod].greedy)
+                    }) {
+                        continue;
+                    }
+                    let stops = actions
+                        .iter()
+                        .filter_map(|a| match a {
+                            Action::Reduce(prod, 0) if self.grammar.productions[*prod].greedy => {
+                                Some(*prod)
+                            }
+                            Action::Reduce(prod, _)
+                                if item.position > 0
+                                    && self.grammar.production_rhs_symbols(*prod).last()
+                                        == Some(&repetition) =>
+                            {
+                                Some(*prod)
+                            }
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    if !stops.is_empty() {
+                        state.greedy.entry(term).or_default().extend(stops);
+                    }
+                }
+                if item.position > 0 {
+                    let mut symbols = vec![rest[0]];
+                    while let Some(symbol) = symbols.pop() {
+                        if !self.grammar.is_nonterm(symbol) || self.grammar.is_empty(symbol) {
+                            continue;
+                        }
+                        let nonterm = self.grammar.symbol_to_nonterm_index(symbol);
+                        if !state.greedy_nonterms.insert(nonterm) {
+                            continue;
+                        }
+                        // Non-terminals in the left corner of the productions.
+                        for &prod in &self.grammar.nonterminals[nonterm].productions {
+                            for symbol in self.grammar.production_rhs_symbols(prod) {
+                                symbols.push(symbol);
+                                if !self.first_sets[symbol].contains(&self.grammar.empty_index) {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Sort terminals for each state according to explicit priority and
     /// terminal recognizer type. String recognizers have precedence over regex
     /// recognizers if most specific match strategy is enabled. For most
@@ -990,11 +1092,26 @@ impl<'g, 's> LRTable<'g, 's> {
             .enumerate()
             .find(|(_, s)| s.is_empty())
         {
-            return Err(Error::Error(format!(
-                "First set empty for grammar symbol {:?}.\n\
-                 An infinite recursion on the grammar symbol.",
-                &self.grammar.symbol_name(SymbolIndex(idx))
-            )));
+            let symbol = SymbolIndex(idx);
+            return Err(Diagnostic::new(
+                Code::InfiniteRecursion,
+                format!(
+                    "First set empty for grammar symbol {:?}.\n\
+                     An infinite recursion on the grammar symbol.",
+                    &self.grammar.symbol_name(symbol)
+                ),
+            )
+            .with_span(if symbol == self.grammar.augmented_index {
+                // Augmented rule is implicit. Report the start rule.
+                self.grammar
+                    .symbol_to_nonterm(self.grammar.start_index)
+                    .span
+            } else if self.grammar.is_nonterm(symbol) {
+                self.grammar.symbol_to_nonterm(symbol).span
+            } else {
+                None
+            })
+            .into());
         }
         Ok(())
     }
@@ -1040,34 +1157,40 @@ impl<'g, 's> LRTable<'g, 's> {
     }
 
     pub fn print_conflicts_report(&self, conflicts: &Vec<Conflict<'g, 's>>) {
+        print!("{}", self.conflicts_report(conflicts));
+    }
+
+    /// A human readable report of the given conflicts.
+    pub fn conflicts_report(&self, conflicts: &Vec<Conflict<'g, 's>>) -> String {
+        let mut report = String::new();
         for conflict in conflicts {
-            println!("{} {}", "In".paint(LOG_BOLD), conflict.state);
-            print!(
+            report.push_str(&format!("{} {}\n", "In".paint(LOG_BOLD), conflict.state));
+            report.push_str(&format!(
                 "When I saw {} and see token {} ahead I can't decide",
                 self.grammar.symbol_name(conflict.state.symbol).paint(LOG),
                 self.grammar
                     .symbol_name(self.grammar.term_to_symbol_index(conflict.follow))
                     .paint(LOG)
-            );
+            ));
             match conflict.kind {
                 ConflictKind::ShiftReduce(prod) => {
-                    println!(
-                        " should I shift or reduce by production:\n{}\n",
+                    report.push_str(&format!(
+                        " should I shift or reduce by production:\n{}\n\n",
                         self.grammar.productions[prod]
                             .to_string(self.grammar)
                             .paint(LOG)
-                    );
+                    ));
                 }
                 ConflictKind::ReduceReduce(prod1, prod2) => {
-                    println!(
-                        " should I reduce by production:\n{}\nor production:\n{}\n",
+                    report.push_str(&format!(
+                        " should I reduce by production:\n{}\nor production:\n{}\n\n",
                         self.grammar.productions[prod1]
                             .to_string(self.grammar)
                             .paint(LOG),
                         self.grammar.productions[prod2]
                             .to_string(self.grammar)
                             .paint(LOG)
-                    );
+                    ));
                 }
             }
         }
@@ -1079,8 +1202,8 @@ impl<'g, 's> LRTable<'g, 's> {
             .iter()
             .filter(|c| matches!(c.kind, ConflictKind::ReduceReduce(..)))
             .count();
-        println!(
-            "{}",
+        report.push_str(&format!(
+            "{}\n",
             format!(
                 "{} conflict(s). {} Shift/Reduce and {} Reduce/Reduce.",
                 shift_reduce_len + reduce_reduce_len,
@@ -1088,7 +1211,58 @@ impl<'g, 's> LRTable<'g, 's> {
                 reduce_reduce_len
             )
             .paint(LOG)
+        ));
+        report
+    }
+
+    /// A diagnostic reporting the given conflicts. Conflicting productions are
+    /// labelled.
+    pub fn conflicts_diagnostic(&self, conflicts: &[Conflict<'g, 's>]) -> Diagnostic {
+        let grammar = self.grammar;
+        let token = |conflict: &Conflict| {
+            grammar.symbol_name(grammar.term_to_symbol_index(conflict.follow))
+        };
+        let mut labeled = BTreeSet::new();
+        let mut diagnostic = Diagnostic::new(
+            Code::Conflicts,
+            "Grammar is not deterministic. There are conflicts.",
         );
+        for conflict in conflicts {
+            let (prods, kind) = match conflict.kind {
+                ConflictKind::ShiftReduce(prod) => (vec![prod], "Shift/Reduce"),
+                ConflictKind::ReduceReduce(prod1, prod2) => (vec![prod1, prod2], "Reduce/Reduce"),
+            };
+            for prod in prods {
+                if labeled.insert(prod) {
+                    let prod = &grammar.productions[prod];
+                    if diagnostic.report.span.is_none() {
+                        diagnostic = diagnostic.with_span(prod.span(grammar));
+                    }
+                    diagnostic = diagnostic.with_label(
+                        prod.span(grammar),
+                        format!(
+                            "{kind} conflict on {} for production '{}'",
+                            token(conflict),
+                            prod.to_string(grammar)
+                        ),
+                    );
+                }
+            }
+        }
+        let shift_reduce_len = conflicts
+            .iter()
+            .filter(|c| matches!(c.kind, ConflictKind::ShiftReduce(..)))
+            .count();
+        diagnostic
+            .with_note(format!(
+                "{} conflict(s). {} Shift/Reduce and {} Reduce/Reduce.",
+                conflicts.len(),
+                shift_reduce_len,
+                conflicts.len() - shift_reduce_len
+            ))
+            .with_help(
+                "Resolve the conflicts using priorities and associativities or use GLR parsing.",
+            )
     }
 
     /// Maximal number of actions per state/token. For LR can't be >1.
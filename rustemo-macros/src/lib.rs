@@ -0,0 +1,298 @@
+//! Procedural macros for Rustemo.
+//!
+//! The [grammar!] macro generates a parser from a grammar given inline in the
+//! Rust source. This is handy for small embedded languages and tests where a
+//! separate `.rustemo` file and a build script would be an overkill.
+//!
+//! The macro is not re-exported by `rustemo` as `rustemo-compiler`, used to
+//! generate the parser, depends on `rustemo`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! rustemo_macros::grammar!(calc, r#"
+//!     E: E '+' E {left}
+//!      | Number;
+//!
+//!     terminals
+//!     Plus: '+';
+//!     Number: /\d+/;
+//! "#);
+//!
+//! fn main() {
+//!     use rustemo::Parser;
+//!     let result = calc::CalcParser::new().parse("1 + 2").unwrap();
+//!     println!("{result:#?}");
+//! }
+//! ```
+#![forbid(unsafe_code)]
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use rustemo::SourceSpan;
+use rustemo_compiler::{Error, Settings};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Attribute, Ident, LitStr, Token, Visibility,
+};
+
+/// Generates a parser module from the given grammar.
+///
+/// The first argument is the name of the parser module optionally preceded by
+/// attributes and visibility, the same as in `rustemo_mod!`. The second
+/// argument is a string literal, raw or regular, with the grammar. A grammar
+/// can't be given as tokens as regexes and multi-character string matches are
+/// not valid Rust tokens. The parser is generated with the default settings.
+///
+/// For the default builder, the actions module is generated beside the parser
+/// module with the `_actions` suffix (e.g. `calc_actions`) and contains the
+/// AST types.
+///
+/// Grammar errors and LR conflicts are reported as compile errors at their
+/// location in the grammar literal.
+///
+/// As the parser and actions modules refer to each other through `super`, the
+/// macro must be used at the module level and not inside a function body.
+#[proc_macro]
+pub fn grammar(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(parse_macro_input!(input as GrammarInput)).into()
+}
+
+struct GrammarInput {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    grammar: LitStr,
+}
+
+impl Parse for GrammarInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let grammar = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            grammar,
+        })
+    }
+}
+
+fn expand(input: GrammarInput) -> TokenStream {
+    // Error reports end up in compiler messages where colors are not supported.
+    yansi::disable();
+
+    let GrammarInput {
+        attrs,
+        vis,
+        name,
+        grammar,
+    } = input;
+    let source = match Settings::new()
+        .silence_warnings(true)
+        .process_grammar_str(&name.to_string(), &grammar.value())
+    {
+        Ok(source) => source,
+        Err(error) => return compile_error(&grammar, &error),
+    };
+
+    let parser: TokenStream = source
+        .parser
+        .parse()
+        .expect("Generated parser must be valid Rust code.");
+    let actions = source.actions.map(|actions| {
+        let actions_mod = format_ident!("{}_actions", name);
+        let actions: TokenStream = actions
+            .parse()
+            .expect("Generated actions must be valid Rust code.");
+        quote! {
+            #[allow(dead_code)]
+            #vis mod #actions_mod {
+                #actions
+            }
+        }
+    });
+
+    quote! {
+        #(#attrs)*
+        #[allow(dead_code)]
+        #vis mod #name {
+            #parser
+        }
+        #actions
+    }
+}
+
+/// Reports the error as a compile error. Each grammar diagnostic is reported
+/// separately at its location.
+fn compile_error(grammar: &LitStr, error: &Error) -> TokenStream {
+    let errors = match error {
+        Error::Diagnostics(diagnostics) => diagnostics
+            .iter()
+            .map(|d| syn::Error::new(error_span(grammar, d.report.span), d.to_locfile_str()))
+            .collect(),
+        Error::RustemoError(rustemo_error) => {
+            let span = match &**rustemo_error {
+                rustemo::Error::ParseError(error) => error.span,
+                _ => None,
+            };
+            vec![syn::Error::new(
+                error_span(grammar, span),
+                error.to_locfile_str(),
+            )]
+        }
+        _ => vec![syn::Error::new(grammar.span(), error.to_locfile_str())],
+    };
+    errors
+        .into_iter()
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+        .expect("There is at least one error.")
+        .to_compile_error()
+}
+
+/// The span of the error location inside the grammar literal if the compiler
+/// supports it. Otherwise, the span of the whole literal.
+fn error_span(grammar: &LitStr, span: Option<SourceSpan>) -> Span {
+    let literal = grammar.token();
+    span.and_then(|span| {
+        let offsets = literal_offsets(&literal.to_string())?;
+        let start = *offsets.get(span.start.pos)?;
+        let end = *offsets.get(span.end.pos.max(span.start.pos + 1).min(offsets.len() - 1))?;
+        literal.subspan(start..end)
+    })
+    .unwrap_or_else(|| grammar.span())
+}
+
+/// Maps each byte offset in the value of the given string literal, and the
+/// offset after the value, to the byte offset in the literal source. Escape
+/// sequences are decoded the same way as by the Rust compiler.
+fn literal_offsets(literal: &str) -> Option<Vec<usize>> {
+    let start = literal.find('"')? + 1;
+    let end = literal.rfind('"')?;
+    if literal.starts_with('r') {
+        // Raw strings have no escapes.
+        return Some((start..=end).collect());
+    }
+    let mut offsets = vec![];
+    let mut chars = literal[..end].char_indices().skip(1).peekable();
+    while let Some((pos, c)) = chars.next() {
+        let value = if c == '\\' {
+            match chars.next()?.1 {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                '\\' => '\\',
+                '\'' => '\'',
+                '"' => '"',
+                'x' => {
+                    let code = [chars.next()?.1, chars.next()?.1]
+                        .iter()
+                        .collect::<String>();
+                    char::from(u8::from_str_radix(&code, 16).ok()?)
+                }
+                'u' => {
+                    chars.next().filter(|(_, c)| *c == '{')?;
+                    let code = chars
+                        .by_ref()
+                        .map(|(_, c)| c)
+                        .take_while(|c| *c != '}')
+                        .filter(|c| *c != '_')
+                        .collect::<String>();
+                    char::from_u32(u32::from_str_radix(&code, 16).ok()?)?
+                }
+                '\n' => {
+                    // Line continuation skips the newline and the leading
+                    // whitespace of the next line.
+                    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                    continue;
+                }
+                _ => return None,
+            }
+        } else {
+            c
+        };
+        offsets.extend(std::iter::repeat_n(pos, value.len_utf8()));
+    }
+    offsets.push(end);
+    Some(offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{expand, literal_offsets, GrammarInput};
+
+    fn expand_str(input: proc_macro2::TokenStream) -> String {
+        expand(syn::parse2::<GrammarInput>(input).unwrap()).to_string()
+    }
+
+    #[test]
+    fn grammar_expansion() {
+        let result = expand_str(quote! {
+            pub calc, r"
+            E: E '+' E {left} | Number;
+            terminals
+            Plus: '+';
+            Number: /\d+/;
+            "
+        });
+        assert!(result.starts_with("# [allow (dead_code)] pub mod calc {"));
+        assert!(result.contains("pub mod calc_actions {"));
+        assert!(result.contains("pub struct CalcParser"));
+    }
+
+    #[test]
+    fn grammar_syntax_error() {
+        let result = expand_str(quote! {
+            calc, r"
+            E: E '+' E {left} | Number
+            terminals
+            "
+        });
+        assert!(result.starts_with("compile_error !"));
+        assert!(result.contains("Error at <str>:74(4,12)"));
+    }
+
+    #[test]
+    fn grammar_conflicts() {
+        let result = expand_str(quote! {
+            calc, r"
+            E: E '+' E | Number;
+            terminals
+            Plus: '+';
+            Number: /\d+/;
+            "
+        });
+        assert!(result.starts_with("compile_error !"));
+        assert!(result.contains("Grammar is not deterministic. There are conflicts."));
+        assert!(result.contains("1 conflict(s). 1 Shift/Reduce and 0 Reduce/Reduce."));
+    }
+
+    #[test]
+    fn grammar_literal_offsets() {
+        assert_eq!(literal_offsets(r#"r"ab""#), Some(vec![2, 3, 4]));
+        assert_eq!(literal_offsets(r##"r#"ab"#"##), Some(vec![3, 4, 5]));
+        assert_eq!(literal_offsets(r#""a\nb""#), Some(vec![1, 2, 4, 5]));
+        assert_eq!(literal_offsets(r#""\x41\"""#), Some(vec![1, 5, 7]));
+        // Two bytes of `é` map to the same escape.
+        assert_eq!(literal_offsets(r#""\u{e9}x""#), Some(vec![1, 1, 7, 8]));
+        assert_eq!(literal_offsets("\"a\\\n   b\""), Some(vec![1, 7, 8]));
+    }
+
+    #[test]
+    fn grammar_syntax_error_escaped() {
+        let result = expand_str(quote! {
+            calc, "E: E '+' E {left} | Number\n terminals\n"
+        });
+        assert!(result.starts_with("compile_error !"));
+        assert!(result.contains("Error at <str>:38(3,0)"));
+    }
+}
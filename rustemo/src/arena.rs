@@ -0,0 +1,173 @@
+//! Typed index arena used by the default builder to allocate recursive AST
+//! values. Values are stored in a `Vec` and referenced by `Id` handles instead
+//! of being individually boxed.
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+/// A handle of a value of type `T` allocated in an [`Arena<T>`].
+pub struct Id<T> {
+    index: usize,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    /// The index of the value in the arena.
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+// Implemented manually to avoid bounds on `T`.
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state)
+    }
+}
+
+impl<T> Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Id({})", self.index)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Id<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.index.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Id<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        usize::deserialize(deserializer).map(|index| Id {
+            index,
+            _type: PhantomData,
+        })
+    }
+}
+
+/// An arena of values of type `T` referenced by [`Id<T>`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arena<T> {
+    values: Vec<T>,
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self { values: vec![] }
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the value to the arena and returns its handle.
+    pub fn alloc(&mut self, value: T) -> Id<T> {
+        self.values.push(value);
+        Id {
+            index: self.values.len() - 1,
+            _type: PhantomData,
+        }
+    }
+
+    pub fn get(&self, id: Id<T>) -> Option<&T> {
+        self.values.get(id.index)
+    }
+
+    pub fn get_mut(&mut self, id: Id<T>) -> Option<&mut T> {
+        self.values.get_mut(id.index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over the handles and the values in the order of allocation.
+    pub fn iter(&self) -> impl Iterator<Item = (Id<T>, &T)> {
+        self.values.iter().enumerate().map(|(index, value)| {
+            (
+                Id {
+                    index,
+                    _type: PhantomData,
+                },
+                value,
+            )
+        })
+    }
+}
+
+impl<T> Index<Id<T>> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, id: Id<T>) -> &Self::Output {
+        &self.values[id.index]
+    }
+}
+
+impl<T> IndexMut<Id<T>> for Arena<T> {
+    fn index_mut(&mut self, id: Id<T>) -> &mut Self::Output {
+        &mut self.values[id.index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arena;
+
+    #[test]
+    fn arena_alloc() {
+        let mut arena = Arena::new();
+        let first = arena.alloc(String::from("first"));
+        let second = arena.alloc(String::from("second"));
+        assert_ne!(first, second);
+        assert_eq!(arena[first], "first");
+        arena[second].push('!');
+        assert_eq!(arena.get(second).unwrap(), "second!");
+        assert_eq!(arena.len(), 2);
+        assert_eq!(
+            arena.iter().map(|(id, _)| id.index()).collect::<Vec<_>>(),
+            [0, 1]
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{input::Input, lexer::Token, parser::State, position::SourceSpan, Position};
+use crate::{
+    input::Input,
+    lexer::{Indentation, LexicalModes, Token},
+    parser::State,
+    position::SourceSpan,
+    Position,
+};
 
 /// Lexer/Parser context is used to keep the state. It provides necessary
 /// information to parsers and actions.
@@ -24,4 +30,19 @@ pub trait Context<'i, I: Input + ?Sized, S: State, TK>: Default {
     /// A layout before the token ahead
     fn layout_ahead(&self) -> Option<&'i I>;
     fn set_layout_ahead(&mut self, layout: Option<&'i I>);
+
+    /// Indentation state. Contexts which don't track indentation return `None`.
+    fn indentation(&self) -> Option<&Indentation> {
+        None
+    }
+    /// Mutable indentation state used by lexers producing indent/dedent tokens.
+    fn indentation_mut(&mut self) -> Option<&mut Indentation> {
+        None
+    }
+
+    /// The stack of lexical modes used by lexers with lexical modes. Contexts
+    /// which don't track lexical modes return `None`.
+    fn lexical_modes_mut(&mut self) -> Option<&mut LexicalModes> {
+        None
+    }
 }
@@ -0,0 +1,309 @@
+//! Grammar coverage recorded by parsers.
+//!
+//! Parsers given a [`Coverage`] by `set_coverage` record the LR states and
+//! actions visited, the terminals shifted and the productions reduced across
+//! all parses. Reports map the recorded indexes back to the grammar using the
+//! [`CoverageInfo`] generated in the parser module when coverage is enabled.
+use std::{cell::RefCell, collections::BTreeMap, fmt::Write, rc::Rc};
+
+use crate::lr::parser::Action;
+
+/// Records coverage for the action taken in the given state with the given
+/// lookahead.
+pub(crate) type Recorder<S, P, TK> = Rc<dyn Fn(S, TK, Action<S, P>)>;
+
+pub(crate) fn recorder<S, P, TK>(coverage: Rc<RefCell<Coverage>>) -> Recorder<S, P, TK>
+where
+    S: Into<usize> + 'static,
+    P: Into<usize> + 'static,
+    TK: Into<usize> + 'static,
+{
+    Rc::new(move |state, token_kind, action| {
+        let mut coverage = coverage.borrow_mut();
+        let (state, token_kind) = (state.into(), token_kind.into());
+        match action {
+            Action::Shift(_) => coverage.shift(state, token_kind),
+            Action::Reduce(prod, _) => coverage.reduce(state, token_kind, prod.into()),
+            Action::Accept => coverage.visit(state, token_kind),
+            Action::Error => (),
+        }
+    })
+}
+
+/// Grammar information used to map recorded coverage to the grammar.
+#[derive(Debug)]
+pub struct CoverageInfo {
+    /// The path of the grammar file.
+    pub grammar_file: &'static str,
+    /// The number of LR states.
+    pub states: usize,
+    /// The number of state and lookahead pairs with actions.
+    pub actions: usize,
+    /// Terminals indexed by token kind.
+    pub terminals: &'static [CoverageItem],
+    /// Productions indexed by production kind.
+    pub productions: &'static [CoverageItem],
+}
+
+/// A terminal or a production in the grammar.
+#[derive(Debug)]
+pub struct CoverageItem {
+    /// The name of the terminal or the rule of the production.
+    pub name: &'static str,
+    /// The terminal name or the production text.
+    pub text: &'static str,
+    /// The line in the grammar file, zero if unknown.
+    pub line: usize,
+}
+
+/// Coverage recorded across parses.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    states: BTreeMap<usize, usize>,
+    actions: BTreeMap<(usize, usize), usize>,
+    terminals: BTreeMap<usize, usize>,
+    productions: BTreeMap<usize, usize>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A new coverage shared between parsers and the code making reports.
+    pub fn shared() -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self::new()))
+    }
+
+    fn visit(&mut self, state: usize, token_kind: usize) {
+        *self.states.entry(state).or_default() += 1;
+        *self.actions.entry((state, token_kind)).or_default() += 1;
+    }
+
+    pub(crate) fn shift(&mut self, state: usize, token_kind: usize) {
+        self.visit(state, token_kind);
+        *self.terminals.entry(token_kind).or_default() += 1;
+    }
+
+    pub(crate) fn reduce(&mut self, state: usize, token_kind: usize, prod: usize) {
+        self.visit(state, token_kind);
+        *self.productions.entry(prod).or_default() += 1;
+    }
+
+    /// How many times an action was taken in the given state.
+    pub fn state_hits(&self, state: usize) -> usize {
+        self.states.get(&state).copied().unwrap_or_default()
+    }
+
+    /// How many times an action was taken in the given state for the given
+    /// lookahead.
+    pub fn action_hits(&self, state: usize, token_kind: usize) -> usize {
+        self.actions
+            .get(&(state, token_kind))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// How many times the given terminal was shifted.
+    pub fn terminal_hits(&self, token_kind: usize) -> usize {
+        self.terminals.get(&token_kind).copied().unwrap_or_default()
+    }
+
+    /// How many times the given production was reduced.
+    pub fn production_hits(&self, prod: usize) -> usize {
+        self.productions.get(&prod).copied().unwrap_or_default()
+    }
+
+    /// Adds the coverage recorded by another parser.
+    pub fn merge(&mut self, other: &Coverage) {
+        fn merge<K: Ord + Copy>(to: &mut BTreeMap<K, usize>, from: &BTreeMap<K, usize>) {
+            for (key, hits) in from {
+                *to.entry(*key).or_default() += hits;
+            }
+        }
+        merge(&mut self.states, &other.states);
+        merge(&mut self.actions, &other.actions);
+        merge(&mut self.terminals, &other.terminals);
+        merge(&mut self.productions, &other.productions);
+    }
+
+    /// A human readable report listing the hits of each production and
+    /// terminal with its location in the grammar file.
+    pub fn report(&self, info: &CoverageInfo) -> String {
+        let mut out = String::new();
+        let file = std::path::Path::new(info.grammar_file)
+            .file_name()
+            .map_or(info.grammar_file.into(), |f| f.to_string_lossy());
+        let mut section = |title: &str, items: &[CoverageItem], hits: &dyn Fn(usize) -> usize| {
+            let covered = (0..items.len()).filter(|&idx| hits(idx) > 0).count();
+            writeln!(out, "{title}: {}", ratio(covered, items.len())).unwrap();
+            for (idx, item) in items.iter().enumerate() {
+                writeln!(
+                    out,
+                    "  {:>6}  {file}:{:<5} {}",
+                    hits(idx),
+                    item.line,
+                    item.text
+                )
+                .unwrap();
+            }
+        };
+        section("Productions", info.productions, &|idx| {
+            self.production_hits(idx)
+        });
+        section("Terminals", info.terminals, &|idx| self.terminal_hits(idx));
+        writeln!(out, "States: {}", ratio(self.states.len(), info.states)).unwrap();
+        writeln!(out, "Actions: {}", ratio(self.actions.len(), info.actions)).unwrap();
+        out
+    }
+
+    /// A report in the lcov tracefile format. Rules are reported as functions,
+    /// their productions as branches and the lines of productions and terminals
+    /// as lines.
+    pub fn lcov(&self, info: &CoverageInfo) -> String {
+        let mut out = String::new();
+        writeln!(out, "TN:").unwrap();
+        writeln!(out, "SF:{}", info.grammar_file).unwrap();
+
+        // Rules in the order of their first production.
+        let mut rules: Vec<(&str, usize, usize)> = vec![];
+        for (idx, prod) in info.productions.iter().enumerate() {
+            let hits = self.production_hits(idx);
+            match rules.iter_mut().find(|(name, ..)| *name == prod.name) {
+                Some(rule) => rule.2 += hits,
+                None => rules.push((prod.name, prod.line, hits)),
+            }
+        }
+        for (name, line, _) in &rules {
+            writeln!(out, "FN:{line},{name}").unwrap();
+        }
+        for (name, _, hits) in &rules {
+            writeln!(out, "FNDA:{hits},{name}").unwrap();
+        }
+        writeln!(out, "FNF:{}", rules.len()).unwrap();
+        writeln!(
+            out,
+            "FNH:{}",
+            rules.iter().filter(|(.., hits)| *hits > 0).count()
+        )
+        .unwrap();
+
+        let mut branches = 0;
+        for (idx, prod) in info.productions.iter().enumerate() {
+            let block = rules
+                .iter()
+                .position(|(name, ..)| *name == prod.name)
+                .unwrap();
+            let hits = self.production_hits(idx);
+            writeln!(out, "BRDA:{},{block},{idx},{hits}", prod.line).unwrap();
+            branches += usize::from(hits > 0);
+        }
+        writeln!(out, "BRF:{}", info.productions.len()).unwrap();
+        writeln!(out, "BRH:{branches}").unwrap();
+
+        let mut lines: BTreeMap<usize, usize> = BTreeMap::new();
+        let items = info
+            .productions
+            .iter()
+            .enumerate()
+            .map(|(idx, prod)| (prod.line, self.production_hits(idx)))
+            .chain(
+                info.terminals
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, term)| (term.line, self.terminal_hits(idx))),
+            );
+        for (line, hits) in items.filter(|&(line, _)| line > 0) {
+            *lines.entry(line).or_default() += hits;
+        }
+        for (line, hits) in &lines {
+            writeln!(out, "DA:{line},{hits}").unwrap();
+        }
+        writeln!(out, "LF:{}", lines.len()).unwrap();
+        writeln!(out, "LH:{}", lines.values().filter(|&&h| h > 0).count()).unwrap();
+        writeln!(out, "end_of_record").unwrap();
+        out
+    }
+}
+
+fn ratio(covered: usize, total: usize) -> String {
+    if total == 0 {
+        format!("{covered}/{total}")
+    } else {
+        format!(
+            "{covered}/{total} ({:.1}%)",
+            covered as f64 * 100.0 / total as f64
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Coverage, CoverageInfo, CoverageItem};
+
+    static INFO: CoverageInfo = CoverageInfo {
+        grammar_file: "/grammars/calc.rustemo",
+        states: 4,
+        actions: 6,
+        terminals: &[
+            CoverageItem {
+                name: "STOP",
+                text: "STOP",
+                line: 0,
+            },
+            CoverageItem {
+                name: "Number",
+                text: "Number",
+                line: 4,
+            },
+            CoverageItem {
+                name: "Plus",
+                text: "Plus",
+                line: 5,
+            },
+        ],
+        productions: &[
+            CoverageItem {
+                name: "E",
+                text: "E: E Plus E",
+                line: 1,
+            },
+            CoverageItem {
+                name: "E",
+                text: "E: Number",
+                line: 1,
+            },
+        ],
+    };
+
+    #[test]
+    fn coverage_report() {
+        let mut coverage = Coverage::new();
+        coverage.shift(0, 1);
+        coverage.reduce(1, 0, 1);
+        let mut other = Coverage::new();
+        other.shift(0, 1);
+        coverage.merge(&other);
+
+        assert_eq!(coverage.terminal_hits(1), 2);
+        assert_eq!(coverage.action_hits(0, 1), 2);
+        assert_eq!(
+            coverage.report(&INFO),
+            "Productions: 1/2 (50.0%)\n\
+             \x20      0  calc.rustemo:1     E: E Plus E\n\
+             \x20      1  calc.rustemo:1     E: Number\n\
+             Terminals: 1/3 (33.3%)\n\
+             \x20      0  calc.rustemo:0     STOP\n\
+             \x20      2  calc.rustemo:4     Number\n\
+             \x20      0  calc.rustemo:5     Plus\n\
+             States: 2/4 (50.0%)\n\
+             Actions: 2/6 (33.3%)\n"
+        );
+        assert_eq!(
+            coverage.lcov(&INFO),
+            "TN:\nSF:/grammars/calc.rustemo\nFN:1,E\nFNDA:1,E\nFNF:1\nFNH:1\n\
+             BRDA:1,0,0,0\nBRDA:1,0,1,1\nBRF:2\nBRH:1\n\
+             DA:1,1\nDA:4,2\nDA:5,0\nLF:3\nLH:2\nend_of_record\n"
+        );
+    }
+}
@@ -6,8 +6,20 @@ use yansi::{Paint, Style};
 pub type Result<R> = std::result::Result<R, Error>;
 
 /// Error type returned in `Err` variant of `Result` type from the parser.
+///
+/// Besides the main `message` and `span`, the error keeps structured
+/// information: token kinds expected at the error location, the input found
+/// there, additional labelled spans, notes, and a help text. Diagnostics of
+/// other tools (e.g. the grammar compiler) may also carry a code and a
+/// warning severity. The error can be rendered for the terminal (`Display`) or as a JSON diagnostic
+/// ([`ParseError::to_json`]).
+///
+/// New fields may be added to the error so it can't be created by a struct
+/// expression outside of this crate. Use [`ParseError::new`] and the `with_*`
+/// methods instead.
 // ANCHOR: parser-error
-#[derive(Debug, thiserror::Error)]
+#[derive(Default, thiserror::Error)]
+#[non_exhaustive]
 pub struct ParseError {
     pub message: String,
 
@@ -16,6 +28,238 @@ pub struct ParseError {
 
     pub file: Option<String>,
     pub span: Option<SourceSpan>,
+
+    /// Names of the token kinds expected at the error location.
+    pub expected: Vec<String>,
+
+    /// A part of the input found at the error location. `None` at the end of
+    /// the input.
+    pub found: Option<String>,
+
+    /// Additional labelled spans (e.g. "opened here").
+    pub labels: Vec<ErrorLabel>,
+
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+
+    /// A stable code of the diagnostic, e.g. `E0003`.
+    pub code: Option<String>,
+
+    pub severity: Severity,
+}
+
+/// Severity of the reported [`ParseError`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// A secondary span with a message attached to [`ParseError`].
+#[derive(Debug, Clone)]
+pub struct ErrorLabel {
+    pub span: SourceSpan,
+    pub message: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    ParseError(Box<ParseError>),
+
+    #[error("{0}")]
+    IOError(#[from] std::io::Error),
+}
+// ANCHOR_END: parser-error
+
+impl ParseError {
+    /// Creates an error with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn with_expected(mut self, expected: Vec<String>) -> Self {
+        self.expected = expected;
+        self
+    }
+
+    pub fn with_found(mut self, found: impl Into<String>) -> Self {
+        self.found = Some(found.into());
+        self
+    }
+
+    /// Adds a secondary label.
+    pub fn with_label(mut self, span: SourceSpan, message: impl Into<String>) -> Self {
+        self.labels.push(ErrorLabel {
+            span,
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// The title of the report, e.g. `Syntax error` or `Warning[W0001]`.
+    fn title(&self) -> String {
+        match (self.severity, &self.code) {
+            (Severity::Error, None) => "Syntax error".to_string(),
+            (Severity::Error, Some(code)) => format!("Error[{code}]"),
+            (Severity::Warning, None) => "Warning".to_string(),
+            (Severity::Warning, Some(code)) => format!("Warning[{code}]"),
+        }
+    }
+
+    /// Renders the error as a JSON object. Useful for editors and other
+    /// tools consuming machine-readable diagnostics.
+    pub fn to_json(&self) -> String {
+        fn span_json(span: &SourceSpan) -> String {
+            let pos_json = |p: &crate::Position| match p.line_col {
+                Some(lc) => format!(
+                    r#"{{"pos":{},"line":{},"column":{}}}"#,
+                    p.pos, lc.line, lc.column
+                ),
+                None => format!(r#"{{"pos":{}}}"#, p.pos),
+            };
+            format!(
+                r#"{{"start":{},"end":{}}}"#,
+                pos_json(&span.start),
+                pos_json(&span.end)
+            )
+        }
+        let opt = |v: Option<String>| v.unwrap_or_else(|| "null".into());
+        format!(
+            concat!(
+                r#"{{"severity":"{}",{}"message":{},"file":{},"span":{},"#,
+                r#""expected":[{}],"found":{},"labels":[{}],"notes":[{}],"help":{}}}"#
+            ),
+            match self.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            self.code
+                .as_deref()
+                .map(|code| format!(r#""code":{},"#, json_str(code)))
+                .unwrap_or_default(),
+            json_str(&self.message),
+            opt(self.file.as_deref().map(json_str)),
+            opt(self.span.as_ref().map(span_json)),
+            self.expected
+                .iter()
+                .map(|e| json_str(e))
+                .collect::<Vec<_>>()
+                .join(","),
+            opt(self.found.as_deref().map(json_str)),
+            self.labels
+                .iter()
+                .map(|l| format!(
+                    r#"{{"span":{},"message":{}}}"#,
+                    span_json(&l.span),
+                    json_str(&l.message)
+                ))
+                .collect::<Vec<_>>()
+                .join(","),
+            self.notes
+                .iter()
+                .map(|n| json_str(n))
+                .collect::<Vec<_>>()
+                .join(","),
+            opt(self.help.as_deref().map(json_str)),
+        )
+    }
+}
+
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Structured parts are shown only if present to keep the output terse.
+        let mut d = f.debug_struct("ParseError");
+        d.field("message", &self.message)
+            .field("src", &self.src)
+            .field("file", &self.file)
+            .field("span", &self.span);
+        if !self.expected.is_empty() {
+            d.field("expected", &self.expected);
+        }
+        if let Some(found) = &self.found {
+            d.field("found", found);
+        }
+        if !self.labels.is_empty() {
+            d.field("labels", &self.labels);
+        }
+        if !self.notes.is_empty() {
+            d.field("notes", &self.notes);
+        }
+        if let Some(help) = &self.help {
+            d.field("help", help);
+        }
+        if let Some(code) = &self.code {
+            d.field("code", code);
+        }
+        if self.severity != Severity::Error {
+            d.field("severity", &self.severity);
+        }
+        d.finish()
+    }
+}
+
+/// Quotes and escapes the given string for JSON output. Terminal styling
+/// (ANSI escape sequences) is removed.
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => {
+                // Skip CSI sequence, e.g. `ESC[1;33m`.
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
 
 fn with_style(d: &impl Display, style: Style) -> String {
@@ -24,7 +268,7 @@ fn with_style(d: &impl Display, style: Style) -> String {
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut loc_str = String::from("Syntax error");
+        let mut loc_str = self.title();
         if self.file.is_some() || self.span.is_some() {
             loc_str.push_str(" at ");
         }
@@ -37,53 +281,85 @@ impl Display for ParseError {
         if let Some(span) = self.span {
             loc_str.push_str(&format!("{span:?}"));
         }
-        if let (Some(src), Some(span)) = (self.src.as_ref(), self.span) {
-            let idx = LineIndex::new(src);
-            let mut prev_empty = false;
+        let label_text = &self.message;
+        let idx = self.src.as_deref().map(LineIndex::new);
+        let mut prev_empty = false;
+        let block = match (&idx, self.span) {
+            (Some(idx), Some(span)) => {
+                let style = |style: Style| move |s| with_style(&s, style);
 
-            let style = |style: Style| move |s| with_style(&s, style);
+                // Labels must be ordered and must not overlap. Secondary labels
+                // overlapping the primary one or each other are skipped.
+                let overlaps = |other: &SourceSpan| {
+                    other.start.pos <= span.end.pos && span.start.pos <= other.end.pos
+                };
+                let mut labels = vec![(span, label_text.as_str(), WARN)];
+                for label in self.labels.iter().filter(|label| !overlaps(&label.span)) {
+                    labels.push((label.span, label.message.as_str(), LOG));
+                }
+                labels.sort_by_key(|(span, _, _)| span.start.pos);
+                let mut last_end = None;
+                labels.retain(|(span, _, _)| {
+                    let keep = last_end.is_none_or(|end| span.start.pos > end);
+                    if keep {
+                        last_end = Some(span.end.pos);
+                    }
+                    keep
+                });
 
-            let block = Block::new(
-                &idx,
-                [Label::new(span.start.pos..span.end.pos)
-                    .with_text(&self.message)
-                    .with_style(style(WARN))],
-            )
-            .unwrap()
-            .map_code(|s| {
-                let sub = usize::from(core::mem::replace(&mut prev_empty, s.is_empty()));
-                let s = s.replace('\t', "    ");
-                let w = unicode_width::UnicodeWidthStr::width(&*s);
-                CodeWidth::new(s, core::cmp::max(w, 1) - sub)
-            });
+                // If the labels can't be rendered (e.g. a span outside of the
+                // input) the plain message is given below.
+                Block::new(
+                    idx,
+                    labels.into_iter().map(|(span, text, st)| {
+                        Label::new(span.start.pos..span.end.pos)
+                            .with_text(text)
+                            .with_style(style(st))
+                    }),
+                )
+                .map(|block| {
+                    block.map_code(|s| {
+                        let sub = usize::from(core::mem::replace(&mut prev_empty, s.is_empty()));
+                        let s = s.replace('\t', "    ");
+                        let w = unicode_width::UnicodeWidthStr::width(&*s);
+                        CodeWidth::new(s, core::cmp::max(w, 1) - sub)
+                    })
+                })
+            }
+            _ => None,
+        };
+        if let Some(block) = block {
             write!(
                 f,
                 "\n{} {}\n{block}{}\n",
                 block.prologue(),
                 loc_str.paint(WARN),
                 block.epilogue()
-            )
+            )?;
         } else {
             write!(
                 f,
                 "{}:\n\t{}",
                 loc_str.paint(WARN),
-                self.message.replace('\n', "\n\t")
-            )
+                label_text.replace('\n', "\n\t")
+            )?;
+            for label in &self.labels {
+                write!(f, "\n\t{:?}: {}", label.span, label.message)?;
+            }
+            if !self.notes.is_empty() || self.help.is_some() {
+                writeln!(f)?;
+            }
         }
+        for note in &self.notes {
+            writeln!(f, "  = {} {note}", "note:".paint(LOG))?;
+        }
+        if let Some(help) = &self.help {
+            writeln!(f, "  = {} {help}", "help:".paint(LOG))?;
+        }
+        Ok(())
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-pub enum Error {
-    #[error(transparent)]
-    ParseError(Box<ParseError>),
-
-    #[error("{0}")]
-    IOError(#[from] std::io::Error),
-}
-// ANCHOR_END: parser-error
-
 impl Error {
     /// Adds source string to the error.
     pub fn with_source(self, src: String) -> Self {
@@ -104,10 +380,18 @@ impl Error {
                 let ParseError {
                     ref message,
                     ref file,
-                    src: _,
                     span,
+                    ref code,
+                    severity,
+                    ..
                 } = **e;
-                let mut loc_str = String::from("Error");
+                let mut loc_str = String::from(match severity {
+                    Severity::Error => "Error",
+                    Severity::Warning => "Warning",
+                });
+                if let Some(code) = code {
+                    loc_str.push_str(&format!("[{code}]"));
+                }
                 if file.is_some() || span.is_some() {
                     loc_str.push_str(" at ");
                 }
@@ -154,30 +438,59 @@ pub(crate) fn error_expected<'i, I, S, TK, C>(
     file_name: &str,
     context: &C,
     expected: &[TK],
+    display: impl Fn(TK) -> Option<&'static str>,
+    message: Option<&str>,
 ) -> Error
 where
     C: Context<'i, I, S, TK>,
     I: Input + ?Sized,
     S: State,
-    TK: Debug,
+    TK: Debug + Copy,
 {
-    let expected = if expected.len() > 1 {
-        format!(
-            "one of {}",
+    let name = |t: &TK| display(*t).map_or_else(|| format!("{t:?}"), String::from);
+    let position = context.position();
+    let inconsistent_dedent = context
+        .indentation()
+        .is_some_and(|indentation| indentation.is_inconsistent_at(position.pos));
+    let message = match message {
+        _ if inconsistent_dedent => {
+            "Inconsistent dedent. The indentation matches no enclosing indentation level."
+                .to_string()
+        }
+        Some(message) => message.to_string(),
+        None if expected.len() > 1 => format!(
+            "Expected one of {}.",
             expected
                 .iter()
-                .map(|t| format!("{:?}", t.paint(LOG)))
+                .map(|t| name(t).paint(LOG).to_string())
                 .collect::<Vec<_>>()
                 .join(", ")
-        )
-    } else {
-        format!("{:?}", expected[0])
+        ),
+        None => format!("Expected {}.", name(&expected[0])),
     };
+    let src = input.try_to_string();
+    // Found is the rest of the current word or a single character.
+    let found = src.as_ref().and_then(|src| {
+        let rest = src.get(position.pos..)?;
+        let word: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .take(20)
+            .collect();
+        if word.is_empty() {
+            rest.chars().next().map(String::from)
+        } else {
+            Some(word)
+        }
+    });
     Error::ParseError(Box::new(ParseError {
-        message: format!("Expected {expected}."),
+        message,
         file: Some(file_name.to_string()),
-        src: input.try_to_string(),
-        span: Some(context.position().into()),
+        src,
+        span: Some(position.into()),
+        expected: expected.iter().map(|t| format!("{t:?}")).collect(),
+        found,
+        ..Default::default()
     }))
 }
 
@@ -185,27 +498,15 @@ where
 #[macro_export]
 macro_rules! err {
     ($message:expr) => {
-        Result::from($crate::ParseError {
-            message: $message,
-            file: None,
-            src: None,
-            span: None,
-        })
+        $crate::err!($message, None, None)
     };
     ($message:expr, $file:expr) => {
-        Result::from($crate::ParseError {
-            message: $message,
-            file: $file,
-            src: None,
-            span: None,
-        })
-    };
-    ($message:expr, $file:expr, $span:expr) => {
-        Result::from($crate::ParseError {
-            message: $message,
-            file: $file,
-            src: None,
-            span: $span,
-        })
+        $crate::err!($message, $file, None)
     };
+    ($message:expr, $file:expr, $span:expr) => {{
+        let mut error = $crate::ParseError::new($message);
+        error.file = $file;
+        error.span = $span;
+        Result::from(error)
+    }};
 }
@@ -1,5 +1,5 @@
 use std::{
-    cell::RefCell,
+    cell::{Ref, RefCell, RefMut},
     collections::{HashSet, VecDeque},
     fmt::Debug,
     rc::Rc,
@@ -8,19 +8,25 @@ use std::{
 use petgraph::{graph::Edges, prelude::*};
 
 use crate::{
-    context::Context, input::Input, lexer::Token, lr::builder::LRBuilder, parser::State,
-    position::SourceSpan, Position,
+    context::Context,
+    error::Result,
+    input::Input,
+    lexer::{Indentation, LexicalModes, Token},
+    lr::builder::LRBuilder,
+    parser::State,
+    position::SourceSpan,
+    Position,
 };
 
 /// Graph Structured Stack
 ///
 /// Nodes keep information about state while edges keep all alternative
 /// sub-trees constructed by reduction across the edge.
-pub struct GssGraph<'i, I: Input + ?Sized, S, P, TK: Copy>(
-    #[allow(clippy::type_complexity)] Graph<GssHead<'i, I, S, TK>, Rc<Parent<'i, I, P, TK>>>,
+pub struct GssGraph<'i, I: Input + ?Sized, S, P, TK: Copy, U = ()>(
+    #[allow(clippy::type_complexity)] Graph<GssHead<'i, I, S, TK, U>, Rc<Parent<'i, I, P, TK>>>,
 );
 
-impl<I, S, P, TK> Default for GssGraph<'_, I, S, P, TK>
+impl<I, S, P, TK, U> Default for GssGraph<'_, I, S, P, TK, U>
 where
     I: Input + ?Sized,
     TK: Copy,
@@ -30,7 +36,7 @@ where
     }
 }
 
-impl<'i, I, S, P, TK> GssGraph<'i, I, S, P, TK>
+impl<'i, I, S, P, TK, U> GssGraph<'i, I, S, P, TK, U>
 where
     I: Input + ?Sized,
     TK: Copy,
@@ -40,17 +46,17 @@ where
     }
 
     #[inline]
-    pub fn add_head(&mut self, head: GssHead<'i, I, S, TK>) -> NodeIndex {
+    pub fn add_head(&mut self, head: GssHead<'i, I, S, TK, U>) -> NodeIndex {
         self.0.add_node(head)
     }
 
     #[inline]
-    pub fn head(&self, head: NodeIndex) -> &GssHead<'i, I, S, TK> {
+    pub fn head(&self, head: NodeIndex) -> &GssHead<'i, I, S, TK, U> {
         self.0.node_weight(head).expect("Invalid Gss head index!")
     }
 
     #[inline]
-    pub fn head_mut(&mut self, head: NodeIndex) -> &mut GssHead<'i, I, S, TK> {
+    pub fn head_mut(&mut self, head: NodeIndex) -> &mut GssHead<'i, I, S, TK, U> {
         self.0
             .node_weight_mut(head)
             .expect("Invalid Gss head index!")
@@ -125,8 +131,13 @@ where
 /// ambiguity, where a head may be followed by multiple different tokens, is
 /// handled by splitting the head and using the same GLR mechanics for syntax
 /// ambiguity handling. Effectively, we have per-token sub-frontiers.
+///
+/// The `U` type is a user state carried by each head. It is cloned whenever a
+/// head is split or a new head is created by shift/reduce so each parsing
+/// alternative sees its own copy. When two heads are merged (the same LR state
+/// at the same position) the user state of the first head is kept.
 #[derive(Debug)]
-pub struct GssHead<'i, I, S, TK>
+pub struct GssHead<'i, I, S, TK, U = ()>
 where
     I: Input + ?Sized,
 {
@@ -150,13 +161,23 @@ where
     /// Token found ahead of this node. Initially set to `None`.
     /// Finding more than one token at the current position will split the head.
     token_ahead: Option<Token<'i, I, TK>>,
+
+    /// User state forked together with the head.
+    user_state: RefCell<U>,
+
+    /// Indentation state forked together with the head.
+    indentation: Indentation,
+
+    /// Lexical modes forked together with the head.
+    lexical_modes: LexicalModes,
 }
 
-impl<I, S, TK> Clone for GssHead<'_, I, S, TK>
+impl<I, S, TK, U> Clone for GssHead<'_, I, S, TK, U>
 where
     I: Input + ?Sized,
     S: State,
     TK: Copy,
+    U: Clone,
 {
     fn clone(&self) -> Self {
         Self {
@@ -165,12 +186,15 @@ where
             position: self.position,
             span: self.span,
             layout_ahead: self.layout_ahead,
-            token_ahead: self.token_ahead().cloned(),
+            token_ahead: self.token_ahead.clone(),
+            user_state: self.user_state.clone(),
+            indentation: self.indentation.clone(),
+            lexical_modes: self.lexical_modes.clone(),
         }
     }
 }
 
-impl<I: Input + ?Sized, S: Default, TK> Default for GssHead<'_, I, S, TK> {
+impl<I: Input + ?Sized, S: Default, TK, U: Default> Default for GssHead<'_, I, S, TK, U> {
     fn default() -> Self {
         Self {
             state: Default::default(),
@@ -182,15 +206,19 @@ impl<I: Input + ?Sized, S: Default, TK> Default for GssHead<'_, I, S, TK> {
             },
             layout_ahead: Default::default(),
             token_ahead: Default::default(),
+            user_state: Default::default(),
+            indentation: Default::default(),
+            lexical_modes: Default::default(),
         }
     }
 }
 
-impl<'i, I, S, TK> GssHead<'i, I, S, TK>
+impl<'i, I, S, TK, U> GssHead<'i, I, S, TK, U>
 where
     I: Input + ?Sized,
     S: State,
     TK: Copy,
+    U: Default + Clone,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -208,27 +236,70 @@ where
             span,
             layout_ahead,
             token_ahead,
+            user_state: Default::default(),
+            indentation: Default::default(),
+            lexical_modes: Default::default(),
         }
     }
     pub fn with_tok_state(&self, token_ahead: Token<'i, I, TK>, state: S) -> Self {
         Self {
             state,
             token_ahead: Some(token_ahead),
-            ..*self
+            ..self.clone()
         }
     }
     pub fn with_tok(&self, token_ahead: Token<'i, I, TK>) -> Self {
         Self {
             token_ahead: Some(token_ahead),
-            ..*self
+            ..self.clone()
+        }
+    }
+    /// Replaces the user state of this head.
+    pub fn with_user_state(self, user_state: U) -> Self {
+        Self {
+            user_state: RefCell::new(user_state),
+            ..self
+        }
+    }
+
+    /// Takes the user, indentation and lexical modes state from the given
+    /// head. Used for heads created by shifting.
+    pub(crate) fn with_state_of(self, head: &Self) -> Self {
+        Self {
+            user_state: head.user_state.clone(),
+            indentation: head.indentation.clone(),
+            lexical_modes: head.lexical_modes.clone(),
+            ..self
         }
     }
 }
 
-impl<'i, S, I, TK> Context<'i, I, S, TK> for GssHead<'i, I, S, TK>
+impl<I, S, TK, U> GssHead<'_, I, S, TK, U>
+where
+    I: Input + ?Sized,
+{
+    /// Immutable access to the user state of this head.
+    pub fn user_state(&self) -> Ref<'_, U> {
+        self.user_state.borrow()
+    }
+
+    /// Mutable access to the user state of this head. Changes are visible
+    /// only to this head and the heads forked from it afterwards.
+    pub fn user_state_mut(&self) -> RefMut<'_, U> {
+        self.user_state.borrow_mut()
+    }
+
+    /// Consumes the head and returns the user state.
+    pub fn into_user_state(self) -> U {
+        self.user_state.into_inner()
+    }
+}
+
+impl<'i, S, I, TK, U> Context<'i, I, S, TK> for GssHead<'i, I, S, TK, U>
 where
     I: Input + ?Sized,
     S: State,
+    U: Default,
 {
     #[inline]
     fn state(&self) -> S {
@@ -279,6 +350,21 @@ where
     fn set_layout_ahead(&mut self, layout: Option<&'i I>) {
         self.layout_ahead = layout
     }
+
+    #[inline]
+    fn indentation(&self) -> Option<&Indentation> {
+        Some(&self.indentation)
+    }
+
+    #[inline]
+    fn indentation_mut(&mut self) -> Option<&mut Indentation> {
+        Some(&mut self.indentation)
+    }
+
+    #[inline]
+    fn lexical_modes_mut(&mut self) -> Option<&mut LexicalModes> {
+        Some(&mut self.lexical_modes)
+    }
 }
 
 /// A node of the Shared Packed Parse Forest (SPPF) (sub)tree
@@ -594,6 +680,11 @@ where
     }
 
     /// Build an output of the tree using the given builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if semantic actions produce an error. Use [`Tree::try_build`] to
+    /// handle the error.
     pub fn build<B: LRBuilder<'i, I, GssHead<'i, I, S, TK>, S, P, TK>, S>(
         &self,
         builder: &mut B,
@@ -603,8 +694,64 @@ where
         P: Copy,
     {
         let mut context = GssHead::default();
-        self.build_inner(&mut context, builder);
-        builder.get_result()
+        self.build_with_context(&mut context, builder)
+    }
+
+    /// Build an output of the tree using the given builder and context.
+    ///
+    /// Used when the context carries a user state which should be initialized
+    /// before and inspected after the build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if semantic actions produce an error. Use
+    /// [`Tree::try_build_with_context`] to handle the error.
+    pub fn build_with_context<B: LRBuilder<'i, I, C, S, P, TK>, C, S>(
+        &self,
+        context: &mut C,
+        builder: &mut B,
+    ) -> B::Output
+    where
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+        P: Copy,
+    {
+        self.try_build_with_context(context, builder)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Build an output of the tree using the given builder. Returns the error
+    /// produced by semantic actions (e.g. a value of a typed terminal which
+    /// can't be converted).
+    pub fn try_build<B: LRBuilder<'i, I, GssHead<'i, I, S, TK>, S, P, TK>, S>(
+        &self,
+        builder: &mut B,
+    ) -> Result<B::Output>
+    where
+        S: State,
+        P: Copy,
+    {
+        let mut context = GssHead::default();
+        self.try_build_with_context(&mut context, builder)
+    }
+
+    /// Build an output of the tree using the given builder and context.
+    /// Returns the error produced by semantic actions.
+    pub fn try_build_with_context<B: LRBuilder<'i, I, C, S, P, TK>, C, S>(
+        &self,
+        context: &mut C,
+        builder: &mut B,
+    ) -> Result<B::Output>
+    where
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+        P: Copy,
+    {
+        self.build_inner(context, builder);
+        match builder.take_error() {
+            Some(error) => Err(error),
+            None => Ok(builder.get_result()),
+        }
     }
 
     fn build_inner<B: LRBuilder<'i, I, C, S, P, TK>, C, S>(&self, context: &mut C, builder: &mut B)
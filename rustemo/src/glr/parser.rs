@@ -7,6 +7,7 @@
 
 use crate::{
     context::Context,
+    coverage::{recorder, Coverage, Recorder},
     error::error_expected,
     glr::gss::Parent,
     input::Input,
@@ -95,23 +96,24 @@ where
     }
 }
 
-type Content<'i, L, I, S, TK> =
-    <<L as Lexer<'i, GssHead<'i, I, S, TK>, S, TK>>::Input as ToOwned>::Owned;
+type Content<'i, L, I, S, TK, U> =
+    <<L as Lexer<'i, GssHead<'i, I, S, TK, U>, S, TK>>::Input as ToOwned>::Owned;
 
-type LayoutParser<'i, I, S, P, TK, NTK, D, L> =
-    Option<LRParser<'i, GssHead<'i, I, S, TK>, S, P, TK, NTK, D, L, SliceBuilder<'i, I>, I>>;
+type LayoutParser<'i, I, S, P, TK, NTK, D, L, U> =
+    Option<LRParser<'i, GssHead<'i, I, S, TK, U>, S, P, TK, NTK, D, L, SliceBuilder<'i, I>, I>>;
 
 /// An implementation of Right-Nulled GLR parsing (RNGLR)
 pub struct GlrParser<
     'i,
     S: State,
-    L: Lexer<'i, GssHead<'i, I, S, TK>, S, TK, Input = I>,
+    L: Lexer<'i, GssHead<'i, I, S, TK, U>, S, TK, Input = I>,
     P,
     TK: Default,
     NTK,
     D: ParserDefinition<S, P, TK, NTK> + 'static,
     I: Input + ?Sized,
     B,
+    U: Default = (),
 > {
     /// Parser definition generated by Rustemo
     definition: &'static D,
@@ -120,12 +122,13 @@ pub struct GlrParser<
     file_name: String,
 
     /// The owned input being parsed
-    content: Option<Content<'i, L, I, S, TK>>,
+    content: Option<Content<'i, L, I, S, TK, U>>,
 
     /// Layout parser if there is the Layout rule in the grammar. We keep the
     /// parser in a RefCell as we need it to be mutable during lookaheads
     /// finding.
-    layout_parser: RefCell<LayoutParser<'i, I, S, P, TK, NTK, D, L>>,
+    #[allow(clippy::type_complexity)]
+    layout_parser: RefCell<LayoutParser<'i, I, S, P, TK, NTK, D, L, U>>,
 
     /// Is partial parse allowed, i.e. not requiring that the whole input is
     /// consumed. Use with care in GLR as it can lead to a *huge* number of
@@ -134,18 +137,20 @@ pub struct GlrParser<
     start_position: Position,
     has_layout: bool,
     lexer: Rc<L>,
+    coverage: Option<Recorder<S, P, TK>>,
 
     phantom: PhantomData<(NTK, B)>,
 }
 
-impl<'i, S, L, P, TK, NTK, D, I, B> GlrParser<'i, S, L, P, TK, NTK, D, I, B>
+impl<'i, S, L, P, TK, NTK, D, I, B, U> GlrParser<'i, S, L, P, TK, NTK, D, I, B, U>
 where
     I: Input + ?Sized + Debug,
-    L: Lexer<'i, GssHead<'i, I, S, TK>, S, TK, Input = I>,
+    L: Lexer<'i, GssHead<'i, I, S, TK, U>, S, TK, Input = I>,
     S: State + Ord + Debug,
     D: ParserDefinition<S, P, TK, NTK>,
     TK: Copy + Default + PartialEq + Ord + Debug + 'i,
     P: Copy + Debug + Into<NTK> + PartialEq,
+    U: Default + Clone + Debug,
 {
     pub fn new(definition: &'static D, partial_parse: bool, has_layout: bool, lexer: L) -> Self {
         Self {
@@ -157,14 +162,48 @@ where
             start_position: I::start_position(),
             has_layout,
             lexer: Rc::new(lexer),
+            coverage: None,
             phantom: PhantomData,
         }
     }
 
+    /// Records grammar coverage of all following parses in the given coverage.
+    pub fn set_coverage(&mut self, coverage: Rc<RefCell<Coverage>>)
+    where
+        S: Into<usize> + 'static,
+        P: Into<usize> + 'static,
+        TK: Into<usize> + 'static,
+    {
+        self.coverage = Some(recorder(coverage));
+    }
+
+    /// Actions for the given state and lookahead. Reductions which would stop
+    /// a greedy repetition continuing in the state are dropped so that the
+    /// heads which would stop the repetition earlier are not created.
+    fn actions(&self, state: S, token_kind: TK) -> Vec<Action<S, P>> {
+        let mut actions = self.definition.actions(state, token_kind);
+        actions.retain(|action| match action {
+            Action::Reduce(prod, _) if self.definition.greedy(state, token_kind, *prod) => {
+                log!(
+                    "    {} {:?} {} {:?}. {} {:?}.",
+                    "Greedy repetition continues in state".paint(LOG),
+                    state,
+                    "for token".paint(LOG),
+                    token_kind,
+                    "Dropping reduction by".paint(LOG),
+                    prod
+                );
+                false
+            }
+            _ => true,
+        });
+        actions
+    }
+
     /// Create pending shifts and reduction for the initial frontier.
     fn initial_process_frontier(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         frontier: &BTreeMap<(Position, TK), BTreeMap<S, NodeIndex>>,
         pending_reductions: &mut BTreeMap<(Position, TK), VecDeque<Reduction<P>>>,
         pending_shifts: &mut Vec<(NodeIndex, S)>,
@@ -183,9 +222,8 @@ where
                     "  {}",
                     format!("Processing head {}", head.index()).paint(LOG)
                 );
-                for action in self
-                    .definition
-                    .actions(state, gss.head(*head).token_ahead().as_ref().unwrap().kind)
+                for action in
+                    self.actions(state, gss.head(*head).token_ahead().as_ref().unwrap().kind)
                 {
                     match action {
                         Action::Reduce(prod, length) => {
@@ -256,7 +294,7 @@ where
     /// ambiguities using the same GLR mechanics.
     fn create_frontier(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         frontier_base: &Vec<NodeIndex>,
         input: &'i I,
     ) -> BTreeMap<(Position, TK), BTreeMap<S, NodeIndex>> {
@@ -323,7 +361,7 @@ where
     /// skip whitespaces/comments before recognizing next tokens.
     fn find_lookaheads(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         head: NodeIndex,
         input: &'i I,
     ) -> Vec<Token<'i, I, TK>> {
@@ -413,7 +451,7 @@ where
     /// Used in lexical ambiguity.
     fn head_for_lookahead(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         head_idx: NodeIndex,
         lookahead: Token<'i, I, TK>,
     ) -> NodeIndex {
@@ -460,7 +498,7 @@ where
     /// operation if possible.
     fn reducer(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         pending_reductions: &mut VecDeque<Reduction<P>>,
         pending_shifts: &mut Vec<(NodeIndex, S)>,
         accepted_heads: &mut Vec<NodeIndex>,
@@ -485,6 +523,14 @@ where
                 ReductionStart::Edge(e) => gss.start(e),
                 ReductionStart::Node(n) => n,
             };
+            if let Some(coverage) = &self.coverage {
+                let head = gss.head(start_head);
+                coverage(
+                    head.state(),
+                    head.token_ahead().as_ref().unwrap().kind,
+                    Action::Reduce(production, reduction.length),
+                );
+            }
             log!(
                 "\n{} '{:?}' over {} by len {}",
                 "Reducing by production".paint(LOG),
@@ -500,10 +546,23 @@ where
                 log!("  {} {path}", "Reducing over path:".paint(LOG));
                 let token_kind_ahead = gss.head(start_head).token_ahead().as_ref().unwrap().kind;
                 let root_state = gss.head(path.root_head).state();
+                if let Some(root_token) = gss.head(path.root_head).token_ahead() {
+                    if self
+                        .definition
+                        .greedy_stop(root_state, root_token.kind, production.into())
+                    {
+                        log!(
+                            "    {} {:?}. Skipping.",
+                            "Reduction stops greedy repetition continuing in state".paint(LOG),
+                            root_state
+                        );
+                        continue;
+                    }
+                }
                 let next_state = self.definition.goto(root_state, production.into());
 
                 // Get all non-error actions
-                let actions = self.definition.actions(next_state, token_kind_ahead);
+                let actions = self.actions(next_state, token_kind_ahead);
 
                 if actions.is_empty() {
                     log!(
@@ -709,7 +768,7 @@ where
     /// Do all pending shifts and create the next frontier base.
     fn shifter(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         pending_shifts: &mut Vec<(NodeIndex, S)>,
         frontier_idx: usize,
     ) -> Vec<NodeIndex> {
@@ -730,6 +789,9 @@ where
         while let Some((head_idx, state)) = pending_shifts.pop() {
             let head = gss.head(head_idx);
             let token = head.token_ahead().cloned().unwrap();
+            if let Some(coverage) = &self.coverage {
+                coverage(head.state(), token.kind, Action::Shift(state));
+            }
             let position = token.value.position_after(head.position());
             log!(
                 "{}",
@@ -747,8 +809,12 @@ where
                     (shifted_head_idx, shifted_head.span())
                 }
                 None => {
-                    let new_head =
-                        GssHead::new(state, frontier_idx, position, token.span, None, None);
+                    // The new head inherits the user, indentation and lexical
+                    // modes state of the shifted head.
+                    let mut new_head =
+                        GssHead::new(state, frontier_idx, position, token.span, None, None)
+                            .with_state_of(head);
+                    self.lexer.token_shifted(&mut new_head, token.kind);
                     #[cfg(debug_assertions)]
                     let new_head_str = format!("{new_head:?}");
                     let new_head_span = new_head.span();
@@ -781,7 +847,7 @@ where
     /// backtracing through the GSS for the reduction length.
     fn find_reduction_paths(
         &self,
-        gss: &mut GssGraph<'i, I, S, P, TK>,
+        gss: &mut GssGraph<'i, I, S, P, TK, U>,
         reduction: &Reduction<P>,
     ) -> Vec<ReductionPath<'i, I, P, TK>> {
         log!(
@@ -861,7 +927,7 @@ where
 
     fn create_forest(
         &self,
-        gss: GssGraph<'i, I, S, P, TK>,
+        gss: GssGraph<'i, I, S, P, TK, U>,
         accepted_heads: Vec<NodeIndex>,
     ) -> Forest<'i, I, P, TK>
     where
@@ -888,7 +954,7 @@ where
     /// there are no heads accepted.
     fn make_error(
         &self,
-        gss: GssGraph<'i, I, S, P, TK>,
+        gss: GssGraph<'i, I, S, P, TK, U>,
         input: &I,
         last_frontier_base: Vec<NodeIndex>,
     ) -> Error {
@@ -917,7 +983,18 @@ where
                 .expect("There must be a head in the last frontier!"),
         );
 
-        let error = error_expected(input, &self.file_name, context, &expected);
+        let message = last_frontier_base
+            .iter()
+            .find_map(|&head_idx| self.definition.error_message(gss.head(head_idx).state()));
+
+        let error = error_expected(
+            input,
+            &self.file_name,
+            context,
+            &expected,
+            |t| self.definition.token_kind_display(t),
+            message,
+        );
 
         log!(
             "\n{}. {}",
@@ -928,15 +1005,16 @@ where
     }
 }
 
-impl<'i, I, S, TK, NTK, L, P, D, B> Parser<'i, I, GssHead<'i, I, S, TK>, S, TK>
-    for GlrParser<'i, S, L, P, TK, NTK, D, I, B>
+impl<'i, I, S, TK, NTK, L, P, D, B, U> Parser<'i, I, GssHead<'i, I, S, TK, U>, S, TK>
+    for GlrParser<'i, S, L, P, TK, NTK, D, I, B, U>
 where
     I: Input + ?Sized + Debug,
-    L: Lexer<'i, GssHead<'i, I, S, TK>, S, TK, Input = I>,
+    L: Lexer<'i, GssHead<'i, I, S, TK, U>, S, TK, Input = I>,
     S: State + Debug + Ord,
     P: Copy + Debug + Into<NTK> + PartialEq,
     TK: Copy + Debug + Ord + Default + 'i,
     D: ParserDefinition<S, P, TK, NTK>,
+    U: Default + Clone + Debug,
 {
     type Output = Forest<'i, I, P, TK>;
 
@@ -948,20 +1026,22 @@ where
 
     fn parse_with_context(
         &self,
-        context: &mut GssHead<'i, I, S, TK>,
+        context: &mut GssHead<'i, I, S, TK, U>,
         input: &'i I,
     ) -> Result<Self::Output> {
-        let mut gss: GssGraph<'i, I, S, P, TK> = GssGraph::new();
+        let mut gss: GssGraph<'i, I, S, P, TK, U> = GssGraph::new();
         let start_head = gss.add_head(context.clone());
         if self.has_layout {
-            *self.layout_parser.borrow_mut() = Some(LRParser::new_default(
+            let mut layout_parser = LRParser::new_default(
                 self.definition,
                 S::default_layout().expect("Layout state not defined."),
                 true,
                 false,
                 Rc::clone(&self.lexer),
                 RefCell::new(SliceBuilder::new(input)),
-            ))
+            );
+            layout_parser.set_recorder(self.coverage.clone());
+            *self.layout_parser.borrow_mut() = Some(layout_parser)
         }
 
         log!("{}: {:?}", "Current state".paint(LOG), context.state());
@@ -1032,6 +1112,16 @@ where
         }
 
         if !accepted_heads.is_empty() {
+            if let Some(coverage) = &self.coverage {
+                for &head in &accepted_heads {
+                    let head = gss.head(head);
+                    coverage(
+                        head.state(),
+                        head.token_ahead().as_ref().unwrap().kind,
+                        Action::Accept,
+                    );
+                }
+            }
             // self.success(gss, accepted_heads)
             let forest = self.create_forest(gss, accepted_heads);
             log!(
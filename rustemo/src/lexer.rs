@@ -43,6 +43,11 @@ where
         input: &'i Self::Input,
         expected_tokens: Vec<(TK, bool)>,
     ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK>> + 'i>;
+
+    /// Called by the parser when a token of the given kind is shifted, before
+    /// the next token is looked for. Lexers whose state depends on the
+    /// consumed tokens, like lexical modes, update the context here.
+    fn token_shifted(&self, _context: &mut C, _token_kind: TK) {}
 }
 
 /// The trait implemented by types used to recognize tokens in string inputs.
@@ -53,14 +58,123 @@ pub trait TokenRecognizer<'i> {
     }
 }
 
+/// A function used by [`StringLexer`] to accept or reject a recognized token
+/// based on the current parsing context.
+///
+/// The function is called with the context, the kind of the recognized token
+/// and the recognized part of the input. If it returns `false` the token is
+/// dropped as if the recognizer didn't match. This is the hook used for
+/// context-sensitive lexing (e.g. the C "typedef problem") where the decision
+/// depends on information collected during parsing, usually kept in the user
+/// state of the context.
+pub type TokenFilter<C, TK> = fn(&C, TK, &str) -> bool;
+
 /// A lexer that operates over string inputs and uses generated string and regex
 /// recognizers provided by the parser table.
 pub struct StringLexer<C, S, TK, TR: 'static, const TERMINAL_COUNT: usize> {
     skip_ws: bool,
     token_recognizers: &'static [TR; TERMINAL_COUNT],
+    token_filter: Option<TokenFilter<C, TK>>,
+    indentation_tokens: Option<IndentationTokens<TK>>,
+    token_modes: Option<&'static [TokenMode; TERMINAL_COUNT]>,
     phantom: PhantomData<(C, S, TK)>,
 }
 
+/// Token kinds of the synthetic tokens produced by [`StringLexer`] when
+/// indentation tracking is enabled.
+#[derive(Debug, Clone, Copy)]
+pub struct IndentationTokens<TK> {
+    /// Produced when a line is indented more than the previous one.
+    pub indent: TK,
+    /// Produced for each indentation level closed by a line.
+    pub dedent: TK,
+    /// Produced at the start of each line after the first one and at the end
+    /// of the input, before indent/dedent tokens.
+    pub newline: Option<TK>,
+}
+
+/// The indentation state kept in the parsing context.
+///
+/// Holds the stack of indentation levels (in characters), the position at
+/// which the last newline token was produced and the position of a dedent to a
+/// column which matches no enclosing indentation level.
+#[derive(Debug, Clone, Default)]
+pub struct Indentation {
+    levels: Vec<usize>,
+    newline_at: Option<usize>,
+    inconsistent_at: Option<usize>,
+}
+
+impl Indentation {
+    /// The current indentation level.
+    pub fn level(&self) -> usize {
+        self.levels.last().copied().unwrap_or(0)
+    }
+
+    /// Is the dedent at the given position inconsistent, i.e. to a column
+    /// which matches no enclosing indentation level.
+    pub(crate) fn is_inconsistent_at(&self, pos: usize) -> bool {
+        self.inconsistent_at == Some(pos)
+    }
+}
+
+/// The lexical mode of a token kind and the change of modes done when a token
+/// of the kind is shifted. Used by [`StringLexer`] when lexical modes are
+/// enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenMode {
+    /// The mode in which the token is recognized. `None` for tokens recognized
+    /// in all modes, e.g. `STOP`.
+    pub mode: Option<usize>,
+    pub action: Option<ModeAction>,
+}
+
+/// A change of lexical modes done when a token is shifted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModeAction {
+    /// Enters the given mode keeping the current mode on the stack.
+    Push(usize),
+    /// Returns to the mode the current mode was entered from.
+    Pop,
+}
+
+/// The stack of lexical modes kept in the parsing context.
+///
+/// The initial mode, with the index `0`, is at the bottom of the stack and is
+/// never popped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LexicalModes {
+    stack: Vec<usize>,
+}
+
+impl LexicalModes {
+    /// The current lexical mode.
+    pub fn current(&self) -> usize {
+        self.stack.last().copied().unwrap_or(0)
+    }
+
+    /// Enters the given mode.
+    pub fn push(&mut self, mode: usize) {
+        self.stack.push(mode)
+    }
+
+    /// Returns to the previous mode. Returns the mode left or `None` in the
+    /// initial mode.
+    pub fn pop(&mut self) -> Option<usize> {
+        self.stack.pop()
+    }
+
+    /// Applies the given change of modes.
+    pub fn apply(&mut self, action: ModeAction) {
+        match action {
+            ModeAction::Push(mode) => self.push(mode),
+            ModeAction::Pop => {
+                self.pop();
+            }
+        }
+    }
+}
+
 impl<
         'i,
         C: Context<'i, str, S, TK>,
@@ -74,10 +188,94 @@ impl<
         Self {
             skip_ws,
             token_recognizers,
+            token_filter: None,
+            indentation_tokens: None,
+            token_modes: None,
             phantom: PhantomData,
         }
     }
 
+    /// Enables indentation tracking. See [`IndentationTokens`].
+    ///
+    /// At the start of each line the lexer compares the line indentation with
+    /// the indentation stack kept in the context and, instead of the regular
+    /// tokens, produces zero-length newline, indent and dedent tokens.
+    pub fn with_indentation(mut self, indentation_tokens: IndentationTokens<TK>) -> Self {
+        self.indentation_tokens = Some(indentation_tokens);
+        self
+    }
+
+    /// Enables lexical modes. See [`TokenMode`].
+    ///
+    /// Only tokens of the current mode, kept in the context, are recognized,
+    /// in combination with the tokens expected by the parser. Whitespaces are
+    /// skipped and indentation tokens are produced only in the initial mode.
+    pub fn with_modes(mut self, token_modes: &'static [TokenMode; TERMINAL_COUNT]) -> Self {
+        self.token_modes = Some(token_modes);
+        self
+    }
+
+    /// Sets a filter called for each recognized token. See [`TokenFilter`].
+    pub fn with_token_filter(mut self, token_filter: TokenFilter<C, TK>) -> Self {
+        self.token_filter = Some(token_filter);
+        self
+    }
+
+    /// Produces a synthetic indentation token if the current position is at
+    /// the start of a line or at the end of the input. A dedent to a column
+    /// which matches no enclosing indentation level is an error.
+    fn indentation_token(
+        input: &'i str,
+        context: &mut C,
+        tokens: IndentationTokens<TK>,
+    ) -> Result<Option<Token<'i, str, TK>>, ()>
+    where
+        TK: Copy,
+    {
+        let position = context.position();
+        let pos = position.pos;
+        let at_end = pos == input.len();
+        let line_start = input[..pos].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_prefix = &input[line_start..pos];
+        if !at_end && !line_prefix.chars().all(char::is_whitespace) {
+            return Ok(None);
+        }
+        let column = if at_end {
+            0
+        } else {
+            line_prefix.chars().count()
+        };
+        let has_content = !input[..pos].trim().is_empty();
+        let indentation = context
+            .indentation_mut()
+            .expect("Context doesn't support indentation tracking.");
+        let kind = match tokens.newline {
+            Some(newline) if has_content && indentation.newline_at != Some(pos) => {
+                indentation.newline_at = Some(pos);
+                newline
+            }
+            _ if column > indentation.level() => {
+                indentation.levels.push(column);
+                tokens.indent
+            }
+            _ if column < indentation.level() => {
+                if column > 0 && !indentation.levels.contains(&column) {
+                    indentation.inconsistent_at = Some(pos);
+                    return Err(());
+                }
+                indentation.levels.pop();
+                tokens.dedent
+            }
+            _ => return Ok(None),
+        };
+        let value = &input[pos..pos];
+        Ok(Some(Token {
+            kind,
+            value,
+            span: value.span_from(position),
+        }))
+    }
+
     fn skip(input: &'i str, context: &mut C) {
         let skipped_len: usize = input[context.position().pos..]
             .chars()
@@ -163,7 +361,25 @@ where
         input: &'i Self::Input,
         expected_tokens: Vec<(TK, bool)>,
     ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK>> + 'i> {
-        if self.skip_ws {
+        let mode = match self.token_modes {
+            Some(_) => context
+                .lexical_modes_mut()
+                .expect("Context doesn't support lexical modes.")
+                .current(),
+            None => 0,
+        };
+        let expected_tokens = match self.token_modes {
+            Some(token_modes) => {
+                log!("  {} {}", "Lexical mode:".paint(LOG), mode);
+                expected_tokens
+                    .into_iter()
+                    .filter(|tok| token_modes[tok.0.into()].mode.is_none_or(|m| m == mode))
+                    .collect()
+            }
+            None => expected_tokens,
+        };
+
+        if self.skip_ws && mode == 0 {
             Self::skip(input, context);
         }
         log!(
@@ -172,14 +388,68 @@ where
             expected_tokens
         );
 
-        Box::new(TokenIterator::new(
-            input,
-            context.position(),
-            expected_tokens
-                .iter()
-                .map(|&tok| (&self.token_recognizers[tok.0.into()], tok.0, tok.1))
-                .collect::<Vec<_>>(),
-        ))
+        if let (Some(indentation_tokens), 0) = (self.indentation_tokens, mode) {
+            match Self::indentation_token(input, context, indentation_tokens) {
+                Ok(Some(token)) => {
+                    log!("\t{} {:?}", "Indentation token:".paint(LOG), token.kind);
+                    return Box::new(std::iter::once(token));
+                }
+                Ok(None) => {}
+                Err(()) => {
+                    log!("\t{}", "Inconsistent dedent.".paint(LOG));
+                    return Box::new(std::iter::empty());
+                }
+            }
+        }
+
+        let recognizers = expected_tokens
+            .iter()
+            .map(|&tok| (&self.token_recognizers[tok.0.into()], tok.0, tok.1))
+            .collect::<Vec<_>>();
+
+        match self.token_filter {
+            None => Box::new(TokenIterator::new(input, context.position(), recognizers)),
+            Some(token_filter) => {
+                // The filter needs the context so the tokens are collected
+                // eagerly. Rejected tokens don't finish the recognition.
+                let position = context.position();
+                let mut tokens = vec![];
+                for (recognizer, token_kind, finish) in recognizers {
+                    if let Some(recognized) = recognizer.recognize(&input[position.pos..]) {
+                        if token_filter(context, token_kind, recognized) {
+                            tokens.push(Token {
+                                kind: token_kind,
+                                value: recognized,
+                                span: recognized.span_from(position),
+                            });
+                            if finish {
+                                break;
+                            }
+                        } else {
+                            log!(
+                                "\t{} {:?}",
+                                "Token rejected by filter:".paint(LOG),
+                                token_kind
+                            );
+                        }
+                    }
+                }
+                Box::new(tokens.into_iter())
+            }
+        }
+    }
+
+    fn token_shifted(&self, context: &mut C, token_kind: TK) {
+        if let Some(action) = self
+            .token_modes
+            .and_then(|token_modes| token_modes[token_kind.into()].action)
+        {
+            log!("\t{} {:?}", "Lexical mode change:".paint(LOG), action);
+            context
+                .lexical_modes_mut()
+                .expect("Context doesn't support lexical modes.")
+                .apply(action);
+        }
     }
 }
 
@@ -10,34 +10,45 @@ mod common;
 pub mod debug;
 pub mod colors;
 
+mod arena;
 mod builder;
 mod context;
+mod coverage;
 mod error;
 mod input;
 mod lexer;
 mod parser;
 mod position;
+mod unparse;
 mod utils;
+mod value;
 
 #[cfg(feature = "glr")]
 mod glr;
 mod lr;
 
 // Public API
+pub use crate::arena::{Arena, Id};
 pub use crate::context::Context;
+pub use crate::coverage::{Coverage, CoverageInfo, CoverageItem};
 pub use crate::error::Result;
-pub use crate::error::{Error, ParseError};
+pub use crate::error::{Error, ErrorLabel, ParseError, Severity};
 pub use crate::input::Input;
-pub use crate::position::{LineColumn, Position, SourceSpan, ValSpan};
+pub use crate::position::{LineColumn, LineIndex, Position, SourceSpan, Spanned, ValSpan};
 
 pub use crate::builder::Builder;
-pub use crate::lexer::{Lexer, StringLexer, Token, TokenRecognizer};
+pub use crate::lexer::{
+    Indentation, IndentationTokens, Lexer, LexicalModes, ModeAction, StringLexer, Token,
+    TokenFilter, TokenMode, TokenRecognizer,
+};
 pub use crate::lr::{
     builder::{LRBuilder, SliceBuilder, TreeBuilder, TreeNode},
     context::LRContext,
-    parser::{Action, LRParser, ParserDefinition},
+    parser::{Action, Delimiter, LRParser, ParserDefinition},
 };
 pub use crate::parser::{Parser, State};
+pub use crate::unparse::{Unparse, Unparser};
+pub use crate::value::{token_value_error, unescape};
 
 #[cfg(feature = "glr")]
 pub use crate::glr::{
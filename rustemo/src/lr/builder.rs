@@ -1,5 +1,5 @@
 use crate::{
-    builder::Builder, context::Context, input::Input, lexer::Token, parser::State,
+    builder::Builder, context::Context, error::Error, input::Input, lexer::Token, parser::State,
     position::SourceSpan,
 };
 use core::fmt::Debug;
@@ -31,6 +31,13 @@ where
     /// * `prod_len` - A RHS length, used to pop appropriate number of
     ///   subresults from the stack
     fn reduce_action(&mut self, context: &C, prod: P, prod_len: usize);
+
+    /// Returns the error produced by the semantic actions, if any. The parser
+    /// checks for the error after each action and stops with the returned
+    /// error.
+    fn take_error(&mut self) -> Option<Error> {
+        None
+    }
 }
 
 /// TreeBuilder is a builder that builds a generic concrete parse tree.
@@ -1,10 +1,23 @@
+use std::cell::{Ref, RefCell, RefMut};
+
 use crate::{
-    context::Context, input::Input, lexer::Token, parser::State, position::SourceSpan, Position,
+    context::Context,
+    input::Input,
+    lexer::{Indentation, LexicalModes, Token},
+    parser::State,
+    position::SourceSpan,
+    Position,
 };
 
 /// [`Context`] implementation for LR parsing
+///
+/// # Generic types
+///
+/// - `U` - user state type. A value of this type is kept in the context and
+///   can be used by lexers, recognizers and semantic actions to share
+///   information during parsing (e.g. a symbol table). Defaults to `()`.
 #[derive(Debug)]
-pub struct LRContext<'i, I: Input + ?Sized, S, TK> {
+pub struct LRContext<'i, I: Input + ?Sized, S, TK, U = ()> {
     position: Position,
 
     /// The span of token/non-terminal during shift/reduce operation.
@@ -16,16 +29,31 @@ pub struct LRContext<'i, I: Input + ?Sized, S, TK> {
     token_ahead: Option<Token<'i, I, TK>>,
 
     state: S,
+
+    /// User provided state. Kept in a `RefCell` as semantic actions get only
+    /// a shared reference to the context.
+    user_state: RefCell<U>,
+
+    indentation: Indentation,
+
+    lexical_modes: LexicalModes,
 }
 
-impl<I: Input + ?Sized, S: Default, TK> Default for LRContext<'_, I, S, TK> {
+impl<I: Input + ?Sized, S: Default, TK, U: Default> Default for LRContext<'_, I, S, TK, U> {
     fn default() -> Self {
         Self::new(I::start_position())
     }
 }
 
-impl<I: Input + ?Sized, S: Default, TK> LRContext<'_, I, S, TK> {
+impl<I: Input + ?Sized, S: Default, TK, U: Default> LRContext<'_, I, S, TK, U> {
     pub fn new(position: Position) -> Self {
+        Self::new_with_user_state(position, U::default())
+    }
+}
+
+impl<I: Input + ?Sized, S: Default, TK, U> LRContext<'_, I, S, TK, U> {
+    /// Creates a context at the given position with the initial user state.
+    pub fn new_with_user_state(position: Position, user_state: U) -> Self {
         Self {
             position,
             span: SourceSpan {
@@ -35,14 +63,39 @@ impl<I: Input + ?Sized, S: Default, TK> LRContext<'_, I, S, TK> {
             layout_ahead: None,
             token_ahead: None,
             state: S::default(),
+            user_state: RefCell::new(user_state),
+            indentation: Indentation::default(),
+            lexical_modes: LexicalModes::default(),
         }
     }
 }
 
-impl<'i, I, S, TK> Context<'i, I, S, TK> for LRContext<'i, I, S, TK>
+impl<I: Input + ?Sized, S, TK, U> LRContext<'_, I, S, TK, U> {
+    /// A shared reference to the user state.
+    #[inline]
+    pub fn user_state(&self) -> Ref<'_, U> {
+        self.user_state.borrow()
+    }
+
+    /// A mutable reference to the user state.
+    ///
+    /// Panics if the state is already borrowed.
+    #[inline]
+    pub fn user_state_mut(&self) -> RefMut<'_, U> {
+        self.user_state.borrow_mut()
+    }
+
+    /// Consumes the context and returns the user state.
+    pub fn into_user_state(self) -> U {
+        self.user_state.into_inner()
+    }
+}
+
+impl<'i, I, S, TK, U> Context<'i, I, S, TK> for LRContext<'i, I, S, TK, U>
 where
     I: Input + ?Sized,
     S: State,
+    U: Default,
 {
     #[inline]
     fn state(&self) -> S {
@@ -93,4 +146,19 @@ where
     fn set_layout_ahead(&mut self, layout: Option<&'i I>) {
         self.layout_ahead = layout
     }
+
+    #[inline]
+    fn indentation(&self) -> Option<&Indentation> {
+        Some(&self.indentation)
+    }
+
+    #[inline]
+    fn indentation_mut(&mut self) -> Option<&mut Indentation> {
+        Some(&mut self.indentation)
+    }
+
+    #[inline]
+    fn lexical_modes_mut(&mut self) -> Option<&mut LexicalModes> {
+        Some(&mut self.lexical_modes)
+    }
 }
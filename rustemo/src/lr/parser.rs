@@ -1,5 +1,6 @@
 use crate::context::Context;
-use crate::error::{error_expected, Result};
+use crate::coverage::{recorder, Coverage, Recorder};
+use crate::error::{error_expected, Error, Result};
 use crate::input::Input;
 use crate::lexer::{Lexer, Token};
 use crate::lr::builder::SliceBuilder;
@@ -27,6 +28,39 @@ pub trait ParserDefinition<S, P, TK, NTK> {
     fn expected_token_kinds(&self, state: S) -> Vec<(TK, bool)>;
     fn longest_match() -> bool;
     fn grammar_order() -> bool;
+
+    /// A user friendly name of the token kind used in error reports instead
+    /// of the token kind name.
+    fn token_kind_display(&self, _token_kind: TK) -> Option<&'static str> {
+        None
+    }
+
+    /// A custom error message reported when a syntax error occurs in the
+    /// given state.
+    fn error_message(&self, _state: S) -> Option<&'static str> {
+        None
+    }
+
+    /// The delimiter shifted to reach the given state. Used to report
+    /// unclosed delimiters.
+    fn delimiter(&self, _state: S) -> Option<Delimiter<TK>> {
+        None
+    }
+
+    /// True if a greedy repetition continues in the given state with the
+    /// given lookahead and a reduction by the given production would stop it.
+    /// GLR parser drops such reductions.
+    fn greedy(&self, _state: S, _token: TK, _prod: P) -> bool {
+        false
+    }
+
+    /// True if a reduction to the given non-terminal starting in the given
+    /// state, where the given lookahead was seen, would stop a greedy
+    /// repetition continuing with that lookahead. GLR parser drops such
+    /// reductions.
+    fn greedy_stop(&self, _state: S, _token: TK, _nonterm: NTK) -> bool {
+        false
+    }
 }
 
 /// An action executed by the (G)LR Parser during parsing
@@ -38,6 +72,14 @@ pub enum Action<S, P> {
     Error,
 }
 
+/// A bracket shifted by the parser. Both variants hold the token kind of the
+/// closing bracket.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Delimiter<TK> {
+    Open(TK),
+    Close(TK),
+}
+
 struct StackItem<S> {
     state: S,
     span: SourceSpan,
@@ -134,6 +176,7 @@ pub struct LRParser<
     has_layout: bool,
     lexer: Rc<L>,
     builder: RefCell<B>,
+    coverage: Option<Recorder<S, P, TK>>,
     phantom: PhantomData<(P, NTK, I)>,
 }
 
@@ -186,15 +229,75 @@ where
             has_layout,
             lexer,
             builder,
+            coverage: None,
             phantom: PhantomData,
         }
     }
 
+    /// Records grammar coverage of all following parses in the given coverage.
+    pub fn set_coverage(&mut self, coverage: Rc<RefCell<Coverage>>)
+    where
+        S: Into<usize> + 'static,
+        P: Into<usize> + 'static,
+        TK: Into<usize> + 'static,
+    {
+        self.coverage = Some(recorder(coverage));
+    }
+
+    pub(crate) fn set_recorder(&mut self, coverage: Option<Recorder<S, P, TK>>) {
+        self.coverage = coverage;
+    }
+
     #[inline]
     pub fn span_str(&self, file: &str, span: SourceSpan) -> String {
         format!("{}:{:?}", file.to_owned(), span)
     }
 
+    /// Completes the error returned by semantic actions with the file name and
+    /// the source.
+    fn action_error(&self, input: &'i I, error: Error) -> Error {
+        match error {
+            Error::ParseError(mut error) => {
+                error.file.get_or_insert_with(|| self.file_name.clone());
+                if error.src.is_none() {
+                    error.src = input.try_to_string();
+                }
+                Error::ParseError(error)
+            }
+            error => error,
+        }
+    }
+
+    /// Labels the innermost unclosed delimiter on the stack if its closing
+    /// delimiter is expected at the location of the syntax error.
+    fn unclosed_delimiter(&self, error: Error, parse_stack: &ParseStack<S, I, C, TK>) -> Error
+    where
+        TK: PartialEq,
+    {
+        let Error::ParseError(error) = error else {
+            return error;
+        };
+        let expected = self.definition.expected_token_kinds(parse_stack.state());
+        let mut closed = vec![];
+        for item in parse_stack.stack.iter().rev() {
+            match self.definition.delimiter(item.state) {
+                Some(Delimiter::Close(closing)) => closed.push(closing),
+                Some(Delimiter::Open(closing)) if closed.last() == Some(&closing) => {
+                    closed.pop();
+                }
+                Some(Delimiter::Open(closing))
+                    if expected.iter().any(|(kind, _)| *kind == closing) =>
+                {
+                    return Error::ParseError(Box::new(
+                        error.with_label(item.span, "opened here"),
+                    ));
+                }
+                _ => (),
+            }
+        }
+        Error::ParseError(error)
+    }
+
     fn next_token(
         &self,
         input: &'i I,
@@ -275,7 +378,14 @@ where
                         span: context.span(),
                     });
                 } else {
-                    return Err(error_expected(input, &self.file_name, context, &expected));
+                    return Err(error_expected(
+                        input,
+                        &self.file_name,
+                        context,
+                        &expected,
+                        |t| self.definition.token_kind_display(t),
+                        self.definition.error_message(context.state()),
+                    ));
                 }
             }
         }
@@ -313,14 +423,16 @@ where
         // produce the output and it never uses partial parse.
         let layout_parser: LayoutParser<'i, C, S, P, TK, NTK, D, L, I> =
             self.has_layout.then(|| {
-                LRParser::new_default(
+                let mut layout_parser = LRParser::new_default(
                     self.definition,
                     S::default_layout().expect("Layout state not defined."),
                     true,
                     false,
                     Rc::clone(&self.lexer),
                     RefCell::new(SliceBuilder::new(input)),
-                )
+                );
+                layout_parser.set_recorder(self.coverage.clone());
+                layout_parser
             });
 
         log!(
@@ -336,11 +448,19 @@ where
         log!("{}: {:#?}", "Stack".paint(LOG), parse_stack);
         log!("{}: {:?}", "Current state".paint(LOG), state);
 
-        let mut next_token = self.next_token(input, context, &layout_parser)?;
+        // Indentation state before the lookahead is recognized. Lexing is
+        // repeated after each reduction and must start from the same state.
+        let mut indentation = context.indentation_mut().cloned();
+        let mut next_token = self
+            .next_token(input, context, &layout_parser)
+            .map_err(|error| self.unclosed_delimiter(error, &parse_stack))?;
         log!("{}: {:?}", "Token ahead".paint(LOG), &next_token);
 
         loop {
             let action = self.definition.actions(state, next_token.kind)[0];
+            if let Some(coverage) = &self.coverage {
+                coverage(state, next_token.kind, action);
+            }
 
             match action {
                 Action::Shift(state_id) => {
@@ -360,7 +480,12 @@ where
                         &next_token
                     );
                     parse_stack.push_state(context, state);
+                    let token_kind = next_token.kind;
                     builder.shift_action(context, next_token);
+                    if let Some(error) = builder.take_error() {
+                        return Err(self.action_error(input, error));
+                    }
+                    self.lexer.token_shifted(context, token_kind);
 
                     log!(
                         "{} at {:?} [{:?}]:\n{}\n",
@@ -369,7 +494,10 @@ where
                         context.span(),
                         input.context_str(context.position())
                     );
-                    next_token = self.next_token(input, context, &layout_parser)?;
+                    indentation = context.indentation_mut().cloned();
+                    next_token = self
+                        .next_token(input, context, &layout_parser)
+                        .map_err(|error| self.unclosed_delimiter(error, &parse_stack))?;
                     log!("{}: {:?}", "Token ahead".paint(LOG), next_token);
                 }
                 Action::Reduce(prod, prod_len) => {
@@ -386,6 +514,9 @@ where
                     parse_stack.push_state(context, state);
                     log!("{} {:?} -> {:?}", "GOTO".paint(LOG), from_state, state);
                     builder.reduce_action(context, prod, prod_len);
+                    if let Some(error) = builder.take_error() {
+                        return Err(self.action_error(input, error));
+                    }
                     context.set_span(context_span);
 
                     // After the reduction we need to run lexer again as the set
@@ -394,7 +525,14 @@ where
                     // TODO: This should be optimized to prevent repetitions of the same
                     //       tokens recognitions.
                     let layout = context.layout_ahead();
-                    next_token = self.next_token(input, context, &layout_parser)?;
+                    if let (Some(current), Some(saved)) =
+                        (context.indentation_mut(), indentation.as_ref())
+                    {
+                        current.clone_from(saved);
+                    }
+                    next_token = self
+                        .next_token(input, context, &layout_parser)
+                        .map_err(|error| self.unclosed_delimiter(error, &parse_stack))?;
                     context.set_layout_ahead(layout);
                     log!("{}: {:?}", "Token ahead".paint(LOG), next_token);
                 }
@@ -1,18 +1,20 @@
 use std::{
     fmt::{Debug, Display},
-    ops::Deref,
     ops::Range,
+    ops::{Deref, DerefMut},
 };
 
 /// A line-column based location for use where applicable (e.g. plain text).
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineColumn {
     pub line: usize,
     pub column: usize,
 }
 
 /// A position in the input file.
-#[derive(PartialEq, PartialOrd, Ord, Eq, Copy, Clone, Default)]
+#[derive(PartialEq, PartialOrd, Ord, Eq, Hash, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     /// Absolute position in bytes.
     pub pos: usize,
@@ -67,7 +69,8 @@ impl From<usize> for Position {
 ///
 /// The path is kept on the parsing context and there is the method on the
 /// context to produce the display of the location with the full file path.
-#[derive(PartialEq, Eq, Clone, Copy, Default)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SourceSpan {
     /// The start position of the span.
     pub start: Position,
@@ -137,7 +140,8 @@ impl Debug for SourceSpan {
 
 /// Value with span. Used in place of parsed values which need span to report
 /// errors during semantic analysis.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValSpan<T> {
     value: T,
     pub span: Option<SourceSpan>,
@@ -169,6 +173,12 @@ impl<T> Deref for ValSpan<T> {
     }
 }
 
+impl<T> DerefMut for ValSpan<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
 impl From<ValSpan<String>> for String {
     fn from(value: ValSpan<String>) -> Self {
         value.value
@@ -181,7 +191,91 @@ impl<T> ValSpan<T> {
             span: location,
         }
     }
+
+    /// Consumes the wrapper returning the wrapped value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
 }
+/// Values which know their span in the parsed input.
+///
+/// Implemented for the AST types generated by the default builder when
+/// location info is used.
+pub trait Spanned {
+    fn span(&self) -> SourceSpan;
+}
+
+impl Spanned for SourceSpan {
+    fn span(&self) -> SourceSpan {
+        *self
+    }
+}
+
+impl<T> Spanned for ValSpan<T> {
+    fn span(&self) -> SourceSpan {
+        self.span.unwrap_or_default()
+    }
+}
+
+impl<T: Spanned + ?Sized> Spanned for Box<T> {
+    fn span(&self) -> SourceSpan {
+        (**self).span()
+    }
+}
+
+impl<T: Spanned + ?Sized> Spanned for &T {
+    fn span(&self) -> SourceSpan {
+        (**self).span()
+    }
+}
+
+/// Start positions of the lines of a textual input used to map positions to
+/// line-column locations.
+///
+/// Lines are 1-based while columns are 0-based byte offsets from the line
+/// start, the same as in the positions produced by the default lexer.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(input: &str) -> Self {
+        Self {
+            line_starts: std::iter::once(0)
+                .chain(input.match_indices('\n').map(|(idx, _)| idx + 1))
+                .collect(),
+        }
+    }
+
+    /// The number of lines in the input.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The line-column location of the given absolute position.
+    pub fn line_column(&self, pos: usize) -> LineColumn {
+        let line = self.line_starts.partition_point(|&start| start <= pos);
+        LineColumn {
+            line,
+            column: pos - self.line_starts[line - 1],
+        }
+    }
+
+    /// The position with the line-column location.
+    pub fn position(&self, pos: usize) -> Position {
+        Position {
+            pos,
+            line_col: Some(self.line_column(pos)),
+        }
+    }
+
+    /// The span with line-column locations of both ends.
+    pub fn span(&self, span: SourceSpan) -> SourceSpan {
+        SourceSpan::new(self.position(span.start.pos), self.position(span.end.pos))
+    }
+}
+
 macro_rules! from_valspan {
     ($type:ty) => {
         impl From<$crate::position::ValSpan<$type>> for $type {
@@ -208,7 +302,7 @@ from_valspan!(
 
 #[cfg(test)]
 mod tests {
-    use super::{Position, SourceSpan};
+    use super::{LineColumn, LineIndex, Position, SourceSpan, Spanned, ValSpan};
 
     #[test]
     pub fn test_position_linebased() {
@@ -239,4 +333,29 @@ mod tests {
 
         assert_eq!(format!("{r:?}"), "[49(5,15)-70]");
     }
+
+    #[test]
+    pub fn test_line_index() {
+        let index = LineIndex::new("first\nsecond\n\nlast");
+
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line_column(0), LineColumn { line: 1, column: 0 });
+        assert_eq!(index.line_column(5), LineColumn { line: 1, column: 5 });
+        assert_eq!(index.line_column(8), LineColumn { line: 2, column: 2 });
+        assert_eq!(index.line_column(13), LineColumn { line: 3, column: 0 });
+        assert_eq!(index.line_column(16), LineColumn { line: 4, column: 2 });
+        assert_eq!(
+            format!("{:?}", index.span(SourceSpan::from((3, 9)))),
+            "[3(1,3)-9(2,3)]"
+        );
+    }
+
+    #[test]
+    pub fn test_spanned() {
+        let value = ValSpan::new("value", Some(SourceSpan::from((2, 7))));
+
+        assert_eq!(value.span(), SourceSpan::from((2, 7)));
+        assert_eq!(Box::new(value).span(), SourceSpan::from((2, 7)));
+        assert_eq!(ValSpan::from(1).span(), SourceSpan::default());
+    }
 }
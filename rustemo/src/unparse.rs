@@ -0,0 +1,160 @@
+//! Printing AST values back to the source text. The printer for the default
+//! builder AST types is generated by `rustemo-compiler` and collects tokens
+//! in the [`Unparser`].
+use std::fmt::Display;
+
+/// Values which can be printed back to the source text.
+pub trait Unparse {
+    fn unparse(&self, out: &mut Unparser);
+}
+
+type Spacing = Box<dyn Fn(&str, &str) -> Option<String>>;
+
+/// Collects tokens of unparsed values into the output text.
+///
+/// Consecutive tokens are separated by the separator, a single space by
+/// default, unless the spacing function given the previous and the next token
+/// returns other whitespace. Indentation tokens of the grammar start new lines
+/// and change the indentation of the following lines.
+pub struct Unparser {
+    output: String,
+    separator: String,
+    indent: String,
+    spacing: Option<Spacing>,
+    level: usize,
+    /// The last token in the current line.
+    last: Option<String>,
+}
+
+impl Default for Unparser {
+    fn default() -> Self {
+        Self {
+            output: String::new(),
+            separator: " ".into(),
+            indent: "    ".into(),
+            spacing: None,
+            level: 0,
+            last: None,
+        }
+    }
+}
+
+impl Unparser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the separator used between tokens.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the string used for each indentation level.
+    pub fn indentation(mut self, indent: impl Into<String>) -> Self {
+        self.indent = indent.into();
+        self
+    }
+
+    /// Sets the function which, given the previous and the next token, returns
+    /// the whitespace between them. If `None` is returned the separator is
+    /// used.
+    pub fn spacing<F>(mut self, spacing: F) -> Self
+    where
+        F: Fn(&str, &str) -> Option<String> + 'static,
+    {
+        self.spacing = Some(Box::new(spacing));
+        self
+    }
+
+    /// Adds the token to the output.
+    pub fn token(&mut self, token: &str) {
+        if token.is_empty() {
+            return;
+        }
+        match &self.last {
+            Some(last) => {
+                match self
+                    .spacing
+                    .as_ref()
+                    .and_then(|spacing| spacing(last, token))
+                {
+                    Some(whitespace) => self.output.push_str(&whitespace),
+                    None => self.output.push_str(&self.separator),
+                }
+            }
+            None if !self.output.is_empty() => {
+                for _ in 0..self.level {
+                    self.output.push_str(&self.indent);
+                }
+            }
+            None => (),
+        }
+        self.output.push_str(token);
+        self.last = Some(token.into());
+    }
+
+    /// Adds the displayed value as a token, e.g. the value of a terminal.
+    pub fn value<T: Display + ?Sized>(&mut self, value: &T) {
+        self.token(&value.to_string())
+    }
+
+    /// Starts a new line.
+    pub fn newline(&mut self) {
+        if self.last.take().is_some() {
+            self.output.push('\n');
+        }
+    }
+
+    /// Increases the indentation of the following lines.
+    pub fn indent(&mut self) {
+        self.newline();
+        self.level += 1;
+    }
+
+    /// Decreases the indentation of the following lines.
+    pub fn dedent(&mut self) {
+        self.newline();
+        self.level = self.level.saturating_sub(1);
+    }
+
+    /// Returns the output text.
+    pub fn finish(self) -> String {
+        self.output
+    }
+
+    /// Prints the value and returns the output text.
+    pub fn unparse<T: Unparse + ?Sized>(mut self, value: &T) -> String {
+        value.unparse(&mut self);
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Unparser;
+
+    #[test]
+    fn unparser_spacing() {
+        let mut out = Unparser::new()
+            .spacing(|prev, next| (prev == "(" || next == ")" || next == ";").then(String::new));
+        for token in ["print", "(", "a", "+", "1", ")", ";"] {
+            out.token(token);
+        }
+        assert_eq!(out.finish(), "print (a + 1);");
+    }
+
+    #[test]
+    fn unparser_indentation() {
+        let mut out = Unparser::new().indentation("  ");
+        out.token("if");
+        out.token("a:");
+        out.newline();
+        out.indent();
+        out.token("b");
+        out.newline();
+        out.dedent();
+        out.token("c");
+        assert_eq!(out.finish(), "if a:\n  b\nc");
+    }
+}
@@ -0,0 +1,90 @@
+//! Conversions of terminal values used by the generated actions of terminals
+//! with `type` meta-data.
+use std::fmt::Display;
+
+use crate::{context::Context, error::Error, input::Input, parser::State, ParseError};
+
+/// Creates an error for the value of the current token which couldn't be
+/// converted. The error is reported at the span of the token.
+pub fn token_value_error<'i, C, I, S, TK>(context: &C, value: &str, error: impl Display) -> Error
+where
+    C: Context<'i, I, S, TK>,
+    I: Input + ?Sized,
+    S: State,
+{
+    Error::ParseError(Box::new(
+        ParseError::new(format!("Invalid value: {error}."))
+            .with_span(context.span())
+            .with_found(value),
+    ))
+}
+
+/// Unescapes a string literal. The surrounding quotes (single or double) are
+/// removed if present. Supports the usual escapes (`\n`, `\r`, `\t`, `\0`, `\\`,
+/// `\'`, `\"`, `\/`, `\b`, `\f`) and Unicode escapes in the form `\u{1F600}`
+/// or `é`.
+pub fn unescape(value: &str) -> std::result::Result<String, String> {
+    let value = match (value.chars().next(), value.chars().last()) {
+        (Some(start @ ('"' | '\'')), Some(end)) if value.len() > 1 && start == end => {
+            &value[1..value.len() - 1]
+        }
+        _ => value,
+    };
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('0') => '\0',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some(c @ ('\\' | '\'' | '"' | '/')) => c,
+            Some('u') => {
+                let rest = chars.as_str();
+                let (hex, len) = if let Some(braced) = rest.strip_prefix('{') {
+                    let end = braced.find('}').ok_or("unterminated unicode escape")?;
+                    (&braced[..end], end + 2)
+                } else {
+                    (rest.get(..4).ok_or("invalid unicode escape")?, 4)
+                };
+                let code = u32::from_str_radix(hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid unicode escape '\\u{}'", &rest[..len]))?;
+                chars = rest[len..].chars();
+                code
+            }
+            Some(c) => return Err(format!("unknown escape '\\{c}'")),
+            None => return Err("unterminated escape".into()),
+        };
+        result.push(escaped);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unescape;
+
+    #[test]
+    fn unescape_strings() {
+        assert_eq!(unescape(r#""a\tb\n""#).unwrap(), "a\tb\n");
+        assert_eq!(unescape(r"'it\'s'").unwrap(), "it's");
+        assert_eq!(unescape(r#"\"\\\/"#).unwrap(), "\"\\/");
+        assert_eq!(unescape(r"é\u{1F600}x").unwrap(), "é😀x");
+        assert_eq!(unescape("\"").unwrap(), "\"");
+        assert_eq!(unescape(r"\q").unwrap_err(), r"unknown escape '\q'");
+        assert_eq!(
+            unescape(r"\u{110000}").unwrap_err(),
+            r"invalid unicode escape '\u{110000}'"
+        );
+        assert_eq!(unescape(r"\u12").unwrap_err(), "invalid unicode escape");
+        assert_eq!(unescape("a\\").unwrap_err(), "unterminated escape");
+    }
+}
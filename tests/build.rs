@@ -5,8 +5,11 @@ use rustemo_compiler::{BuilderType, LexerType, ParserAlgo, Settings};
 fn main() {
     fn default_settings() -> Settings {
         use rustemo_compiler::GeneratorTableType::*;
+        // ASTs in most tests are compared without location info. Tests of
+        // spans turn it on.
         rustemo_compiler::Settings::new()
             .force(true)
+            .builder_loc_info(false)
             .generator_table_type(if std::env::var("CARGO_FEATURE_ARRAYS").is_ok() {
                 Arrays
             } else {
@@ -20,10 +23,13 @@ fn main() {
     #[allow(clippy::type_complexity)]
     let tests: &[(&str, Box<dyn Fn(Settings) -> Settings>)] = &[
         ("rule_patterns", Box::new(|s| s)),
+        ("sentences", Box::new(|s| s)),
+        ("coverage", Box::new(|s| s.coverage(true))),
         ("sugar", Box::new(|s| s)),
         ("unicode", Box::new(|s| s)),
         ("fancy_regex", Box::new(|s| s.fancy_regex(true))),
         ("errors/syntax_errors", Box::new(|s| s)),
+        ("errors/custom_messages", Box::new(|s| s)),
         ("ambiguity", Box::new(|s| s.prefer_shifts(true))),
         // LR lexical ambiguities
         ("lexical_ambiguity/priorities", Box::new(|s| s)),
@@ -70,7 +76,42 @@ fn main() {
                 s.force(false).actions_in_source_tree()
             }),
         ),
+        (
+            "builder/user_state",
+            Box::new(|s| {
+                s.force(false)
+                    .actions_in_source_tree()
+                    .user_state_type("super::TypeNames".into())
+                    .token_filter("super::type_name_filter".into())
+            }),
+        ),
         ("builder/loc_info", Box::new(|s| s.builder_loc_info(true))),
+        ("builder/typed_terminals", Box::new(|s| s)),
+        (
+            "builder/derives",
+            Box::new(|s| {
+                s.derives(vec!["PartialEq".into(), "Eq".into(), "Hash".into()])
+                    .serde(true)
+            }),
+        ),
+        (
+            "builder/derives_loc_info",
+            Box::new(|s| {
+                s.builder_loc_info(true)
+                    .derives(vec!["PartialEq".into()])
+                    .serde(true)
+            }),
+        ),
+        ("builder/visitor", Box::new(|s| s.visitors(true))),
+        ("builder/arena", Box::new(|s| s.builder_arena(true))),
+        (
+            "builder/unparse",
+            Box::new(|s| s.unparse(true).derives(vec!["PartialEq".into()])),
+        ),
+        (
+            "builder/visitor_loc_info",
+            Box::new(|s| s.builder_loc_info(true).visitors(true)),
+        ),
         // Lexer
         (
             "lexer/custom_lexer",
@@ -81,6 +122,12 @@ fn main() {
                     .actions_in_source_tree()
             }),
         ),
+        (
+            "lexer/token_filter",
+            Box::new(|s| s.token_filter("super::type_name_filter".into())),
+        ),
+        ("lexer/indentation", Box::new(|s| s.indentation(true))),
+        ("lexer/lexical_modes", Box::new(|s| s)),
         // Special
         ("special/lalr_reduce_reduce_conflict", Box::new(|s| s)),
         ("special/nondeterministic_palindromes", Box::new(|s| s)),
@@ -89,6 +136,19 @@ fn main() {
         // GLR
         ("glr/errors", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
         ("glr/forest", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
+        (
+            "glr/coverage",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR).coverage(true)),
+        ),
+        ("glr/greedy", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
+        (
+            "glr/indentation",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR).indentation(true)),
+        ),
+        (
+            "glr/lexical_modes",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR)),
+        ),
         // GLR builders
         (
             "glr/build/basic",
@@ -98,6 +158,20 @@ fn main() {
             "glr/build/loc_info",
             Box::new(|s| s.parser_algo(ParserAlgo::GLR).builder_loc_info(true)),
         ),
+        (
+            "glr/build/typed_terminals",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR)),
+        ),
+        (
+            "glr/build/user_state",
+            Box::new(|s| {
+                s.force(false)
+                    .parser_algo(ParserAlgo::GLR)
+                    .actions_in_source_tree()
+                    .user_state_type("super::TypeNames".into())
+                    .token_filter("super::type_name_filter".into())
+            }),
+        ),
         // GLR lexical ambiguities
         (
             "glr/lexical_ambiguity/priorities",
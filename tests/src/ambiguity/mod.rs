@@ -1,9 +1,12 @@
 use rustemo::{rustemo_mod, Parser};
 use rustemo_compiler::{local_file, output_cmp};
 
+use self::precedence::PrecedenceParser;
 use self::reduce_empty_1::ReduceEmpty1Parser;
 use self::reduce_empty_2::ReduceEmpty2Parser;
 
+rustemo_mod!(precedence, "/src/ambiguity");
+rustemo_mod!(precedence_actions, "/src/ambiguity");
 rustemo_mod!(reduce_empty_1, "/src/ambiguity");
 rustemo_mod!(reduce_empty_1_actions, "/src/ambiguity");
 rustemo_mod!(reduce_empty_2, "/src/ambiguity");
@@ -37,6 +40,12 @@ fn no_assoc_prod_conflicts() {
         rustemo_compiler::process_grammar(local_file!(file!(), "no_prio_assoc_invalid.rustemo"));
     output_cmp!(
         "src/ambiguity/no_prio_assoc_invalid.err",
-        format!("{:#?}", result)
+        result.unwrap_err().to_locfile_str()
     );
 }
+
+#[test]
+fn precedence_block() {
+    let result = PrecedenceParser::new().parse("-2 ^ 3 ^ 2 * 4 + 6 / 3 - 1 - 1");
+    output_cmp!("src/ambiguity/precedence.ast", format!("{:#?}", result));
+}
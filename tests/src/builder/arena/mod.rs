@@ -0,0 +1,40 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+use serial_test::serial;
+
+use self::arena::ArenaParser;
+use self::arena_actions::{AstArena, Expr, Stmt};
+
+rustemo_mod!(arena, "/src/builder/arena");
+rustemo_mod!(arena_actions, "/src/builder/arena");
+
+// ANCHOR: eval
+/// Sums all number constants in the expression following `Id`s through the
+/// arena.
+fn sum(arena: &AstArena, expr: &Expr) -> u32 {
+    match expr {
+        Expr::Add(add) => sum(arena, &arena.expr[add.left]) + sum(arena, &arena.expr[add.right]),
+        Expr::Paren(inner) => sum(arena, &arena.expr[*inner]),
+        Expr::Number(number) => number.parse().unwrap(),
+        Expr::Ident(_) => 0,
+    }
+}
+// ANCHOR_END: eval
+
+#[test]
+#[serial(arena)]
+fn arena() {
+    let (result, arena) = ArenaParser::new()
+        .parse("a = 1 + (2 + b); { c = 3; { } }")
+        .unwrap();
+    output_cmp!(
+        "src/builder/arena/arena.ast",
+        format!("{result:#?}\n{arena:#?}")
+    );
+    let Stmt::Assign(assign) = &result.stmts[0] else {
+        panic!("Assignment expected.")
+    };
+    assert_eq!(sum(&arena, &assign.value), 3);
+    assert_eq!(arena.expr.len(), 5);
+    assert_eq!(arena.stmt1.len(), 1);
+}
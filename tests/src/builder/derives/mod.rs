@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+use serial_test::serial;
+
+use self::derives::DerivesParser;
+use self::derives_actions::{Block, Print};
+
+rustemo_mod!(derives, "/src/builder/derives");
+rustemo_mod!(derives_actions, "/src/builder/derives");
+
+#[test]
+#[serial(derives)]
+fn derives_serde() {
+    let result = DerivesParser::new()
+        .parse("{ a = 1 + b; print a; { print; } }")
+        .unwrap();
+    let json = serde_json::to_string_pretty(&result).unwrap();
+    output_cmp!("src/builder/derives/derives.json", &json);
+
+    let deserialized: Block = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, result);
+    let mut set = HashSet::new();
+    set.insert(result.clone());
+    assert!(set.contains(&deserialized));
+}
+
+#[test]
+#[serial(derives)]
+fn derives_rule_meta() {
+    // Default is derived only for `Print` by the rule meta-data.
+    assert!(Print::default().value.is_none());
+}
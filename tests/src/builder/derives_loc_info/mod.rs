@@ -0,0 +1,22 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+use serial_test::serial;
+
+use self::derives::DerivesParser;
+use self::derives_actions::Block;
+
+rustemo_mod!(derives, "/src/builder/derives_loc_info");
+rustemo_mod!(derives_actions, "/src/builder/derives_loc_info");
+
+#[test]
+#[serial(derives_loc_info)]
+fn derives_loc_info_serde() {
+    let result = DerivesParser::new()
+        .parse("{ a = 1 + b; print a; }")
+        .unwrap();
+    let json = serde_json::to_string_pretty(&result).unwrap();
+    output_cmp!("src/builder/derives_loc_info/derives.json", &json);
+
+    let deserialized: Block = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, result);
+}
@@ -1,8 +1,9 @@
-use rustemo::{rustemo_mod, Parser};
+use rustemo::{rustemo_mod, LineIndex, Parser, Spanned};
 use rustemo_compiler::{local_file, output_cmp};
 use serial_test::serial;
 
 use self::json::JsonParser;
+use self::json_actions::Value;
 
 rustemo_mod!(json, "/src/builder/loc_info");
 rustemo_mod!(json_actions, "/src/builder/loc_info");
@@ -19,3 +20,27 @@ fn loc_info() {
         format!("{:#?}", result)
     );
 }
+
+// ANCHOR: spanned
+#[test]
+#[serial(loc_info)]
+fn loc_info_spanned() {
+    let input = "{\n  \"a\": [1,\n        2],\n  \"b\": null\n}";
+    let result = JsonParser::new().parse(input).unwrap();
+    let Value::Object(members, _) = &result else {
+        panic!("Object expected.")
+    };
+    let spans = members
+        .iter()
+        .flatten()
+        .map(|member| format!("{:?}", member.value.span()))
+        .collect::<Vec<_>>();
+    assert_eq!(spans, ["[9(2,7)-23(3,10)]", "[32(4,7)-36(4,11)]"]);
+
+    // Line-column locations can be computed from absolute positions.
+    let index = LineIndex::new(input);
+    let span = result.span();
+    assert_eq!(index.span((span.start.pos, span.end.pos).into()), span);
+    assert_eq!(index.line_column(span.end.pos).line, 5);
+}
+// ANCHOR_END: spanned
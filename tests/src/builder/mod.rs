@@ -1,4 +1,12 @@
+mod arena;
 mod custom_builder;
+mod derives;
+mod derives_loc_info;
 mod generic_tree;
 mod loc_info;
+mod typed_terminals;
+mod unparse;
 mod use_context;
+mod user_state;
+mod visitor;
+mod visitor_loc_info;
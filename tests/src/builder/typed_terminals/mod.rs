@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::{local_file, output_cmp};
+
+use self::config::ConfigParser;
+
+rustemo_mod!(config, "/src/builder/typed_terminals");
+rustemo_mod!(config_actions, "/src/builder/typed_terminals");
+
+/// A custom type of terminal values given in the grammar.
+#[derive(Debug, Clone)]
+pub struct Percent(pub u8);
+
+impl FromStr for Percent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_end_matches('%').parse() {
+            Ok(value) if value <= 100 => Ok(Percent(value)),
+            _ => Err("percent must be in range 0-100".into()),
+        }
+    }
+}
+
+#[test]
+fn typed_terminals() {
+    let result = ConfigParser::new()
+        .parse(r#"a = 42; b = -1.5; c = true; d = "x\ty\"z"; e = 50%;"#)
+        .unwrap();
+    output_cmp!(
+        local_file!(file!(), "typed_terminals.ast")
+            .to_str()
+            .unwrap(),
+        format!("{result:#?}")
+    );
+    let percent = match &result.entries.unwrap()[4].value {
+        config_actions::Value::Percent(percent) => percent.0,
+        _ => panic!("Percent value expected!"),
+    };
+    assert_eq!(percent, 50);
+}
+
+#[test]
+fn typed_terminals_int_error() {
+    let result = ConfigParser::new().parse("a = 1;\nb = 99999999999999999999;");
+    output_cmp!(
+        local_file!(file!(), "typed_terminals_int.err")
+            .to_str()
+            .unwrap(),
+        result.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn typed_terminals_string_error() {
+    let result = ConfigParser::new().parse(r#"a = "x\qy";"#);
+    output_cmp!(
+        local_file!(file!(), "typed_terminals_string.err")
+            .to_str()
+            .unwrap(),
+        result.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn typed_terminals_custom_type_error() {
+    let result = ConfigParser::new().parse("a = 150%;");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    assert_eq!(error.found.as_deref(), Some("150%"));
+    output_cmp!(
+        local_file!(file!(), "typed_terminals_custom_type.err")
+            .to_str()
+            .unwrap(),
+        error.to_string()
+    );
+}
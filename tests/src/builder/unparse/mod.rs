@@ -0,0 +1,48 @@
+use rustemo::{rustemo_mod, Parser, Unparser};
+use rustemo_compiler::output_cmp;
+
+use self::unparse::UnparseParser;
+
+rustemo_mod!(unparse, "/src/builder/unparse");
+rustemo_mod!(unparse_actions, "/src/builder/unparse");
+
+const INPUT: &str = "fn f(a, b) { x = a+b * (2 + pi); print x, y.z.w; } print;";
+
+// ANCHOR: unparse
+#[test]
+fn unparse_display() {
+    let result = UnparseParser::new().parse(INPUT).unwrap();
+    let printed = result.to_string();
+    output_cmp!("src/builder/unparse/unparse.txt", &printed);
+
+    // The printed text parses to the same AST.
+    assert_eq!(UnparseParser::new().parse(&printed).unwrap(), result);
+}
+
+#[test]
+fn unparse_spacing() {
+    let result = UnparseParser::new().parse(INPUT).unwrap();
+    let printed = Unparser::new()
+        .spacing(|prev, next| match (prev, next) {
+            (_, "," | ";" | ")" | ".") | ("(" | ".", _) => Some(String::new()),
+            (";" | "{", _) => Some("\n".into()),
+            _ => None,
+        })
+        .unparse(&result);
+    output_cmp!("src/builder/unparse/unparse_spacing.txt", &printed);
+    assert_eq!(UnparseParser::new().parse(&printed).unwrap(), result);
+}
+// ANCHOR_END: unparse
+
+#[test]
+fn unparse_modifiers() {
+    let result = UnparseParser::new()
+        .parse("fn g(a, b, c) { print <1, <2, 3>>, x,; } print;")
+        .unwrap();
+    let printed = result.to_string();
+    assert_eq!(
+        printed,
+        "fn g ( a , b , c ) { print < 1 , < 2 , 3 > > , x ; } print ;"
+    );
+    assert_eq!(UnparseParser::new().parse(&printed).unwrap(), result);
+}
@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+
+use rustemo::{rustemo_mod, Input, LRContext, Parser};
+use rustemo_compiler::output_cmp;
+#[allow(dead_code)]
+#[rustfmt::skip]
+mod user_state_actions;
+
+rustemo_mod!(user_state, "/src/builder/user_state");
+
+use self::user_state::{TokenKind, UserStateParser};
+use self::user_state_actions::Ctx;
+
+// ANCHOR: user_state
+/// Type names declared so far.
+pub type TypeNames = BTreeSet<String>;
+
+/// Accepts `TypeName` only for builtin types and the types declared by
+/// `typedef`.
+fn type_name_filter(ctx: &Ctx<'_>, token_kind: TokenKind, value: &str) -> bool {
+    match token_kind {
+        TokenKind::TypeName => value == "int" || ctx.user_state().contains(value),
+        _ => true,
+    }
+}
+// ANCHOR_END: user_state
+
+#[test]
+fn user_state() {
+    let result = UserStateParser::new().parse("int a; typedef int my; b; my c; d;");
+    output_cmp!(
+        "src/builder/user_state/user_state.ast",
+        format!("{:#?}", result)
+    );
+}
+
+#[test]
+fn user_state_with_context() {
+    let mut context =
+        LRContext::new_with_user_state(str::start_position(), TypeNames::from(["known".into()]));
+    let result = UserStateParser::new()
+        .parse_with_context(&mut context, "known a; typedef known my; my b;");
+    assert!(result.is_ok());
+    assert_eq!(
+        context.into_user_state(),
+        TypeNames::from(["known".into(), "my".into()])
+    );
+}
@@ -0,0 +1,68 @@
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Token as RustemoToken;
+use super::user_state::{TokenKind, Context};
+pub type Input = str;
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type TypeName = String;
+pub fn type_name(_ctx: &Ctx, token: Token) -> TypeName {
+    token.value.into()
+}
+pub type Name = String;
+pub fn name(_ctx: &Ctx, token: Token) -> Name {
+    token.value.into()
+}
+pub type Unit = Stmt1;
+pub fn unit_stmt1(_ctx: &Ctx, stmt1: Stmt1) -> Unit {
+    stmt1
+}
+pub type Stmt1 = Vec<Stmt>;
+pub fn stmt1_c1(_ctx: &Ctx, mut stmt1: Stmt1, stmt: Stmt) -> Stmt1 {
+    stmt1.push(stmt);
+    stmt1
+}
+pub fn stmt1_stmt(_ctx: &Ctx, stmt: Stmt) -> Stmt1 {
+    vec![stmt]
+}
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Typedef(Typedef),
+    Decl(Decl),
+    Expr(Expr),
+}
+pub fn stmt_typedef(_ctx: &Ctx, typedef: Typedef) -> Stmt {
+    Stmt::Typedef(typedef)
+}
+pub fn stmt_decl(_ctx: &Ctx, decl: Decl) -> Stmt {
+    Stmt::Decl(decl)
+}
+pub fn stmt_expr(_ctx: &Ctx, expr: Expr) -> Stmt {
+    Stmt::Expr(expr)
+}
+#[derive(Debug, Clone)]
+pub struct Typedef {
+    pub type_name: TypeName,
+    pub new_type: NewType,
+}
+pub fn typedef_c1(_ctx: &Ctx, type_name: TypeName, new_type: NewType) -> Typedef {
+    Typedef { type_name, new_type }
+}
+pub type NewType = Name;
+pub fn new_type_name(ctx: &Ctx, name: Name) -> NewType {
+    ctx.user_state_mut().insert(name.clone());
+    name
+}
+#[derive(Debug, Clone)]
+pub struct Decl {
+    pub type_name: TypeName,
+    pub name: Name,
+}
+pub fn decl_c1(_ctx: &Ctx, type_name: TypeName, name: Name) -> Decl {
+    Decl { type_name, name }
+}
+pub type Expr = Name;
+pub fn expr_name(_ctx: &Ctx, name: Name) -> Expr {
+    name
+}
@@ -0,0 +1,94 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+use serial_test::serial;
+
+use self::visitor::{
+    visitor::{fold_expr, walk_block, Fold, Visitor, VisitorMut},
+    VisitorParser,
+};
+use self::visitor_actions::{Block, Expr, Ident};
+
+rustemo_mod!(
+    #[allow(clippy::module_inception)]
+    visitor,
+    "/src/builder/visitor"
+);
+rustemo_mod!(visitor_actions, "/src/builder/visitor");
+
+const INPUT: &str = "{ a = 1 + 2; print a + b; { skip c = 3 + 4 + a; } print; }";
+
+/// Collects names of referenced variables and counts nested blocks.
+#[derive(Default)]
+struct Names {
+    names: Vec<String>,
+    blocks: usize,
+}
+
+impl Visitor for Names {
+    fn visit_block(&mut self, node: &Block) {
+        self.blocks += 1;
+        walk_block(self, node)
+    }
+
+    fn visit_ident(&mut self, node: &Ident) {
+        self.names.push(node.clone());
+    }
+}
+
+struct Uppercase;
+
+impl VisitorMut for Uppercase {
+    fn visit_ident_mut(&mut self, node: &mut Ident) {
+        *node = node.to_uppercase();
+    }
+}
+
+// ANCHOR: fold
+/// Folds additions of number constants.
+struct ConstFold;
+
+impl Fold for ConstFold {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        match fold_expr(self, node) {
+            Expr::Add(add) => match (&*add.left, &*add.right) {
+                (Expr::Number(left), Expr::Number(right)) => Expr::Number(
+                    (left.parse::<u32>().unwrap() + right.parse::<u32>().unwrap()).to_string(),
+                ),
+                _ => Expr::Add(add),
+            },
+            node => node,
+        }
+    }
+}
+// ANCHOR_END: fold
+
+#[test]
+#[serial(visitor)]
+fn visitor() {
+    let result = VisitorParser::new().parse(INPUT).unwrap();
+    let mut names = Names::default();
+    names.visit_block(&result);
+    assert_eq!(names.names, ["a", "a", "b", "c", "a"]);
+    assert_eq!(names.blocks, 2);
+}
+
+#[test]
+#[serial(visitor)]
+fn visitor_mut() {
+    let mut result = VisitorParser::new().parse(INPUT).unwrap();
+    Uppercase.visit_block_mut(&mut result);
+    let mut names = Names::default();
+    names.visit_block(&result);
+    assert_eq!(names.names, ["A", "A", "B", "C", "A"]);
+}
+
+#[test]
+#[serial(visitor)]
+fn fold() {
+    let result = VisitorParser::new().parse(INPUT).unwrap();
+    let result = ConstFold.fold_block(result);
+    output_cmp!(
+        "src/builder/visitor/visitor_fold.ast",
+        format!("{result:#?}")
+    );
+}
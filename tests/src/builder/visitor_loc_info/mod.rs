@@ -0,0 +1,84 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+use serial_test::serial;
+
+use self::visitor::{
+    visitor::{fold_expr, Fold, Visitor, VisitorMut},
+    VisitorParser,
+};
+use self::visitor_actions::{Expr, Ident, Number};
+
+rustemo_mod!(
+    #[allow(clippy::module_inception)]
+    visitor,
+    "/src/builder/visitor_loc_info"
+);
+rustemo_mod!(visitor_actions, "/src/builder/visitor_loc_info");
+
+const INPUT: &str = "{ a = 1 + 2; print a + b; }";
+
+#[derive(Default)]
+struct Spans(Vec<String>);
+
+impl Visitor for Spans {
+    fn visit_ident(&mut self, node: &Ident) {
+        self.0.push(format!("{node}@{:?}", node.span.unwrap()));
+    }
+}
+
+struct Uppercase;
+
+impl VisitorMut for Uppercase {
+    fn visit_ident_mut(&mut self, node: &mut Ident) {
+        *node = Ident::new(node.to_uppercase(), node.span);
+    }
+}
+
+/// Folds additions of number constants keeping the span of the addition.
+struct ConstFold;
+
+impl Fold for ConstFold {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        match fold_expr(self, node) {
+            Expr::Add(add) => match (&*add.left, &*add.right) {
+                (Expr::Number(left, _), Expr::Number(right, _)) => Expr::Number(
+                    Number::new(
+                        (left.parse::<u32>().unwrap() + right.parse::<u32>().unwrap()).to_string(),
+                        Some(add.span),
+                    ),
+                    add.span,
+                ),
+                _ => Expr::Add(add),
+            },
+            node => node,
+        }
+    }
+}
+
+#[test]
+#[serial(visitor_loc_info)]
+fn visitor_loc_info() {
+    let mut result = VisitorParser::new().parse(INPUT).unwrap();
+    Uppercase.visit_block_mut(&mut result);
+    let mut spans = Spans::default();
+    spans.visit_block(&result);
+    assert_eq!(
+        spans.0,
+        [
+            "A@[2(1,2)-3(1,3)]",
+            "A@[19(1,19)-20(1,20)]",
+            "B@[23(1,23)-24(1,24)]"
+        ]
+    );
+}
+
+#[test]
+#[serial(visitor_loc_info)]
+fn fold_loc_info() {
+    let result = VisitorParser::new().parse(INPUT).unwrap();
+    let result = ConstFold.fold_block(result);
+    output_cmp!(
+        "src/builder/visitor_loc_info/visitor_fold.ast",
+        format!("{result:#?}")
+    );
+}
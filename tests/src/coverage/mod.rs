@@ -0,0 +1,58 @@
+use rustemo::{rustemo_mod, Coverage, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::coverage::{CoverageParser, COVERAGE_INFO};
+
+rustemo_mod!(coverage, "/src/coverage");
+rustemo_mod!(coverage_actions, "/src/coverage");
+
+// ANCHOR: coverage
+#[test]
+fn coverage_report() {
+    let coverage = Coverage::shared();
+    let parser = CoverageParser::new().with_coverage(coverage.clone());
+    for input in ["a = 1 + 2;", "b = a * 3; c = a + b * 2;"] {
+        parser.parse(input).unwrap();
+    }
+    output_cmp!(
+        "src/coverage/coverage.report",
+        coverage.borrow().report(&COVERAGE_INFO)
+    );
+}
+// ANCHOR_END: coverage
+
+#[test]
+fn coverage_lcov() {
+    let coverage = Coverage::shared();
+    let parser = CoverageParser::new().with_coverage(coverage.clone());
+    parser.parse("print (x + 1) * 2;").unwrap();
+    output_cmp!(
+        "src/coverage/coverage.lcov",
+        coverage
+            .borrow()
+            .lcov(&COVERAGE_INFO)
+            .replace(COVERAGE_INFO.grammar_file, "coverage.rustemo")
+    );
+}
+
+#[test]
+fn coverage_merge() {
+    let (first, second) = (Coverage::shared(), Coverage::shared());
+    CoverageParser::new()
+        .with_coverage(first.clone())
+        .parse("a = 1;")
+        .unwrap();
+    CoverageParser::new()
+        .with_coverage(second.clone())
+        .parse("print a;")
+        .unwrap();
+
+    let both = Coverage::shared();
+    let parser = CoverageParser::new().with_coverage(both.clone());
+    parser.parse("a = 1;").unwrap();
+    parser.parse("print a;").unwrap();
+
+    let mut merged = first.borrow().clone();
+    merged.merge(&second.borrow());
+    assert_eq!(merged, *both.borrow());
+}
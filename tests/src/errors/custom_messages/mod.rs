@@ -0,0 +1,65 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::{local_file, output_cmp};
+
+rustemo_mod!(block, "/src/errors/custom_messages");
+rustemo_mod!(block_actions, "/src/errors/custom_messages");
+
+use self::block::BlockParser;
+
+#[test]
+fn custom_messages_display() {
+    let result = BlockParser::new().parse("{ a = 1; b 2; }");
+    output_cmp!(
+        local_file!(file!(), "block_display.err").to_str().unwrap(),
+        result.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn custom_messages_state_message() {
+    let result = BlockParser::new().parse("{ a = 1; b = ; }");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    // Structured data is not affected by custom messages.
+    assert_eq!(error.expected, ["Number"]);
+    output_cmp!(
+        local_file!(file!(), "block_message.err").to_str().unwrap(),
+        error.to_string()
+    );
+}
+
+#[test]
+fn custom_messages_merged_states() {
+    let result = BlockParser::new().parse("{ a = }");
+    output_cmp!(
+        local_file!(file!(), "block_message_first.err")
+            .to_str()
+            .unwrap(),
+        result.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn custom_messages_semicolon() {
+    let result = BlockParser::new().parse("{ a = 1 }");
+    output_cmp!(
+        local_file!(file!(), "block_semicolon.err")
+            .to_str()
+            .unwrap(),
+        result.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn custom_messages_missing() {
+    // `block.messages` beside the grammar is used. States without a message
+    // are reported.
+    let result = rustemo_compiler::Settings::new()
+        .warnings_as_errors(true)
+        .process_grammar(local_file!(file!(), "block.rustemo"));
+    output_cmp!(
+        "src/errors/custom_messages/block_missing.err",
+        result.unwrap_err().to_locfile_str()
+    );
+}
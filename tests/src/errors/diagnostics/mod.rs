@@ -0,0 +1,40 @@
+use rustemo_compiler::{local_file, output_cmp, Code, ParserAlgo, Settings};
+
+#[test]
+fn warnings_as_errors() {
+    let result = Settings::new()
+        .warnings_as_errors(true)
+        .process_grammar(local_file!(file!(), "warnings.rustemo"));
+    output_cmp!(
+        "src/errors/diagnostics/warnings.err",
+        result.unwrap_err().to_locfile_str()
+    );
+}
+
+#[test]
+fn allow_warnings() {
+    let result = Settings::new()
+        .warnings_as_errors(true)
+        .allow_warnings(vec![Code::UnusedTerminal, Code::DuplicateMatch])
+        .process_grammar(local_file!(file!(), "warnings.rustemo"));
+    output_cmp!(
+        "src/errors/diagnostics/warnings_allow.err",
+        result.unwrap_err().to_locfile_str()
+    );
+}
+
+#[test]
+fn lint() {
+    let warnings = Settings::new()
+        .parser_algo(ParserAlgo::GLR)
+        .check_grammar(local_file!(file!(), "lint.rustemo"))
+        .unwrap();
+    output_cmp!(
+        "src/errors/diagnostics/lint.err",
+        warnings
+            .iter()
+            .map(|w| w.to_locfile_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
@@ -16,6 +16,6 @@ fn infinite_recursion_2() {
         rustemo_compiler::process_grammar(local_file!(file!(), "infinite_recursion_2.rustemo"));
     output_cmp!(
         "src/errors/infinite_recursion/infinite_recursion_2.err",
-        result.unwrap_err().to_string()
+        result.unwrap_err().to_locfile_str()
     );
 }
@@ -0,0 +1,19 @@
+use rustemo_compiler::{local_file, output_cmp};
+
+#[test]
+fn undefined_mode() {
+    let result = rustemo_compiler::process_grammar(local_file!(file!(), "undefined_mode.rustemo"));
+    output_cmp!(
+        "src/errors/invalid_mode/undefined_mode.err",
+        result.unwrap_err().to_locfile_str()
+    )
+}
+
+#[test]
+fn duplicate_mode() {
+    let result = rustemo_compiler::process_grammar(local_file!(file!(), "duplicate_mode.rustemo"));
+    output_cmp!(
+        "src/errors/invalid_mode/duplicate_mode.err",
+        result.unwrap_err().to_locfile_str()
+    )
+}
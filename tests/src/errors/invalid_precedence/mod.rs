@@ -0,0 +1,21 @@
+use rustemo_compiler::{local_file, output_cmp};
+
+#[test]
+fn precedence_undeclared() {
+    let result =
+        rustemo_compiler::process_grammar(local_file!(file!(), "precedence_undeclared.rustemo"));
+    output_cmp!(
+        "src/errors/invalid_precedence/precedence_undeclared.err",
+        result.unwrap_err().to_locfile_str()
+    )
+}
+
+#[test]
+fn precedence_rule() {
+    let result =
+        rustemo_compiler::process_grammar(local_file!(file!(), "precedence_rule.rustemo"));
+    output_cmp!(
+        "src/errors/invalid_precedence/precedence_rule.err",
+        result.unwrap_err().to_locfile_str()
+    )
+}
@@ -1,4 +1,8 @@
+mod custom_messages;
+mod diagnostics;
 mod infinite_recursion;
+mod invalid_mode;
+mod invalid_precedence;
 mod recognizer_not_defined;
 mod syntax_errors;
 mod terminal_not_defined;
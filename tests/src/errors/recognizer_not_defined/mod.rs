@@ -6,7 +6,7 @@ fn recognizer_not_defined_for_default_lexer() {
         rustemo_compiler::process_grammar(local_file!(file!(), "recognizer_not_defined.rustemo"));
     output_cmp!(
         "src/errors/recognizer_not_defined/recognizer_not_defined.err",
-        result.unwrap_err().to_string()
+        result.unwrap_err().to_locfile_str()
     );
 }
 
@@ -3,7 +3,10 @@ use rustemo_compiler::{local_file, output_cmp};
 
 rustemo_mod!(calc, "/src/errors/syntax_errors");
 rustemo_mod!(calc_actions, "/src/errors/syntax_errors");
+rustemo_mod!(brackets, "/src/errors/syntax_errors");
+rustemo_mod!(brackets_actions, "/src/errors/syntax_errors");
 
+use self::brackets::BracketsParser;
 use self::calc::CalcParser;
 
 #[test]
@@ -27,3 +30,81 @@ fn syntax_error_incomplete() {
         result.unwrap_err().to_string()
     );
 }
+
+#[test]
+fn syntax_error_structured() {
+    let result = CalcParser::new().parse("2 + 3 / 4 + 5");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    assert_eq!(error.expected, ["STOP", "Plus"]);
+    assert_eq!(error.found.as_deref(), Some("/"));
+    output_cmp!(
+        local_file!(file!(), "calc_unexpected.json")
+            .to_str()
+            .unwrap(),
+        error.to_json()
+    );
+}
+
+#[test]
+fn syntax_error_labels() {
+    let result = CalcParser::new().parse("2 + 3 / 4 + 5");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    let error = error
+        .with_label((0, 5).into(), "valid expression")
+        .with_note("only addition is supported")
+        .with_help("replace `/` with `+`");
+    output_cmp!(
+        local_file!(file!(), "calc_labels.err").to_str().unwrap(),
+        error.to_string()
+    );
+}
+
+#[test]
+fn syntax_error_overlapping_labels() {
+    let result = CalcParser::new().parse("1 + 2 3");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    // The second label overlaps the primary one and is skipped.
+    let error = error
+        .with_label((0, 1).into(), "first operand")
+        .with_label((4, 7).into(), "operands");
+    output_cmp!(
+        local_file!(file!(), "calc_labels_overlap.err")
+            .to_str()
+            .unwrap(),
+        error.to_string()
+    );
+
+    // Labels which can't be rendered fall back to the plain message.
+    let error = error.with_label((40, 50).into(), "outside of the input");
+    assert!(error.to_string().contains("outside of the input"));
+}
+
+#[test]
+fn syntax_error_unclosed_bracket() {
+    let result = BracketsParser::new().parse("(1 + [2 + 3) + 4");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    assert_eq!(error.labels.len(), 1);
+    assert_eq!(error.labels[0].message, "opened here");
+    output_cmp!(
+        local_file!(file!(), "brackets_unclosed.err")
+            .to_str()
+            .unwrap(),
+        error.to_string()
+    );
+
+    // Closed brackets are not labelled.
+    let result = BracketsParser::new().parse("(1 + [2] 3)");
+    let Err(rustemo::Error::ParseError(error)) = result else {
+        panic!("Parse error expected!")
+    };
+    let span = error.labels[0].span;
+    assert_eq!((span.start.pos, span.end.pos), (0, 1));
+}
@@ -0,0 +1,92 @@
+grammar export;
+
+program
+    : (stmt (Semi stmt)* Semi?)?
+    ;
+
+stmt
+    : (assign | ifStmt | call)?
+    ;
+
+assign
+    : Ident '=' expr
+    ;
+
+ifStmt
+    : 'if' expr 'then' stmt ('else' stmt)?
+    ;
+
+call
+    : Ident '(' (expr (Comma expr)*)? ')' tuple?
+    ;
+
+tuple
+    : '(' expr Comma expr (Comma expr)? ')'
+    ;
+
+expr
+    : expr '+' expr
+    | expr '*' expr
+    | Ident+
+    | Number
+    ;
+
+Semi
+    : ';'
+    ;
+
+Comma
+    : ','
+    ;
+
+Ident
+    : [a-zA-Z_] [a-zA-Z0-9_]*
+    ;
+
+Number
+    : [0-9]+ ('.' [0-9]+)?
+    ;
+
+Hex
+    : '0' [xX] ([0-9a-fA-F] [0-9a-fA-F]? [0-9a-fA-F]? [0-9a-fA-F]?)
+    ;
+
+Str
+    : '"' (~["\\] | '\\' ~[\n])* '"'
+    ;
+
+// Word is recognized by the regex /\bword\b/ which can't be translated.
+
+If
+    : 'if'
+    ;
+
+Then
+    : 'then'
+    ;
+
+Else
+    : 'else'
+    ;
+
+Eq
+    : '='
+    ;
+
+Plus
+    : '+'
+    ;
+
+Mul
+    : '*'
+    ;
+
+OBracket
+    : '('
+    ;
+
+CBracket
+    : ')'
+    ;
+
+// Custom is recognized by a custom recognizer.
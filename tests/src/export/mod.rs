@@ -0,0 +1,43 @@
+use rustemo_compiler::{export::ExportGrammar, grammar::Grammar, local_file, output_cmp};
+
+fn grammar() -> ExportGrammar {
+    ExportGrammar::from_file(local_file!(file!(), "export.rustemo")).unwrap()
+}
+
+#[test]
+fn export_ebnf() {
+    output_cmp!("src/export/export.ebnf", grammar().to_ebnf());
+}
+
+#[test]
+fn export_w3c_ebnf() {
+    output_cmp!("src/export/export_w3c.ebnf", grammar().to_w3c_ebnf());
+}
+
+#[test]
+fn export_antlr() {
+    output_cmp!("src/export/export.g4", grammar().to_antlr("export"));
+}
+
+#[test]
+fn export_desugared() {
+    let grammar = Grammar::from_file(local_file!(file!(), "export.rustemo")).unwrap();
+    output_cmp!(
+        "src/export/export_desugared.ebnf",
+        ExportGrammar::from_grammar(&grammar).to_ebnf()
+    );
+}
+
+#[test]
+fn export_railroad() {
+    let diagrams = grammar().to_railroad();
+    assert_eq!(
+        diagrams
+            .iter()
+            .map(|(rule, _)| rule.as_str())
+            .collect::<Vec<_>>(),
+        ["Program", "Stmt", "Assign", "IfStmt", "Call", "Tuple", "Expr"]
+    );
+    let (_, svg) = diagrams.iter().find(|(rule, _)| rule == "Call").unwrap();
+    output_cmp!("src/export/Call.svg", svg);
+}
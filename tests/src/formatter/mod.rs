@@ -0,0 +1,55 @@
+use rustemo_compiler::{formatter, local_file, output_cmp};
+
+#[test]
+fn format_grammar() {
+    let formatted = formatter::format_file(local_file!(file!(), "unformatted.rustemo")).unwrap();
+    output_cmp!("src/formatter/formatted.rustemo", formatted);
+}
+
+#[test]
+fn format_grammar_idempotent() {
+    let formatted = formatter::format_file(local_file!(file!(), "formatted.rustemo")).unwrap();
+    assert_eq!(formatter::format_grammar(&formatted).unwrap(), formatted);
+}
+
+#[test]
+fn format_grammar_syntax_error() {
+    let result = formatter::format_grammar("S: A | ;");
+    output_cmp!(
+        "src/formatter/syntax_error.err",
+        result.unwrap_err().to_locfile_str()
+    );
+}
+
+#[test]
+fn format_grammar_inline_comments() {
+    let formatted =
+        formatter::format_file(local_file!(file!(), "inline_comments.rustemo")).unwrap();
+    assert_eq!(formatter::format_grammar(&formatted).unwrap(), formatted);
+    output_cmp!("src/formatter/inline_comments_formatted.rustemo", formatted);
+}
+
+#[test]
+fn format_grammar_keywords_as_names() {
+    // Names and user meta-data named as keywords are kept.
+    let grammar = "\
+S         : precedence | A {prec: 3} | B {precise: 2};
+precedence: A+;
+
+terminals
+
+A: 'a';
+B: 'b';
+";
+    assert_eq!(formatter::format_grammar(grammar).unwrap(), grammar);
+
+    let grammar = "\
+S: modeline A;
+
+terminals
+
+modeline: 'm' {pop: 2, popular: 1, push: 3};
+A       : 'a';
+";
+    assert_eq!(formatter::format_grammar(grammar).unwrap(), grammar);
+}
@@ -1,2 +1,4 @@
 mod basic;
 mod loc_info;
+mod typed_terminals;
+mod user_state;
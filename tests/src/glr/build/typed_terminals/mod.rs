@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::config::{ConfigParser, DefaultBuilder};
+
+rustemo_mod!(config, "/src/glr/build/typed_terminals");
+rustemo_mod!(config_actions, "/src/glr/build/typed_terminals");
+
+#[derive(Debug, Clone)]
+pub struct Percent(pub u8);
+
+impl FromStr for Percent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_end_matches('%').parse() {
+            Ok(value) if value <= 100 => Ok(Percent(value)),
+            _ => Err("percent must be in range 0-100".into()),
+        }
+    }
+}
+
+#[test]
+fn glr_typed_terminals() {
+    let forest = ConfigParser::new()
+        .parse(r#"a = 42; b = -1.5; c = true; d = "x\ty\"z"; e = 50%;"#)
+        .unwrap();
+    let result = forest
+        .get_first_tree()
+        .unwrap()
+        .try_build(&mut DefaultBuilder::new())
+        .unwrap();
+    output_cmp!(
+        // The same result is expected as for the LR parser.
+        "src/builder/typed_terminals/typed_terminals.ast",
+        format!("{result:#?}")
+    );
+    let percent = match &result.entries.unwrap()[4].value {
+        config_actions::Value::Percent(percent) => percent.0,
+        _ => panic!("Percent value expected!"),
+    };
+    assert_eq!(percent, 50);
+}
+
+#[test]
+fn glr_typed_terminals_error() {
+    let forest = ConfigParser::new().parse("a = 1; b = 150%;").unwrap();
+    let error = forest
+        .get_first_tree()
+        .unwrap()
+        .try_build(&mut DefaultBuilder::new())
+        .unwrap_err();
+    assert_eq!(
+        error.to_pos_str(),
+        "Error at [11(1,11)-15(1,15)]:\n\tInvalid value: percent must be in range 0-100."
+    );
+}
@@ -0,0 +1,47 @@
+use std::collections::BTreeSet;
+
+use rustemo::{rustemo_mod, GssHead, Parser};
+use rustemo_compiler::output_cmp;
+#[allow(dead_code)]
+#[rustfmt::skip]
+mod user_state_actions;
+
+rustemo_mod!(user_state, "/src/glr/build/user_state");
+
+use self::user_state::{DefaultBuilder, TokenKind, UserStateParser};
+use self::user_state_actions::Ctx;
+
+/// Type names declared so far.
+pub type TypeNames = BTreeSet<String>;
+
+/// Accepts `TypeName` only for builtin types and the types found in the user
+/// state of the current head.
+fn type_name_filter(ctx: &Ctx<'_>, token_kind: TokenKind, value: &str) -> bool {
+    match token_kind {
+        TokenKind::TypeName => value == "int" || ctx.user_state().contains(value),
+        _ => true,
+    }
+}
+
+#[test]
+fn glr_user_state() {
+    // Type names used by the token filter during parsing.
+    let mut context = GssHead::default().with_user_state(TypeNames::from(["my".into()]));
+    let forest = UserStateParser::new()
+        .parse_with_context(&mut context, "my a; typedef my t; b;")
+        .unwrap();
+    assert_eq!(forest.solutions(), 1);
+
+    // Semantic actions collect type names in the build context.
+    let mut context = GssHead::default();
+    let mut builder = DefaultBuilder::new();
+    let result = forest
+        .get_first_tree()
+        .unwrap()
+        .build_with_context(&mut context, &mut builder);
+    output_cmp!(
+        "src/glr/build/user_state/user_state.ast",
+        format!("{result:#?}")
+    );
+    assert_eq!(context.into_user_state(), TypeNames::from(["t".into()]));
+}
@@ -0,0 +1,20 @@
+use rustemo::{rustemo_mod, Coverage, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::coverage::{CoverageParser, COVERAGE_INFO};
+
+rustemo_mod!(coverage, "/src/glr/coverage");
+rustemo_mod!(coverage_actions, "/src/glr/coverage");
+
+#[test]
+fn glr_coverage_report() {
+    let coverage = Coverage::shared();
+    let parser = CoverageParser::new().with_coverage(coverage.clone());
+    for input in ["a = 1 + 2;", "b = a * 3; c = a + b * 2;"] {
+        parser.parse(input).unwrap();
+    }
+    output_cmp!(
+        "src/glr/coverage/coverage.report",
+        coverage.borrow().report(&COVERAGE_INFO)
+    );
+}
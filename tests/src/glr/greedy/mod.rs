@@ -0,0 +1,81 @@
+use rustemo::{rustemo_mod, Parser, TreeBuilder};
+use rustemo_compiler::output_cmp;
+
+rustemo_mod!(zero_or_more, "/src/glr/greedy");
+rustemo_mod!(zero_or_more_actions, "/src/glr/greedy");
+rustemo_mod!(zero_or_more_nongreedy, "/src/glr/greedy");
+rustemo_mod!(zero_or_more_nongreedy_actions, "/src/glr/greedy");
+rustemo_mod!(one_or_more, "/src/glr/greedy");
+rustemo_mod!(one_or_more_actions, "/src/glr/greedy");
+rustemo_mod!(items, "/src/glr/greedy");
+rustemo_mod!(items_actions, "/src/glr/greedy");
+rustemo_mod!(optional, "/src/glr/greedy");
+rustemo_mod!(optional_actions, "/src/glr/greedy");
+rustemo_mod!(unrelated, "/src/glr/greedy");
+rustemo_mod!(unrelated_actions, "/src/glr/greedy");
+
+use self::items::ItemsParser;
+use self::one_or_more::OneOrMoreParser;
+use self::optional::OptionalParser;
+use self::unrelated::UnrelatedParser;
+use self::zero_or_more::ZeroOrMoreParser;
+use self::zero_or_more_nongreedy::ZeroOrMoreNongreedyParser;
+
+// ANCHOR: greedy
+#[test]
+fn glr_greedy_zero_or_more() {
+    // Without greedy repetition there is a solution for each split of the
+    // input between the two repetitions.
+    let forest = ZeroOrMoreNongreedyParser::new().parse("a a a").unwrap();
+    assert_eq!(forest.solutions(), 4);
+
+    // Greedy repetition consumes the whole input.
+    let forest = ZeroOrMoreParser::new().parse("a a a").unwrap();
+    assert_eq!(forest.solutions(), 1);
+    let mut builder = TreeBuilder::new();
+    output_cmp!(
+        "src/glr/greedy/zero_or_more.ast",
+        format!(
+            "{:#?}",
+            forest.get_first_tree().unwrap().build::<TreeBuilder<
+                '_,
+                str,
+                zero_or_more::ProdKind,
+                zero_or_more::TokenKind,
+            >, zero_or_more::State>(&mut builder)
+        )
+    );
+}
+// ANCHOR_END: greedy
+
+#[test]
+fn glr_greedy_one_or_more() {
+    // Greedy repetition leaves nothing to the second one so the parse fails.
+    assert!(OneOrMoreParser::new().parse("a a a").is_err());
+}
+
+#[test]
+fn glr_greedy_optional() {
+    for input in ["", "a", "a a"] {
+        let forest = OptionalParser::new().parse(input).unwrap();
+        assert_eq!(forest.solutions(), 1);
+    }
+}
+
+#[test]
+fn glr_greedy_items() {
+    // The last name is consumed by the repetition instead of the tail.
+    let forest = ItemsParser::new().parse("1 a 2 b").unwrap();
+    assert_eq!(forest.solutions(), 1);
+    let tree = forest.get_first_tree().unwrap();
+    assert_eq!(tree.children()[1].children().len(), 0);
+}
+
+#[test]
+fn glr_greedy_unrelated_reductions() {
+    // Reductions not stopping the repetition are kept.
+    for input in ["p a c", "p a a b", "p b"] {
+        let forest = UnrelatedParser::new().parse(input).unwrap();
+        assert_eq!(forest.solutions(), 1);
+    }
+}
@@ -0,0 +1,24 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::indentation::IndentationParser;
+
+rustemo_mod!(indentation, "/src/glr/indentation");
+rustemo_mod!(indentation_actions, "/src/glr/indentation");
+
+#[test]
+fn glr_indentation() {
+    let forest = IndentationParser::new()
+        .parse("a\nb:\n    c\n    d:\n        e\ng:\n  h")
+        .unwrap();
+    assert_eq!(forest.solutions(), 1);
+
+    let mut builder = indentation::DefaultBuilder::new();
+    output_cmp!(
+        "src/glr/indentation/indentation.ast",
+        format!(
+            "{:#?}",
+            forest.get_first_tree().unwrap().build(&mut builder)
+        )
+    );
+}
@@ -0,0 +1,24 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::lexical_modes::LexicalModesParser;
+
+rustemo_mod!(lexical_modes, "/src/glr/lexical_modes");
+rustemo_mod!(lexical_modes_actions, "/src/glr/lexical_modes");
+
+#[test]
+fn glr_lexical_modes() {
+    let forest = LexicalModesParser::new()
+        .parse(r#""Hi ${name}, ${ 1 + "n ${x}" }!  " + 2"#)
+        .unwrap();
+    assert_eq!(forest.solutions(), 1);
+
+    let mut builder = lexical_modes::DefaultBuilder::new();
+    output_cmp!(
+        "src/glr/lexical_modes/lexical_modes.ast",
+        format!(
+            "{:#?}",
+            forest.get_first_tree().unwrap().build(&mut builder)
+        )
+    );
+}
@@ -1,7 +1,11 @@
 mod build;
+mod coverage;
 mod errors;
 mod evaluate;
 mod forest;
+mod greedy;
+mod indentation;
 mod lexical_ambiguity;
+mod lexical_modes;
 mod regressions;
 mod special;
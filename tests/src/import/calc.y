@@ -0,0 +1,40 @@
+/* Infix notation calculator.  */
+%{
+  #include <math.h>
+  int yylex (void);
+%}
+
+%define api.value.type {double}
+%token NUM
+%token LE "<="
+%left LE
+%left '-' '+'
+%left '*' '/'
+%precedence NEG   /* negation--unary minus */
+%right '^'        /* exponentiation */
+
+%% /* The grammar follows.  */
+input:
+  %empty
+| input line
+;
+
+line:
+  '\n'
+| exp '\n'  { printf ("\t%.10g\n", $1); }
+| error '\n' { yyerrok; }
+;
+
+exp:
+  NUM
+| exp '+' exp        { $$ = $1 + $3;      }
+| exp '-' exp        { $$ = $1 - $3;      }
+| exp '*' exp        { $$ = $1 * $3;      }
+| exp '/' exp        { $$ = $1 / $3;      }
+| '-' exp  %prec NEG { $$ = -$2;          }
+| exp '^' exp        { $$ = pow ($1, $3); }
+| exp "<=" exp
+| '(' exp ')'        { $$ = $2;           }
+;
+%%
+int main() { return yyparse(); }
@@ -0,0 +1,46 @@
+use rustemo_compiler::{
+    import::{self, ImportFormat},
+    local_file, output_cmp, LexerType, Settings,
+};
+
+#[test]
+fn import_bison() {
+    let grammar = import::import_file(local_file!(file!(), "calc.y"), None).unwrap();
+    output_cmp!("src/import/calc_bison.rustemo", grammar);
+    // Bison tokens are provided by an external lexer.
+    let warnings = Settings::new()
+        .lexer_type(LexerType::Custom)
+        .check_grammar(local_file!(file!(), "calc_bison.rustemo"))
+        .unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn import_bison_nonassoc() {
+    let grammar = import::import_grammar(
+        "%token NUM\n%nonassoc '<'\n%%\nexp: NUM | exp '<' exp;",
+        ImportFormat::BISON,
+    )
+    .unwrap();
+    assert!(grammar.starts_with("// TODO: `%nonassoc '<'` is imported as a priority only."));
+}
+
+#[test]
+fn import_lalrpop() {
+    let grammar = import::import_file(local_file!(file!(), "calc.lalrpop"), None).unwrap();
+    output_cmp!("src/import/calc_lalrpop.rustemo", grammar);
+    let warnings = Settings::new()
+        .check_grammar(local_file!(file!(), "calc_lalrpop.rustemo"))
+        .unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn import_bison_syntax_error() {
+    let result =
+        import::import_grammar("%token NUM\n%%\nexp: NUM | ;\n: NUM;", ImportFormat::BISON);
+    output_cmp!(
+        "src/import/syntax_error.err",
+        result.unwrap_err().to_locfile_str()
+    );
+}
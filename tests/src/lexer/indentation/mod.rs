@@ -0,0 +1,47 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::indentation::IndentationParser;
+
+rustemo_mod!(indentation, "/src/lexer/indentation");
+rustemo_mod!(indentation_actions, "/src/lexer/indentation");
+
+#[test]
+fn indentation() {
+    let result = IndentationParser::new().parse(
+        r#"
+a
+b:
+    c
+    d:
+        e
+
+        f
+g:
+  h
+"#,
+    );
+    output_cmp!(
+        "src/lexer/indentation/indentation.ast",
+        format!("{:#?}", result)
+    );
+}
+
+#[test]
+fn indentation_error() {
+    let result = IndentationParser::new().parse("a:\nb\n");
+    output_cmp!(
+        "src/lexer/indentation/indentation.err",
+        format!("{:?}", result)
+    );
+}
+
+#[test]
+fn indentation_inconsistent_dedent() {
+    // `c` is dedented to a column which matches no enclosing indentation level.
+    let result = IndentationParser::new().parse("a:\n    b\n  c\n");
+    output_cmp!(
+        "src/lexer/indentation/indentation_dedent.err",
+        format!("{:?}", result)
+    );
+}
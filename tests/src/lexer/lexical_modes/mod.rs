@@ -0,0 +1,25 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::lexical_modes::LexicalModesParser;
+
+rustemo_mod!(lexical_modes, "/src/lexer/lexical_modes");
+rustemo_mod!(lexical_modes_actions, "/src/lexer/lexical_modes");
+
+#[test]
+fn lexical_modes() {
+    let result = LexicalModesParser::new().parse(r#""Hi ${name}, ${ 1 + "n ${x}" }!  " + 2"#);
+    output_cmp!(
+        "src/lexer/lexical_modes/lexical_modes.ast",
+        format!("{:#?}", result)
+    );
+}
+
+#[test]
+fn lexical_modes_error() {
+    let result = LexicalModesParser::new().parse(r#"1 + "a ${b"#);
+    output_cmp!(
+        "src/lexer/lexical_modes/lexical_modes.err",
+        format!("{:?}", result)
+    );
+}
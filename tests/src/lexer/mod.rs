@@ -1 +1,4 @@
 mod custom_lexer;
+mod indentation;
+mod lexical_modes;
+mod token_filter;
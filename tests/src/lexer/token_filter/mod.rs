@@ -0,0 +1,27 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::token_filter::{TokenFilterParser, TokenKind};
+use self::token_filter_actions::Ctx;
+
+rustemo_mod!(token_filter, "/src/lexer/token_filter");
+rustemo_mod!(token_filter_actions, "/src/lexer/token_filter");
+
+// ANCHOR: token-filter
+/// Accepts `TypeName` only for builtin types and names ending with `_t`.
+fn type_name_filter(_ctx: &Ctx<'_>, token_kind: TokenKind, value: &str) -> bool {
+    match token_kind {
+        TokenKind::TypeName => ["int", "float"].contains(&value) || value.ends_with("_t"),
+        _ => true,
+    }
+}
+// ANCHOR_END: token-filter
+
+#[test]
+fn token_filter() {
+    let result = TokenFilterParser::new().parse("int a; b; my_t c; d;");
+    output_cmp!(
+        "src/lexer/token_filter/token_filter.ast",
+        format!("{:#?}", result)
+    );
+}
@@ -2,15 +2,21 @@
 #![cfg(test)]
 mod ambiguity;
 mod builder;
+mod coverage;
 mod errors;
+mod export;
 mod fancy_regex;
+mod formatter;
 mod from_file;
+mod import;
 mod layout;
 mod lexer;
 mod lexical_ambiguity;
+mod macros;
 mod output_dir;
 mod partial;
 mod rule_patterns;
+mod sentences;
 mod special;
 mod sugar;
 mod unicode;
@@ -0,0 +1,52 @@
+use rustemo::Parser;
+use rustemo_compiler::{local_file, output_cmp};
+use rustemo_macros::grammar;
+
+// ANCHOR: grammar-macro
+grammar!(
+    calc,
+    r#"
+    E: E '+' E {Add, left, 1}
+     | E '*' E {Mul, left, 2}
+     | '(' E ')' {Paren}
+     | Number;
+
+    terminals
+    Plus: '+';
+    Mul: '*';
+    LParen: '(';
+    RParen: ')';
+    Number: /\d+/;
+    "#
+);
+// ANCHOR_END: grammar-macro
+
+grammar!(
+    /// Parser module documentation.
+    pub(crate) list,
+    r#"
+    List: Item+[Comma];
+    Item: Name;
+
+    terminals
+    Comma: ',';
+    Name: /\w+/;
+    "#
+);
+
+#[test]
+fn grammar_macro() {
+    let result = calc::CalcParser::new().parse("1 + 2 * (3 + 4)");
+    output_cmp!(
+        local_file!(file!(), "calc.ast").to_str().unwrap(),
+        format!("{result:#?}")
+    );
+}
+
+#[test]
+fn grammar_macro_actions() {
+    let result: list_actions::List = list::ListParser::new().parse("a, b, c").unwrap();
+    let names: Vec<&str> = result.iter().map(|name| name.as_str()).collect();
+    assert_eq!(names, ["a", "b", "c"]);
+    assert_eq!(result[1].span.unwrap().start.pos, 3);
+}
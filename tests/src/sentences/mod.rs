@@ -0,0 +1,59 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::{
+    grammar::Grammar,
+    local_file,
+    sentences::{Entropy, SentenceGenerator},
+};
+
+use self::sentences::SentencesParser;
+
+rustemo_mod!(sentences, "/src/sentences");
+rustemo_mod!(sentences_actions, "/src/sentences");
+
+fn grammar() -> Grammar {
+    Grammar::from_file(local_file!(file!(), "sentences.rustemo")).unwrap()
+}
+
+// ANCHOR: sentences
+#[test]
+fn sentences_parse() {
+    let grammar = grammar();
+    let mut generator = SentenceGenerator::new(&grammar).unwrap().max_depth(10);
+    let mut entropy = Entropy::seed(42);
+    for _ in 0..200 {
+        let sentence = generator.generate(&mut entropy).unwrap();
+        assert!(
+            SentencesParser::new().parse(&sentence).is_ok(),
+            "{sentence}"
+        );
+    }
+    assert!(generator.unused_productions().is_empty());
+}
+// ANCHOR_END: sentences
+
+#[test]
+fn sentences_parse_bytes() {
+    let grammar = grammar();
+    let mut generator = SentenceGenerator::new(&grammar).unwrap();
+    for data in [&[][..], &[1, 2, 3], &[255; 64], b"fuzzer input bytes"] {
+        let sentence = generator.generate(&mut Entropy::bytes(data)).unwrap();
+        assert!(
+            SentencesParser::new().parse(&sentence).is_ok(),
+            "{sentence}"
+        );
+    }
+}
+
+#[test]
+fn sentences_near_miss() {
+    let grammar = grammar();
+    let mut generator = SentenceGenerator::new(&grammar).unwrap().near_miss(true);
+    let mut entropy = Entropy::seed(42);
+    let invalid = (0..100)
+        .filter(|_| {
+            let sentence = generator.generate(&mut entropy).unwrap();
+            SentencesParser::new().parse(&sentence).is_err()
+        })
+        .count();
+    assert!(invalid > 50, "{invalid}");
+}
@@ -0,0 +1,22 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::group::GroupParser;
+
+rustemo_mod!(group, "/src/sugar/group");
+rustemo_mod!(group_actions, "/src/sugar/group");
+
+#[test]
+fn group_1() {
+    let result = GroupParser::new().parse("f(a = 1, b = c, 3); g([1, 2, 3]) h([]).");
+    output_cmp!("src/sugar/group/group_1.ast", format!("{result:#?}"));
+}
+
+#[test]
+fn group_1_error() {
+    let result = GroupParser::new().parse("f(a = 1,)");
+    output_cmp!(
+        "src/sugar/group/group_1.err",
+        result.unwrap_err().to_string()
+    );
+}
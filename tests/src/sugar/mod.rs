@@ -1,3 +1,5 @@
+mod group;
+mod modifiers;
 mod one_or_more;
 mod optional;
 mod zero_or_more;
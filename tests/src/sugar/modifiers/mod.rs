@@ -0,0 +1,28 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::modifiers::ModifiersParser;
+
+rustemo_mod!(modifiers, "/src/sugar/modifiers");
+rustemo_mod!(modifiers_actions, "/src/sugar/modifiers");
+
+// ANCHOR: modifiers
+#[test]
+fn modifiers_1() {
+    let result = ModifiersParser::new()
+        .parse("list 1, 2, 3,; list; pair 1, 2; few 1 2 3; many 1, 2, 3, 4; right 1, 2, 3,;");
+    output_cmp!(
+        "src/sugar/modifiers/modifiers_1.ast",
+        format!("{result:#?}")
+    );
+}
+
+#[test]
+fn modifiers_1_error() {
+    let result = ModifiersParser::new().parse("few 1 2 3 4;");
+    output_cmp!(
+        "src/sugar/modifiers/modifiers_1.err",
+        result.unwrap_err().to_string()
+    );
+}
+// ANCHOR_END: modifiers